@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{contracterror, panic_with_error, Env};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    Initialized = 101,
+    NotInitialized = 102,
+    Unauthorized = 103,
+    EmptyCourseId = 104,
+    RecipientHasNoAccess = 105,
+    CertificateAlreadyIssued = 106,
+    CertificateNotFound = 107,
+    CertificateAlreadyRevoked = 108,
+}
+
+pub fn handle_error(env: &Env, error: Error) -> ! {
+    panic_with_error!(env, error);
+}
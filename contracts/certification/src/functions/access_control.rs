@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{contractclient, Address, Env, String};
+
+use crate::error::{handle_error, Error};
+use crate::functions::config::get_contract_addr;
+use crate::schema::{KEY_COURSE_ACCESS_ADDR, KEY_COURSE_REG_ADDR, KEY_USER_MGMT_ADDR};
+
+/// Subset of the user management contract interface used for admin checks.
+#[allow(dead_code)]
+#[contractclient(name = "UserManagementClient")]
+pub trait UserManagementInterface {
+    fn is_admin(env: Env, who: Address) -> bool;
+}
+
+/// Subset of the course registry contract interface used for ownership checks.
+#[allow(dead_code)]
+#[contractclient(name = "CourseRegistryClient")]
+pub trait CourseRegistryInterface {
+    fn is_course_creator(env: Env, course_id: String, user: Address) -> bool;
+}
+
+/// Subset of the course access contract interface used for enrollment checks.
+#[allow(dead_code)]
+#[contractclient(name = "CourseAccessClient")]
+pub trait CourseAccessInterface {
+    fn check_access(env: Env, course_id: String, user: Address) -> bool;
+}
+
+/// Checks admin status through the configured user management contract.
+pub fn is_admin(env: &Env, who: &Address) -> bool {
+    UserManagementClient::new(env, &get_contract_addr(env, KEY_USER_MGMT_ADDR)).is_admin(who)
+}
+
+/// Checks through the configured course registry whether `user` created a course.
+pub fn is_course_creator(env: &Env, course_id: &String, user: &Address) -> bool {
+    CourseRegistryClient::new(env, &get_contract_addr(env, KEY_COURSE_REG_ADDR))
+        .is_course_creator(course_id, user)
+}
+
+/// Checks through the configured course access contract whether `user` can access a course.
+pub fn has_course_access(env: &Env, course_id: &String, user: &Address) -> bool {
+    CourseAccessClient::new(env, &get_contract_addr(env, KEY_COURSE_ACCESS_ADDR))
+        .check_access(course_id, user)
+}
+
+/// Requires the caller to be authenticated and either an admin or the course creator.
+pub fn require_course_manager(env: &Env, caller: &Address, course_id: &String) {
+    caller.require_auth();
+
+    if !(is_admin(env, caller) || is_course_creator(env, course_id, caller)) {
+        handle_error(env, Error::Unauthorized)
+    }
+}
@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::storage::Instance;
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::schema::{KEY_COURSE_ACCESS_ADDR, KEY_COURSE_REG_ADDR, KEY_OWNER, KEY_USER_MGMT_ADDR};
+
+const INIT_EVENT: Symbol = symbol_short!("initialz");
+
+/// Initializes the contract with its owner and the addresses of the contracts
+/// it consults when issuing certificates.
+///
+/// # Panics
+///
+/// * `Error::Initialized` if the contract has already been initialized.
+pub fn initialize(
+    env: Env,
+    caller: Address,
+    user_mgmt_addr: Address,
+    course_registry_addr: Address,
+    course_access_addr: Address,
+) {
+    caller.require_auth();
+
+    let inst: Instance = env.storage().instance();
+    if inst.has(&(KEY_OWNER,)) {
+        handle_error(&env, Error::Initialized)
+    }
+
+    inst.set(&(KEY_OWNER,), &caller);
+    inst.set(&(KEY_USER_MGMT_ADDR,), &user_mgmt_addr);
+    inst.set(&(KEY_COURSE_REG_ADDR,), &course_registry_addr);
+    inst.set(&(KEY_COURSE_ACCESS_ADDR,), &course_access_addr);
+
+    env.events().publish(
+        (INIT_EVENT,),
+        (caller, user_mgmt_addr, course_registry_addr, course_access_addr),
+    );
+}
+
+/// Returns a configured contract address, panicking if the contract is not initialized.
+pub fn get_contract_addr(env: &Env, key: &str) -> Address {
+    env.storage()
+        .instance()
+        .get(&(key,))
+        .unwrap_or_else(|| handle_error(env, Error::NotInitialized))
+}
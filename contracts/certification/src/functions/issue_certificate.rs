@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{has_course_access, require_course_manager};
+use crate::schema::{Certificate, DataKey};
+
+const CERTIFICATE_ISSUED_EVENT: &str = "CertificateIssued";
+
+const CERTIFICATE_ID_PREFIX: &[u8] = b"cert_";
+
+/// Issues a certificate for a course to a recipient (course creator or admin).
+///
+/// # Panics
+///
+/// * `Error::EmptyCourseId` if `course_id` is empty.
+/// * `Error::Unauthorized` if the issuer is neither the course creator nor an admin.
+/// * `Error::RecipientHasNoAccess` if the recipient has no access to the course.
+/// * `Error::CertificateAlreadyIssued` if the recipient already holds an
+///   unrevoked certificate for the course.
+pub fn certification_issue_certificate(
+    env: Env,
+    issuer: Address,
+    course_id: String,
+    recipient: Address,
+) -> Certificate {
+    if course_id.is_empty() {
+        handle_error(&env, Error::EmptyCourseId)
+    }
+    require_course_manager(&env, &issuer, &course_id);
+
+    let active_key: DataKey = DataKey::UserCourseCertificate(recipient.clone(), course_id.clone());
+    if env.storage().persistent().has(&active_key) {
+        handle_error(&env, Error::CertificateAlreadyIssued)
+    }
+    if !has_course_access(&env, &course_id, &recipient) {
+        handle_error(&env, Error::RecipientHasNoAccess)
+    }
+
+    let certificate: Certificate = Certificate {
+        id: next_certificate_id(&env),
+        course_id: course_id.clone(),
+        recipient: recipient.clone(),
+        issuer: issuer.clone(),
+        issued_at: env.ledger().timestamp(),
+        revoked: false,
+    };
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Certificate(certificate.id.clone()), &certificate);
+    env.storage().persistent().set(&active_key, &certificate.id);
+
+    let user_key: DataKey = DataKey::UserCertificates(recipient.clone());
+    let mut ids: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&user_key)
+        .unwrap_or(Vec::new(&env));
+    ids.push_back(certificate.id.clone());
+    env.storage().persistent().set(&user_key, &ids);
+
+    env.events().publish(
+        (Symbol::new(&env, CERTIFICATE_ISSUED_EVENT), certificate.id.clone()),
+        (course_id, recipient, issuer),
+    );
+
+    certificate
+}
+
+/// Generates the next certificate ID, `cert_<n>`.
+fn next_certificate_id(env: &Env) -> String {
+    let seq: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CertificateSeq)
+        .unwrap_or(0)
+        + 1;
+    env.storage().persistent().set(&DataKey::CertificateSeq, &seq);
+
+    let mut digits: [u8; 10] = [0; 10];
+    let mut len: usize = 0;
+    let mut value: u32 = seq;
+    loop {
+        digits[len] = b'0' + (value % 10) as u8;
+        len += 1;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+
+    let mut buf: [u8; 15] = [0; 15];
+    buf[..CERTIFICATE_ID_PREFIX.len()].copy_from_slice(CERTIFICATE_ID_PREFIX);
+    for index in 0..len {
+        buf[CERTIFICATE_ID_PREFIX.len() + index] = digits[len - 1 - index];
+    }
+    String::from_bytes(env, &buf[..CERTIFICATE_ID_PREFIX.len() + len])
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use soroban_sdk::{testutils::Address as _, Address, String};
+
+    #[test]
+    fn test_issue_certificate() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let recipient = Address::generate(&env);
+        mocks.course_access.grant(&course_id, &recipient);
+
+        let certificate = client.issue_certificate(&mocks.admin, &course_id, &recipient);
+        assert_eq!(certificate.id, String::from_str(&env, "cert_1"));
+        assert_eq!(certificate.recipient, recipient);
+        assert_eq!(certificate.issuer, mocks.admin);
+        assert!(!certificate.revoked);
+
+        let other = Address::generate(&env);
+        mocks.course_access.grant(&course_id, &other);
+        let second = client.issue_certificate(&mocks.admin, &course_id, &other);
+        assert_eq!(second.id, String::from_str(&env, "cert_2"));
+    }
+
+    #[test]
+    fn test_course_creator_issues_certificate() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let creator = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        mocks.course_registry.set_creator(&course_id, &creator);
+        mocks.course_access.grant(&course_id, &recipient);
+
+        let certificate = client.issue_certificate(&creator, &course_id, &recipient);
+        assert_eq!(certificate.issuer, creator);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_issue_duplicate_certificate() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let recipient = Address::generate(&env);
+        mocks.course_access.grant(&course_id, &recipient);
+
+        client.issue_certificate(&mocks.admin, &course_id, &recipient);
+        client.issue_certificate(&mocks.admin, &course_id, &recipient);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #105)")]
+    fn test_issue_certificate_without_access() {
+        let (env, client, mocks) = setup_with_mocks();
+
+        client.issue_certificate(
+            &mocks.admin,
+            &String::from_str(&env, "course_1"),
+            &Address::generate(&env),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #103)")]
+    fn test_issue_certificate_unauthorized() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let recipient = Address::generate(&env);
+        mocks.course_access.grant(&course_id, &recipient);
+
+        client.issue_certificate(&Address::generate(&env), &course_id, &recipient);
+    }
+}
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::schema::{Certificate, DataKey};
+
+/// Returns every certificate issued to a user, including revoked ones, oldest first.
+pub fn certification_list_user_certificates(env: Env, user: Address) -> Vec<Certificate> {
+    let ids: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::UserCertificates(user))
+        .unwrap_or(Vec::new(&env));
+
+    let mut certificates: Vec<Certificate> = Vec::new(&env);
+    for id in ids.iter() {
+        if let Some(certificate) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Certificate>(&DataKey::Certificate(id))
+        {
+            certificates.push_back(certificate);
+        }
+    }
+    certificates
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use soroban_sdk::{testutils::Address as _, Address, String};
+
+    #[test]
+    fn test_list_user_certificates() {
+        let (env, client, mocks) = setup_with_mocks();
+        let recipient = Address::generate(&env);
+        assert!(client.list_user_certificates(&recipient).is_empty());
+
+        for course in ["course_1", "course_2"] {
+            let course_id = String::from_str(&env, course);
+            mocks.course_access.grant(&course_id, &recipient);
+            client.issue_certificate(&mocks.admin, &course_id, &recipient);
+        }
+
+        let certificates = client.list_user_certificates(&recipient);
+        assert_eq!(certificates.len(), 2);
+        assert_eq!(certificates.get(0).unwrap().course_id, String::from_str(&env, "course_1"));
+        assert_eq!(certificates.get(1).unwrap().course_id, String::from_str(&env, "course_2"));
+    }
+}
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+pub mod access_control;
+pub mod config;
+pub mod issue_certificate;
+pub mod list_user_certificates;
+pub mod revoke_certificate;
+pub mod verify_certificate;
+
+#[cfg(test)]
+pub mod test_utils {
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    use crate::{Certification, CertificationClient};
+
+    /// User management mock whose admin set is configured per test.
+    pub mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn set_admin(env: Env, who: Address) {
+                env.storage().instance().set(&who, &true);
+            }
+
+            pub fn is_admin(env: Env, who: Address) -> bool {
+                env.storage().instance().get(&who).unwrap_or(false)
+            }
+        }
+    }
+
+    /// Course registry mock whose course creators are configured per test.
+    pub mod mock_course_registry {
+        use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+        #[contract]
+        pub struct CourseRegistry;
+
+        #[contractimpl]
+        impl CourseRegistry {
+            pub fn set_creator(env: Env, course_id: String, creator: Address) {
+                env.storage().instance().set(&course_id, &creator);
+            }
+
+            pub fn is_course_creator(env: Env, course_id: String, user: Address) -> bool {
+                env.storage().instance().get::<_, Address>(&course_id) == Some(user)
+            }
+        }
+    }
+
+    /// Course access mock whose enrollments are configured per test.
+    pub mod mock_course_access {
+        use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+        #[contract]
+        pub struct CourseAccess;
+
+        #[contractimpl]
+        impl CourseAccess {
+            pub fn grant(env: Env, course_id: String, user: Address) {
+                env.storage().instance().set(&(course_id, user), &true);
+            }
+
+            pub fn check_access(env: Env, course_id: String, user: Address) -> bool {
+                env.storage().instance().has(&(course_id, user))
+            }
+        }
+    }
+
+    pub struct TestMocks {
+        pub admin: Address,
+        pub course_registry: mock_course_registry::CourseRegistryClient<'static>,
+        pub course_access: mock_course_access::CourseAccessClient<'static>,
+    }
+
+    /// Registers the contract with configurable dependency mocks and one admin.
+    pub fn setup_with_mocks() -> (Env, CertificationClient<'static>, TestMocks) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+        let course_registry_id = env.register(mock_course_registry::CourseRegistry, ());
+        let course_access_id = env.register(mock_course_access::CourseAccess, ());
+        let contract_id = env.register(Certification, ());
+        let client = CertificationClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        mock_user_management::UserManagementClient::new(&env, &user_mgmt_id).set_admin(&admin);
+        client.initialize(&admin, &user_mgmt_id, &course_registry_id, &course_access_id);
+
+        let mocks = TestMocks {
+            admin,
+            course_registry: mock_course_registry::CourseRegistryClient::new(&env, &course_registry_id),
+            course_access: mock_course_access::CourseAccessClient::new(&env, &course_access_id),
+        };
+        (env, client, mocks)
+    }
+}
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::is_admin;
+use crate::schema::{Certificate, DataKey};
+
+const CERTIFICATE_REVOKED_EVENT: &str = "CertificateRevoked";
+
+/// Revokes a certificate (its issuer or an admin).
+///
+/// The recipient may be issued a new certificate for the course afterwards.
+///
+/// # Panics
+///
+/// * `Error::CertificateNotFound` if the certificate does not exist.
+/// * `Error::Unauthorized` if the caller is neither the issuer nor an admin.
+/// * `Error::CertificateAlreadyRevoked` if the certificate was already revoked.
+pub fn certification_revoke_certificate(env: Env, issuer: Address, certificate_id: String) {
+    issuer.require_auth();
+
+    let key: DataKey = DataKey::Certificate(certificate_id.clone());
+    let mut certificate: Certificate = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| handle_error(&env, Error::CertificateNotFound));
+
+    if certificate.issuer != issuer && !is_admin(&env, &issuer) {
+        handle_error(&env, Error::Unauthorized)
+    }
+    if certificate.revoked {
+        handle_error(&env, Error::CertificateAlreadyRevoked)
+    }
+
+    certificate.revoked = true;
+    env.storage().persistent().set(&key, &certificate);
+    env.storage().persistent().remove(&DataKey::UserCourseCertificate(
+        certificate.recipient.clone(),
+        certificate.course_id.clone(),
+    ));
+
+    env.events().publish(
+        (Symbol::new(&env, CERTIFICATE_REVOKED_EVENT), certificate_id),
+        (certificate.course_id, certificate.recipient, issuer),
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use soroban_sdk::{testutils::Address as _, Address, String};
+
+    #[test]
+    fn test_revoke_certificate() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let recipient = Address::generate(&env);
+        mocks.course_access.grant(&course_id, &recipient);
+        let certificate = client.issue_certificate(&mocks.admin, &course_id, &recipient);
+
+        client.revoke_certificate(&mocks.admin, &certificate.id);
+        assert!(!client.verify_certificate(&certificate.id));
+        assert!(client.list_user_certificates(&recipient).get(0).unwrap().revoked);
+
+        // A revoked certificate no longer blocks a new one
+        let reissued = client.issue_certificate(&mocks.admin, &course_id, &recipient);
+        assert!(client.verify_certificate(&reissued.id));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #108)")]
+    fn test_revoke_certificate_twice() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let recipient = Address::generate(&env);
+        mocks.course_access.grant(&course_id, &recipient);
+        let certificate = client.issue_certificate(&mocks.admin, &course_id, &recipient);
+
+        client.revoke_certificate(&mocks.admin, &certificate.id);
+        client.revoke_certificate(&mocks.admin, &certificate.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #103)")]
+    fn test_revoke_certificate_unauthorized() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let recipient = Address::generate(&env);
+        mocks.course_access.grant(&course_id, &recipient);
+        let certificate = client.issue_certificate(&mocks.admin, &course_id, &recipient);
+
+        client.revoke_certificate(&Address::generate(&env), &certificate.id);
+    }
+}
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Env, String};
+
+use crate::schema::{Certificate, DataKey};
+
+/// Returns whether a certificate exists and has not been revoked.
+pub fn certification_verify_certificate(env: Env, certificate_id: String) -> bool {
+    env.storage()
+        .persistent()
+        .get::<DataKey, Certificate>(&DataKey::Certificate(certificate_id))
+        .is_some_and(|certificate| !certificate.revoked)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use soroban_sdk::{testutils::Address as _, Address, String};
+
+    #[test]
+    fn test_verify_certificate() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let recipient = Address::generate(&env);
+        mocks.course_access.grant(&course_id, &recipient);
+        let certificate = client.issue_certificate(&mocks.admin, &course_id, &recipient);
+
+        assert!(client.verify_certificate(&certificate.id));
+        assert!(!client.verify_certificate(&String::from_str(&env, "cert_99")));
+    }
+}
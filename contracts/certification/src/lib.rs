@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+#![no_std]
+
+/// Contract version for tracking deployments and upgrades
+pub const VERSION: &str = "1.0.0";
+
+mod error;
+mod functions;
+mod schema;
+
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+
+use schema::Certificate;
+
+/// Certification Contract
+///
+/// This contract issues course completion certificates on-chain and lets
+/// anyone verify them. Certificates can only be issued to users who have
+/// access to the course in the Course Access contract.
+#[contract]
+pub struct Certification;
+
+#[contractimpl]
+impl Certification {
+    /// One-time constructor to set the owner and dependent contract addresses.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the contract deployer/owner
+    /// * `user_mgmt_addr` - Address of the user management contract
+    /// * `course_registry_addr` - Address of the course registry contract
+    /// * `course_access_addr` - Address of the course access contract
+    ///
+    /// # Panics
+    ///
+    /// * If the contract has already been initialized
+    pub fn initialize(
+        env: Env,
+        caller: Address,
+        user_mgmt_addr: Address,
+        course_registry_addr: Address,
+        course_access_addr: Address,
+    ) {
+        functions::config::initialize(
+            env,
+            caller,
+            user_mgmt_addr,
+            course_registry_addr,
+            course_access_addr,
+        )
+    }
+
+    /// Issue a course certificate to a user.
+    ///
+    /// Only the course creator or an admin can issue certificates, and only
+    /// to users who have access to the course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `issuer` - The address of the course creator or admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `recipient` - The address of the user receiving the certificate
+    ///
+    /// # Returns
+    ///
+    /// Returns the issued `Certificate`.
+    ///
+    /// # Panics
+    ///
+    /// * If the course ID is empty
+    /// * If the issuer is neither the course creator nor an admin
+    /// * If the recipient has no access to the course
+    /// * If the recipient already holds an unrevoked certificate for the course
+    pub fn issue_certificate(
+        env: Env,
+        issuer: Address,
+        course_id: String,
+        recipient: Address,
+    ) -> Certificate {
+        functions::issue_certificate::certification_issue_certificate(
+            env, issuer, course_id, recipient,
+        )
+    }
+
+    /// Check whether a certificate is valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `certificate_id` - The unique identifier of the certificate
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the certificate exists and has not been revoked.
+    pub fn verify_certificate(env: Env, certificate_id: String) -> bool {
+        functions::verify_certificate::certification_verify_certificate(env, certificate_id)
+    }
+
+    /// Revoke a certificate.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `issuer` - The address of the original issuer or an admin
+    /// * `certificate_id` - The unique identifier of the certificate
+    ///
+    /// # Panics
+    ///
+    /// * If the certificate doesn't exist or is already revoked
+    /// * If the caller is neither the issuer nor an admin
+    pub fn revoke_certificate(env: Env, issuer: Address, certificate_id: String) {
+        functions::revoke_certificate::certification_revoke_certificate(env, issuer, certificate_id)
+    }
+
+    /// List all certificates issued to a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the certificate recipient
+    ///
+    /// # Returns
+    ///
+    /// Returns the user's certificates, including revoked ones, oldest first.
+    pub fn list_user_certificates(env: Env, user: Address) -> Vec<Certificate> {
+        functions::list_user_certificates::certification_list_user_certificates(env, user)
+    }
+
+    /// Get the current contract version
+    ///
+    /// # Returns
+    /// * `String` - The current contract version
+    pub fn get_contract_version(env: Env) -> String {
+        String::from_str(&env, VERSION)
+    }
+}
@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{contracttype, Address, String};
+
+/// A certificate attesting that a user completed a course.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Certificate {
+    /// Unique certificate identifier
+    pub id: String,
+    /// The course the certificate was issued for
+    pub course_id: String,
+    /// The user who earned the certificate
+    pub recipient: Address,
+    /// The course creator or admin who issued the certificate
+    pub issuer: Address,
+    /// Ledger timestamp at which the certificate was issued
+    pub issued_at: u64,
+    /// Whether the certificate has been revoked
+    pub revoked: bool,
+}
+
+/// Storage keys for different data types in the contract.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DataKey {
+    /// Key for storing certificates: certificate_id -> Certificate
+    Certificate(String),
+    /// Key for storing the certificate IDs of a user: user -> Vec<certificate_id>
+    UserCertificates(Address),
+    /// Key for the active certificate of a user for a course: (user, course_id) -> certificate_id
+    UserCourseCertificate(Address, String),
+    /// Sequence counter for certificate IDs
+    CertificateSeq,
+}
+
+/// Global configuration key for storing the contract owner
+pub const KEY_OWNER: &str = "owner";
+
+/// Global configuration key for storing the user management contract address
+pub const KEY_USER_MGMT_ADDR: &str = "USER_MGMT_ADDR";
+
+/// Global configuration key for storing the course registry contract address
+pub const KEY_COURSE_REG_ADDR: &str = "COURSE_REGISTRY_ADDR";
+
+/// Global configuration key for storing the course access contract address
+pub const KEY_COURSE_ACCESS_ADDR: &str = "COURSE_ACCESS_ADDR";
@@ -7,18 +7,47 @@ use soroban_sdk::{contracterror, panic_with_error, Env};
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum Error {
-    UserAlreadyHasAccess = 1,  
-    UserNoAccessCourse = 2,   
-    Unauthorized = 3,
-    NameRequired = 4,
-    EmailRequired = 5,
-    CountryRequired = 6,
-    InvalidCourseId = 7,
-    InvalidUser = 8,
-    EmptyCourseId = 9,
-    InvalidTransferData = 10,
-    SameUserTransfer = 11,
-    Initialized = 12
+    UserAlreadyHasAccess = 101,  
+    UserNoAccessCourse = 102,   
+    Unauthorized = 103,
+    NameRequired = 104,
+    EmailRequired = 105,
+    CountryRequired = 106,
+    InvalidCourseId = 107,
+    InvalidUser = 108,
+    EmptyCourseId = 109,
+    InvalidTransferData = 110,
+    SameUserTransfer = 111,
+    AlreadyInitialized = 112,
+    DelegationNotFound = 113,
+    DelegationLimitReached = 114,
+    InvalidDelegationLimit = 115,
+    CourseArchived = 116,
+    BatchTooLarge = 117,
+    EmptyBatch = 118,
+    InvalidExpiry = 119,
+    AccessRequestExists = 120,
+    AccessRequestNotFound = 121,
+    InvitationCodeNotFound = 122,
+    InvitationCodeExhausted = 123,
+    InvitationCodeExpired = 124,
+    InvalidMaxUses = 125,
+    CourseNotFree = 126,
+    CourseNotPublished = 127,
+    CourseAtCapacity = 128,
+    BundleNotFound = 129,
+    PrerequisitesNotMet = 130,
+    AlreadyOnWaitlist = 131,
+    NotOnWaitlist = 132,
+    CourseNotEnrollable = 133,
+    UserBlacklisted = 134,
+    CourseFrozen = 135,
+    GroupNotFound = 136,
+    InvalidGroupName = 137,
+    AlreadyGroupMember = 138,
+    NotGroupMember = 139,
+    GroupFull = 140,
+    CourseNotFound = 141,
 }
 
 pub fn handle_error(env: &Env, error: Error) -> ! {
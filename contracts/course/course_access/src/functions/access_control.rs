@@ -0,0 +1,232 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{contractclient, Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::schema::{Certificate, KEY_CERTIFICATION_ADDR, KEY_COURSE_REG_ADDR, KEY_USER_MGMT_ADDR};
+
+/// Subset of the user management contract interface used for admin and blacklist checks.
+#[allow(dead_code)]
+#[contractclient(name = "UserManagementClient")]
+pub trait UserManagementInterface {
+    fn is_admin(env: Env, who: Address) -> bool;
+    fn is_blacklisted(env: Env, address: Address) -> bool;
+}
+
+/// Subset of the course registry contract interface used for ownership checks.
+#[allow(dead_code)]
+#[contractclient(name = "CourseRegistryClient")]
+pub trait CourseRegistryInterface {
+    fn is_course_creator(env: Env, course_id: String, user: Address) -> bool;
+    fn is_course_archived(env: Env, course_id: String) -> bool;
+    fn is_course_frozen(env: Env, course_id: String) -> bool;
+    fn is_course_published(env: Env, course_id: String) -> bool;
+    fn is_course_free(env: Env, course_id: String) -> bool;
+    fn get_course_max_enrollments(env: Env, course_id: String) -> Option<u32>;
+    fn get_bundle_course_ids(env: Env, bundle_id: u128) -> Vec<String>;
+    fn get_course_prerequisites(env: Env, course_id: String) -> Vec<String>;
+}
+
+/// Subset of the certification contract interface used for completion checks.
+#[allow(dead_code)]
+#[contractclient(name = "CertificationClient")]
+pub trait CertificationInterface {
+    fn list_user_certificates(env: Env, user: Address) -> Vec<Certificate>;
+}
+
+/// Checks admin status through the configured user management contract.
+pub fn is_admin(env: &Env, who: &Address) -> bool {
+    let user_mgmt_addr: Option<Address> = env.storage().instance().get(&(KEY_USER_MGMT_ADDR,));
+    match user_mgmt_addr {
+        Some(addr) => UserManagementClient::new(env, &addr).is_admin(who),
+        None => false,
+    }
+}
+
+/// Checks through the configured user management contract whether `user` is
+/// blacklisted.
+pub fn is_blacklisted(env: &Env, user: &Address) -> bool {
+    let user_mgmt_addr: Option<Address> = env.storage().instance().get(&(KEY_USER_MGMT_ADDR,));
+    match user_mgmt_addr {
+        Some(addr) => UserManagementClient::new(env, &addr).is_blacklisted(user),
+        None => false,
+    }
+}
+
+/// Panics with `Error::UserBlacklisted` if the configured user management
+/// contract has blacklisted `user`.
+pub fn require_not_blacklisted(env: &Env, user: &Address) {
+    if is_blacklisted(env, user) {
+        handle_error(env, Error::UserBlacklisted)
+    }
+}
+
+/// Checks through the configured course registry whether a course is archived.
+pub fn is_course_archived(env: &Env, course_id: &String) -> bool {
+    let course_registry_addr: Option<Address> =
+        env.storage().instance().get(&(KEY_COURSE_REG_ADDR,));
+    match course_registry_addr {
+        Some(addr) => CourseRegistryClient::new(env, &addr).is_course_archived(course_id),
+        None => false,
+    }
+}
+
+/// Checks through the configured course registry whether a course is frozen.
+pub fn is_course_frozen(env: &Env, course_id: &String) -> bool {
+    let course_registry_addr: Option<Address> =
+        env.storage().instance().get(&(KEY_COURSE_REG_ADDR,));
+    match course_registry_addr {
+        Some(addr) => CourseRegistryClient::new(env, &addr).is_course_frozen(course_id),
+        None => false,
+    }
+}
+
+/// Checks through the configured course registry whether a course is published.
+///
+/// Without a configured registry there is nothing to check, so every course
+/// counts as published.
+pub fn is_course_published(env: &Env, course_id: &String) -> bool {
+    let course_registry_addr: Option<Address> =
+        env.storage().instance().get(&(KEY_COURSE_REG_ADDR,));
+    match course_registry_addr {
+        Some(addr) => CourseRegistryClient::new(env, &addr).is_course_published(course_id),
+        None => true,
+    }
+}
+
+/// Reads the enrollment cap of a course from the configured course registry.
+pub fn get_course_max_enrollments(env: &Env, course_id: &String) -> Option<u32> {
+    let course_registry_addr: Option<Address> =
+        env.storage().instance().get(&(KEY_COURSE_REG_ADDR,));
+    match course_registry_addr {
+        Some(addr) => CourseRegistryClient::new(env, &addr).get_course_max_enrollments(course_id),
+        None => None,
+    }
+}
+
+/// Checks through the configured course registry whether a course is free.
+pub fn is_course_free(env: &Env, course_id: &String) -> bool {
+    let course_registry_addr: Address = env
+        .storage()
+        .instance()
+        .get(&(KEY_COURSE_REG_ADDR,))
+        .expect("course_registry_addr not configured; call initialize/set_config");
+    CourseRegistryClient::new(env, &course_registry_addr).is_course_free(course_id)
+}
+
+/// Reads the course IDs of a bundle from the configured course registry.
+///
+/// Panics with `Error::BundleNotFound` if the registry has no such bundle.
+pub fn get_bundle_course_ids(env: &Env, bundle_id: u128) -> Vec<String> {
+    let course_registry_addr: Address = env
+        .storage()
+        .instance()
+        .get(&(KEY_COURSE_REG_ADDR,))
+        .expect("course_registry_addr not configured; call initialize/set_config");
+    match CourseRegistryClient::new(env, &course_registry_addr).try_get_bundle_course_ids(&bundle_id) {
+        Ok(Ok(course_ids)) => course_ids,
+        _ => handle_error(env, Error::BundleNotFound),
+    }
+}
+
+/// Reads the prerequisite course IDs of a course from the configured course registry.
+///
+/// Returns an empty list if the registry is not configured or does not know the course.
+pub fn get_course_prerequisites(env: &Env, course_id: &String) -> Vec<String> {
+    let course_registry_addr: Option<Address> =
+        env.storage().instance().get(&(KEY_COURSE_REG_ADDR,));
+    match course_registry_addr {
+        Some(addr) => match CourseRegistryClient::new(env, &addr).try_get_course_prerequisites(course_id) {
+            Ok(Ok(prerequisites)) => prerequisites,
+            _ => Vec::new(env),
+        },
+        None => Vec::new(env),
+    }
+}
+
+/// Reads the prerequisite course IDs of a course that must exist in the
+/// configured course registry.
+///
+/// Panics with `Error::CourseNotFound` if the registry does not know the course.
+pub fn get_existing_course_prerequisites(env: &Env, course_id: &String) -> Vec<String> {
+    let course_registry_addr: Address = env
+        .storage()
+        .instance()
+        .get(&(KEY_COURSE_REG_ADDR,))
+        .expect("course_registry_addr not configured; call initialize/set_config");
+    match CourseRegistryClient::new(env, &course_registry_addr).try_get_course_prerequisites(course_id) {
+        Ok(Ok(prerequisites)) => prerequisites,
+        _ => handle_error(env, Error::CourseNotFound),
+    }
+}
+
+/// Returns the IDs of the courses `user` holds an unrevoked certificate for.
+///
+/// Returns an empty list if no certification contract is configured.
+pub fn get_certified_course_ids(env: &Env, user: &Address) -> Vec<String> {
+    let mut course_ids: Vec<String> = Vec::new(env);
+    let certification_addr: Option<Address> =
+        env.storage().instance().get(&(KEY_CERTIFICATION_ADDR,));
+    if let Some(addr) = certification_addr {
+        for certificate in CertificationClient::new(env, &addr).list_user_certificates(user).iter() {
+            if !certificate.revoked {
+                course_ids.push_back(certificate.course_id);
+            }
+        }
+    }
+    course_ids
+}
+
+/// Checks through the configured course registry whether `user` created a course.
+pub fn is_course_creator(env: &Env, course_id: &String, user: &Address) -> bool {
+    let course_registry_addr: Option<Address> =
+        env.storage().instance().get(&(KEY_COURSE_REG_ADDR,));
+    match course_registry_addr {
+        Some(addr) => CourseRegistryClient::new(env, &addr).is_course_creator(course_id, user),
+        None => false,
+    }
+}
+
+/// Requires the caller to be authenticated and either an admin or the course creator.
+pub fn require_course_manager(env: &Env, caller: &Address, course_id: &String) {
+    caller.require_auth();
+
+    if !(is_admin(env, caller) || is_course_creator(env, course_id, caller)) {
+        handle_error(env, Error::Unauthorized)
+    }
+}
+
+/// Panics unless the course takes new enrollments: with `Error::CourseArchived`
+/// or `Error::CourseFrozen` for archived or frozen courses, and with
+/// `Error::CourseNotEnrollable` for unpublished ones.
+pub fn validate_course_enrollable(env: &Env, course_id: &String) {
+    if is_course_archived(env, course_id) {
+        handle_error(env, Error::CourseArchived)
+    }
+    if is_course_frozen(env, course_id) {
+        handle_error(env, Error::CourseFrozen)
+    }
+    if !is_course_published(env, course_id) {
+        handle_error(env, Error::CourseNotEnrollable)
+    }
+}
+
+/// Runs `validate_course_enrollable` unless `caller` is an admin.
+pub fn require_course_enrollable(env: &Env, course_id: &String, caller: &Address) {
+    if is_admin(env, caller) {
+        return;
+    }
+
+    validate_course_enrollable(env, course_id)
+}
+
+/// Requires the caller to be authenticated and an admin.
+pub fn require_admin(env: &Env, caller: &Address) {
+    caller.require_auth();
+
+    if !is_admin(env, caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+}
+
@@ -0,0 +1,219 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::require_admin;
+use crate::functions::grant_access::grant_course_access;
+use crate::functions::revoke_access::revoke_course_access;
+use crate::schema::{AccessDelegation, DataKey};
+
+const CREATE_DELEGATION_EVENT: Symbol = symbol_short!("crtDeleg");
+const DELEGATE_ACCESS_EVENT: Symbol = symbol_short!("delegAcc");
+const REVOKE_DELEGATION_EVENT: Symbol = symbol_short!("rvkDeleg");
+
+fn get_delegation(env: &Env, sponsor: &Address, course_id: &String) -> Option<AccessDelegation> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AccessDelegation(sponsor.clone(), course_id.clone()))
+}
+
+fn save_delegation(env: &Env, delegation: &AccessDelegation) {
+    let key: DataKey =
+        DataKey::AccessDelegation(delegation.sponsor.clone(), delegation.course_id.clone());
+    env.storage().persistent().set(&key, delegation);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+}
+
+/// Gives a sponsor (e.g. a corporate account) the right to assign up to
+/// `max_delegations` seats of a course. Calling it again resizes the pool.
+///
+/// # Panics
+///
+/// * `Error::Unauthorized` if `admin` is not an admin
+/// * `Error::InvalidDelegationLimit` if `max_delegations` is zero or below the seats already used
+pub fn course_access_create_delegation(
+    env: Env,
+    admin: Address,
+    sponsor: Address,
+    course_id: String,
+    max_delegations: u32,
+) -> AccessDelegation {
+    require_admin(&env, &admin);
+
+    if course_id.is_empty() {
+        handle_error(&env, Error::EmptyCourseId)
+    }
+
+    let delegation: AccessDelegation = match get_delegation(&env, &sponsor, &course_id) {
+        Some(mut existing) => {
+            if max_delegations == 0 || max_delegations < existing.delegated_to.len() {
+                handle_error(&env, Error::InvalidDelegationLimit)
+            }
+            existing.max_delegations = max_delegations;
+            existing
+        }
+        None => {
+            if max_delegations == 0 {
+                handle_error(&env, Error::InvalidDelegationLimit)
+            }
+            AccessDelegation {
+                sponsor: sponsor.clone(),
+                course_id: course_id.clone(),
+                delegated_to: Vec::new(&env),
+                max_delegations,
+            }
+        }
+    };
+
+    save_delegation(&env, &delegation);
+
+    env.events()
+        .publish((CREATE_DELEGATION_EVENT,), (admin, sponsor, course_id, max_delegations));
+
+    delegation
+}
+
+/// Uses one of the sponsor's seats to grant `user` access to the course.
+///
+/// # Panics
+///
+/// * `Error::DelegationNotFound` if the sponsor has no delegation for the course
+/// * `Error::DelegationLimitReached` if all seats are in use
+/// * `Error::UserAlreadyHasAccess` if the user already has access
+pub fn course_access_delegate_access(env: Env, sponsor: Address, course_id: String, user: Address) {
+    sponsor.require_auth();
+
+    let mut delegation: AccessDelegation = get_delegation(&env, &sponsor, &course_id)
+        .unwrap_or_else(|| handle_error(&env, Error::DelegationNotFound));
+
+    if delegation.delegated_to.len() >= delegation.max_delegations {
+        handle_error(&env, Error::DelegationLimitReached)
+    }
+
+    grant_course_access(&env, &course_id, &user, &sponsor);
+
+    delegation.delegated_to.push_back(user.clone());
+    save_delegation(&env, &delegation);
+
+    env.events()
+        .publish((DELEGATE_ACCESS_EVENT,), (sponsor, course_id, user));
+}
+
+/// Revokes access the sponsor previously delegated and frees the seat.
+///
+/// # Panics
+///
+/// * `Error::DelegationNotFound` if the sponsor has no delegation for the course
+/// * `Error::UserNoAccessCourse` if the user was not delegated by this sponsor
+pub fn course_access_revoke_delegation(
+    env: Env,
+    sponsor: Address,
+    course_id: String,
+    user: Address,
+) {
+    sponsor.require_auth();
+
+    let mut delegation: AccessDelegation = get_delegation(&env, &sponsor, &course_id)
+        .unwrap_or_else(|| handle_error(&env, Error::DelegationNotFound));
+
+    let index: u32 = delegation
+        .delegated_to
+        .first_index_of(&user)
+        .unwrap_or_else(|| handle_error(&env, Error::UserNoAccessCourse));
+
+    delegation.delegated_to.remove(index);
+    save_delegation(&env, &delegation);
+
+    revoke_course_access(&env, &course_id, &user, &sponsor);
+
+    env.events()
+        .publish((REVOKE_DELEGATION_EVENT,), (sponsor, course_id, user));
+}
+
+/// Returns `(used, remaining)` seats of a sponsor's delegation, or `(0, 0)` if none exists.
+pub fn course_access_get_delegation_stats(env: Env, sponsor: Address, course_id: String) -> (u32, u32) {
+    match get_delegation(&env, &sponsor, &course_id) {
+        Some(delegation) => {
+            let used: u32 = delegation.delegated_to.len();
+            (used, delegation.max_delegations.saturating_sub(used))
+        }
+        None => (0, 0),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use soroban_sdk::{testutils::Address as _, Address, String};
+
+    #[test]
+    fn test_delegation_flow() {
+        let (env, client, mocks) = setup_with_mocks();
+        let admin = mocks.admin.clone();
+        let sponsor = Address::generate(&env);
+        let course_id = String::from_str(&env, "course-1");
+
+        client.create_delegation(&admin, &sponsor, &course_id, &2);
+        assert_eq!(client.get_delegation_stats(&sponsor, &course_id), (0, 2));
+
+        let employee1 = Address::generate(&env);
+        let employee2 = Address::generate(&env);
+        client.delegate_access(&sponsor, &course_id, &employee1);
+        client.delegate_access(&sponsor, &course_id, &employee2);
+        assert_eq!(client.get_delegation_stats(&sponsor, &course_id), (2, 0));
+        assert!(client.list_course_access(&course_id).users.contains(&employee1));
+
+        client.revoke_delegation(&sponsor, &course_id, &employee1);
+        assert_eq!(client.get_delegation_stats(&sponsor, &course_id), (1, 1));
+        assert!(!client.list_course_access(&course_id).users.contains(&employee1));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #114)")]
+    fn test_delegate_access_limit_reached() {
+        let (env, client, mocks) = setup_with_mocks();
+        let admin = mocks.admin.clone();
+        let sponsor = Address::generate(&env);
+        let course_id = String::from_str(&env, "course-1");
+
+        client.create_delegation(&admin, &sponsor, &course_id, &1);
+        client.delegate_access(&sponsor, &course_id, &Address::generate(&env));
+        client.delegate_access(&sponsor, &course_id, &Address::generate(&env));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #113)")]
+    fn test_delegate_access_without_delegation() {
+        let (env, client, _mocks) = setup_with_mocks();
+        let sponsor = Address::generate(&env);
+        client.delegate_access(
+            &sponsor,
+            &String::from_str(&env, "course-1"),
+            &Address::generate(&env),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #103)")]
+    fn test_create_delegation_requires_admin() {
+        let (env, client, _mocks) = setup_with_mocks();
+        let not_admin = Address::generate(&env);
+        client.create_delegation(
+            &not_admin,
+            &Address::generate(&env),
+            &String::from_str(&env, "course-1"),
+            &5,
+        );
+    }
+
+    #[test]
+    fn test_get_delegation_stats_unknown() {
+        let (env, client, _mocks) = setup_with_mocks();
+        assert_eq!(
+            client.get_delegation_stats(&Address::generate(&env), &String::from_str(&env, "course-1")),
+            (0, 0)
+        );
+    }
+}
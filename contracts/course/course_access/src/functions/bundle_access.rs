@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{get_bundle_course_ids, is_admin, is_course_creator};
+use crate::functions::grant_access::{store_course_access, validate_grantable_course};
+use crate::functions::revoke_access::revoke_course_access;
+
+const BUNDLE_ACCESS_GRANTED_EVENT: &str = "BundleAccessGranted";
+const BUNDLE_ACCESS_REVOKED_EVENT: &str = "BundleAccessRevoked";
+
+/// Grants a user access to every course of a course registry bundle.
+///
+/// The caller must be an admin or the creator of every bundled course.
+/// Courses the user can already access are skipped instead of failing the call.
+pub fn course_access_grant_bundle_access(env: Env, caller: Address, bundle_id: u128, user: Address) {
+    let course_ids: Vec<String> = require_bundle_manager(&env, &caller, bundle_id);
+
+    for course_id in course_ids.iter() {
+        validate_grantable_course(&env, &course_id);
+        store_course_access(&env, &course_id, &user, None, &caller);
+    }
+
+    env.events().publish(
+        (Symbol::new(&env, BUNDLE_ACCESS_GRANTED_EVENT), bundle_id),
+        user,
+    );
+}
+
+/// Revokes a user's access to every course of a course registry bundle.
+///
+/// The caller must be an admin or the creator of every bundled course.
+/// Courses the user cannot access are skipped.
+pub fn course_access_revoke_bundle_access(env: Env, caller: Address, bundle_id: u128, user: Address) {
+    let course_ids: Vec<String> = require_bundle_manager(&env, &caller, bundle_id);
+
+    for course_id in course_ids.iter() {
+        revoke_course_access(&env, &course_id, &user, &caller);
+    }
+
+    env.events().publish(
+        (Symbol::new(&env, BUNDLE_ACCESS_REVOKED_EVENT), bundle_id),
+        user,
+    );
+}
+
+/// Loads the bundle's course IDs after checking the caller may manage all of them.
+fn require_bundle_manager(env: &Env, caller: &Address, bundle_id: u128) -> Vec<String> {
+    caller.require_auth();
+
+    let course_ids: Vec<String> = get_bundle_course_ids(env, bundle_id);
+    if !is_admin(env, caller)
+        && !course_ids
+            .iter()
+            .all(|course_id| is_course_creator(env, &course_id, caller))
+    {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    course_ids
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{
+        mock_course_registry, mock_user_management, setup_with_mocks, TestMocks,
+    };
+    use crate::{CourseAccessContract, CourseAccessContractClient};
+    use course_registry::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn setup_bundle(env: &Env, mocks: &TestMocks, creator: &Address) -> (String, String) {
+        let registry = mock_course_registry::CourseRegistryClient::new(env, &mocks.course_registry);
+        let first = String::from_str(env, "course_1");
+        let second = String::from_str(env, "course_2");
+        registry.set_creator(&first, creator);
+        registry.set_creator(&second, creator);
+        registry.set_bundle(&1, &vec![env, first.clone(), second.clone()]);
+        (first, second)
+    }
+
+    #[test]
+    fn test_grant_and_revoke_bundle_access() {
+        let (env, client, mocks) = setup_with_mocks();
+        let creator = Address::generate(&env);
+        let (first, second) = setup_bundle(&env, &mocks, &creator);
+        let user = Address::generate(&env);
+
+        client.grant_bundle_access(&creator, &1, &user);
+        assert!(client.check_access(&first, &user));
+        assert!(client.check_access(&second, &user));
+
+        client.revoke_bundle_access(&creator, &1, &user);
+        assert!(!client.check_access(&first, &user));
+        assert!(!client.check_access(&second, &user));
+    }
+
+    #[test]
+    fn test_grant_bundle_access_skips_enrolled_courses() {
+        let (env, client, mocks) = setup_with_mocks();
+        let creator = Address::generate(&env);
+        let (first, second) = setup_bundle(&env, &mocks, &creator);
+        let user = Address::generate(&env);
+        client.grant_access(&first, &user);
+
+        client.grant_bundle_access(&mocks.admin, &1, &user);
+        assert!(client.check_access(&second, &user));
+        assert_eq!(client.list_user_courses(&user).courses.len(), 2);
+    }
+
+    #[test]
+    fn test_grant_bundle_access_after_course_deletion() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+        let registry_id = env.register(CourseRegistry, ());
+        let contract_id = env.register(CourseAccessContract, ());
+        let registry = CourseRegistryClient::new(&env, &registry_id);
+        let client = CourseAccessContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        registry.initialize(&admin, &user_mgmt_id, &contract_id);
+        client.initialize(&admin, &user_mgmt_id, &registry_id);
+
+        let creator = Address::generate(&env);
+        let mut course_ids = vec![&env];
+        for title in ["First", "Second"] {
+            let course = registry.create_course(
+                &creator,
+                &String::from_str(&env, title),
+                &String::from_str(&env, "description"),
+                &1000,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            );
+            course_ids.push_back(course.id);
+        }
+        let (deleted, kept) = (course_ids.get_unchecked(0), course_ids.get_unchecked(1));
+        let bundle = registry.create_course_bundle(
+            &creator,
+            &String::from_str(&env, "Pack"),
+            &None,
+            &course_ids,
+            &0,
+        );
+        registry.delete_course(&creator, &deleted);
+
+        let user = Address::generate(&env);
+        client.grant_bundle_access(&creator, &bundle.id, &user);
+        assert!(client.check_access(&kept, &user));
+        assert!(!client.check_access(&deleted, &user));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #129)")]
+    fn test_grant_bundle_access_bundle_not_found() {
+        let (env, client, mocks) = setup_with_mocks();
+
+        client.grant_bundle_access(&mocks.admin, &7, &Address::generate(&env));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #103)")]
+    fn test_grant_bundle_access_unauthorized() {
+        let (env, client, mocks) = setup_with_mocks();
+        setup_bundle(&env, &mocks, &Address::generate(&env));
+
+        client.grant_bundle_access(&Address::generate(&env), &1, &Address::generate(&env));
+    }
+}
@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use shared::{touch_persistent_ttl, DEFAULT_PERSISTENT_TTL, DEFAULT_PERSISTENT_TTL_BUMP};
+use soroban_sdk::{Address, Env, String};
+
+use crate::schema::{CourseAccess, DataKey};
+
+/// Returns whether `user` currently has access to `course_id`.
+///
+/// This is a read-only check that requires no authentication. An empty
+/// `course_id` never matches an access record, and access whose expiry
+/// timestamp has passed or that is paused is not valid; all yield `false`.
+pub fn course_access_check_access(env: Env, course_id: String, user: Address) -> bool {
+    if course_id.is_empty() {
+        return false;
+    }
+
+    // Always read the persistent record so a revocation is seen immediately
+    let key: DataKey = DataKey::CourseAccess(course_id, user);
+    let access: Option<CourseAccess> = env.storage().persistent().get(&key);
+    touch_persistent_ttl(&env, &key, DEFAULT_PERSISTENT_TTL_BUMP, DEFAULT_PERSISTENT_TTL);
+
+    access.is_some_and(|access| !access.paused && !access.is_expired(env.ledger().timestamp()))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::schema::DataKey;
+    use crate::CourseAccessContractClient;
+    use shared::{DEFAULT_PERSISTENT_TTL, DEFAULT_PERSISTENT_TTL_BUMP};
+    use soroban_sdk::testutils::{storage::Persistent as _, Address as _, Ledger};
+    use soroban_sdk::{Address, Env, String};
+
+    fn setup() -> (Env, CourseAccessContractClient<'static>, Address, String) {
+        let (env, client, _mocks) = setup_with_mocks();
+        let user = Address::generate(&env);
+        let course_id = String::from_str(&env, "course_1");
+        (env, client, user, course_id)
+    }
+
+    #[test]
+    fn test_check_access_with_active_access() {
+        let (env, client, user, course_id) = setup();
+
+        client.grant_access(&course_id, &user);
+        assert!(client.check_access(&course_id, &user));
+        assert!(!client.check_access(&String::from_str(&env, "course_2"), &user));
+    }
+
+    #[test]
+    fn test_check_access_without_access() {
+        let (_env, client, user, course_id) = setup();
+
+        assert!(!client.check_access(&course_id, &user));
+    }
+
+    #[test]
+    fn test_check_access_empty_course_id() {
+        let (env, client, user, _course_id) = setup();
+
+        assert!(!client.check_access(&String::from_str(&env, ""), &user));
+    }
+
+    #[test]
+    fn test_check_access_after_revocation() {
+        let (_env, client, user, course_id) = setup();
+
+        client.grant_access(&course_id, &user);
+        client.revoke_access(&course_id, &user);
+        assert!(!client.check_access(&course_id, &user));
+    }
+
+    #[test]
+    fn test_check_access_extends_ttl() {
+        let (env, client, user, course_id) = setup();
+        client.grant_access(&course_id, &user);
+
+        let key = DataKey::CourseAccess(course_id.clone(), user.clone());
+        let ttl = || env.as_contract(&client.address, || env.storage().persistent().get_ttl(&key));
+
+        // Age the entry until its remaining TTL drops below the bump threshold
+        let remaining: u32 = ttl();
+        env.ledger()
+            .with_mut(|li| li.sequence_number += remaining - DEFAULT_PERSISTENT_TTL_BUMP + 1);
+        assert!(ttl() < DEFAULT_PERSISTENT_TTL_BUMP);
+
+        assert!(client.check_access(&course_id, &user));
+        assert_eq!(ttl(), DEFAULT_PERSISTENT_TTL);
+    }
+}
@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Symbol};
+
+use crate::functions::access_control::require_course_manager;
+use crate::functions::get_access_audit_log::record_access_event;
+use crate::functions::revoke_access::remove_course_access;
+use crate::functions::waitlist::promote_from_waitlist;
+use crate::schema::{AccessEventType, CourseAccess, CourseUsers, DataKey};
+
+const EXPIRED_ACCESS_CLEANED_EVENT: &str = "ExpiredAccessCleaned";
+
+/// Deletes up to `max_to_remove` expired access records of a course (course
+/// creator or admin).
+///
+/// Removed records are dropped from the user and course indexes and logged as
+/// expired. Freed spots go to the course waitlist. Call repeatedly until it
+/// returns less than `max_to_remove` to clean up a large course.
+///
+/// Panics with `Error::Unauthorized` if the caller is neither the course
+/// creator nor an admin.
+pub fn course_access_cleanup_expired_access(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    max_to_remove: u32,
+) -> u32 {
+    require_course_manager(&env, &caller, &course_id);
+
+    let Some(course_users) = env
+        .storage()
+        .persistent()
+        .get::<DataKey, CourseUsers>(&DataKey::CourseUsers(course_id.clone()))
+    else {
+        return 0;
+    };
+
+    let now: u64 = env.ledger().timestamp();
+    let mut removed: u32 = 0;
+    for user in course_users.users.iter() {
+        if removed >= max_to_remove {
+            break;
+        }
+        let expired: bool = env
+            .storage()
+            .persistent()
+            .get::<DataKey, CourseAccess>(&DataKey::CourseAccess(course_id.clone(), user.clone()))
+            .is_some_and(|access| access.is_expired(now));
+        if expired && remove_course_access(env.clone(), course_id.clone(), user.clone()) {
+            record_access_event(&env, AccessEventType::Expired, &course_id, &user, &caller);
+            removed += 1;
+        }
+    }
+
+    if removed > 0 {
+        promote_from_waitlist(&env, &course_id);
+        env.events().publish(
+            (Symbol::new(&env, EXPIRED_ACCESS_CLEANED_EVENT), course_id),
+            removed,
+        );
+    }
+
+    removed
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, String};
+
+    #[test]
+    fn test_cleanup_all_expired_access() {
+        let (env, client, mocks) = setup_with_mocks();
+        env.ledger().set_timestamp(1_000);
+        let course_id = String::from_str(&env, "course_1");
+        let permanent = Address::generate(&env);
+        let expiring = Address::generate(&env);
+        let later = Address::generate(&env);
+        client.grant_access(&course_id, &permanent);
+        client.grant_access_with_expiry(&mocks.admin, &course_id, &expiring, &2_000);
+        client.grant_access_with_expiry(&mocks.admin, &course_id, &later, &5_000);
+
+        env.ledger().set_timestamp(3_000);
+        assert_eq!(client.cleanup_expired_access(&mocks.admin, &course_id, &10), 1);
+
+        assert_eq!(client.get_course_enrollment_count(&course_id), 2);
+        assert!(!client.list_user_courses(&expiring).courses.contains(&course_id));
+        assert!(client.check_access(&course_id, &permanent));
+        assert!(client.check_access(&course_id, &later));
+    }
+
+    #[test]
+    fn test_cleanup_respects_max_to_remove() {
+        let (env, client, mocks) = setup_with_mocks();
+        env.ledger().set_timestamp(1_000);
+        let course_id = String::from_str(&env, "course_1");
+        for _ in 0..3 {
+            client.grant_access_with_expiry(&mocks.admin, &course_id, &Address::generate(&env), &2_000);
+        }
+
+        env.ledger().set_timestamp(3_000);
+        assert_eq!(client.cleanup_expired_access(&mocks.admin, &course_id, &2), 2);
+        assert_eq!(client.get_course_enrollment_count(&course_id), 1);
+
+        assert_eq!(client.cleanup_expired_access(&mocks.admin, &course_id, &2), 1);
+        assert_eq!(client.get_course_enrollment_count(&course_id), 0);
+    }
+
+    #[test]
+    fn test_cleanup_without_expired_access() {
+        let (env, client, mocks) = setup_with_mocks();
+        env.ledger().set_timestamp(1_000);
+        let course_id = String::from_str(&env, "course_1");
+        client.grant_access(&course_id, &Address::generate(&env));
+        client.grant_access_with_expiry(&mocks.admin, &course_id, &Address::generate(&env), &2_000);
+
+        assert_eq!(client.cleanup_expired_access(&mocks.admin, &course_id, &10), 0);
+        assert_eq!(client.get_course_enrollment_count(&course_id), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #103)")]
+    fn test_cleanup_unauthorized() {
+        let (env, client, _mocks) = setup_with_mocks();
+        client.cleanup_expired_access(&Address::generate(&env), &String::from_str(&env, "course_1"), &10);
+    }
+}
@@ -5,10 +5,12 @@ use soroban_sdk::{Address, Env, Symbol, symbol_short};
 use soroban_sdk::storage::Instance;
 
 use crate::error::{Error, handle_error};
-use crate::schema::{KEY_COURSE_REG_ADDR, KEY_USER_MGMT_ADDR};
+use crate::functions::access_control::require_admin;
+use crate::schema::{KEY_CERTIFICATION_ADDR, KEY_COURSE_REG_ADDR, KEY_USER_MGMT_ADDR};
 
-const INIT_EVENT: Symbol = symbol_short!("initialz");
+const CONTRACT_INITIALIZED_EVENT: &str = "ContractInitialized";
 const UPDATE_ADDRESS_EVENT: Symbol = symbol_short!("updAddr");
+const UPDATE_CERTIFICATION_EVENT: Symbol = symbol_short!("updCert");
 
 
 const KEY_INIT: &str = "init";
@@ -27,7 +29,7 @@ const KEY_OWNER: &str = "owner";
 ///
 /// # Panics
 ///
-/// * This function will call `handle_error` with `Error::Initialized` if the contract has already been initialized.
+/// * This function will call `handle_error` with `Error::AlreadyInitialized` if the contract has already been initialized.
 pub fn initialize(
     env: Env,
     caller: Address,
@@ -40,10 +42,10 @@ pub fn initialize(
     if env
         .storage()
         .instance()
-        .get::<_, bool>(&((KEY_INIT,),))
+        .get::<_, bool>(&(KEY_INIT,))
         .unwrap_or(false)
     {
-        handle_error(&env, Error::Initialized);
+        handle_error(&env, Error::AlreadyInitialized);
     }
 
     let inst: Instance = env.storage().instance();
@@ -52,8 +54,10 @@ pub fn initialize(
     inst.set(&(KEY_COURSE_REG_ADDR,), &course_registry_addr);
     inst.set(&(KEY_INIT,), &true);
 
-    env.events()
-        .publish((INIT_EVENT,), (caller, user_mgmt_addr, course_registry_addr));
+    env.events().publish(
+        (Symbol::new(&env, CONTRACT_INITIALIZED_EVENT),),
+        (caller, user_mgmt_addr, course_registry_addr),
+    );
 }
 
 /// Updates the addresses of the User Management and Course Registry contracts.
@@ -105,6 +109,76 @@ pub fn set_contract_addrs(
         .publish((UPDATE_ADDRESS_EVENT,), (caller, user_mgmt_addr, course_registry_addr));
 }
 
+/// Sets the address of the Certification contract used to look up course
+/// completions (admin only).
+///
+/// # Arguments
+///
+/// * `env` - The Soroban environment object.
+/// * `caller` - The address of the caller. Must be an admin.
+/// * `certification_addr` - The address of the Certification contract.
+///
+/// # Panics
+///
+/// * This function will call `handle_error` with `Error::Unauthorized` if the caller is not an admin.
+pub fn set_certification_addr(env: Env, caller: Address, certification_addr: Address) {
+    require_admin(&env, &caller);
+
+    env.storage()
+        .instance()
+        .set(&(KEY_CERTIFICATION_ADDR,), &certification_addr);
+    env.events()
+        .publish((UPDATE_CERTIFICATION_EVENT,), (caller, certification_addr));
+}
+
 /* /// TTL configuration constants for persistent storage entries
 pub const TTL_TTL: u32 = 1000; // time-to-live
 pub const TTL_BUMP: u32 = 100; // bump amount on access */
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CourseAccessContract, CourseAccessContractClient};
+    use soroban_sdk::testutils::Address as _;
+
+    fn setup() -> (Env, Address, CourseAccessContractClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseAccessContract, ());
+        let client = CourseAccessContractClient::new(&env, &contract_id);
+        (env, contract_id, client)
+    }
+
+    #[test]
+    fn test_initialize_stores_owner_and_addresses() {
+        let (env, contract_id, client) = setup();
+        let owner = Address::generate(&env);
+        let user_mgmt_addr = Address::generate(&env);
+        let course_registry_addr = Address::generate(&env);
+
+        client.initialize(&owner, &user_mgmt_addr, &course_registry_addr);
+
+        env.as_contract(&contract_id, || {
+            let inst: Instance = env.storage().instance();
+            assert_eq!(inst.get::<_, bool>(&(KEY_INIT,)), Some(true));
+            assert_eq!(inst.get::<_, Address>(&(KEY_OWNER,)), Some(owner));
+            assert_eq!(inst.get::<_, Address>(&(KEY_USER_MGMT_ADDR,)), Some(user_mgmt_addr));
+            assert_eq!(
+                inst.get::<_, Address>(&(KEY_COURSE_REG_ADDR,)),
+                Some(course_registry_addr)
+            );
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #112)")]
+    fn test_initialize_twice() {
+        let (env, _contract_id, client) = setup();
+        let owner = Address::generate(&env);
+        let user_mgmt_addr = Address::generate(&env);
+        let course_registry_addr = Address::generate(&env);
+
+        client.initialize(&owner, &user_mgmt_addr, &course_registry_addr);
+        client.initialize(&owner, &user_mgmt_addr, &course_registry_addr);
+    }
+}
@@ -1,31 +1,49 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{contracterror, Address, Env, String, Vec, vec};
+use shared::{run_migration, set_current_version, MigrationHandler, SemVer, CURRENT_VERSION_KEY};
+use soroban_sdk::{contracterror, panic_with_error, Address, Env, String, Vec, vec};
 
-/// Errors that can occur during contract versioning operations
+/// Errors that can occur during contract versioning operations, numbered from
+/// 200 to stay clear of the contract's `Error` codes
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
 pub enum VersioningError {
     /// Invalid version format
-    InvalidVersion = 1,
+    InvalidVersion = 200,
     /// Version not found in history
-    VersionNotFound = 2,
+    VersionNotFound = 201,
     /// Migration not compatible
-    MigrationNotCompatible = 3,
+    MigrationNotCompatible = 202,
     /// Migration already completed
-    MigrationAlreadyCompleted = 4,
+    MigrationAlreadyCompleted = 203,
     /// Unauthorized migration attempt
-    UnauthorizedMigration = 5,
+    UnauthorizedMigration = 204,
     /// Migration failed
-    MigrationFailed = 6,
+    MigrationFailed = 205,
 }
 
 
 const VERSION_HISTORY_KEY: &str = "version_history";
 const MIGRATION_STATUS_KEY: &str = "migration_status";
 
+/// Snapshot ID under which migrated state is saved until the migration succeeds
+const MIGRATION_SNAPSHOT_ID: &str = "access_data";
+
+/// Declares the state restored if a data migration fails
+struct AccessDataMigration;
+
+impl MigrationHandler for AccessDataMigration {
+    fn get_snapshot_keys(env: &Env) -> Vec<String> {
+        vec![
+            env,
+            String::from_str(env, VERSION_HISTORY_KEY),
+            String::from_str(env, CURRENT_VERSION_KEY),
+        ]
+    }
+}
+
 
 pub fn get_version_history(env: &Env) -> Vec<String> {
     let key = String::from_str(env, VERSION_HISTORY_KEY);
@@ -70,10 +88,19 @@ fn set_migration_status(env: &Env, status: String) {
 }
 
 
-pub fn is_version_compatible(_env: &Env, _from_version: String, _to_version: String) -> bool {
-    // Simple compatibility check - for now, assume all versions are compatible
-    // In a real implementation, you would parse semantic versions properly
-    true
+/// Parse a version string, panicking with `VersioningError::InvalidVersion` if it is malformed
+fn parse_version(env: &Env, version: &String) -> SemVer {
+    SemVer::parse(version).unwrap_or_else(|_| panic_with_error!(env, VersioningError::InvalidVersion))
+}
+
+/// Check compatibility between two versions
+///
+/// Moving to the same or a higher version is compatible; downgrades are not.
+pub fn is_version_compatible(env: &Env, from_version: String, to_version: String) -> bool {
+    SemVer::is_compatible_upgrade(
+        &parse_version(env, &from_version),
+        &parse_version(env, &to_version),
+    )
 }
 
 
@@ -115,11 +142,15 @@ pub fn migrate_access_data(
     }
     
 
-    let migration_result: bool = perform_access_data_migration(env, &from_version, &to_version);
+    // Perform the migration, restoring the snapshotted state if it fails
+    let migration_result: bool = run_migration::<AccessDataMigration, _>(env, MIGRATION_SNAPSHOT_ID, || {
+        perform_access_data_migration(env, &from_version, &to_version)
+    });
     
     if migration_result {
         // Update version history with new version
         store_version_in_history(env, to_version.clone());
+        set_current_version(env, to_version.clone());
         
         // Set successful migration status
         let status: String = String::from_str(env, "Migration completed successfully");
@@ -193,16 +224,32 @@ mod test {
     #[test]
     fn test_version_compatibility() {
         let env = Env::default();
-        
-        // All versions are compatible in our simplified implementation
-        assert!(is_version_compatible(&env, 
-            String::from_str(&env, "1.0.0"), 
+
+        assert!(is_version_compatible(&env,
+            String::from_str(&env, "1.0.0"),
             String::from_str(&env, "1.1.0")));
-        
-        // All versions are compatible in our simplified implementation
-        assert!(is_version_compatible(&env, 
-            String::from_str(&env, "1.0.0"), 
+
+        assert!(is_version_compatible(&env,
+            String::from_str(&env, "1.0.0"),
             String::from_str(&env, "2.0.0")));
+
+        assert!(is_version_compatible(&env,
+            String::from_str(&env, "1.1.0"),
+            String::from_str(&env, "1.1.0")));
+
+        // Downgrades are rejected
+        assert!(!is_version_compatible(&env,
+            String::from_str(&env, "2.0.0"),
+            String::from_str(&env, "1.9.9")));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #200)")]
+    fn test_version_compatibility_malformed() {
+        let env = Env::default();
+        is_version_compatible(&env,
+            String::from_str(&env, "1.0"),
+            String::from_str(&env, "1.1.0"));
     }
 
     #[test]
@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String};
+
+use crate::functions::access_control::require_course_manager;
+use crate::schema::{CourseAccess, CourseUsers, DataKey, EnrollmentBreakdown};
+
+/// Counts the enrolled users of a course by the state of their access.
+///
+/// Expired access counts as expired even when it is also paused.
+fn count_enrollments_by_state(env: &Env, course_id: &String) -> EnrollmentBreakdown {
+    let mut breakdown: EnrollmentBreakdown = EnrollmentBreakdown {
+        active: 0,
+        paused: 0,
+        expired: 0,
+    };
+    let Some(course_users) = env
+        .storage()
+        .persistent()
+        .get::<DataKey, CourseUsers>(&DataKey::CourseUsers(course_id.clone()))
+    else {
+        return breakdown;
+    };
+
+    let now: u64 = env.ledger().timestamp();
+    for user in course_users.users.iter() {
+        let Some(access) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, CourseAccess>(&DataKey::CourseAccess(course_id.clone(), user))
+        else {
+            continue;
+        };
+
+        if access.is_expired(now) {
+            breakdown.expired += 1;
+        } else if access.paused {
+            breakdown.paused += 1;
+        } else {
+            breakdown.active += 1;
+        }
+    }
+    breakdown
+}
+
+/// Returns the number of users of a course whose access is neither paused
+/// nor expired, or `0` if the course is unknown.
+pub fn course_access_count_active_enrollments(env: Env, course_id: String) -> u32 {
+    count_enrollments_by_state(&env, &course_id).active
+}
+
+/// Returns the enrolled users of a course split into active, paused and
+/// expired (course creator or admin).
+pub fn course_access_get_enrollment_breakdown(
+    env: Env,
+    caller: Address,
+    course_id: String,
+) -> EnrollmentBreakdown {
+    require_course_manager(&env, &caller, &course_id);
+    count_enrollments_by_state(&env, &course_id)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{mock_course_registry, setup_with_mocks};
+    use crate::schema::EnrollmentBreakdown;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, String};
+
+    #[test]
+    fn test_enrollment_breakdown() {
+        let (env, client, mocks) = setup_with_mocks();
+        env.ledger().set_timestamp(1_000);
+        let course_id = String::from_str(&env, "course_1");
+        let paused = Address::generate(&env);
+        let expiring = Address::generate(&env);
+        client.grant_access(&course_id, &Address::generate(&env));
+        client.grant_access(&course_id, &paused);
+        client.grant_access_with_expiry(&mocks.admin, &course_id, &expiring, &2_000);
+        client.pause_access(&mocks.admin, &course_id, &paused);
+
+        assert_eq!(client.count_active_enrollments(&course_id), 2);
+        assert_eq!(
+            client.get_enrollment_breakdown(&mocks.admin, &course_id),
+            EnrollmentBreakdown {
+                active: 2,
+                paused: 1,
+                expired: 0,
+            }
+        );
+        assert_eq!(client.get_course_enrollment_count(&course_id), 3);
+    }
+
+    #[test]
+    fn test_enrollment_breakdown_follows_transitions() {
+        let (env, client, mocks) = setup_with_mocks();
+        env.ledger().set_timestamp(1_000);
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+        let expiring = Address::generate(&env);
+        client.grant_access(&course_id, &user);
+        client.grant_access_with_expiry(&mocks.admin, &course_id, &expiring, &2_000);
+
+        env.ledger().set_timestamp(2_001);
+        client.pause_access(&mocks.admin, &course_id, &user);
+        assert_eq!(client.count_active_enrollments(&course_id), 0);
+        assert_eq!(
+            client.get_enrollment_breakdown(&mocks.admin, &course_id),
+            EnrollmentBreakdown {
+                active: 0,
+                paused: 1,
+                expired: 1,
+            }
+        );
+
+        client.resume_access(&mocks.admin, &course_id, &user);
+        assert_eq!(client.count_active_enrollments(&course_id), 1);
+        assert_eq!(
+            client.count_active_enrollments(&String::from_str(&env, "missing")),
+            0
+        );
+    }
+
+    #[test]
+    fn test_enrollment_breakdown_by_creator() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let creator = Address::generate(&env);
+        mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry)
+            .set_creator(&course_id, &creator);
+        client.grant_access(&course_id, &Address::generate(&env));
+
+        assert_eq!(
+            client.get_enrollment_breakdown(&creator, &course_id).active,
+            1
+        );
+        let result = client.try_get_enrollment_breakdown(&Address::generate(&env), &course_id);
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(103)))
+        );
+    }
+}
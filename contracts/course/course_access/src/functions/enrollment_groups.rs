@@ -0,0 +1,272 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{require_course_enrollable, require_course_manager};
+use crate::functions::grant_access::{store_course_access, validate_grantable_course};
+use crate::schema::{DataKey, EnrollmentGroup, MAX_GROUP_MEMBERS, MAX_GROUP_NAME_LENGTH};
+
+const ENROLLMENT_GROUP_CREATED_EVENT: &str = "EnrollmentGroupCreated";
+const GROUP_ACCESS_GRANTED_EVENT: &str = "GroupAccessGranted";
+
+fn get_group(env: &Env, group_id: u128) -> EnrollmentGroup {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EnrollmentGroup(group_id))
+        .unwrap_or_else(|| handle_error(env, Error::GroupNotFound))
+}
+
+/// Loads a group after checking `owner` authorized the call and owns it.
+fn require_group_owner(env: &Env, owner: &Address, group_id: u128) -> EnrollmentGroup {
+    owner.require_auth();
+
+    let group: EnrollmentGroup = get_group(env, group_id);
+    if group.owner != *owner {
+        handle_error(env, Error::Unauthorized)
+    }
+    group
+}
+
+fn save_group(env: &Env, group: &EnrollmentGroup) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::EnrollmentGroup(group.id), group);
+}
+
+/// Creates an empty enrollment group owned by `owner`.
+///
+/// Panics with `Error::InvalidGroupName` if `name` is empty or longer than
+/// `MAX_GROUP_NAME_LENGTH`.
+pub fn course_access_create_enrollment_group(
+    env: Env,
+    owner: Address,
+    name: String,
+) -> EnrollmentGroup {
+    owner.require_auth();
+
+    if name.is_empty() || name.len() > MAX_GROUP_NAME_LENGTH {
+        handle_error(&env, Error::InvalidGroupName)
+    }
+
+    let id: u128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::EnrollmentGroupSeq)
+        .unwrap_or(0)
+        + 1;
+    env.storage()
+        .persistent()
+        .set(&DataKey::EnrollmentGroupSeq, &id);
+
+    let group: EnrollmentGroup = EnrollmentGroup {
+        id,
+        name,
+        owner: owner.clone(),
+        members: Vec::new(&env),
+    };
+    save_group(&env, &group);
+
+    env.events().publish(
+        (Symbol::new(&env, ENROLLMENT_GROUP_CREATED_EVENT), id),
+        owner,
+    );
+
+    group
+}
+
+/// Adds a member to a group (group owner only).
+///
+/// # Panics
+///
+/// * `Error::GroupNotFound` if the group does not exist.
+/// * `Error::Unauthorized` if `owner` does not own the group.
+/// * `Error::AlreadyGroupMember` if `member` is already in the group.
+/// * `Error::GroupFull` if the group has `MAX_GROUP_MEMBERS` members.
+pub fn course_access_add_group_member(env: Env, owner: Address, group_id: u128, member: Address) {
+    let mut group: EnrollmentGroup = require_group_owner(&env, &owner, group_id);
+
+    if group.members.contains(&member) {
+        handle_error(&env, Error::AlreadyGroupMember)
+    }
+    if group.members.len() >= MAX_GROUP_MEMBERS {
+        handle_error(&env, Error::GroupFull)
+    }
+
+    group.members.push_back(member);
+    save_group(&env, &group);
+}
+
+/// Removes a member from a group (group owner only). Course access already
+/// granted through the group is kept.
+///
+/// # Panics
+///
+/// * `Error::GroupNotFound` if the group does not exist.
+/// * `Error::Unauthorized` if `owner` does not own the group.
+/// * `Error::NotGroupMember` if `member` is not in the group.
+pub fn course_access_remove_group_member(
+    env: Env,
+    owner: Address,
+    group_id: u128,
+    member: Address,
+) {
+    let mut group: EnrollmentGroup = require_group_owner(&env, &owner, group_id);
+
+    let Some(index) = group.members.first_index_of(&member) else {
+        handle_error(&env, Error::NotGroupMember)
+    };
+    group.members.remove(index);
+    save_group(&env, &group);
+}
+
+/// Grants course access to every current member of a group (course creator
+/// or admin).
+///
+/// Members who already have access are skipped instead of failing the call.
+///
+/// # Returns
+///
+/// * `u32` - The number of newly enrolled members.
+pub fn course_access_grant_group_access(
+    env: Env,
+    caller: Address,
+    group_id: u128,
+    course_id: String,
+) -> u32 {
+    let group: EnrollmentGroup = get_group(&env, group_id);
+    require_course_manager(&env, &caller, &course_id);
+    require_course_enrollable(&env, &course_id, &caller);
+    validate_grantable_course(&env, &course_id);
+
+    let mut granted: u32 = 0;
+    for member in group.members.iter() {
+        if store_course_access(&env, &course_id, &member, None, &caller) {
+            granted += 1;
+        }
+    }
+
+    env.events().publish(
+        (Symbol::new(&env, GROUP_ACCESS_GRANTED_EVENT), group_id),
+        (course_id, granted),
+    );
+
+    granted
+}
+
+/// Returns the members of a group in the order they were added.
+///
+/// Panics with `Error::GroupNotFound` if the group does not exist.
+pub fn course_access_list_group_members(env: Env, group_id: u128) -> Vec<Address> {
+    get_group(&env, group_id).members
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{mock_course_registry, setup_with_mocks};
+    use soroban_sdk::{testutils::Address as _, vec, Address, String};
+
+    #[test]
+    fn test_create_enrollment_group() {
+        let (env, client, _mocks) = setup_with_mocks();
+        let owner = Address::generate(&env);
+
+        let group = client.create_enrollment_group(&owner, &String::from_str(&env, "Acme team"));
+        let second = client.create_enrollment_group(&owner, &String::from_str(&env, "Beta team"));
+
+        assert_eq!(group.id, 1);
+        assert_eq!(second.id, 2);
+        assert_eq!(group.owner, owner);
+        assert!(client.list_group_members(&group.id).is_empty());
+
+        let result = client.try_create_enrollment_group(&owner, &String::from_str(&env, ""));
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(137)))
+        );
+    }
+
+    #[test]
+    fn test_add_and_remove_group_members() {
+        let (env, client, _mocks) = setup_with_mocks();
+        let owner = Address::generate(&env);
+        let group = client.create_enrollment_group(&owner, &String::from_str(&env, "Acme team"));
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+
+        client.add_group_member(&owner, &group.id, &alice);
+        client.add_group_member(&owner, &group.id, &bob);
+        assert_eq!(
+            client.list_group_members(&group.id),
+            vec![&env, alice.clone(), bob.clone()]
+        );
+        let result = client.try_add_group_member(&owner, &group.id, &alice);
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(138)))
+        );
+
+        client.remove_group_member(&owner, &group.id, &alice);
+        assert_eq!(client.list_group_members(&group.id), vec![&env, bob]);
+        let result = client.try_remove_group_member(&owner, &group.id, &alice);
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(139)))
+        );
+    }
+
+    #[test]
+    fn test_grant_group_access_skips_enrolled_members() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let creator = Address::generate(&env);
+        mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry)
+            .set_creator(&course_id, &creator);
+        let owner = Address::generate(&env);
+        let group = client.create_enrollment_group(&owner, &String::from_str(&env, "Acme team"));
+        let enrolled = Address::generate(&env);
+        let new_member = Address::generate(&env);
+        client.add_group_member(&owner, &group.id, &enrolled);
+        client.add_group_member(&owner, &group.id, &new_member);
+        client.grant_access(&course_id, &enrolled);
+
+        assert_eq!(
+            client.grant_group_access(&creator, &group.id, &course_id),
+            1
+        );
+        assert!(client.check_access(&course_id, &enrolled));
+        assert!(client.check_access(&course_id, &new_member));
+        assert_eq!(
+            client.grant_group_access(&creator, &group.id, &course_id),
+            0
+        );
+    }
+
+    #[test]
+    fn test_group_unauthorized_operations() {
+        let (env, client, _mocks) = setup_with_mocks();
+        let owner = Address::generate(&env);
+        let stranger = Address::generate(&env);
+        let group = client.create_enrollment_group(&owner, &String::from_str(&env, "Acme team"));
+        client.add_group_member(&owner, &group.id, &Address::generate(&env));
+
+        let result = client.try_add_group_member(&stranger, &group.id, &stranger);
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(103)))
+        );
+        // The group owner is not a course manager
+        let result =
+            client.try_grant_group_access(&owner, &group.id, &String::from_str(&env, "course_1"));
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(103)))
+        );
+        let result = client.try_list_group_members(&99);
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(136)))
+        );
+    }
+}
@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::functions::access_control::require_course_manager;
+use crate::schema::{AccessEvent, AccessEventType, DataKey, MAX_LIST_PAGE_SIZE};
+
+/// Appends an entry to the append-only access audit log of a course.
+pub fn record_access_event(
+    env: &Env,
+    event_type: AccessEventType,
+    course_id: &String,
+    user: &Address,
+    actor: &Address,
+) {
+    let seq_key: DataKey = DataKey::AccessLogSeq(course_id.clone());
+    let seq: u32 = env.storage().persistent().get(&seq_key).unwrap_or(0);
+
+    let event: AccessEvent = AccessEvent {
+        event_type,
+        course_id: course_id.clone(),
+        user: user.clone(),
+        actor: actor.clone(),
+        timestamp: env.ledger().timestamp(),
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::AccessLogEntry(course_id.clone(), seq), &event);
+    env.storage().persistent().set(&seq_key, &(seq + 1));
+}
+
+/// Returns a page of a course's access audit log, oldest entry first
+/// (course creator or admin).
+///
+/// `limit` is capped at `MAX_LIST_PAGE_SIZE`.
+pub fn course_access_get_access_audit_log(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    offset: u32,
+    limit: u32,
+) -> Vec<AccessEvent> {
+    require_course_manager(&env, &caller, &course_id);
+
+    let total: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AccessLogSeq(course_id.clone()))
+        .unwrap_or(0);
+    let end: u32 = offset
+        .saturating_add(limit.min(MAX_LIST_PAGE_SIZE))
+        .min(total);
+
+    let mut events: Vec<AccessEvent> = Vec::new(&env);
+    for seq in offset..end {
+        if let Some(event) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AccessLogEntry(course_id.clone(), seq))
+        {
+            events.push_back(event);
+        }
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{mock_course_registry, setup_with_mocks};
+    use crate::schema::AccessEventType;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, String};
+
+    #[test]
+    fn test_audit_log_records_each_event_type() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+
+        env.ledger().set_timestamp(100);
+        client.grant_access_with_expiry(&mocks.admin, &course_id, &user, &200);
+        client.pause_access(&mocks.admin, &course_id, &user);
+        client.resume_access(&mocks.admin, &course_id, &user);
+        env.ledger().set_timestamp(300);
+        client.grant_access(&course_id, &user);
+        client.revoke_access(&course_id, &user);
+
+        let log = client.get_access_audit_log(&mocks.admin, &course_id, &0, &10);
+        assert_eq!(log.len(), 6);
+        let expected = [
+            AccessEventType::Granted,
+            AccessEventType::Paused,
+            AccessEventType::Resumed,
+            AccessEventType::Expired,
+            AccessEventType::Granted,
+            AccessEventType::Revoked,
+        ];
+        for (event, event_type) in log.iter().zip(expected) {
+            assert_eq!(event.event_type, event_type);
+            assert_eq!(event.user, user);
+        }
+
+        let first = log.get(0).unwrap();
+        assert_eq!(first.actor, mocks.admin);
+        assert_eq!(first.timestamp, 100);
+        assert_eq!(log.get(5).unwrap().actor, client.address);
+    }
+
+    #[test]
+    fn test_audit_log_pagination() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        for _ in 0..3 {
+            client.grant_access(&course_id, &Address::generate(&env));
+        }
+
+        assert_eq!(client.get_access_audit_log(&mocks.admin, &course_id, &0, &2).len(), 2);
+        assert_eq!(client.get_access_audit_log(&mocks.admin, &course_id, &2, &2).len(), 1);
+        assert_eq!(client.get_access_audit_log(&mocks.admin, &course_id, &5, &2).len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #103)")]
+    fn test_audit_log_unauthorized() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let creator = Address::generate(&env);
+        mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry)
+            .set_creator(&course_id, &creator);
+
+        client.get_access_audit_log(&Address::generate(&env), &course_id, &0, &10);
+    }
+}
@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::schema::{CourseAccess, DataKey, UserCourses};
+
+/// Returns the access record of `user` for `course_id`, including expired
+/// and paused access.
+///
+/// This is a read-only query that requires no authentication.
+///
+/// Panics with `Error::UserNoAccessCourse` if the user has no access record
+/// for the course.
+pub fn course_access_get_access_details(
+    env: Env,
+    user: Address,
+    course_id: String,
+) -> CourseAccess {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseAccess(course_id, user))
+        .unwrap_or_else(|| handle_error(&env, Error::UserNoAccessCourse))
+}
+
+/// Returns the access records of every course `user` is enrolled in, in the
+/// order of the user's course list.
+///
+/// This is a read-only query that requires no authentication.
+pub fn course_access_get_user_access_snapshot(env: Env, user: Address) -> Vec<CourseAccess> {
+    let mut snapshot: Vec<CourseAccess> = Vec::new(&env);
+    let Some(user_courses) = env
+        .storage()
+        .persistent()
+        .get::<DataKey, UserCourses>(&DataKey::UserCourses(user.clone()))
+    else {
+        return snapshot;
+    };
+
+    for course_id in user_courses.courses.iter() {
+        if let Some(access) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, CourseAccess>(&DataKey::CourseAccess(course_id, user.clone()))
+        {
+            snapshot.push_back(access);
+        }
+    }
+    snapshot
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, String};
+
+    #[test]
+    fn test_get_access_details() {
+        let (env, client, mocks) = setup_with_mocks();
+        env.ledger().set_timestamp(1_000);
+        let user = Address::generate(&env);
+        let course_id = String::from_str(&env, "course_1");
+        client.grant_access_with_expiry(&mocks.admin, &course_id, &user, &5_000);
+        client.pause_access(&mocks.admin, &course_id, &user);
+
+        let access = client.get_access_details(&user, &course_id);
+
+        assert_eq!(access.course_id, course_id);
+        assert_eq!(access.user, user);
+        assert_eq!(access.expiry_timestamp, Some(5_000));
+        assert!(access.paused);
+    }
+
+    #[test]
+    fn test_get_access_details_without_access() {
+        let (env, client, _mocks) = setup_with_mocks();
+
+        let result = client.try_get_access_details(
+            &Address::generate(&env),
+            &String::from_str(&env, "course_1"),
+        );
+
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(102)))
+        );
+    }
+
+    #[test]
+    fn test_get_user_access_snapshot() {
+        let (env, client, mocks) = setup_with_mocks();
+        env.ledger().set_timestamp(1_000);
+        let user = Address::generate(&env);
+        let first = String::from_str(&env, "course_1");
+        let second = String::from_str(&env, "course_2");
+        client.grant_access(&first, &user);
+        client.grant_access_with_expiry(&mocks.admin, &second, &user, &5_000);
+        client.grant_access(&first, &Address::generate(&env));
+
+        let snapshot = client.get_user_access_snapshot(&user);
+
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(
+            snapshot.get(0).unwrap(),
+            client.get_access_details(&user, &first)
+        );
+        assert_eq!(snapshot.get(1).unwrap().expiry_timestamp, Some(5_000));
+        assert!(client
+            .get_user_access_snapshot(&Address::generate(&env))
+            .is_empty());
+    }
+}
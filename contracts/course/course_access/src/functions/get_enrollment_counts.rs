@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use shared::{touch_persistent_ttl, DEFAULT_PERSISTENT_TTL, DEFAULT_PERSISTENT_TTL_BUMP};
+use soroban_sdk::{Address, Env, String};
+
+use crate::schema::{CourseUsers, DataKey, UserCourses};
+
+/// Returns the number of courses a user has access to, or `0` if unknown.
+pub fn course_access_get_user_access_count(env: Env, user: Address) -> u32 {
+    let key: DataKey = DataKey::UserCourses(user);
+    let user_courses: Option<UserCourses> = env.storage().persistent().get(&key);
+    touch_persistent_ttl(&env, &key, DEFAULT_PERSISTENT_TTL_BUMP, DEFAULT_PERSISTENT_TTL);
+
+    user_courses.map_or(0, |user_courses| user_courses.courses.len())
+}
+
+/// Returns the number of users enrolled in a course, or `0` if unknown.
+pub fn course_access_get_course_enrollment_count(env: Env, course_id: String) -> u32 {
+    let key: DataKey = DataKey::CourseUsers(course_id);
+    let course_users: Option<CourseUsers> = env.storage().persistent().get(&key);
+    touch_persistent_ttl(&env, &key, DEFAULT_PERSISTENT_TTL_BUMP, DEFAULT_PERSISTENT_TTL);
+
+    course_users.map_or(0, |course_users| course_users.users.len())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::{CourseAccessContract, CourseAccessContractClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    #[test]
+    fn test_enrollment_counts() {
+        let (env, client, _mocks) = setup_with_mocks();
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let course_1 = String::from_str(&env, "course_1");
+        let course_2 = String::from_str(&env, "course_2");
+
+        client.grant_access(&course_1, &alice);
+        client.grant_access(&course_2, &alice);
+        client.grant_access(&course_1, &bob);
+        assert_eq!(client.get_user_access_count(&alice), 2);
+        assert_eq!(client.get_user_access_count(&bob), 1);
+        assert_eq!(client.get_course_enrollment_count(&course_1), 2);
+        assert_eq!(client.get_course_enrollment_count(&course_2), 1);
+
+        client.revoke_access(&course_1, &alice);
+        assert_eq!(client.get_user_access_count(&alice), 1);
+        assert_eq!(client.get_course_enrollment_count(&course_1), 1);
+    }
+
+    #[test]
+    fn test_enrollment_counts_unknown() {
+        let env = Env::default();
+        let contract_id = env.register(CourseAccessContract, ());
+        let client = CourseAccessContractClient::new(&env, &contract_id);
+
+        assert_eq!(client.get_user_access_count(&Address::generate(&env)), 0);
+        assert_eq!(client.get_course_enrollment_count(&String::from_str(&env, "missing")), 0);
+    }
+}
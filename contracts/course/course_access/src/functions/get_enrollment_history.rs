@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::functions::get_access_details::{
+    course_access_get_access_details, course_access_get_user_access_snapshot,
+};
+use crate::schema::{CourseAccess, MAX_LIST_PAGE_SIZE};
+use shared::Paginator;
+
+/// Returns a page of the user's access records, most recent enrollment first.
+///
+/// Requires authentication from `user`. `limit` is clamped to
+/// `MAX_LIST_PAGE_SIZE`.
+pub fn course_access_get_enrollment_history(
+    env: Env,
+    user: Address,
+    offset: u32,
+    limit: u32,
+) -> Vec<CourseAccess> {
+    user.require_auth();
+
+    let records: Vec<CourseAccess> = course_access_get_user_access_snapshot(env.clone(), user);
+
+    // Insertion sort by enrollment time, newest first; ties keep list order
+    let mut sorted: Vec<CourseAccess> = Vec::new(&env);
+    for record in records.iter() {
+        let mut index: u32 = 0;
+        while index < sorted.len() && sorted.get_unchecked(index).enrolled_at >= record.enrolled_at
+        {
+            index += 1;
+        }
+        sorted.insert(index, record);
+    }
+
+    Paginator::new(offset, limit, MAX_LIST_PAGE_SIZE)
+        .paginate(&sorted, &env)
+        .0
+}
+
+/// Returns the ledger timestamp at which `user` was granted access to
+/// `course_id`.
+///
+/// Panics with `Error::UserNoAccessCourse` if the user has no access record
+/// for the course.
+pub fn course_access_get_enrollment_timestamp(env: Env, user: Address, course_id: String) -> u64 {
+    course_access_get_access_details(env, user, course_id).enrolled_at
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, String};
+
+    #[test]
+    fn test_enrollment_history_ordering() {
+        let (env, client, _mocks) = setup_with_mocks();
+        let user = Address::generate(&env);
+        for (course, timestamp) in [
+            ("course_1", 1_000),
+            ("course_2", 3_000),
+            ("course_3", 2_000),
+        ] {
+            env.ledger().set_timestamp(timestamp);
+            client.grant_access(&String::from_str(&env, course), &user);
+        }
+
+        let history = client.get_enrollment_history(&user, &0, &10);
+        assert_eq!(history.len(), 3);
+        assert_eq!(
+            history.get(0).unwrap().course_id,
+            String::from_str(&env, "course_2")
+        );
+        assert_eq!(
+            history.get(1).unwrap().course_id,
+            String::from_str(&env, "course_3")
+        );
+        assert_eq!(
+            history.get(2).unwrap().course_id,
+            String::from_str(&env, "course_1")
+        );
+
+        let page = client.get_enrollment_history(&user, &1, &1);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().enrolled_at, 2_000);
+        assert!(client.get_enrollment_history(&user, &3, &10).is_empty());
+    }
+
+    #[test]
+    fn test_enrollment_history_empty() {
+        let (env, client, _mocks) = setup_with_mocks();
+        assert!(client
+            .get_enrollment_history(&Address::generate(&env), &0, &10)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_get_enrollment_timestamp() {
+        let (env, client, _mocks) = setup_with_mocks();
+        let user = Address::generate(&env);
+        let course_id = String::from_str(&env, "course_1");
+        env.ledger().set_timestamp(5_000);
+        client.grant_access(&course_id, &user);
+
+        assert_eq!(client.get_enrollment_timestamp(&user, &course_id), 5_000);
+
+        let result =
+            client.try_get_enrollment_timestamp(&user, &String::from_str(&env, "course_2"));
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(102)))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Auth, InvalidAction)")]
+    fn test_enrollment_history_unauthorized() {
+        let (env, client, _mocks) = setup_with_mocks();
+        let user = Address::generate(&env);
+        client.grant_access(&String::from_str(&env, "course_1"), &user);
+
+        env.set_auths(&[]);
+        client.get_enrollment_history(&user, &0, &10);
+    }
+}
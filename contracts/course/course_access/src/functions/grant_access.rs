@@ -3,34 +3,111 @@
 
 use soroban_sdk::{Address, Env, String, Vec, Symbol, symbol_short};
 
-use crate::schema::{CourseAccess, DataKey, UserCourses, CourseUsers};
+use crate::schema::{AccessEventType, CourseAccess, DataKey, UserCourses, CourseUsers};
 use crate::error::{Error, handle_error};
+use crate::functions::access_control::{
+    get_course_max_enrollments, is_course_archived, is_course_frozen, require_not_blacklisted,
+    validate_course_enrollable,
+};
+use crate::functions::get_access_audit_log::record_access_event;
+use crate::functions::get_enrollment_counts::course_access_get_course_enrollment_count;
+use crate::functions::prerequisite_check::check_prerequisite_completion_before_grant;
 
 const COURSE_ACCESS_EVENT: Symbol = symbol_short!("crsAccess");
 
 /// Grant access to a specific user for a given course
 pub fn course_access_grant_access(env: Env, course_id: String, user: Address) {
+    // There is no caller to authorize, so no admin can bypass the check
+    validate_course_enrollable(&env, &course_id);
+    grant_course_access(&env, &course_id, &user, &env.current_contract_address());
+}
+
+/// Grants access on behalf of `actor`, who is recorded in the audit log.
+///
+/// Panics with `Error::UserAlreadyHasAccess` if the user already has access.
+pub fn grant_course_access(env: &Env, course_id: &String, user: &Address, actor: &Address) {
+    validate_grantable_course(env, course_id);
+
+    // Check if access already exists to prevent duplicates
+    if !store_course_access(env, course_id, user, None, actor) {
+        handle_error(env, Error::UserAlreadyHasAccess)
+    }
+}
+
+/// Panics unless `course_id` is well-formed and open for enrollment.
+pub fn validate_grantable_course(env: &Env, course_id: &String) {
+    if let Some(error) = grantable_course_error(env, course_id) {
+        handle_error(env, error)
+    }
+}
+
+/// Returns the error `validate_grantable_course` would raise, if any.
+pub fn grantable_course_error(env: &Env, course_id: &String) -> Option<Error> {
     // Validate input parameters
     if course_id.is_empty() {
-        handle_error(&env, Error::EmptyCourseId);
+        return Some(Error::EmptyCourseId);
     }
     
     // Check course_id length to prevent extremely long IDs
     if course_id.len() > 100 {
-        handle_error(&env, Error::InvalidCourseId);
+        return Some(Error::InvalidCourseId);
+    }
+
+    // Archived courses no longer accept enrollments
+    if is_course_archived(env, course_id) {
+        return Some(Error::CourseArchived);
     }
 
+    // Frozen courses keep their enrolled users but take no new ones
+    if is_course_frozen(env, course_id) {
+        return Some(Error::CourseFrozen);
+    }
+
+    None
+}
+
+/// Stores a course access entry and updates the user and course indexes.
+///
+/// Returns `false` without changing anything if the user already has access
+/// that has not expired. Expired access is replaced by the new entry. The grant
+/// (and the expiry it replaces) is recorded in the audit log under `actor`.
+///
+/// Panics with `Error::UserBlacklisted` if the user is blacklisted,
+/// `Error::PrerequisitesNotMet` if prerequisites are required and the user
+/// lacks one, or `Error::CourseAtCapacity` if the course enrollment cap is
+/// reached.
+pub fn store_course_access(
+    env: &Env,
+    course_id: &String,
+    user: &Address,
+    expiry_timestamp: Option<u64>,
+    actor: &Address,
+) -> bool {
+    // Every grant path ends here, so blacklisted users can't slip through any of them
+    require_not_blacklisted(env, user);
+
     let key: DataKey = DataKey::CourseAccess(course_id.clone(), user.clone());
+    let replaces_expired: bool = match env.storage().persistent().get::<DataKey, CourseAccess>(&key) {
+        Some(existing) if !existing.is_expired(env.ledger().timestamp()) => return false,
+        Some(_) => true,
+        None => false,
+    };
 
-    // Check if access already exists to prevent duplicates
-    if env.storage().persistent().has(&key) {
-        handle_error(&env, Error::UserAlreadyHasAccess)
+    check_prerequisite_completion_before_grant(env, course_id, user, actor);
+
+    if let Some(cap) = get_course_max_enrollments(env, course_id) {
+        if course_access_get_course_enrollment_count(env.clone(), course_id.clone()) >= cap {
+            handle_error(env, Error::CourseAtCapacity)
+        }
     }
 
     // Create the course access entry
     let course_access: CourseAccess = CourseAccess {
         course_id: course_id.clone(),
         user: user.clone(),
+        expiry_timestamp,
+        paused: false,
+        enrolled_at: env.ledger().timestamp(),
     };
 
     // Store the access entry
@@ -45,9 +122,9 @@ pub fn course_access_grant_access(env: Env, course_id: String, user: Address) {
         .get(&user_courses_key)
         .unwrap_or(UserCourses {
             user: user.clone(),
-            courses: Vec::new(&env),
+            courses: Vec::new(env),
         });
-    if !user_courses.courses.contains(&course_id) {
+    if !user_courses.courses.contains(course_id) {
         user_courses.courses.push_back(course_id.clone());
         env.storage().persistent().set(&user_courses_key, &user_courses);
         env.storage().persistent().extend_ttl(&user_courses_key, 100, 1000);
@@ -61,13 +138,169 @@ pub fn course_access_grant_access(env: Env, course_id: String, user: Address) {
         .get(&course_users_key)
         .unwrap_or(CourseUsers {
             course: course_id.clone(),
-            users: Vec::new(&env),
+            users: Vec::new(env),
         });
-    if !course_users.users.contains(&user) {
+    if !course_users.users.contains(user) {
         course_users.users.push_back(user.clone());
         env.storage().persistent().set(&course_users_key, &course_users);
         env.storage().persistent().extend_ttl(&course_users_key, 100, 1000);
     }
     env.events()
-        .publish((COURSE_ACCESS_EVENT, &user.clone()), (course_id, user, course_users.users.len(),));
+        .publish((COURSE_ACCESS_EVENT, &user.clone()), (course_id.clone(), user.clone(), course_users.users.len(),));
+
+    if replaces_expired {
+        record_access_event(env, AccessEventType::Expired, course_id, user, &env.current_contract_address());
+    }
+    record_access_event(env, AccessEventType::Granted, course_id, user, actor);
+
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{mock_course_registry, mock_user_management, setup_with_mocks};
+    use crate::{CourseAccessContract, CourseAccessContractClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    #[test]
+    fn test_grant_access_to_active_course() {
+        let (env, client, _mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+
+        client.grant_access(&course_id, &user);
+        assert!(client.check_access(&course_id, &user));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #116)")]
+    fn test_grant_access_to_archived_course() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry)
+            .set_archived(&course_id);
+
+        client.grant_access(&course_id, &Address::generate(&env));
+    }
+
+    #[test]
+    fn test_grant_access_to_frozen_course() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let enrolled = Address::generate(&env);
+        client.grant_access(&course_id, &enrolled);
+        let registry = mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry);
+        registry.set_frozen(&course_id, &true);
+
+        let result = client.try_grant_access(&course_id, &Address::generate(&env));
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(135))));
+        // Users enrolled before the freeze keep their access
+        assert!(client.check_access(&course_id, &enrolled));
+
+        registry.set_frozen(&course_id, &false);
+        let user = Address::generate(&env);
+        client.grant_access(&course_id, &user);
+        assert!(client.check_access(&course_id, &user));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #133)")]
+    fn test_grant_access_to_unpublished_course() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry)
+            .set_unpublished(&course_id);
+
+        client.grant_access(&course_id, &Address::generate(&env));
+    }
+
+    #[test]
+    fn test_grant_access_without_course_registry() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let client = CourseAccessContractClient::new(&env, &env.register(CourseAccessContract, ()));
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+
+        client.grant_access(&course_id, &user);
+        assert!(client.check_access(&course_id, &user));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #134)")]
+    fn test_grant_access_to_blacklisted_user() {
+        let (env, client, mocks) = setup_with_mocks();
+        let user = Address::generate(&env);
+        mock_user_management::UserManagementClient::new(&env, &mocks.user_management)
+            .set_blacklisted(&user);
+
+        client.grant_access(&String::from_str(&env, "course_1"), &user);
+    }
+
+    #[test]
+    fn test_enrollability_check_on_batch_grant() {
+        let (env, client, mocks) = setup_with_mocks();
+        let registry = mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry);
+        let course_id = String::from_str(&env, "course_1");
+        let creator = Address::generate(&env);
+        registry.set_creator(&course_id, &creator);
+        registry.set_unpublished(&course_id);
+        let users = vec![&env, Address::generate(&env)];
+
+        let result = client.try_grant_access_batch(&creator, &course_id, &users);
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(133))));
+
+        // Admins bypass the check
+        assert_eq!(client.grant_access_batch(&mocks.admin, &course_id, &users), 1);
+        assert!(client.check_access(&course_id, &users.get(0).unwrap()));
+    }
+
+    #[test]
+    fn test_batch_grant_to_frozen_course() {
+        let (env, client, mocks) = setup_with_mocks();
+        let registry = mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry);
+        let course_id = String::from_str(&env, "course_1");
+        let creator = Address::generate(&env);
+        registry.set_creator(&course_id, &creator);
+        registry.set_frozen(&course_id, &true);
+        let users = vec![&env, Address::generate(&env)];
+
+        let result = client.try_grant_access_batch(&creator, &course_id, &users);
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(135))));
+    }
+
+    #[test]
+    fn test_grant_access_below_capacity() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry)
+            .set_max_enrollments(&course_id, &2);
+
+        client.grant_access(&course_id, &Address::generate(&env));
+        client.grant_access(&course_id, &Address::generate(&env));
+        assert_eq!(client.get_course_enrollment_count(&course_id), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #128)")]
+    fn test_grant_access_at_capacity() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry)
+            .set_max_enrollments(&course_id, &1);
+
+        client.grant_access(&course_id, &Address::generate(&env));
+        client.grant_access(&course_id, &Address::generate(&env));
+    }
+
+    #[test]
+    fn test_grant_access_unlimited() {
+        let (env, client, _mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+
+        for _ in 0..5 {
+            client.grant_access(&course_id, &Address::generate(&env));
+        }
+        assert_eq!(client.get_course_enrollment_count(&course_id), 5);
+    }
 }
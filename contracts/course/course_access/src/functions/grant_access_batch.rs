@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{require_course_enrollable, require_course_manager};
+use crate::functions::grant_access::{store_course_access, validate_grantable_course};
+use crate::schema::MAX_BATCH_SIZE;
+
+const BATCH_ACCESS_GRANTED_EVENT: &str = "BatchAccessGranted";
+
+/// Grants course access to several users at once (course creator or admin).
+///
+/// Users who already have access are skipped instead of failing the batch.
+///
+/// # Returns
+///
+/// * `u32` - The number of newly enrolled users.
+pub fn course_access_grant_access_batch(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    users: Vec<Address>,
+) -> u32 {
+    validate_batch_size(&env, &users);
+    require_course_manager(&env, &caller, &course_id);
+    require_course_enrollable(&env, &course_id, &caller);
+    validate_grantable_course(&env, &course_id);
+
+    let mut granted: u32 = 0;
+    for user in users.iter() {
+        if store_course_access(&env, &course_id, &user, None, &caller) {
+            granted += 1;
+        }
+    }
+
+    env.events().publish(
+        (Symbol::new(&env, BATCH_ACCESS_GRANTED_EVENT), course_id),
+        granted,
+    );
+
+    granted
+}
+
+/// Panics unless `users` holds between one and `MAX_BATCH_SIZE` entries.
+pub fn validate_batch_size(env: &Env, users: &Vec<Address>) {
+    if users.is_empty() {
+        handle_error(env, Error::EmptyBatch)
+    }
+    if users.len() > MAX_BATCH_SIZE {
+        handle_error(env, Error::BatchTooLarge)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{mock_course_registry, setup_with_mocks};
+    use crate::schema::MAX_BATCH_SIZE;
+    use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+
+    fn generate_users(env: &Env, count: u32) -> Vec<Address> {
+        let mut users: Vec<Address> = Vec::new(env);
+        for _ in 0..count {
+            users.push_back(Address::generate(env));
+        }
+        users
+    }
+
+    #[test]
+    fn test_grant_access_batch() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let users = generate_users(&env, 3);
+
+        assert_eq!(client.grant_access_batch(&mocks.admin, &course_id, &users), 3);
+        for user in users.iter() {
+            assert!(client.check_access(&course_id, &user));
+        }
+    }
+
+    #[test]
+    fn test_grant_access_batch_skips_enrolled_users() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let creator = Address::generate(&env);
+        mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry)
+            .set_creator(&course_id, &creator);
+        let users = generate_users(&env, 3);
+        client.grant_access(&course_id, &users.get(1).unwrap());
+
+        assert_eq!(client.grant_access_batch(&creator, &course_id, &users), 2);
+        assert_eq!(client.list_course_access(&course_id).users.len(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #117)")]
+    fn test_grant_access_batch_too_large() {
+        let (env, client, mocks) = setup_with_mocks();
+        let users = generate_users(&env, MAX_BATCH_SIZE + 1);
+
+        client.grant_access_batch(&mocks.admin, &String::from_str(&env, "course_1"), &users);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #128)")]
+    fn test_grant_access_batch_over_capacity() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry)
+            .set_max_enrollments(&course_id, &2);
+
+        client.grant_access_batch(&mocks.admin, &course_id, &generate_users(&env, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #103)")]
+    fn test_grant_access_batch_unauthorized() {
+        let (env, client, _mocks) = setup_with_mocks();
+        let stranger = Address::generate(&env);
+        let users = generate_users(&env, 2);
+
+        client.grant_access_batch(&stranger, &String::from_str(&env, "course_1"), &users);
+    }
+}
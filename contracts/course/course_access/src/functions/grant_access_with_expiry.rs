@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::require_course_manager;
+use crate::functions::grant_access::{store_course_access, validate_grantable_course};
+use crate::schema::{CourseAccess, DataKey};
+
+const ACCESS_EXTENDED_EVENT: &str = "AccessExtended";
+
+/// Grants course access that expires at `expiry_timestamp` (course creator or admin).
+///
+/// # Panics
+///
+/// * `Error::InvalidExpiry` if `expiry_timestamp` is not in the future.
+/// * `Error::Unauthorized` if the caller is neither the course creator nor an admin.
+/// * `Error::UserAlreadyHasAccess` if the user already has unexpired access.
+pub fn course_access_grant_access_with_expiry(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    user: Address,
+    expiry_timestamp: u64,
+) {
+    if expiry_timestamp <= env.ledger().timestamp() {
+        handle_error(&env, Error::InvalidExpiry)
+    }
+    validate_grantable_course(&env, &course_id);
+    require_course_manager(&env, &caller, &course_id);
+
+    if !store_course_access(&env, &course_id, &user, Some(expiry_timestamp), &caller) {
+        handle_error(&env, Error::UserAlreadyHasAccess)
+    }
+}
+
+/// Pushes the expiry of a user's course access forward (course creator or admin).
+///
+/// # Panics
+///
+/// * `Error::UserNoAccessCourse` if the user has no access record for the course.
+/// * `Error::InvalidExpiry` if the access never expires, or `new_expiry` is not
+///   later than both the current time and the current expiry.
+/// * `Error::Unauthorized` if the caller is neither the course creator nor an admin.
+pub fn course_access_extend_access(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    user: Address,
+    new_expiry: u64,
+) {
+    require_course_manager(&env, &caller, &course_id);
+
+    let key: DataKey = DataKey::CourseAccess(course_id.clone(), user.clone());
+    let mut access: CourseAccess = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| handle_error(&env, Error::UserNoAccessCourse));
+
+    let current_expiry: u64 = access
+        .expiry_timestamp
+        .unwrap_or_else(|| handle_error(&env, Error::InvalidExpiry));
+    if new_expiry <= current_expiry || new_expiry <= env.ledger().timestamp() {
+        handle_error(&env, Error::InvalidExpiry)
+    }
+
+    access.expiry_timestamp = Some(new_expiry);
+    env.storage().persistent().set(&key, &access);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+
+    env.events().publish(
+        (Symbol::new(&env, ACCESS_EXTENDED_EVENT), course_id),
+        (user, new_expiry),
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, String};
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #119)")]
+    fn test_grant_access_with_past_expiry() {
+        let (env, client, mocks) = setup_with_mocks();
+        env.ledger().set_timestamp(1000);
+
+        client.grant_access_with_expiry(
+            &mocks.admin,
+            &String::from_str(&env, "course_1"),
+            &Address::generate(&env),
+            &500,
+        );
+    }
+
+    #[test]
+    fn test_access_expires() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+        env.ledger().set_timestamp(1000);
+
+        client.grant_access_with_expiry(&mocks.admin, &course_id, &user, &2000);
+        assert!(client.check_access(&course_id, &user));
+
+        env.ledger().set_timestamp(2000);
+        assert!(client.check_access(&course_id, &user));
+
+        env.ledger().set_timestamp(2001);
+        assert!(!client.check_access(&course_id, &user));
+
+        // Expired access can be granted again
+        client.grant_access_with_expiry(&mocks.admin, &course_id, &user, &3000);
+        assert!(client.check_access(&course_id, &user));
+    }
+
+    #[test]
+    fn test_extend_access() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+        env.ledger().set_timestamp(1000);
+        client.grant_access_with_expiry(&mocks.admin, &course_id, &user, &2000);
+
+        client.extend_access(&mocks.admin, &course_id, &user, &5000);
+
+        env.ledger().set_timestamp(4000);
+        assert!(client.check_access(&course_id, &user));
+        env.ledger().set_timestamp(5001);
+        assert!(!client.check_access(&course_id, &user));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #119)")]
+    fn test_extend_access_backwards() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+        env.ledger().set_timestamp(1000);
+        client.grant_access_with_expiry(&mocks.admin, &course_id, &user, &2000);
+
+        client.extend_access(&mocks.admin, &course_id, &user, &1500);
+    }
+}
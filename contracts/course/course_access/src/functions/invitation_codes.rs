@@ -0,0 +1,217 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::require_course_manager;
+use crate::functions::check_access::course_access_check_access;
+use crate::functions::grant_access::{grant_course_access, validate_grantable_course};
+use crate::schema::{DataKey, InvitationCode};
+
+const INVITATION_CODE_CREATED_EVENT: &str = "InvitationCodeCreated";
+const INVITATION_CODE_REDEEMED_EVENT: &str = "InvitationCodeRedeemed";
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// Creates an invitation code for a course (course creator or admin).
+///
+/// The code is the first 8 hex characters of the SHA-256 of the course ID,
+/// the creator, the ledger timestamp and a contract-wide counter, so codes
+/// created in the same ledger still differ.
+///
+/// # Panics
+///
+/// * `Error::InvalidMaxUses` if `max_uses` is zero.
+/// * `Error::InvalidExpiry` if `expires_at` is not in the future.
+/// * `Error::Unauthorized` if the caller is neither the course creator nor an admin.
+pub fn course_access_create_invitation_code(
+    env: Env,
+    creator: Address,
+    course_id: String,
+    max_uses: u32,
+    expires_at: Option<u64>,
+) -> String {
+    if max_uses == 0 {
+        handle_error(&env, Error::InvalidMaxUses)
+    }
+    if expires_at.is_some_and(|expiry| expiry <= env.ledger().timestamp()) {
+        handle_error(&env, Error::InvalidExpiry)
+    }
+    validate_grantable_course(&env, &course_id);
+    require_course_manager(&env, &creator, &course_id);
+
+    let mut code: String = generate_code(&env, &course_id, &creator);
+    while env
+        .storage()
+        .persistent()
+        .has(&DataKey::InvitationCode(code.clone()))
+    {
+        code = generate_code(&env, &course_id, &creator);
+    }
+
+    let invitation: InvitationCode = InvitationCode {
+        code: code.clone(),
+        course_id: course_id.clone(),
+        max_uses,
+        used_count: 0,
+        expires_at,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::InvitationCode(code.clone()), &invitation);
+
+    env.events().publish(
+        (Symbol::new(&env, INVITATION_CODE_CREATED_EVENT), course_id),
+        (creator, max_uses, expires_at),
+    );
+
+    code
+}
+
+/// Redeems an invitation code and grants the user access to its course.
+///
+/// Redeeming a code for a course the user can already access is a no-op and
+/// does not consume a use.
+///
+/// # Panics
+///
+/// * `Error::InvitationCodeNotFound` if the code does not exist.
+/// * `Error::InvitationCodeExhausted` if the code has no uses left.
+/// * `Error::InvitationCodeExpired` if the code has expired.
+pub fn course_access_redeem_invitation_code(env: Env, user: Address, code: String) {
+    user.require_auth();
+
+    let key: DataKey = DataKey::InvitationCode(code);
+    let mut invitation: InvitationCode = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| handle_error(&env, Error::InvitationCodeNotFound));
+
+    if course_access_check_access(env.clone(), invitation.course_id.clone(), user.clone()) {
+        return;
+    }
+    if invitation.used_count >= invitation.max_uses {
+        handle_error(&env, Error::InvitationCodeExhausted)
+    }
+    if invitation
+        .expires_at
+        .is_some_and(|expiry| env.ledger().timestamp() >= expiry)
+    {
+        handle_error(&env, Error::InvitationCodeExpired)
+    }
+
+    invitation.used_count += 1;
+    env.storage().persistent().set(&key, &invitation);
+    grant_course_access(&env, &invitation.course_id, &user, &user);
+
+    env.events().publish(
+        (Symbol::new(&env, INVITATION_CODE_REDEEMED_EVENT), invitation.course_id),
+        user,
+    );
+}
+
+fn generate_code(env: &Env, course_id: &String, creator: &Address) -> String {
+    let seq: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::InvitationCodeSeq)
+        .unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&DataKey::InvitationCodeSeq, &(seq + 1));
+
+    let mut preimage: Bytes = course_id.clone().to_xdr(env);
+    preimage.append(&creator.clone().to_xdr(env));
+    preimage.extend_from_array(&env.ledger().timestamp().to_be_bytes());
+    preimage.extend_from_array(&seq.to_be_bytes());
+    let hash: [u8; 32] = env.crypto().sha256(&preimage).to_array();
+
+    let mut hex: [u8; 8] = [0; 8];
+    for (index, byte) in hash.iter().take(4).enumerate() {
+        hex[index * 2] = HEX_DIGITS[(byte >> 4) as usize];
+        hex[index * 2 + 1] = HEX_DIGITS[(byte & 0x0f) as usize];
+    }
+    String::from_bytes(env, &hex)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{mock_user_management, setup_with_mocks};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, String};
+
+    #[test]
+    fn test_redeem_invitation_code() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+
+        let code = client.create_invitation_code(&mocks.admin, &course_id, &5, &None);
+        assert_eq!(code.len(), 8);
+        let other = client.create_invitation_code(&mocks.admin, &course_id, &5, &None);
+        assert_ne!(code, other);
+
+        client.redeem_invitation_code(&user, &code);
+        assert!(client.check_access(&course_id, &user));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #123)")]
+    fn test_redeem_exhausted_invitation_code() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+
+        let code = client.create_invitation_code(&mocks.admin, &course_id, &1, &None);
+        client.redeem_invitation_code(&Address::generate(&env), &code);
+        client.redeem_invitation_code(&Address::generate(&env), &code);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #124)")]
+    fn test_redeem_expired_invitation_code() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        env.ledger().set_timestamp(1000);
+
+        let code = client.create_invitation_code(&mocks.admin, &course_id, &5, &Some(2000));
+        env.ledger().set_timestamp(2000);
+        client.redeem_invitation_code(&Address::generate(&env), &code);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #122)")]
+    fn test_redeem_unknown_invitation_code() {
+        let (env, client, _mocks) = setup_with_mocks();
+
+        client.redeem_invitation_code(&Address::generate(&env), &String::from_str(&env, "deadbeef"));
+    }
+
+    #[test]
+    fn test_redeem_invitation_code_twice() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+        let code = client.create_invitation_code(&mocks.admin, &course_id, &1, &None);
+
+        client.redeem_invitation_code(&user, &code);
+        // The second redemption neither fails nor consumes the last use
+        client.redeem_invitation_code(&user, &code);
+        assert!(client.check_access(&course_id, &user));
+    }
+
+    #[test]
+    fn test_redeem_invitation_code_blacklisted_user() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+        mock_user_management::UserManagementClient::new(&env, &mocks.user_management)
+            .set_blacklisted(&user);
+        let code = client.create_invitation_code(&mocks.admin, &course_id, &1, &None);
+
+        let result = client.try_redeem_invitation_code(&user, &code);
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(134))));
+        assert!(!client.check_access(&course_id, &user));
+    }
+}
@@ -0,0 +1,229 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::functions::access_control::{
+    get_certified_course_ids, get_course_prerequisites, get_existing_course_prerequisites,
+};
+use crate::functions::check_access::course_access_check_access;
+use crate::schema::LearningPathProgress;
+
+/// Returns the entry course followed by every course it transitively
+/// requires, breadth-first and without duplicates.
+fn collect_learning_path(env: &Env, entry_course_id: &String) -> Vec<String> {
+    let mut path: Vec<String> = Vec::new(env);
+    path.push_back(entry_course_id.clone());
+
+    let mut index: u32 = 0;
+    while index < path.len() {
+        let course_id: String = path.get(index).unwrap();
+        let prerequisites: Vec<String> = if index == 0 {
+            get_existing_course_prerequisites(env, &course_id)
+        } else {
+            get_course_prerequisites(env, &course_id)
+        };
+        for prerequisite_id in prerequisites.iter() {
+            if !path.contains(&prerequisite_id) {
+                path.push_back(prerequisite_id);
+            }
+        }
+        index += 1;
+    }
+    path
+}
+
+/// Summarizes a user's progress along the prerequisite path of a course.
+///
+/// Each course on the path is completed if the user holds an unrevoked
+/// certificate for it, in progress if the user has valid access to it, and
+/// not started otherwise. Completions are read from the certification
+/// contract set with `set_certification_address`; without one no course
+/// counts as completed.
+///
+/// Panics with `Error::CourseNotFound` if the course registry does not know
+/// the entry course.
+pub fn course_access_get_learning_path_progress(
+    env: Env,
+    user: Address,
+    entry_course_id: String,
+) -> LearningPathProgress {
+    user.require_auth();
+
+    let path: Vec<String> = collect_learning_path(&env, &entry_course_id);
+    let certified: Vec<String> = get_certified_course_ids(&env, &user);
+
+    let mut completed: Vec<String> = Vec::new(&env);
+    let mut in_progress: Vec<String> = Vec::new(&env);
+    let mut not_started: Vec<String> = Vec::new(&env);
+    for course_id in path.iter() {
+        if certified.contains(&course_id) {
+            completed.push_back(course_id);
+        } else if course_access_check_access(env.clone(), course_id.clone(), user.clone()) {
+            in_progress.push_back(course_id);
+        } else {
+            not_started.push_back(course_id);
+        }
+    }
+
+    let overall_progress_percent: u32 = completed.len() * 100 / path.len();
+    LearningPathProgress {
+        path,
+        completed,
+        in_progress,
+        not_started,
+        overall_progress_percent,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{mock_course_registry, setup_with_mocks, TestMocks};
+    use crate::CourseAccessContractClient;
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    mod mock_certification {
+        use crate::schema::Certificate;
+        use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+
+        #[contract]
+        pub struct Certification;
+
+        #[contractimpl]
+        impl Certification {
+            pub fn certify(env: Env, user: Address, course_id: String, revoked: bool) {
+                let mut certificates: Vec<Certificate> =
+                    Self::list_user_certificates(env.clone(), user.clone());
+                certificates.push_back(Certificate {
+                    id: course_id.clone(),
+                    course_id,
+                    recipient: user.clone(),
+                    issuer: user.clone(),
+                    issued_at: 0,
+                    revoked,
+                });
+                env.storage().instance().set(&user, &certificates);
+            }
+
+            pub fn list_user_certificates(env: Env, user: Address) -> Vec<Certificate> {
+                env.storage()
+                    .instance()
+                    .get(&user)
+                    .unwrap_or(Vec::new(&env))
+            }
+        }
+    }
+
+    /// Sets up `advanced` requiring `intermediate` and `tools`, which both
+    /// require `basics`.
+    fn setup() -> (
+        Env,
+        CourseAccessContractClient<'static>,
+        TestMocks,
+        mock_certification::CertificationClient<'static>,
+    ) {
+        let (env, client, mocks) = setup_with_mocks();
+        let registry =
+            mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry);
+        let basics = String::from_str(&env, "basics");
+        registry.set_prerequisites(
+            &String::from_str(&env, "advanced"),
+            &vec![
+                &env,
+                String::from_str(&env, "intermediate"),
+                String::from_str(&env, "tools"),
+            ],
+        );
+        registry.set_prerequisites(
+            &String::from_str(&env, "intermediate"),
+            &vec![&env, basics.clone()],
+        );
+        registry.set_prerequisites(&String::from_str(&env, "tools"), &vec![&env, basics]);
+
+        let certification_id = env.register(mock_certification::Certification, ());
+        client.set_certification_address(&mocks.admin, &certification_id);
+        let certification = mock_certification::CertificationClient::new(&env, &certification_id);
+        (env, client, mocks, certification)
+    }
+
+    #[test]
+    fn test_learning_path_complete() {
+        let (env, client, _mocks, certification) = setup();
+        let user = Address::generate(&env);
+        let entry = String::from_str(&env, "advanced");
+
+        let path = client.get_learning_path_progress(&user, &entry).path;
+        for course_id in path.iter() {
+            certification.certify(&user, &course_id, &false);
+        }
+
+        let progress = client.get_learning_path_progress(&user, &entry);
+        assert_eq!(
+            progress.path,
+            vec![
+                &env,
+                String::from_str(&env, "advanced"),
+                String::from_str(&env, "intermediate"),
+                String::from_str(&env, "tools"),
+                String::from_str(&env, "basics")
+            ]
+        );
+        assert_eq!(progress.completed, progress.path);
+        assert!(progress.in_progress.is_empty());
+        assert_eq!(progress.overall_progress_percent, 100);
+    }
+
+    #[test]
+    fn test_learning_path_partial_progress() {
+        let (env, client, _mocks, certification) = setup();
+        let user = Address::generate(&env);
+        let basics = String::from_str(&env, "basics");
+        let intermediate = String::from_str(&env, "intermediate");
+        let tools = String::from_str(&env, "tools");
+        certification.certify(&user, &basics, &false);
+        // A revoked certificate does not count as a completion
+        certification.certify(&user, &tools, &true);
+        client.grant_access(&intermediate, &user);
+        client.grant_access(&tools, &user);
+
+        let progress =
+            client.get_learning_path_progress(&user, &String::from_str(&env, "advanced"));
+
+        assert_eq!(progress.completed, vec![&env, basics]);
+        assert_eq!(progress.in_progress, vec![&env, intermediate, tools]);
+        assert_eq!(
+            progress.not_started,
+            vec![&env, String::from_str(&env, "advanced")]
+        );
+        assert_eq!(progress.overall_progress_percent, 25);
+    }
+
+    #[test]
+    fn test_learning_path_unknown_entry_course() {
+        let (env, client, mocks, _certification) = setup();
+        let course_id = String::from_str(&env, "missing");
+        mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry)
+            .set_missing(&course_id);
+
+        let result = client.try_get_learning_path_progress(&Address::generate(&env), &course_id);
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(141)))
+        );
+    }
+
+    #[test]
+    fn test_learning_path_without_progress() {
+        let (env, client, _mocks, _certification) = setup();
+
+        let progress = client.get_learning_path_progress(
+            &Address::generate(&env),
+            &String::from_str(&env, "intermediate"),
+        );
+
+        assert_eq!(progress.path.len(), 2);
+        assert_eq!(progress.not_started, progress.path);
+        assert!(progress.completed.is_empty());
+        assert_eq!(progress.overall_progress_percent, 0);
+    }
+}
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+use shared::{touch_persistent_ttl, DEFAULT_PERSISTENT_TTL, DEFAULT_PERSISTENT_TTL_BUMP};
 use soroban_sdk::{Env, String, Vec};
 
 use crate::schema::{CourseUsers, DataKey};
@@ -26,6 +27,7 @@ pub fn course_access_list_course_access(env: Env, course_id: String) -> CourseUs
             course: course_id.clone(),
             users: Vec::new(&env),
         });
+    touch_persistent_ttl(&env, &key, DEFAULT_PERSISTENT_TTL_BUMP, DEFAULT_PERSISTENT_TTL);
 
     return res
 }
@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::require_course_manager;
+use shared::{Page, Paginator};
+
+use crate::schema::{CourseUserPage, CourseUsers, DataKey, MAX_LIST_PAGE_SIZE};
+
+/// Returns a page of the users enrolled in a course (course creator or admin).
+///
+/// Courses without enrolled users, including unknown courses, yield an empty page.
+pub fn course_access_list_course_users(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    offset: u32,
+    limit: u32,
+) -> CourseUserPage {
+    if course_id.is_empty() {
+        handle_error(&env, Error::EmptyCourseId);
+    }
+    if course_id.len() > 100 {
+        handle_error(&env, Error::InvalidCourseId);
+    }
+    require_course_manager(&env, &caller, &course_id);
+
+    let enrolled: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get::<DataKey, CourseUsers>(&DataKey::CourseUsers(course_id))
+        .map(|course_users| course_users.users)
+        .unwrap_or(Vec::new(&env));
+
+    let page: Page<Address> = Paginator::new(offset, limit, MAX_LIST_PAGE_SIZE).page(&enrolled, &env);
+
+    CourseUserPage {
+        users: page.items,
+        total: page.total,
+        has_more: page.has_more,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{mock_course_registry, setup_with_mocks};
+    use soroban_sdk::{testutils::Address as _, Address, String};
+
+    #[test]
+    fn test_list_course_users_by_creator() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let creator = Address::generate(&env);
+        mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry)
+            .set_creator(&course_id, &creator);
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        client.grant_access(&course_id, &first);
+        client.grant_access(&course_id, &second);
+
+        let page = client.list_course_users(&creator, &course_id, &0, &10);
+        assert_eq!(page.total, 2);
+        assert!(!page.has_more);
+        assert_eq!(page.users.get(0).unwrap(), first);
+        assert_eq!(page.users.get(1).unwrap(), second);
+    }
+
+    #[test]
+    fn test_list_course_users_empty_course() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+        client.grant_access(&course_id, &user);
+        client.revoke_access(&course_id, &user);
+
+        let page = client.list_course_users(&mocks.admin, &course_id, &0, &10);
+        assert_eq!(page.total, 0);
+        assert!(page.users.is_empty());
+        assert!(!page.has_more);
+    }
+
+    #[test]
+    fn test_list_course_users_pagination() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        for _ in 0..5 {
+            client.grant_access(&course_id, &Address::generate(&env));
+        }
+
+        let first = client.list_course_users(&mocks.admin, &course_id, &0, &2);
+        assert_eq!(first.users.len(), 2);
+        assert_eq!(first.total, 5);
+        assert!(first.has_more);
+
+        let last = client.list_course_users(&mocks.admin, &course_id, &4, &2);
+        assert_eq!(last.users.len(), 1);
+        assert!(!last.has_more);
+
+        let beyond = client.list_course_users(&mocks.admin, &course_id, &10, &2);
+        assert!(beyond.users.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #103)")]
+    fn test_list_course_users_unauthorized() {
+        let (env, client, _mocks) = setup_with_mocks();
+        let stranger = Address::generate(&env);
+
+        client.list_course_users(&stranger, &String::from_str(&env, "course_1"), &0, &10);
+    }
+
+    #[test]
+    fn test_list_course_users_unknown_course() {
+        let (env, client, mocks) = setup_with_mocks();
+
+        let page = client.list_course_users(&mocks.admin, &String::from_str(&env, "missing"), &0, &10);
+        assert_eq!(page.total, 0);
+        assert!(page.users.is_empty());
+    }
+}
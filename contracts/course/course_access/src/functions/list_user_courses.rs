@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+use shared::{touch_persistent_ttl, DEFAULT_PERSISTENT_TTL, DEFAULT_PERSISTENT_TTL_BUMP};
 use soroban_sdk::{Address, Env, Vec};
 
 use crate::schema::{DataKey, UserCourses};
@@ -12,6 +13,7 @@ pub fn list_user_courses(env: Env, user: Address) -> UserCourses {
         user: user.clone(),
         courses: Vec::new(&env),
     });
+    touch_persistent_ttl(&env, &key, DEFAULT_PERSISTENT_TTL_BUMP, DEFAULT_PERSISTENT_TTL);
 
     return res
 }
@@ -1,12 +1,204 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+pub mod access_control;
+pub mod access_delegation;
+pub mod bundle_access;
+pub mod check_access;
+pub mod cleanup_expired_access;
 pub mod config;
 pub mod contract_versioning;
+pub mod count_active_enrollments;
+pub mod enrollment_groups;
+pub mod get_access_audit_log;
+pub mod get_access_details;
+pub mod get_enrollment_counts;
+pub mod get_enrollment_history;
 pub mod grant_access;
+pub mod grant_access_batch;
+pub mod grant_access_with_expiry;
+pub mod invitation_codes;
+pub mod learning_path_progress;
 pub mod list_course_access;
+pub mod list_course_users;
 pub mod list_user_courses;
+pub mod pause_access;
+pub mod prerequisite_check;
+pub mod request_access;
 pub mod revoke_access;
+pub mod revoke_access_batch;
 pub mod revoke_all_access;
 pub mod save_profile;
+pub mod schedule_revocation;
+pub mod self_enroll;
 pub mod transfer_course_access;
+pub mod waitlist;
+
+#[cfg(test)]
+pub mod test_utils {
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    use crate::{CourseAccessContract, CourseAccessContractClient};
+
+    /// User management mock whose admin set is configured per test.
+    pub mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn set_admin(env: Env, who: Address) {
+                env.storage().instance().set(&who, &true);
+            }
+
+            pub fn is_admin(env: Env, who: Address) -> bool {
+                env.storage().instance().get(&who).unwrap_or(false)
+            }
+
+            pub fn set_blacklisted(env: Env, address: Address) {
+                env.storage().instance().set(&(address,), &true);
+            }
+
+            pub fn is_blacklisted(env: Env, address: Address) -> bool {
+                env.storage().instance().has(&(address,))
+            }
+
+            /// Lets the real course registry publish courses in cross-contract tests.
+            pub fn is_email_verified(_env: Env, _user: Address) -> bool {
+                true
+            }
+        }
+    }
+
+    /// Course registry mock whose course creators are configured per test.
+    pub mod mock_course_registry {
+        use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String, Vec};
+
+        #[contract]
+        pub struct CourseRegistry;
+
+        #[contractimpl]
+        impl CourseRegistry {
+            pub fn set_creator(env: Env, course_id: String, creator: Address) {
+                env.storage().instance().set(&course_id, &creator);
+            }
+
+            pub fn is_course_creator(env: Env, course_id: String, user: Address) -> bool {
+                env.storage().instance().get::<_, Address>(&course_id) == Some(user)
+            }
+
+            pub fn set_archived(env: Env, course_id: String) {
+                env.storage().instance().set(&(course_id,), &true);
+            }
+
+            pub fn is_course_archived(env: Env, course_id: String) -> bool {
+                env.storage().instance().has(&(course_id,))
+            }
+
+            pub fn set_frozen(env: Env, course_id: String, frozen: bool) {
+                env.storage()
+                    .instance()
+                    .set(&(symbol_short!("frozen"), course_id), &frozen);
+            }
+
+            pub fn is_course_frozen(env: Env, course_id: String) -> bool {
+                env.storage()
+                    .instance()
+                    .get(&(symbol_short!("frozen"), course_id))
+                    .unwrap_or(false)
+            }
+
+            /// Courses are free unless marked otherwise
+            pub fn set_paid(env: Env, course_id: String) {
+                env.storage().instance().set(&(symbol_short!("paid"), course_id), &true);
+            }
+
+            pub fn is_course_free(env: Env, course_id: String) -> bool {
+                !env.storage().instance().has(&(symbol_short!("paid"), course_id))
+            }
+
+            pub fn set_max_enrollments(env: Env, course_id: String, max_enrollments: u32) {
+                env.storage()
+                    .instance()
+                    .set(&(symbol_short!("cap"), course_id), &max_enrollments);
+            }
+
+            pub fn get_course_max_enrollments(env: Env, course_id: String) -> Option<u32> {
+                env.storage().instance().get(&(symbol_short!("cap"), course_id))
+            }
+
+            /// Courses are published unless marked otherwise
+            pub fn set_unpublished(env: Env, course_id: String) {
+                env.storage().instance().set(&(symbol_short!("unpub"), course_id), &true);
+            }
+
+            pub fn is_course_published(env: Env, course_id: String) -> bool {
+                !env.storage().instance().has(&(symbol_short!("unpub"), course_id))
+            }
+
+            pub fn set_bundle(env: Env, bundle_id: u128, course_ids: Vec<String>) {
+                env.storage()
+                    .instance()
+                    .set(&(symbol_short!("bundle"), bundle_id), &course_ids);
+            }
+
+            pub fn get_bundle_course_ids(env: Env, bundle_id: u128) -> Vec<String> {
+                env.storage()
+                    .instance()
+                    .get(&(symbol_short!("bundle"), bundle_id))
+                    .expect("bundle not found")
+            }
+
+            pub fn set_prerequisites(env: Env, course_id: String, prerequisites: Vec<String>) {
+                env.storage()
+                    .instance()
+                    .set(&(symbol_short!("prereqs"), course_id), &prerequisites);
+            }
+
+            /// Courses exist unless marked otherwise
+            pub fn set_missing(env: Env, course_id: String) {
+                env.storage().instance().set(&(symbol_short!("missing"), course_id), &true);
+            }
+
+            pub fn get_course_prerequisites(env: Env, course_id: String) -> Vec<String> {
+                if env.storage().instance().has(&(symbol_short!("missing"), course_id.clone())) {
+                    panic!("course not found");
+                }
+                env.storage()
+                    .instance()
+                    .get(&(symbol_short!("prereqs"), course_id))
+                    .unwrap_or(Vec::new(&env))
+            }
+        }
+    }
+
+    pub struct TestMocks {
+        pub admin: Address,
+        pub user_management: Address,
+        pub course_registry: Address,
+    }
+
+    /// Registers the contract with configurable dependency mocks and one admin.
+    pub fn setup_with_mocks() -> (Env, CourseAccessContractClient<'static>, TestMocks) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+        let course_registry_id = env.register(mock_course_registry::CourseRegistry, ());
+        let contract_id = env.register(CourseAccessContract, ());
+        let client = CourseAccessContractClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        mock_user_management::UserManagementClient::new(&env, &user_mgmt_id).set_admin(&admin);
+        client.initialize(&admin, &user_mgmt_id, &course_registry_id);
+
+        let mocks = TestMocks {
+            admin,
+            user_management: user_mgmt_id,
+            course_registry: course_registry_id,
+        };
+        (env, client, mocks)
+    }
+}
@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::require_course_manager;
+use crate::functions::get_access_audit_log::record_access_event;
+use crate::schema::{AccessEventType, CourseAccess, DataKey};
+
+const ACCESS_PAUSED_EVENT: &str = "AccessPaused";
+const ACCESS_RESUMED_EVENT: &str = "AccessResumed";
+
+/// Suspends a user's course access without revoking it (course creator or admin).
+///
+/// # Panics
+///
+/// * `Error::Unauthorized` if the caller is neither the course creator nor an admin.
+/// * `Error::UserNoAccessCourse` if the user has no access record for the course.
+pub fn course_access_pause_access(env: Env, caller: Address, course_id: String, user: Address) {
+    set_paused(&env, &caller, &course_id, &user, true);
+    record_access_event(&env, AccessEventType::Paused, &course_id, &user, &caller);
+
+    env.events().publish(
+        (Symbol::new(&env, ACCESS_PAUSED_EVENT), course_id),
+        user,
+    );
+}
+
+/// Resumes a paused course access (course creator or admin).
+///
+/// # Panics
+///
+/// * `Error::Unauthorized` if the caller is neither the course creator nor an admin.
+/// * `Error::UserNoAccessCourse` if the user has no access record for the course.
+pub fn course_access_resume_access(env: Env, caller: Address, course_id: String, user: Address) {
+    set_paused(&env, &caller, &course_id, &user, false);
+    record_access_event(&env, AccessEventType::Resumed, &course_id, &user, &caller);
+
+    env.events().publish(
+        (Symbol::new(&env, ACCESS_RESUMED_EVENT), course_id),
+        user,
+    );
+}
+
+fn set_paused(env: &Env, caller: &Address, course_id: &String, user: &Address, paused: bool) {
+    require_course_manager(env, caller, course_id);
+
+    let key: DataKey = DataKey::CourseAccess(course_id.clone(), user.clone());
+    let mut access: CourseAccess = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| handle_error(env, Error::UserNoAccessCourse));
+
+    access.paused = paused;
+    env.storage().persistent().set(&key, &access);
+    env.storage().persistent().extend_ttl(&key, 100, 1000);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{mock_course_registry, setup_with_mocks};
+    use soroban_sdk::{testutils::Address as _, Address, String};
+
+    #[test]
+    fn test_pause_and_resume_access() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let creator = Address::generate(&env);
+        mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry)
+            .set_creator(&course_id, &creator);
+        let user = Address::generate(&env);
+        client.grant_access(&course_id, &user);
+
+        client.pause_access(&creator, &course_id, &user);
+        assert!(!client.check_access(&course_id, &user));
+        // The access record is kept while paused
+        assert_eq!(client.list_user_courses(&user).courses.len(), 1);
+
+        client.resume_access(&creator, &course_id, &user);
+        assert!(client.check_access(&course_id, &user));
+    }
+
+    #[test]
+    fn test_paused_access_blocks_regrant_by_admin() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+        client.grant_access(&course_id, &user);
+        client.pause_access(&mocks.admin, &course_id, &user);
+
+        assert!(client.try_grant_access(&course_id, &user).is_err());
+        assert!(!client.check_access(&course_id, &user));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #102)")]
+    fn test_pause_access_without_record() {
+        let (env, client, mocks) = setup_with_mocks();
+
+        client.pause_access(
+            &mocks.admin,
+            &String::from_str(&env, "course_1"),
+            &Address::generate(&env),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #103)")]
+    fn test_pause_access_unauthorized() {
+        let (env, client, _mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+        client.grant_access(&course_id, &user);
+
+        client.pause_access(&Address::generate(&env), &course_id, &user);
+    }
+}
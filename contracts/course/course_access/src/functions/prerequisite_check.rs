@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{get_course_prerequisites, is_admin, require_admin};
+use crate::functions::check_access::course_access_check_access;
+use crate::schema::DataKey;
+
+const REQUIRE_PREREQUISITES_EVENT: &str = "RequirePrerequisitesSet";
+
+/// Turns the prerequisite check on course grants on or off (admin only).
+///
+/// Panics with `Error::Unauthorized` if the caller is not an admin.
+pub fn course_access_set_require_prerequisites(env: Env, caller: Address, required: bool) {
+    require_admin(&env, &caller);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::RequirePrerequisites, &required);
+
+    env.events().publish(
+        (Symbol::new(&env, REQUIRE_PREREQUISITES_EVENT),),
+        (caller, required),
+    );
+}
+
+/// Returns whether grants check prerequisites (defaults to `false`).
+pub fn course_access_get_require_prerequisites(env: Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RequirePrerequisites)
+        .unwrap_or(false)
+}
+
+/// Returns the prerequisites of `course_id` that `user` has no valid access to.
+pub fn course_access_get_missing_prerequisites(
+    env: Env,
+    user: Address,
+    course_id: String,
+) -> Vec<String> {
+    let mut missing: Vec<String> = Vec::new(&env);
+    for prerequisite_id in get_course_prerequisites(&env, &course_id).iter() {
+        if !course_access_check_access(env.clone(), prerequisite_id.clone(), user.clone()) {
+            missing.push_back(prerequisite_id);
+        }
+    }
+    missing
+}
+
+/// Panics with `Error::PrerequisitesNotMet` if prerequisites are required and
+/// `user` lacks access to any of them. Grants made by an admin are exempt.
+pub fn check_prerequisite_completion_before_grant(
+    env: &Env,
+    course_id: &String,
+    user: &Address,
+    actor: &Address,
+) {
+    if !course_access_get_require_prerequisites(env.clone()) || is_admin(env, actor) {
+        return;
+    }
+
+    if !course_access_get_missing_prerequisites(env.clone(), user.clone(), course_id.clone())
+        .is_empty()
+    {
+        handle_error(env, Error::PrerequisitesNotMet)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{mock_course_registry, setup_with_mocks};
+    use soroban_sdk::{testutils::Address as _, vec, Address, String};
+
+    #[test]
+    fn test_flag_disabled_skips_check() {
+        let (env, client, mocks) = setup_with_mocks();
+        let registry = mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry);
+        let user = Address::generate(&env);
+        let course_id = String::from_str(&env, "advanced");
+        registry.set_prerequisites(&course_id, &vec![&env, String::from_str(&env, "basics")]);
+
+        assert!(!client.get_require_prerequisites());
+        client.grant_access(&course_id, &user);
+
+        assert!(client.check_access(&course_id, &user));
+    }
+
+    #[test]
+    fn test_flag_enabled_all_prerequisites_met() {
+        let (env, client, mocks) = setup_with_mocks();
+        let registry = mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry);
+        let user = Address::generate(&env);
+        let basics = String::from_str(&env, "basics");
+        let intermediate = String::from_str(&env, "intermediate");
+        let course_id = String::from_str(&env, "advanced");
+        registry.set_prerequisites(&course_id, &vec![&env, basics.clone(), intermediate.clone()]);
+        client.set_require_prerequisites(&mocks.admin, &true);
+
+        client.grant_access(&basics, &user);
+        client.grant_access(&intermediate, &user);
+        assert!(client.get_missing_prerequisites(&user, &course_id).is_empty());
+
+        client.grant_access(&course_id, &user);
+        assert!(client.check_access(&course_id, &user));
+    }
+
+    #[test]
+    fn test_flag_enabled_partial_prerequisites() {
+        let (env, client, mocks) = setup_with_mocks();
+        let registry = mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry);
+        let user = Address::generate(&env);
+        let basics = String::from_str(&env, "basics");
+        let intermediate = String::from_str(&env, "intermediate");
+        let course_id = String::from_str(&env, "advanced");
+        registry.set_prerequisites(&course_id, &vec![&env, basics.clone(), intermediate.clone()]);
+        client.set_require_prerequisites(&mocks.admin, &true);
+
+        client.grant_access(&basics, &user);
+
+        assert_eq!(
+            client.get_missing_prerequisites(&user, &course_id),
+            vec![&env, intermediate]
+        );
+        let result = client.try_grant_access(&course_id, &user);
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(130))));
+        assert!(!client.check_access(&course_id, &user));
+    }
+
+    #[test]
+    fn test_admin_bypasses_prerequisites() {
+        let (env, client, mocks) = setup_with_mocks();
+        let registry = mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry);
+        let user = Address::generate(&env);
+        let course_id = String::from_str(&env, "advanced");
+        registry.set_prerequisites(&course_id, &vec![&env, String::from_str(&env, "basics")]);
+        client.set_require_prerequisites(&mocks.admin, &true);
+
+        client.grant_access_with_expiry(&mocks.admin, &course_id, &user, &1000);
+
+        assert!(client.check_access(&course_id, &user));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #103)")]
+    fn test_set_require_prerequisites_non_admin() {
+        let (env, client, _mocks) = setup_with_mocks();
+        client.set_require_prerequisites(&Address::generate(&env), &true);
+    }
+}
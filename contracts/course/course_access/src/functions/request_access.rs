@@ -0,0 +1,211 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::require_course_manager;
+use crate::functions::check_access::course_access_check_access;
+use crate::functions::grant_access::{grant_course_access, validate_grantable_course};
+use crate::schema::{AccessRequest, AccessRequestStatus, DataKey};
+
+const ACCESS_REQUESTED_EVENT: &str = "AccessRequested";
+const ACCESS_REQUEST_APPROVED_EVENT: &str = "AccessRequestApproved";
+const ACCESS_REQUEST_REJECTED_EVENT: &str = "AccessRequestRejected";
+
+/// Files a pending request for access to a course.
+///
+/// A user whose earlier request was decided may ask again.
+///
+/// # Panics
+///
+/// * `Error::UserAlreadyHasAccess` if the requester already has access.
+/// * `Error::AccessRequestExists` if the requester already has a pending request.
+pub fn course_access_request_access(env: Env, requester: Address, course_id: String) {
+    requester.require_auth();
+    validate_grantable_course(&env, &course_id);
+
+    if course_access_check_access(env.clone(), course_id.clone(), requester.clone()) {
+        handle_error(&env, Error::UserAlreadyHasAccess)
+    }
+
+    let key: DataKey = DataKey::AccessRequest(course_id.clone(), requester.clone());
+    if let Some(existing) = env.storage().persistent().get::<DataKey, AccessRequest>(&key) {
+        if existing.status == AccessRequestStatus::Pending {
+            handle_error(&env, Error::AccessRequestExists)
+        }
+    }
+
+    let request: AccessRequest = AccessRequest {
+        course_id: course_id.clone(),
+        requester: requester.clone(),
+        requested_at: env.ledger().timestamp(),
+        status: AccessRequestStatus::Pending,
+    };
+    env.storage().persistent().set(&key, &request);
+
+    let mut pending: Vec<Address> = get_pending_requesters(&env, &course_id);
+    pending.push_back(requester.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::PendingAccessRequests(course_id.clone()), &pending);
+
+    env.events().publish(
+        (Symbol::new(&env, ACCESS_REQUESTED_EVENT), course_id),
+        requester,
+    );
+}
+
+/// Approves a pending access request and grants access (course creator or admin).
+pub fn course_access_approve_access_request(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    requester: Address,
+) {
+    decide_request(&env, &caller, &course_id, &requester, AccessRequestStatus::Approved);
+    grant_course_access(&env, &course_id, &requester, &caller);
+
+    env.events().publish(
+        (Symbol::new(&env, ACCESS_REQUEST_APPROVED_EVENT), course_id),
+        (requester, caller),
+    );
+}
+
+/// Rejects a pending access request (course creator or admin).
+pub fn course_access_reject_access_request(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    requester: Address,
+) {
+    decide_request(&env, &caller, &course_id, &requester, AccessRequestStatus::Rejected);
+
+    env.events().publish(
+        (Symbol::new(&env, ACCESS_REQUEST_REJECTED_EVENT), course_id),
+        (requester, caller),
+    );
+}
+
+/// Lists the pending access requests of a course (course creator or admin).
+pub fn course_access_list_pending_requests(
+    env: Env,
+    caller: Address,
+    course_id: String,
+) -> Vec<AccessRequest> {
+    require_course_manager(&env, &caller, &course_id);
+
+    let mut requests: Vec<AccessRequest> = Vec::new(&env);
+    for requester in get_pending_requesters(&env, &course_id).iter() {
+        if let Some(request) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, AccessRequest>(&DataKey::AccessRequest(course_id.clone(), requester))
+        {
+            requests.push_back(request);
+        }
+    }
+    requests
+}
+
+fn get_pending_requesters(env: &Env, course_id: &String) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PendingAccessRequests(course_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Moves a pending request to `status` and drops it from the pending index.
+fn decide_request(
+    env: &Env,
+    caller: &Address,
+    course_id: &String,
+    requester: &Address,
+    status: AccessRequestStatus,
+) {
+    require_course_manager(env, caller, course_id);
+
+    let key: DataKey = DataKey::AccessRequest(course_id.clone(), requester.clone());
+    let mut request: AccessRequest = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .filter(|request: &AccessRequest| request.status == AccessRequestStatus::Pending)
+        .unwrap_or_else(|| handle_error(env, Error::AccessRequestNotFound));
+
+    request.status = status;
+    env.storage().persistent().set(&key, &request);
+
+    let mut pending: Vec<Address> = get_pending_requesters(env, course_id);
+    if let Some(index) = pending.first_index_of(requester) {
+        pending.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingAccessRequests(course_id.clone()), &pending);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::schema::AccessRequestStatus;
+    use soroban_sdk::{testutils::Address as _, Address, String};
+
+    #[test]
+    fn test_request_and_approve_access() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+
+        client.request_access(&user, &course_id);
+        let pending = client.list_pending_requests(&mocks.admin, &course_id);
+        assert_eq!(pending.len(), 1);
+        let request = pending.get(0).unwrap();
+        assert_eq!(request.requester, user);
+        assert_eq!(request.status, AccessRequestStatus::Pending);
+        assert!(!client.check_access(&course_id, &user));
+
+        client.approve_access_request(&mocks.admin, &course_id, &user);
+        assert!(client.check_access(&course_id, &user));
+        assert!(client.list_pending_requests(&mocks.admin, &course_id).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #120)")]
+    fn test_duplicate_access_request() {
+        let (env, client, _mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+
+        client.request_access(&user, &course_id);
+        client.request_access(&user, &course_id);
+    }
+
+    #[test]
+    fn test_reject_access_request() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+
+        client.request_access(&user, &course_id);
+        client.reject_access_request(&mocks.admin, &course_id, &user);
+        assert!(!client.check_access(&course_id, &user));
+        assert!(client.list_pending_requests(&mocks.admin, &course_id).is_empty());
+
+        // A rejected user may ask again
+        client.request_access(&user, &course_id);
+        assert_eq!(client.list_pending_requests(&mocks.admin, &course_id).len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #121)")]
+    fn test_approve_decided_request() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+
+        client.request_access(&user, &course_id);
+        client.reject_access_request(&mocks.admin, &course_id, &user);
+        client.approve_access_request(&mocks.admin, &course_id, &user);
+    }
+}
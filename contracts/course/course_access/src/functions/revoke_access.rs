@@ -3,7 +3,9 @@
 
 use soroban_sdk::{Address, Env, String, Symbol, symbol_short};
 
-use crate::schema::{DataKey, UserCourses, CourseUsers};
+use crate::functions::get_access_audit_log::record_access_event;
+use crate::functions::waitlist::promote_from_waitlist;
+use crate::schema::{AccessEventType, DataKey, UserCourses, CourseUsers};
 use crate::error::{Error, handle_error};
 
 const COURSE_ACCESS_REVOKE_EVENT: Symbol = symbol_short!("crsAcRvk");
@@ -25,6 +27,24 @@ const COURSE_ACCESS_REVOKE_EVENT: Symbol = symbol_short!("crsAcRvk");
 /// * `bool` - Returns `true` if the access was successfully revoked (entry existed and was removed),
 ///   or `false` if no access entry was found for the user-course combination.
 pub fn course_access_revoke_access(env: Env, course_id: String, user: Address) -> bool {
+    revoke_course_access(&env, &course_id, &user, &env.current_contract_address())
+}
+
+/// Revokes access on behalf of `actor`, who is recorded in the audit log.
+///
+/// The freed spot goes to the first eligible user on the course waitlist.
+/// Returns `false` if the user had no access record for the course.
+pub fn revoke_course_access(env: &Env, course_id: &String, user: &Address, actor: &Address) -> bool {
+    let revoked: bool = remove_course_access(env.clone(), course_id.clone(), user.clone());
+    if revoked {
+        record_access_event(env, AccessEventType::Revoked, course_id, user, actor);
+        promote_from_waitlist(env, course_id);
+    }
+    revoked
+}
+
+/// Removes the access entry and its index entries, publishing the revoke event.
+pub fn remove_course_access(env: Env, course_id: String, user: Address) -> bool {
     // Validate input parameters
     if course_id.is_empty() {
         handle_error(&env, Error::EmptyCourseId);
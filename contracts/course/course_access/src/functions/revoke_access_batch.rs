@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::require_course_manager;
+use crate::functions::revoke_access::revoke_course_access;
+use crate::schema::{BatchRevokeResult, MAX_BATCH_SIZE};
+
+/// Revokes course access from several users at once (course creator or admin).
+///
+/// Users without access are counted as skipped instead of failing the batch.
+pub fn course_access_revoke_access_batch(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    users: Vec<Address>,
+) -> BatchRevokeResult {
+    if users.len() > MAX_BATCH_SIZE {
+        handle_error(&env, Error::BatchTooLarge)
+    }
+    require_course_manager(&env, &caller, &course_id);
+
+    let mut result: BatchRevokeResult = BatchRevokeResult {
+        revoked_count: 0,
+        skipped_count: 0,
+    };
+    for user in users.iter() {
+        if revoke_course_access(&env, &course_id, &user, &caller) {
+            result.revoked_count += 1;
+        } else {
+            result.skipped_count += 1;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{mock_course_registry, setup_with_mocks};
+    use soroban_sdk::{testutils::Address as _, vec, Address, String, Vec};
+
+    #[test]
+    fn test_revoke_access_batch_mixed() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let creator = Address::generate(&env);
+        mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry)
+            .set_creator(&course_id, &creator);
+        let enrolled = Address::generate(&env);
+        let other = Address::generate(&env);
+        client.grant_access(&course_id, &enrolled);
+
+        let result = client.revoke_access_batch(&creator, &course_id, &vec![&env, enrolled.clone(), other]);
+        assert_eq!(result.revoked_count, 1);
+        assert_eq!(result.skipped_count, 1);
+        assert!(!client.check_access(&course_id, &enrolled));
+    }
+
+    #[test]
+    fn test_revoke_access_batch_all_skipped() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let users = vec![&env, Address::generate(&env), Address::generate(&env)];
+
+        let result = client.revoke_access_batch(&mocks.admin, &course_id, &users);
+        assert_eq!(result.revoked_count, 0);
+        assert_eq!(result.skipped_count, 2);
+    }
+
+    #[test]
+    fn test_revoke_access_batch_empty_users() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+
+        let result = client.revoke_access_batch(&mocks.admin, &course_id, &Vec::new(&env));
+        assert_eq!(result.revoked_count, 0);
+        assert_eq!(result.skipped_count, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #103)")]
+    fn test_revoke_access_batch_unauthorized() {
+        let (env, client, _mocks) = setup_with_mocks();
+        let stranger = Address::generate(&env);
+        let users = vec![&env, Address::generate(&env)];
+
+        client.revoke_access_batch(&stranger, &String::from_str(&env, "course_1"), &users);
+    }
+}
@@ -1,9 +1,10 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{symbol_short, Address, Env, IntoVal, String, Symbol, Vec};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
 
 use crate::error::{handle_error, Error};
+use crate::functions::access_control::{CourseRegistryClient, UserManagementClient};
 use crate::schema::{DataKey, KEY_COURSE_REG_ADDR, KEY_USER_MGMT_ADDR};
 
 /// Storage key symbol for user data
@@ -35,11 +36,7 @@ pub fn revoke_all_access(env: Env, caller: Address, course_id: String) -> u32 {
         .instance()
         .get(&(KEY_USER_MGMT_ADDR,))
         .expect("user_mgmt_addr not configured; call initialize/set_config");
-    let is_admin: bool = env.invoke_contract(
-        &user_mgmt_addr,
-        &Symbol::new(&env, "is_admin"),
-        (caller.clone(),).into_val(&env),
-    );
+    let is_admin: bool = UserManagementClient::new(&env, &user_mgmt_addr).is_admin(&caller);
 
     // Resolve creator via cross-contract if configured
     let course_registry_addr: Address = env
@@ -47,11 +44,8 @@ pub fn revoke_all_access(env: Env, caller: Address, course_id: String) -> u32 {
         .instance()
         .get(&(KEY_COURSE_REG_ADDR,))
         .expect("course_registry_addr not configured; call initialize/set_config");
-    let is_creator: bool = env.invoke_contract(
-        &course_registry_addr,
-        &Symbol::new(&env, "is_course_creator"),
-        (course_id.clone(), caller.clone()).into_val(&env),
-    );
+    let is_creator: bool =
+        CourseRegistryClient::new(&env, &course_registry_addr).is_course_creator(&course_id, &caller);
 
     // Authorization: only admin or course creator
     if !(is_admin || is_creator) {
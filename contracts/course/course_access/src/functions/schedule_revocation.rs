@@ -0,0 +1,275 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{require_admin, require_course_manager};
+use crate::functions::revoke_access::revoke_course_access;
+use crate::schema::DataKey;
+
+/// Length of the buckets scheduled revocations are grouped in
+pub const REVOCATION_BUCKET_SECONDS: u64 = 86_400;
+
+const REVOCATION_SCHEDULED_EVENT: &str = "RevocationScheduled";
+const SCHEDULED_REVOCATIONS_PROCESSED_EVENT: &str = "ScheduledRevocationsProcessed";
+
+fn get_revocation_days(env: &Env) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ScheduledRevocationDays)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Start of the day `timestamp` falls in.
+fn revocation_day(timestamp: u64) -> u64 {
+    timestamp - timestamp % REVOCATION_BUCKET_SECONDS
+}
+
+/// Schedules a user's access to a course to be revoked (course creator or
+/// admin).
+///
+/// Entries are grouped per day to keep processing cheap, but each one is only
+/// revoked once its own `revoke_at` has passed. Scheduling the same user and
+/// course again within a day moves the existing entry to the new time.
+///
+/// # Panics
+///
+/// * `Error::Unauthorized` if the caller is neither the creator nor an admin.
+/// * `Error::InvalidExpiry` if `revoke_at` is not in the future.
+/// * `Error::UserNoAccessCourse` if the user has no access to the course.
+pub fn course_access_schedule_revocation(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    user: Address,
+    revoke_at: u64,
+) {
+    require_course_manager(&env, &caller, &course_id);
+
+    if revoke_at <= env.ledger().timestamp() {
+        handle_error(&env, Error::InvalidExpiry)
+    }
+    if !env
+        .storage()
+        .persistent()
+        .has(&DataKey::CourseAccess(course_id.clone(), user.clone()))
+    {
+        handle_error(&env, Error::UserNoAccessCourse)
+    }
+
+    let day: u64 = revocation_day(revoke_at);
+    let key: DataKey = DataKey::ScheduledRevocations(day);
+    let mut entries: Vec<(String, Address, u64)> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(&env));
+    let entry: (String, Address, u64) = (course_id.clone(), user.clone(), revoke_at);
+    match entries
+        .iter()
+        .position(|(entry_course, entry_user, _)| entry_course == course_id && entry_user == user)
+    {
+        Some(index) => entries.set(index as u32, entry),
+        None => entries.push_back(entry),
+    }
+    env.storage().persistent().set(&key, &entries);
+
+    let mut days: Vec<u64> = get_revocation_days(&env);
+    if !days.contains(day) {
+        let index: u32 = days.iter().take_while(|existing| *existing < day).count() as u32;
+        days.insert(index, day);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ScheduledRevocationDays, &days);
+    }
+
+    env.events().publish(
+        (Symbol::new(&env, REVOCATION_SCHEDULED_EVENT), course_id),
+        (user, revoke_at),
+    );
+}
+
+/// Revokes every scheduled entry due at or before `target_timestamp` (admin
+/// only).
+///
+/// A `target_timestamp` later than the current ledger time is clamped to it,
+/// so access is never revoked ahead of schedule. Processed entries are
+/// removed, so calling again for the same timestamp is a no-op. Entries whose
+/// access was already revoked are dropped silently.
+///
+/// Panics with `Error::Unauthorized` if the caller is not an admin.
+pub fn course_access_process_scheduled_revocations(
+    env: Env,
+    caller: Address,
+    target_timestamp: u64,
+) {
+    require_admin(&env, &caller);
+
+    let target_timestamp: u64 = target_timestamp.min(env.ledger().timestamp());
+    let mut days: Vec<u64> = get_revocation_days(&env);
+    let mut revoked: u32 = 0;
+    while let Some(day) = days.first() {
+        if day > target_timestamp {
+            break;
+        }
+
+        let key: DataKey = DataKey::ScheduledRevocations(day);
+        let entries: Vec<(String, Address, u64)> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(&env));
+        let mut pending: Vec<(String, Address, u64)> = Vec::new(&env);
+        for (course_id, user, revoke_at) in entries.iter() {
+            if revoke_at > target_timestamp {
+                pending.push_back((course_id, user, revoke_at));
+            } else if revoke_course_access(&env, &course_id, &user, &caller) {
+                revoked += 1;
+            }
+        }
+
+        // Only the day holding `target_timestamp` can have entries left
+        if !pending.is_empty() {
+            env.storage().persistent().set(&key, &pending);
+            break;
+        }
+        env.storage().persistent().remove(&key);
+        days.pop_front();
+    }
+
+    if days.is_empty() {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ScheduledRevocationDays);
+    } else {
+        env.storage()
+            .persistent()
+            .set(&DataKey::ScheduledRevocationDays, &days);
+    }
+
+    env.events().publish(
+        (Symbol::new(&env, SCHEDULED_REVOCATIONS_PROCESSED_EVENT),),
+        (caller, target_timestamp, revoked),
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::REVOCATION_BUCKET_SECONDS;
+    use crate::functions::test_utils::setup_with_mocks;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, String};
+
+    const DAY: u64 = REVOCATION_BUCKET_SECONDS;
+
+    #[test]
+    fn test_schedule_and_process_revocations() {
+        let (env, client, mocks) = setup_with_mocks();
+        env.ledger().set_timestamp(DAY);
+        let course_id = String::from_str(&env, "course_1");
+        let soon = Address::generate(&env);
+        let same_day = Address::generate(&env);
+        let later = Address::generate(&env);
+        client.grant_access(&course_id, &soon);
+        client.grant_access(&course_id, &same_day);
+        client.grant_access(&course_id, &later);
+
+        client.schedule_revocation(&mocks.admin, &course_id, &soon, &(DAY + 100));
+        client.schedule_revocation(&mocks.admin, &course_id, &same_day, &(DAY + 500));
+        client.schedule_revocation(&mocks.admin, &course_id, &later, &(3 * DAY));
+
+        // Entries sharing a day are revoked at their own time
+        env.ledger().set_timestamp(DAY + 100);
+        client.process_scheduled_revocations(&mocks.admin, &(DAY + 100));
+        assert!(!client.check_access(&course_id, &soon));
+        assert!(client.check_access(&course_id, &same_day));
+
+        env.ledger().set_timestamp(2 * DAY);
+        client.process_scheduled_revocations(&mocks.admin, &(2 * DAY));
+        assert!(!client.check_access(&course_id, &same_day));
+        assert!(client.check_access(&course_id, &later));
+
+        env.ledger().set_timestamp(3 * DAY);
+        client.process_scheduled_revocations(&mocks.admin, &(3 * DAY));
+        assert!(!client.check_access(&course_id, &later));
+    }
+
+    #[test]
+    fn test_future_target_is_clamped() {
+        let (env, client, mocks) = setup_with_mocks();
+        env.ledger().set_timestamp(DAY);
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+        client.grant_access(&course_id, &user);
+        client.schedule_revocation(&mocks.admin, &course_id, &user, &(2 * DAY));
+
+        client.process_scheduled_revocations(&mocks.admin, &(5 * DAY));
+        assert!(client.check_access(&course_id, &user));
+
+        env.ledger().set_timestamp(2 * DAY);
+        client.process_scheduled_revocations(&mocks.admin, &(5 * DAY));
+        assert!(!client.check_access(&course_id, &user));
+    }
+
+    #[test]
+    fn test_reprocessing_is_noop() {
+        let (env, client, mocks) = setup_with_mocks();
+        env.ledger().set_timestamp(DAY);
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+        client.grant_access(&course_id, &user);
+        client.schedule_revocation(&mocks.admin, &course_id, &user, &(2 * DAY));
+
+        env.ledger().set_timestamp(2 * DAY);
+        client.process_scheduled_revocations(&mocks.admin, &(2 * DAY));
+        assert!(!client.check_access(&course_id, &user));
+
+        // Access granted again after processing stays untouched
+        client.grant_access(&course_id, &user);
+        client.process_scheduled_revocations(&mocks.admin, &(2 * DAY));
+        assert!(client.check_access(&course_id, &user));
+    }
+
+    #[test]
+    fn test_schedule_revocation_validation() {
+        let (env, client, mocks) = setup_with_mocks();
+        env.ledger().set_timestamp(DAY);
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+
+        let result = client.try_schedule_revocation(&mocks.admin, &course_id, &user, &(2 * DAY));
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(102)))
+        );
+
+        client.grant_access(&course_id, &user);
+        let result = client.try_schedule_revocation(&mocks.admin, &course_id, &user, &DAY);
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(119)))
+        );
+    }
+
+    #[test]
+    fn test_unauthorized_scheduling() {
+        let (env, client, _mocks) = setup_with_mocks();
+        env.ledger().set_timestamp(DAY);
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+        client.grant_access(&course_id, &user);
+
+        let result = client.try_schedule_revocation(&user, &course_id, &user, &(2 * DAY));
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(103)))
+        );
+        let result = client.try_process_scheduled_revocations(&user, &(2 * DAY));
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(103)))
+        );
+        assert!(client.check_access(&course_id, &user));
+    }
+}
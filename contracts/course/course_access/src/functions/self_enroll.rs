@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_course_free, is_course_published};
+use crate::functions::grant_access::{store_course_access, validate_grantable_course};
+
+/// Enrolls the calling user in a free, published course.
+///
+/// Enrolling in a course the user already has access to is a no-op.
+///
+/// # Panics
+///
+/// * `Error::CourseArchived` if the course is archived.
+/// * `Error::CourseNotFree` if the course registry does not list the course as free.
+/// * `Error::CourseNotPublished` if the course is not published.
+pub fn course_access_self_enroll(env: Env, user: Address, course_id: String) {
+    user.require_auth();
+    validate_grantable_course(&env, &course_id);
+
+    if !is_course_free(&env, &course_id) {
+        handle_error(&env, Error::CourseNotFree)
+    }
+    if !is_course_published(&env, &course_id) {
+        handle_error(&env, Error::CourseNotPublished)
+    }
+
+    store_course_access(&env, &course_id, &user, None, &user);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{
+        mock_course_registry, mock_user_management, setup_with_mocks, TestMocks,
+    };
+    use crate::{CourseAccessContract, CourseAccessContractClient};
+    use course_registry::schema::{ContentType, EditCourseParams};
+    use course_registry::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn free_published_course(env: &Env, _mocks: &TestMocks) -> String {
+        // Mock courses are free and published by default
+        String::from_str(env, "course_1")
+    }
+
+    #[test]
+    fn test_self_enroll_free_course() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = free_published_course(&env, &mocks);
+        let user = Address::generate(&env);
+
+        client.self_enroll(&user, &course_id);
+        assert!(client.check_access(&course_id, &user));
+
+        // Enrolling again leaves the enrollment untouched
+        client.self_enroll(&user, &course_id);
+        assert_eq!(client.list_course_access(&course_id).users.len(), 1);
+    }
+
+    #[test]
+    fn test_self_enroll_free_course_in_registry() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+        let registry_id = env.register(CourseRegistry, ());
+        let contract_id = env.register(CourseAccessContract, ());
+        let registry = CourseRegistryClient::new(&env, &registry_id);
+        let client = CourseAccessContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        registry.initialize(&admin, &user_mgmt_id, &contract_id);
+        client.initialize(&admin, &user_mgmt_id, &registry_id);
+
+        let creator = Address::generate(&env);
+        let course = registry.create_course(
+            &creator,
+            &String::from_str(&env, "Intro to Soroban"),
+            &String::from_str(&env, "A free course"),
+            &1000,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        registry.add_module(
+            &creator,
+            &course.id,
+            &0,
+            &String::from_str(&env, "Welcome"),
+            &ContentType::Unspecified,
+            &None,
+        );
+        let publish = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: Some(true),
+            new_level: None,
+            new_duration_hours: None,
+        };
+        registry.edit_course(&creator, &course.id, &publish);
+        registry.set_course_free(&creator, &course.id, &true);
+
+        let user = Address::generate(&env);
+        client.self_enroll(&user, &course.id);
+        assert!(client.check_access(&course.id, &user));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #128)")]
+    fn test_self_enroll_full_course() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = free_published_course(&env, &mocks);
+        mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry)
+            .set_max_enrollments(&course_id, &1);
+
+        client.self_enroll(&Address::generate(&env), &course_id);
+        client.self_enroll(&Address::generate(&env), &course_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #126)")]
+    fn test_self_enroll_paid_course() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = free_published_course(&env, &mocks);
+        mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry)
+            .set_paid(&course_id);
+
+        client.self_enroll(&Address::generate(&env), &course_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #116)")]
+    fn test_self_enroll_archived_course() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = free_published_course(&env, &mocks);
+        mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry)
+            .set_archived(&course_id);
+
+        client.self_enroll(&Address::generate(&env), &course_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #127)")]
+    fn test_self_enroll_unpublished_course() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry)
+            .set_unpublished(&course_id);
+
+        client.self_enroll(&Address::generate(&env), &course_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #134)")]
+    fn test_self_enroll_blacklisted_user() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = free_published_course(&env, &mocks);
+        let user = Address::generate(&env);
+        mock_user_management::UserManagementClient::new(&env, &mocks.user_management)
+            .set_blacklisted(&user);
+
+        client.self_enroll(&user, &course_id);
+    }
+}
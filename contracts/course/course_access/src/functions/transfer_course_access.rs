@@ -29,14 +29,19 @@ pub fn transfer_course_access(env: Env, course_id: String, from: Address, to: Ad
     let key: DataKey = DataKey::CourseAccess(course_id.clone(), from.clone());
 
     // Check if access exists to transfer
-    if !env.storage().persistent().has(&key) {
-        handle_error(&env, Error::UserNoAccessCourse);
-    }
+    let existing: CourseAccess = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| handle_error(&env, Error::UserNoAccessCourse));
 
     // Create the course access entry for the new user
     let course_access: CourseAccess = CourseAccess {
         course_id: course_id.clone(),
         user: to.clone(),
+        expiry_timestamp: existing.expiry_timestamp,
+        paused: existing.paused,
+        enrolled_at: env.ledger().timestamp(),
     };
 
     // Store the access entry with the composite key for the new user
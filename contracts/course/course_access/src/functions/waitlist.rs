@@ -0,0 +1,297 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{
+    get_course_max_enrollments, is_blacklisted, is_course_published, require_course_manager,
+};
+use crate::functions::check_access::course_access_check_access;
+use crate::functions::get_enrollment_counts::course_access_get_course_enrollment_count;
+use crate::functions::grant_access::{grantable_course_error, store_course_access};
+use crate::functions::prerequisite_check::{
+    course_access_get_missing_prerequisites, course_access_get_require_prerequisites,
+};
+use crate::schema::{CourseAccess, DataKey};
+
+const WAITLIST_JOINED_EVENT: &str = "WaitlistJoined";
+const WAITLIST_LEFT_EVENT: &str = "WaitlistLeft";
+const WAITLIST_PROMOTED_EVENT: &str = "WaitlistPromoted";
+
+/// Queues `user` for a spot in a course.
+///
+/// # Panics
+///
+/// * `Error::UserAlreadyHasAccess` if the user already has access to the course.
+/// * `Error::AlreadyOnWaitlist` if the user is already queued.
+pub fn course_access_join_waitlist(env: Env, user: Address, course_id: String) {
+    user.require_auth();
+
+    if course_access_check_access(env.clone(), course_id.clone(), user.clone()) {
+        handle_error(&env, Error::UserAlreadyHasAccess)
+    }
+
+    let mut waitlist: Vec<Address> = get_waitlist(&env, &course_id);
+    if waitlist.contains(&user) {
+        handle_error(&env, Error::AlreadyOnWaitlist)
+    }
+    waitlist.push_back(user.clone());
+    save_waitlist(&env, &course_id, &waitlist);
+
+    env.events().publish(
+        (Symbol::new(&env, WAITLIST_JOINED_EVENT), course_id),
+        (user, waitlist.len()),
+    );
+}
+
+/// Removes `user` from the waitlist of a course.
+///
+/// Panics with `Error::NotOnWaitlist` if the user is not queued.
+pub fn course_access_leave_waitlist(env: Env, user: Address, course_id: String) {
+    user.require_auth();
+
+    let mut waitlist: Vec<Address> = get_waitlist(&env, &course_id);
+    let index: u32 = waitlist
+        .first_index_of(&user)
+        .unwrap_or_else(|| handle_error(&env, Error::NotOnWaitlist));
+    waitlist.remove(index);
+    save_waitlist(&env, &course_id, &waitlist);
+
+    env.events()
+        .publish((Symbol::new(&env, WAITLIST_LEFT_EVENT), course_id), user);
+}
+
+/// Returns the users waiting for a course in join order (course creator or admin).
+///
+/// Panics with `Error::Unauthorized` if the caller is neither.
+pub fn course_access_list_waitlist(env: Env, caller: Address, course_id: String) -> Vec<Address> {
+    require_course_manager(&env, &caller, &course_id);
+
+    get_waitlist(&env, &course_id)
+}
+
+/// Grants the freed spot of a course to the first eligible waiting user.
+///
+/// Users who gained access in the meantime leave the queue; blacklisted users,
+/// users whose access is paused and users still missing required prerequisites
+/// keep their place. Nothing
+/// happens while the course is at capacity or not open for enrollment, so the
+/// revocation that freed the spot never fails on the promotion.
+pub fn promote_from_waitlist(env: &Env, course_id: &String) {
+    let waitlist: Vec<Address> = get_waitlist(env, course_id);
+    if waitlist.is_empty() {
+        return;
+    }
+
+    if grantable_course_error(env, course_id).is_some() || !is_course_published(env, course_id) {
+        return;
+    }
+
+    if let Some(cap) = get_course_max_enrollments(env, course_id) {
+        if course_access_get_course_enrollment_count(env.clone(), course_id.clone()) >= cap {
+            return;
+        }
+    }
+
+    let check_prerequisites: bool = course_access_get_require_prerequisites(env.clone());
+    let mut promoted: Option<Address> = None;
+    let mut remaining: Vec<Address> = Vec::new(env);
+    for candidate in waitlist.iter() {
+        if course_access_check_access(env.clone(), course_id.clone(), candidate.clone()) {
+            continue;
+        }
+        // A paused record would block the grant, so the spot goes to the next user
+        let eligible: bool = !is_blacklisted(env, &candidate)
+            && !has_paused_access(env, course_id, &candidate)
+            && (!check_prerequisites
+                || course_access_get_missing_prerequisites(env.clone(), candidate.clone(), course_id.clone())
+                    .is_empty());
+        if promoted.is_none() && eligible {
+            promoted = Some(candidate);
+        } else {
+            remaining.push_back(candidate);
+        }
+    }
+    save_waitlist(env, course_id, &remaining);
+
+    if let Some(user) = promoted {
+        store_course_access(env, course_id, &user, None, &env.current_contract_address());
+        env.events().publish(
+            (Symbol::new(env, WAITLIST_PROMOTED_EVENT), course_id.clone()),
+            user,
+        );
+    }
+}
+
+fn has_paused_access(env: &Env, course_id: &String, user: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get::<DataKey, CourseAccess>(&DataKey::CourseAccess(course_id.clone(), user.clone()))
+        .is_some_and(|access| access.paused && !access.is_expired(env.ledger().timestamp()))
+}
+
+fn get_waitlist(env: &Env, course_id: &String) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Waitlist(course_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn save_waitlist(env: &Env, course_id: &String, waitlist: &Vec<Address>) {
+    let key: DataKey = DataKey::Waitlist(course_id.clone());
+    if waitlist.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, waitlist);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{mock_course_registry, mock_user_management, setup_with_mocks};
+    use soroban_sdk::{testutils::Address as _, vec, Address, String};
+
+    #[test]
+    fn test_join_and_list_waitlist() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+
+        client.join_waitlist(&first, &course_id);
+        client.join_waitlist(&second, &course_id);
+
+        assert_eq!(
+            client.list_waitlist(&mocks.admin, &course_id),
+            vec![&env, first.clone(), second]
+        );
+        let result = client.try_join_waitlist(&first, &course_id);
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(131))));
+    }
+
+    #[test]
+    fn test_leave_waitlist() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+        client.join_waitlist(&user, &course_id);
+
+        client.leave_waitlist(&user, &course_id);
+
+        assert!(client.list_waitlist(&mocks.admin, &course_id).is_empty());
+        let result = client.try_leave_waitlist(&user, &course_id);
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(132))));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #101)")]
+    fn test_join_waitlist_with_access() {
+        let (env, client, _mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let user = Address::generate(&env);
+        client.grant_access(&course_id, &user);
+
+        client.join_waitlist(&user, &course_id);
+    }
+
+    #[test]
+    fn test_revoke_promotes_first_waiting_user() {
+        let (env, client, mocks) = setup_with_mocks();
+        let registry = mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry);
+        let course_id = String::from_str(&env, "course_1");
+        registry.set_max_enrollments(&course_id, &1);
+        let enrolled = Address::generate(&env);
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        client.grant_access(&course_id, &enrolled);
+        assert!(client.try_grant_access(&course_id, &first).is_err());
+
+        client.join_waitlist(&first, &course_id);
+        client.join_waitlist(&second, &course_id);
+        client.revoke_access(&course_id, &enrolled);
+
+        assert!(client.check_access(&course_id, &first));
+        assert!(!client.check_access(&course_id, &second));
+        assert_eq!(client.list_waitlist(&mocks.admin, &course_id), vec![&env, second]);
+    }
+
+    #[test]
+    fn test_promotion_when_course_not_full() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let enrolled = Address::generate(&env);
+        let waiting = Address::generate(&env);
+        client.grant_access(&course_id, &enrolled);
+
+        // No enrollment cap: joining and promotion skip the capacity check
+        client.join_waitlist(&waiting, &course_id);
+        client.revoke_access(&course_id, &enrolled);
+
+        assert!(client.check_access(&course_id, &waiting));
+        assert!(client.list_waitlist(&mocks.admin, &course_id).is_empty());
+    }
+
+    #[test]
+    fn test_no_promotion_into_closed_course() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let enrolled = Address::generate(&env);
+        let waiting = Address::generate(&env);
+        client.grant_access(&course_id, &enrolled);
+        client.join_waitlist(&waiting, &course_id);
+        mock_course_registry::CourseRegistryClient::new(&env, &mocks.course_registry)
+            .set_archived(&course_id);
+
+        client.revoke_access(&course_id, &enrolled);
+
+        assert!(!client.check_access(&course_id, &waiting));
+        assert_eq!(client.list_waitlist(&mocks.admin, &course_id), vec![&env, waiting]);
+    }
+
+    #[test]
+    fn test_promotion_skips_blacklisted_user() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let enrolled = Address::generate(&env);
+        let blacklisted = Address::generate(&env);
+        let next = Address::generate(&env);
+        client.grant_access(&course_id, &enrolled);
+        client.join_waitlist(&blacklisted, &course_id);
+        client.join_waitlist(&next, &course_id);
+        mock_user_management::UserManagementClient::new(&env, &mocks.user_management)
+            .set_blacklisted(&blacklisted);
+
+        client.revoke_access(&course_id, &enrolled);
+
+        assert!(client.check_access(&course_id, &next));
+        assert_eq!(client.list_waitlist(&mocks.admin, &course_id), vec![&env, blacklisted]);
+    }
+
+    #[test]
+    fn test_promotion_skips_paused_user() {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_id = String::from_str(&env, "course_1");
+        let enrolled = Address::generate(&env);
+        let paused = Address::generate(&env);
+        let next = Address::generate(&env);
+        client.grant_access(&course_id, &enrolled);
+        client.grant_access(&course_id, &paused);
+        client.pause_access(&mocks.admin, &course_id, &paused);
+        client.join_waitlist(&paused, &course_id);
+        client.join_waitlist(&next, &course_id);
+
+        client.revoke_access(&course_id, &enrolled);
+
+        assert!(client.check_access(&course_id, &next));
+        assert!(!client.check_access(&course_id, &paused));
+        assert_eq!(client.list_waitlist(&mocks.admin, &course_id), vec![&env, paused]);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #103)")]
+    fn test_list_waitlist_unauthorized() {
+        let (env, client, _mocks) = setup_with_mocks();
+        client.list_waitlist(&Address::generate(&env), &String::from_str(&env, "course_1"));
+    }
+}
@@ -15,8 +15,8 @@ mod test;
 
 use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
 
-use functions::{config::initialize,config::set_contract_addrs, grant_access::course_access_grant_access, revoke_access::course_access_revoke_access, revoke_all_access::revoke_all_access, save_profile::save_user_profile, list_user_courses::list_user_courses, list_course_access::course_access_list_course_access, contract_versioning::{is_version_compatible, get_migration_status, get_version_history, migrate_access_data}, transfer_course_access::transfer_course_access};
-use schema::{CourseUsers, UserCourses};
+use functions::{access_delegation::{course_access_create_delegation, course_access_delegate_access, course_access_get_delegation_stats, course_access_revoke_delegation}, bundle_access::{course_access_grant_bundle_access, course_access_revoke_bundle_access}, check_access::course_access_check_access, cleanup_expired_access::course_access_cleanup_expired_access, config::initialize,config::set_contract_addrs, config::set_certification_addr, count_active_enrollments::{course_access_count_active_enrollments, course_access_get_enrollment_breakdown}, enrollment_groups::{course_access_add_group_member, course_access_create_enrollment_group, course_access_grant_group_access, course_access_list_group_members, course_access_remove_group_member}, get_enrollment_counts::{course_access_get_course_enrollment_count, course_access_get_user_access_count}, get_enrollment_history::{course_access_get_enrollment_history, course_access_get_enrollment_timestamp}, grant_access::course_access_grant_access, grant_access_batch::course_access_grant_access_batch, grant_access_with_expiry::{course_access_extend_access, course_access_grant_access_with_expiry}, request_access::{course_access_approve_access_request, course_access_list_pending_requests, course_access_reject_access_request, course_access_request_access}, revoke_access::course_access_revoke_access, revoke_access_batch::course_access_revoke_access_batch, revoke_all_access::revoke_all_access, save_profile::save_user_profile, schedule_revocation::{course_access_process_scheduled_revocations, course_access_schedule_revocation}, self_enroll::course_access_self_enroll, list_user_courses::list_user_courses, invitation_codes::{course_access_create_invitation_code, course_access_redeem_invitation_code}, learning_path_progress::course_access_get_learning_path_progress, list_course_access::course_access_list_course_access, list_course_users::course_access_list_course_users, pause_access::{course_access_pause_access, course_access_resume_access}, prerequisite_check::{course_access_get_missing_prerequisites, course_access_get_require_prerequisites, course_access_set_require_prerequisites}, contract_versioning::{is_version_compatible, get_migration_status, get_version_history, migrate_access_data}, get_access_audit_log::course_access_get_access_audit_log, get_access_details::{course_access_get_access_details, course_access_get_user_access_snapshot}, transfer_course_access::transfer_course_access, waitlist::{course_access_join_waitlist, course_access_leave_waitlist, course_access_list_waitlist}};
+use schema::{AccessDelegation, AccessEvent, AccessRequest, BatchRevokeResult, CourseAccess, CourseUserPage, CourseUsers, EnrollmentBreakdown, EnrollmentGroup, LearningPathProgress, UserCourses};
 
 /// Course Access Contract
 ///
@@ -85,6 +85,7 @@ impl CourseAccessContract {
     /// # Panics
     ///
     /// * If course doesn't exist
+    /// * If the course is unpublished, archived or frozen
     /// * If caller is not authorized (not course creator or admin)
     /// * If user already has access
     ///
@@ -116,6 +117,656 @@ impl CourseAccessContract {
         course_access_grant_access(env, course_id, user)
     }
 
+    /// Enroll the calling user in a free, published course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user enrolling
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Panics
+    ///
+    /// * If the course ID is invalid or the course is archived
+    /// * If the course doesn't exist, is not marked free or is not published
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Already enrolled**: Returns without changes
+    pub fn self_enroll(env: Env, user: Address, course_id: String) {
+        course_access_self_enroll(env, user, course_id)
+    }
+
+    /// Grant access to several users for a given course.
+    ///
+    /// Only the course creator or an admin can grant access in bulk. Users
+    /// who already have access are skipped rather than failing the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `users` - The addresses of the users to grant access to
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of newly enrolled users.
+    ///
+    /// # Panics
+    ///
+    /// * If `users` is empty or holds more than `MAX_BATCH_SIZE` addresses
+    /// * If the course ID is invalid or the course is archived
+    /// * If the course is unpublished, unless the caller is an admin
+    /// * If caller is not authorized (not course creator or admin)
+    pub fn grant_access_batch(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        users: Vec<Address>,
+    ) -> u32 {
+        course_access_grant_access_batch(env, caller, course_id, users)
+    }
+
+    /// Create an enrollment group for managing a team's access together.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `owner` - The address that will manage the group's members
+    /// * `name` - The group name
+    ///
+    /// # Returns
+    ///
+    /// Returns the new, empty `EnrollmentGroup`.
+    ///
+    /// # Panics
+    ///
+    /// * If `name` is empty or longer than 100 characters
+    pub fn create_enrollment_group(env: Env, owner: Address, name: String) -> EnrollmentGroup {
+        course_access_create_enrollment_group(env, owner, name)
+    }
+
+    /// Add a member to an enrollment group.
+    ///
+    /// Only the group owner can change its members.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `owner` - The address of the group owner
+    /// * `group_id` - The unique identifier of the group
+    /// * `member` - The address of the user to add
+    ///
+    /// # Panics
+    ///
+    /// * If the group doesn't exist or the caller doesn't own it
+    /// * If the user is already a member or the group is full
+    pub fn add_group_member(env: Env, owner: Address, group_id: u128, member: Address) {
+        course_access_add_group_member(env, owner, group_id, member)
+    }
+
+    /// Remove a member from an enrollment group.
+    ///
+    /// Course access already granted through the group is kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `owner` - The address of the group owner
+    /// * `group_id` - The unique identifier of the group
+    /// * `member` - The address of the user to remove
+    ///
+    /// # Panics
+    ///
+    /// * If the group doesn't exist or the caller doesn't own it
+    /// * If the user is not a member
+    pub fn remove_group_member(env: Env, owner: Address, group_id: u128, member: Address) {
+        course_access_remove_group_member(env, owner, group_id, member)
+    }
+
+    /// Grant every member of an enrollment group access to a course.
+    ///
+    /// Only the course creator or an admin can grant group access. Members
+    /// who already have access are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or admin
+    /// * `group_id` - The unique identifier of the group
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of newly enrolled members.
+    ///
+    /// # Panics
+    ///
+    /// * If the group doesn't exist
+    /// * If caller is not authorized (not course creator or admin)
+    /// * If the course cannot take new enrollments
+    pub fn grant_group_access(env: Env, caller: Address, group_id: u128, course_id: String) -> u32 {
+        course_access_grant_group_access(env, caller, group_id, course_id)
+    }
+
+    /// List the members of an enrollment group.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `group_id` - The unique identifier of the group
+    ///
+    /// # Returns
+    ///
+    /// Returns the members in the order they were added.
+    ///
+    /// # Panics
+    ///
+    /// * If the group doesn't exist
+    pub fn list_group_members(env: Env, group_id: u128) -> Vec<Address> {
+        course_access_list_group_members(env, group_id)
+    }
+
+    /// Schedule a user's access to a course to be revoked at a later time.
+    ///
+    /// Revocations take effect once `revoke_at` has passed and an admin calls
+    /// `process_scheduled_revocations`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address scheduling the revocation (course creator or admin)
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user whose access ends
+    /// * `revoke_at` - Unix timestamp after which access is revoked
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not authorized (not course creator or admin)
+    /// * If `revoke_at` is not in the future
+    /// * If the user has no access to the course
+    pub fn schedule_revocation(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        user: Address,
+        revoke_at: u64,
+    ) {
+        course_access_schedule_revocation(env, caller, course_id, user, revoke_at)
+    }
+
+    /// Revoke every scheduled access due at or before a timestamp.
+    ///
+    /// A timestamp later than the current ledger time is clamped to it.
+    /// Processed entries are removed, so repeated calls are safe.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The admin processing the revocations
+    /// * `target_timestamp` - Unix timestamp up to which revocations are processed
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not an admin
+    pub fn process_scheduled_revocations(env: Env, caller: Address, target_timestamp: u64) {
+        course_access_process_scheduled_revocations(env, caller, target_timestamp)
+    }
+
+    /// Grant a user access to every course in a bundle.
+    ///
+    /// Courses the user can already access are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - An admin or the creator of every bundled course
+    /// * `bundle_id` - The unique identifier of the bundle in the course registry
+    /// * `user` - The address of the user to grant access to
+    ///
+    /// # Panics
+    ///
+    /// * If the bundle doesn't exist
+    /// * If caller is not authorized for every bundled course
+    /// * If a bundled course is archived or at capacity
+    pub fn grant_bundle_access(env: Env, caller: Address, bundle_id: u128, user: Address) {
+        course_access_grant_bundle_access(env, caller, bundle_id, user)
+    }
+
+    /// Revoke a user's access to every course in a bundle.
+    ///
+    /// Courses the user cannot access are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - An admin or the creator of every bundled course
+    /// * `bundle_id` - The unique identifier of the bundle in the course registry
+    /// * `user` - The address of the user to revoke access from
+    ///
+    /// # Panics
+    ///
+    /// * If the bundle doesn't exist
+    /// * If caller is not authorized for every bundled course
+    pub fn revoke_bundle_access(env: Env, caller: Address, bundle_id: u128, user: Address) {
+        course_access_revoke_bundle_access(env, caller, bundle_id, user)
+    }
+
+    /// Grant a user access to a course until a given ledger timestamp.
+    ///
+    /// Only the course creator or an admin can grant expiring access.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user to grant access to
+    /// * `expiry_timestamp` - Ledger timestamp after which the access is invalid
+    ///
+    /// # Panics
+    ///
+    /// * If `expiry_timestamp` is not in the future
+    /// * If the course ID is invalid or the course is archived
+    /// * If caller is not authorized (not course creator or admin)
+    /// * If user already has unexpired access
+    pub fn grant_access_with_expiry(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        user: Address,
+        expiry_timestamp: u64,
+    ) {
+        course_access_grant_access_with_expiry(env, caller, course_id, user, expiry_timestamp)
+    }
+
+    /// Push the expiry of a user's course access forward.
+    ///
+    /// Only the course creator or an admin can extend access.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user whose access is extended
+    /// * `new_expiry` - The new expiry ledger timestamp
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not authorized (not course creator or admin)
+    /// * If the user has no access record for the course
+    /// * If the access never expires or `new_expiry` does not extend it
+    pub fn extend_access(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        user: Address,
+        new_expiry: u64,
+    ) {
+        course_access_extend_access(env, caller, course_id, user, new_expiry)
+    }
+
+    /// Get the access audit log of a course, oldest entry first.
+    ///
+    /// Only the course creator or an admin can read the log.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `offset` - Number of entries to skip
+    /// * `limit` - Maximum number of entries to return (capped at 100)
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not authorized (not course creator or admin)
+    pub fn get_access_audit_log(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<AccessEvent> {
+        course_access_get_access_audit_log(env, caller, course_id, offset, limit)
+    }
+
+    /// Temporarily suspend a user's course access without revoking it.
+    ///
+    /// Only the course creator or an admin can pause access.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user whose access is paused
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not authorized (not course creator or admin)
+    /// * If the user has no access record for the course
+    pub fn pause_access(env: Env, caller: Address, course_id: String, user: Address) {
+        course_access_pause_access(env, caller, course_id, user)
+    }
+
+    /// Resume a user's paused course access.
+    ///
+    /// Only the course creator or an admin can resume access.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user whose access is resumed
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not authorized (not course creator or admin)
+    /// * If the user has no access record for the course
+    pub fn resume_access(env: Env, caller: Address, course_id: String, user: Address) {
+        course_access_resume_access(env, caller, course_id, user)
+    }
+
+    /// Check whether a user has access to a course.
+    ///
+    /// This is a read-only query and requires no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user to check
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the user currently has access to the course, and
+    /// `false` otherwise, including when `course_id` is empty.
+    pub fn check_access(env: Env, course_id: String, user: Address) -> bool {
+        course_access_check_access(env, course_id, user)
+    }
+
+    /// Get the full access record of a user for a course.
+    ///
+    /// Unlike `check_access`, this returns expired and paused access too.
+    /// This is a read-only query and requires no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the access record, including its expiry and paused state.
+    ///
+    /// # Panics
+    ///
+    /// * If the user has no access record for the course
+    pub fn get_access_details(env: Env, user: Address, course_id: String) -> CourseAccess {
+        course_access_get_access_details(env, user, course_id)
+    }
+
+    /// Get the access records of every course a user is enrolled in.
+    ///
+    /// This is a read-only query and requires no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user
+    ///
+    /// # Returns
+    ///
+    /// Returns one access record per enrolled course, in the order of the
+    /// user's course list; empty if the user has no access.
+    pub fn get_user_access_snapshot(env: Env, user: Address) -> Vec<CourseAccess> {
+        course_access_get_user_access_snapshot(env, user)
+    }
+
+    /// Get a page of a user's enrollments, most recent first.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user, who must authorize the call
+    /// * `offset` - Number of records to skip
+    /// * `limit` - Maximum number of records to return, capped at 100
+    ///
+    /// # Returns
+    ///
+    /// Returns the user's access records sorted by `enrolled_at` descending.
+    ///
+    /// # Panics
+    ///
+    /// * If `user` does not authorize the call
+    pub fn get_enrollment_history(
+        env: Env,
+        user: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<CourseAccess> {
+        course_access_get_enrollment_history(env, user, offset, limit)
+    }
+
+    /// Get the time at which a user was granted access to a course.
+    ///
+    /// This is a read-only query and requires no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the ledger timestamp of the enrollment.
+    ///
+    /// # Panics
+    ///
+    /// * If the user has no access record for the course
+    pub fn get_enrollment_timestamp(env: Env, user: Address, course_id: String) -> u64 {
+        course_access_get_enrollment_timestamp(env, user, course_id)
+    }
+
+    /// Require users to hold access to a course's prerequisites before being granted it.
+    ///
+    /// When enabled, grants made by anyone other than an admin fail with
+    /// `PrerequisitesNotMet` if the user lacks access to a prerequisite
+    /// listed in the course registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The admin changing the setting
+    /// * `required` - Whether prerequisites are checked on grant
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not an admin
+    pub fn set_require_prerequisites(env: Env, caller: Address, required: bool) {
+        course_access_set_require_prerequisites(env, caller, required)
+    }
+
+    /// Check whether grants require access to the course prerequisites.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if prerequisites are checked on grant, `false` by default.
+    pub fn get_require_prerequisites(env: Env) -> bool {
+        course_access_get_require_prerequisites(env)
+    }
+
+    /// List the prerequisites of a course a user does not yet have access to.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user to check
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the missing prerequisite course IDs, empty if all are met.
+    pub fn get_missing_prerequisites(env: Env, user: Address, course_id: String) -> Vec<String> {
+        course_access_get_missing_prerequisites(env, user, course_id)
+    }
+
+    /// Join the waitlist of a course.
+    ///
+    /// The first eligible waiting user is granted access when a spot frees up
+    /// through a revocation.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user joining the waitlist
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Panics
+    ///
+    /// * If the user already has access to the course
+    /// * If the user is already on the waitlist
+    pub fn join_waitlist(env: Env, user: Address, course_id: String) {
+        course_access_join_waitlist(env, user, course_id)
+    }
+
+    /// Leave the waitlist of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user leaving the waitlist
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Panics
+    ///
+    /// * If the user is not on the waitlist
+    pub fn leave_waitlist(env: Env, user: Address, course_id: String) {
+        course_access_leave_waitlist(env, user, course_id)
+    }
+
+    /// Delete expired access records of a course to free storage.
+    ///
+    /// Only the course creator or an admin can clean up a course. At most
+    /// `max_to_remove` records are deleted per call to stay within compute
+    /// limits.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address performing the cleanup
+    /// * `course_id` - The unique identifier of the course
+    /// * `max_to_remove` - Maximum number of records to delete
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of expired records deleted.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is neither the course creator nor an admin
+    pub fn cleanup_expired_access(env: Env, caller: Address, course_id: String, max_to_remove: u32) -> u32 {
+        course_access_cleanup_expired_access(env, caller, course_id, max_to_remove)
+    }
+
+    /// List the users waiting for a course, in join order.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address requesting the list (course creator or admin)
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the waiting users, first in line first.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is neither the course creator nor an admin
+    pub fn list_waitlist(env: Env, caller: Address, course_id: String) -> Vec<Address> {
+        course_access_list_waitlist(env, caller, course_id)
+    }
+
+    /// Get the number of courses a user has access to.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of accessible courses, or `0` for unknown users.
+    pub fn get_user_access_count(env: Env, user: Address) -> u32 {
+        course_access_get_user_access_count(env, user)
+    }
+
+    /// Get the number of users enrolled in a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of enrolled users, or `0` for unknown courses.
+    pub fn get_course_enrollment_count(env: Env, course_id: String) -> u32 {
+        course_access_get_course_enrollment_count(env, course_id)
+    }
+
+    /// Get the number of users whose access to a course is currently usable.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of enrolled users whose access is neither paused
+    /// nor expired, or `0` for unknown courses.
+    pub fn count_active_enrollments(env: Env, course_id: String) -> u32 {
+        course_access_count_active_enrollments(env, course_id)
+    }
+
+    /// Get the enrollments of a course split into active, paused and expired.
+    ///
+    /// Only the course creator or an admin can read the breakdown.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or admin
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns an `EnrollmentBreakdown`; expired access counts as expired
+    /// even when it is also paused.
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not authorized (not course creator or admin)
+    pub fn get_enrollment_breakdown(
+        env: Env,
+        caller: Address,
+        course_id: String,
+    ) -> EnrollmentBreakdown {
+        course_access_get_enrollment_breakdown(env, caller, course_id)
+    }
+
     /// Revoke access for a specific user from a course.
     ///
     /// Removes a user's access to a specific course. Only authorized users
@@ -163,6 +814,153 @@ impl CourseAccessContract {
         course_access_revoke_access(env, course_id, user)
     }
 
+    /// Create an invitation code that enrolls its redeemers in a course.
+    ///
+    /// Only the course creator or an admin can create invitation codes.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator or admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `max_uses` - Maximum number of redemptions
+    /// * `expires_at` - Optional ledger timestamp from which the code is invalid
+    ///
+    /// # Returns
+    ///
+    /// Returns the generated 8-character hex code.
+    ///
+    /// # Panics
+    ///
+    /// * If `max_uses` is zero or `expires_at` is not in the future
+    /// * If the course ID is invalid or the course is archived
+    /// * If caller is not authorized (not course creator or admin)
+    pub fn create_invitation_code(
+        env: Env,
+        creator: Address,
+        course_id: String,
+        max_uses: u32,
+        expires_at: Option<u64>,
+    ) -> String {
+        course_access_create_invitation_code(env, creator, course_id, max_uses, expires_at)
+    }
+
+    /// Redeem an invitation code to gain access to its course.
+    ///
+    /// Redeeming a code for a course the user already has access to does nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user redeeming the code
+    /// * `code` - The invitation code
+    ///
+    /// # Panics
+    ///
+    /// * If the code does not exist
+    /// * If the code has no uses left or has expired
+    pub fn redeem_invitation_code(env: Env, user: Address, code: String) {
+        course_access_redeem_invitation_code(env, user, code)
+    }
+
+    /// Ask for access to a course that requires approval.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `requester` - The address of the user asking for access
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Panics
+    ///
+    /// * If the course ID is invalid or the course is archived
+    /// * If the requester already has access or a pending request
+    pub fn request_access(env: Env, requester: Address, course_id: String) {
+        course_access_request_access(env, requester, course_id)
+    }
+
+    /// Approve a pending access request and grant access.
+    ///
+    /// Only the course creator or an admin can approve requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `requester` - The address of the user who requested access
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not authorized (not course creator or admin)
+    /// * If there is no pending request from the requester
+    pub fn approve_access_request(env: Env, caller: Address, course_id: String, requester: Address) {
+        course_access_approve_access_request(env, caller, course_id, requester)
+    }
+
+    /// Reject a pending access request.
+    ///
+    /// Only the course creator or an admin can reject requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `requester` - The address of the user who requested access
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not authorized (not course creator or admin)
+    /// * If there is no pending request from the requester
+    pub fn reject_access_request(env: Env, caller: Address, course_id: String, requester: Address) {
+        course_access_reject_access_request(env, caller, course_id, requester)
+    }
+
+    /// List the pending access requests of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or admin
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the pending requests in the order they were made.
+    pub fn list_pending_requests(env: Env, caller: Address, course_id: String) -> Vec<AccessRequest> {
+        course_access_list_pending_requests(env, caller, course_id)
+    }
+
+    /// Revoke access for several users from a course.
+    ///
+    /// Only the course creator or an admin can revoke access in bulk. Users
+    /// without access are skipped rather than failing the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `users` - The addresses of the users to revoke access from
+    ///
+    /// # Returns
+    ///
+    /// Returns how many users were revoked and how many were skipped.
+    ///
+    /// # Panics
+    ///
+    /// * If `users` holds more than `MAX_BATCH_SIZE` addresses
+    /// * If caller is not authorized (not course creator or admin)
+    pub fn revoke_access_batch(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        users: Vec<Address>,
+    ) -> BatchRevokeResult {
+        course_access_revoke_access_batch(env, caller, course_id, users)
+    }
+
     /// Save or update a user's profile on-chain.
     ///
     /// Stores user profile information in the contract storage.
@@ -293,6 +1091,36 @@ impl CourseAccessContract {
         course_access_list_course_access(env, course_id)
     }
 
+    /// List the users enrolled in a course, one page at a time.
+    ///
+    /// Only the course creator or an admin can list enrolled users.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `offset` - Number of users to skip
+    /// * `limit` - Maximum number of users to return
+    ///
+    /// # Returns
+    ///
+    /// Returns the requested page with the total number of enrolled users.
+    ///
+    /// # Panics
+    ///
+    /// * If the course ID is empty or too long
+    /// * If caller is not authorized (not course creator or admin)
+    pub fn list_course_users(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        offset: u32,
+        limit: u32,
+    ) -> CourseUserPage {
+        course_access_list_course_users(env, caller, course_id, offset, limit)
+    }
+
     /// Revoke all user access for a course.
     ///
     /// Removes access for all users from the specified course.
@@ -384,6 +1212,53 @@ impl CourseAccessContract {
         set_contract_addrs(env, caller, user_mgmt_addr, course_registry_addr)
     }
 
+    /// Set the address of the certification contract.
+    ///
+    /// The certification contract is used to look up which courses a user
+    /// has completed. Only admins can change it.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of an admin
+    /// * `certification_addr` - Address of the certification contract
+    ///
+    /// # Panics
+    ///
+    /// * If caller is not an admin
+    pub fn set_certification_address(env: Env, caller: Address, certification_addr: Address) {
+        set_certification_addr(env, caller, certification_addr)
+    }
+
+    /// Get a user's progress along the prerequisite path of a course.
+    ///
+    /// The path holds the entry course and every course it transitively
+    /// requires. Courses with an unrevoked certificate are completed, courses
+    /// the user has access to are in progress, and the rest are not started.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the learner
+    /// * `entry_course_id` - The unique identifier of the course the path leads to
+    ///
+    /// # Returns
+    ///
+    /// Returns a `LearningPathProgress` with the path, its courses grouped
+    /// by status and the percentage of completed courses.
+    ///
+    /// # Panics
+    ///
+    /// * If the user does not authorize the call
+    /// * If the course registry does not know the entry course
+    pub fn get_learning_path_progress(
+        env: Env,
+        user: Address,
+        entry_course_id: String,
+    ) -> LearningPathProgress {
+        course_access_get_learning_path_progress(env, user, entry_course_id)
+    }
+
     /// Get the current contract version
     ///
     /// Returns the semantic version of the current contract deployment.
@@ -466,4 +1341,85 @@ impl CourseAccessContract {
     pub fn transfer_course(env: Env, course_id: String, from: Address, to: Address){
         transfer_course_access(env, course_id, from, to)
     }
+
+    /// Create or resize a sponsor's pool of delegated course seats (admin only).
+    ///
+    /// Enterprise customers purchase bulk access and then assign the seats
+    /// to their employees through `delegate_access`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The admin granting the sponsor rights
+    /// * `sponsor` - The account that will assign the seats
+    /// * `course_id` - The course the seats grant access to
+    /// * `max_delegations` - Maximum number of seats in the pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the stored `AccessDelegation`.
+    ///
+    /// # Panics
+    ///
+    /// * If `admin` is not an admin
+    /// * If `max_delegations` is zero or below the seats already in use
+    pub fn create_delegation(
+        env: Env,
+        admin: Address,
+        sponsor: Address,
+        course_id: String,
+        max_delegations: u32,
+    ) -> AccessDelegation {
+        course_access_create_delegation(env, admin, sponsor, course_id, max_delegations)
+    }
+
+    /// Assign one of the sponsor's seats to a user, granting course access.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `sponsor` - The sponsor owning the seat pool
+    /// * `course_id` - The course to grant access to
+    /// * `user` - The user receiving access
+    ///
+    /// # Panics
+    ///
+    /// * If the sponsor has no delegation for the course
+    /// * If all seats are in use
+    /// * If the user already has access
+    pub fn delegate_access(env: Env, sponsor: Address, course_id: String, user: Address) {
+        course_access_delegate_access(env, sponsor, course_id, user)
+    }
+
+    /// Revoke access previously delegated by a sponsor and free the seat.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `sponsor` - The sponsor owning the seat pool
+    /// * `course_id` - The course to revoke access from
+    /// * `user` - The user losing access
+    ///
+    /// # Panics
+    ///
+    /// * If the sponsor has no delegation for the course
+    /// * If the user was not delegated by this sponsor
+    pub fn revoke_delegation(env: Env, sponsor: Address, course_id: String, user: Address) {
+        course_access_revoke_delegation(env, sponsor, course_id, user)
+    }
+
+    /// Get the seat usage of a sponsor's delegation.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `sponsor` - The sponsor owning the seat pool
+    /// * `course_id` - The course of the delegation
+    ///
+    /// # Returns
+    ///
+    /// Returns `(used, remaining)` seats, or `(0, 0)` if no delegation exists.
+    pub fn get_delegation_stats(env: Env, sponsor: Address, course_id: String) -> (u32, u32) {
+        course_access_get_delegation_stats(env, sponsor, course_id)
+    }
 }
@@ -14,6 +14,19 @@ pub struct CourseAccess {
     pub course_id: String,
     /// The address of the user who has access
     pub user: Address,
+    /// Ledger timestamp after which the access is no longer valid, if any
+    pub expiry_timestamp: Option<u64>,
+    /// Whether the access is temporarily suspended
+    pub paused: bool,
+    /// Ledger timestamp at which the access was granted
+    pub enrolled_at: u64,
+}
+
+impl CourseAccess {
+    /// Returns whether the access has passed its expiry timestamp.
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expiry_timestamp.is_some_and(|expiry| expiry < now)
+    }
 }
 
 /// Contains all courses that a specific user has access to.
@@ -44,6 +57,32 @@ pub enum DataKey {
     UserCourses(Address),
     /// Key for storing users per course: course_id -> CourseUsers
     CourseUsers(String),
+    /// Key for storing sponsor seat pools: (sponsor, course_id) -> AccessDelegation
+    AccessDelegation(Address, String),
+    /// Key for storing access requests: (course_id, requester) -> AccessRequest
+    AccessRequest(String, Address),
+    /// Key for storing requesters awaiting a decision: course_id -> Vec<Address>
+    PendingAccessRequests(String),
+    /// Key for storing invitation codes: code -> InvitationCode
+    InvitationCode(String),
+    /// Key for the counter mixed into generated invitation codes
+    InvitationCodeSeq,
+    /// Key for the number of audit log entries of a course
+    AccessLogSeq(String),
+    /// Key for one audit log entry: (course_id, sequence) -> AccessEvent
+    AccessLogEntry(String, u32),
+    /// Key for whether grants require access to the course prerequisites: bool
+    RequirePrerequisites,
+    /// Key for storing users queued for a full course, in join order: course_id -> Vec<Address>
+    Waitlist(String),
+    /// Key for the last issued enrollment group ID
+    EnrollmentGroupSeq,
+    /// Key for storing enrollment groups: group_id -> EnrollmentGroup
+    EnrollmentGroup(u128),
+    /// Key for storing the access revocations due on a day: day timestamp -> Vec<(course_id, user, revoke_at)>
+    ScheduledRevocations(u64),
+    /// Key for storing the day timestamps with pending revocations, ascending: Vec<u64>
+    ScheduledRevocationDays,
 }
 
 /// Represents a user's profile information.
@@ -78,8 +117,181 @@ pub struct CourseUsers {
     pub users: Vec<Address>,
 }
 
+/// Pool of course seats a sponsor can assign on behalf of an organization.
+///
+/// Created by an admin for a sponsor (e.g. a corporate account); the sponsor
+/// then grants access to individual users until `max_delegations` is reached.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AccessDelegation {
+    /// The account that owns the seat pool
+    pub sponsor: Address,
+    /// The course the seats grant access to
+    pub course_id: String,
+    /// Users who currently occupy a seat
+    pub delegated_to: Vec<Address>,
+    /// Maximum number of seats the sponsor can assign
+    pub max_delegations: u32,
+}
+
+/// Lifecycle state of an access request.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum AccessRequestStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+/// A user's request to be granted access to a course.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AccessRequest {
+    /// The course the user asked to access
+    pub course_id: String,
+    /// The user who made the request
+    pub requester: Address,
+    /// Ledger timestamp at which the request was made
+    pub requested_at: u64,
+    /// Current state of the request
+    pub status: AccessRequestStatus,
+}
+
+/// A secret code that enrolls whoever redeems it in a course.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct InvitationCode {
+    /// The 8-character hex code
+    pub code: String,
+    /// The course the code grants access to
+    pub course_id: String,
+    /// Maximum number of redemptions
+    pub max_uses: u32,
+    /// Number of redemptions so far
+    pub used_count: u32,
+    /// Ledger timestamp from which the code can no longer be redeemed, if any
+    pub expires_at: Option<u64>,
+}
+
+/// Kind of change recorded in the access audit log.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum AccessEventType {
+    Granted,
+    Revoked,
+    Paused,
+    Resumed,
+    Expired,
+}
+
+/// One entry of a course's access audit log.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AccessEvent {
+    /// What happened to the access
+    pub event_type: AccessEventType,
+    /// The course the access belongs to
+    pub course_id: String,
+    /// The user whose access changed
+    pub user: Address,
+    /// The address that made the change; the contract itself for
+    /// unauthenticated calls and automatic expiry
+    pub actor: Address,
+    /// Ledger timestamp of the change
+    pub timestamp: u64,
+}
+
+/// A page of enrolled users returned by `list_course_users`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CourseUserPage {
+    /// Users on this page
+    pub users: Vec<Address>,
+    /// Total number of users enrolled in the course
+    pub total: u32,
+    /// Whether more users follow this page
+    pub has_more: bool,
+}
+
+/// Outcome of a batch access revocation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchRevokeResult {
+    /// Number of users whose access was removed
+    pub revoked_count: u32,
+    /// Number of users who had no access to the course
+    pub skipped_count: u32,
+}
+
+/// A user's standing on the prerequisite path leading to a course.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LearningPathProgress {
+    /// The entry course followed by its transitive prerequisites, breadth-first
+    pub path: Vec<String>,
+    /// Courses of the path the user holds a valid certificate for
+    pub completed: Vec<String>,
+    /// Courses of the path the user has access to but no certificate for
+    pub in_progress: Vec<String>,
+    /// Courses of the path the user has not started
+    pub not_started: Vec<String>,
+    /// Share of completed courses on the path, from 0 to 100
+    pub overall_progress_percent: u32,
+}
+
+/// Certificate as returned by the certification contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Certificate {
+    pub id: String,
+    pub course_id: String,
+    pub recipient: Address,
+    pub issuer: Address,
+    pub issued_at: u64,
+    pub revoked: bool,
+}
+
+/// Enrollments of a course split by the state of their access.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EnrollmentBreakdown {
+    /// Users whose access is neither paused nor expired
+    pub active: u32,
+    /// Users whose unexpired access is paused
+    pub paused: u32,
+    /// Users whose access has passed its expiry timestamp
+    pub expired: u32,
+}
+
+/// A team of users whose course access is managed together.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EnrollmentGroup {
+    pub id: u128,
+    pub name: String,
+    /// The address allowed to change the group's members
+    pub owner: Address,
+    /// Members in the order they were added
+    pub members: Vec<Address>,
+}
+
+/// Maximum number of members of an enrollment group
+pub const MAX_GROUP_MEMBERS: u32 = 100;
+
+/// Maximum length of an enrollment group name
+pub const MAX_GROUP_NAME_LENGTH: u32 = 100;
+
+/// Maximum number of users accepted by a single batch operation
+pub const MAX_BATCH_SIZE: u32 = 50;
+
+/// Maximum number of entries returned by one page of a list function
+pub const MAX_LIST_PAGE_SIZE: u32 = 100;
+
 /// Global configuration key for storing the user management contract address
 pub const KEY_USER_MGMT_ADDR: &str = "USER_MGMT_ADDR";
 
 /// Global configuration key for storing the course registry contract address
 pub const KEY_COURSE_REG_ADDR: &str = "COURSE_REGISTRY_ADDR";
+
+/// Global configuration key for storing the certification contract address
+pub const KEY_CERTIFICATION_ADDR: &str = "CERTIFICATION_ADDR";
@@ -21,6 +21,9 @@ mod user_management {
             // For testing, always return true to simplify admin checks
             true
         }
+        pub fn is_blacklisted(_env: Env, _address: Address) -> bool {
+            false
+        }
         pub fn save_user_profile(_env: Env, _user: Address, _name: String, _email: String) {
             // Mock implementation
         }
@@ -41,6 +44,18 @@ mod course_registry {
         pub fn is_course_creator(_env: Env, _course_id: String, _user: Address) -> bool {
             true
         }
+        pub fn is_course_archived(_env: Env, _course_id: String) -> bool {
+            false
+        }
+        pub fn is_course_frozen(_env: Env, _course_id: String) -> bool {
+            false
+        }
+        pub fn is_course_published(_env: Env, _course_id: String) -> bool {
+            true
+        }
+        pub fn get_course_max_enrollments(_env: Env, _course_id: String) -> Option<u32> {
+            None
+        }
     }
 }
 
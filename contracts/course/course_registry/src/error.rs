@@ -7,59 +7,91 @@ use soroban_sdk::{contracterror, panic_with_error, Env};
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum Error {
-    OnlyCreatorCanAddGoals = 1,
-    EmptyGoalContent = 2,
-    CourseIdNotExist = 3,
-    OnlyCreatorCanArchive = 4,
-    CourseAlreadyArchived = 5,
-    Unauthorized = 6,
-    NameRequired = 7,
-    EmptyCourseTitle = 8,
-    InvalidPrice = 9,
-    DuplicateCourseTitle = 10,
-    DuplicateCourseId = 11,
-    OnlyCreatorCanEditPrereqs = 12,
-    PrereqCourseNotFound = 13,
-    SelfPrerequisite = 14,
-    CircularDependency = 15,
-    EmptyCourseId = 16,
-    CourseNotFound = 17,
-    EmptyNewGoalContent = 18,
-    EmptyGoalId = 19,
-    GoalCourseMismatch = 20,
-    ModuleNotFound = 21,
+    EmptyGoalContent = 102,
+    CourseAlreadyArchived = 105,
+    Unauthorized = 106,
+    NameRequired = 107,
+    EmptyCourseTitle = 108,
+    InvalidPrice = 109,
+    DuplicateCourseTitle = 110,
+    DuplicateCourseId = 111,
+    PrereqCourseNotFound = 113,
+    SelfPrerequisite = 114,
+    CircularDependency = 115,
+    EmptyCourseId = 116,
+    CourseNotFound = 117,
+    EmptyGoalId = 119,
+    GoalCourseMismatch = 120,
+    ModuleNotFound = 121,
+    EmptyModuleId = 122,
+    PrereqNotInList = 123,
+    InvalidModulePosition = 124,
+    InvalidModuleTitle = 125,
+    InvalidCourseDescription = 126,
+    InvalidCategoryName = 127,
+    InvalidTitleLength = 129,
+    InvalidLanguageLength = 143,
+    InvalidThumbnailUrlLength = 144,
+    InvalidDurationValue = 145,
+    InvalidLimitValue = 146,
+    InvalidPrerequisiteId = 149,
+    TooManyPrerequisites = 151,
+    AlreadyInitialized = 155,
+    DuplicatePrerequisite = 156,
+    // Rate limiting errors
+    CourseRateLimitExceeded = 157,
+    CategoryNotFound = 159,
+    ModuleOrderMismatch = 160,
+    InvalidContentUrl = 161,
+    TooManyTags = 162,
+    DuplicateTag = 163,
+    InvalidTag = 164,
+    CategoryHasCourses = 165,
+    DuplicateCategoryName = 166,
+    PrerequisiteDepthExceeded = 167,
+    CapBelowCurrentEnrollment = 168,
+    InvalidRating = 169,
+    DuplicateReview = 170,
+    BundleNotFound = 171,
+    InvalidSchedule = 172,
+    CourseHasDependents = 173,
+    AttachmentNotFound = 174,
+    ReviewNotFound = 175,
+    FeaturedListFull = 176,
+}
+
+/// Errors that no longer fit in `Error`, which is at the 50-variant limit of
+/// the contract spec. Variants keep the codes they had in `Error`, and codes
+/// below 400 are only used by variants that moved here from `Error`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExtendedError {
+    OnlyCreatorCanAddGoals = 101,
+    CourseIdNotExist = 103,
+    OnlyCreatorCanArchive = 104,
+    OnlyCreatorCanEditPrereqs = 112,
+    EmptyNewGoalContent = 118,
+    EmptyCategory = 128,
+    InvalidOffsetValue = 147,
+    InvalidGoalContent = 148,
+    EmptyPrerequisiteList = 150,
+    EmptyPrerequisiteId = 152,
+    InvalidCourseId = 153,
+    InvalidPrice100 = 154,
+    CourseRateLimitNotConfigured = 158,
     UnauthorizedCaller = 401,
     UnauthorizedCourseAccess = 402,
     InvalidAdminOperation = 403,
     EmptyModuleTitle = 404,
     DuplicateModulePosition = 405,
-    EmptyModuleId = 22,
-    PrereqNotInList = 23,
-    InvalidModulePosition = 24,
-    InvalidModuleTitle = 25,
-    InvalidCourseDescription = 26,
-    InvalidCategoryName = 27,
-    EmptyCategory = 28,
-    InvalidTitleLength = 29,
-    InvalidLanguageLength = 43,
-    InvalidThumbnailUrlLength = 44,
-    InvalidDurationValue = 45,
-    InvalidLimitValue = 46,
-    InvalidOffsetValue = 47,
-    InvalidGoalContent = 48,
-    InvalidPrerequisiteId = 49,
-    EmptyPrerequisiteList = 50,
-    TooManyPrerequisites = 51,
-    EmptyPrerequisiteId = 52,
-    InvalidCourseId = 53,
-    InvalidPrice100 = 54,
-    AlreadyInitialized = 55,
-    DuplicatePrerequisite = 56,
-    // Rate limiting errors
-    CourseRateLimitExceeded = 57,
-    CourseRateLimitNotConfigured = 58,
+    InvalidLanguage = 406,
 }
 
 pub fn handle_error(env: &Env, error: Error) -> ! {
     panic_with_error!(env, error);
 }
+
+pub fn handle_extended_error(env: &Env, error: ExtendedError) -> ! {
+    panic_with_error!(env, error);
+}
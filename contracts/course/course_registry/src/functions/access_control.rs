@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol, IntoVal};
+use soroban_sdk::{contractclient, symbol_short, Address, Env, String, Symbol};
 
 use crate::error::{handle_error, Error};
 use crate::schema::Course;
@@ -11,9 +11,26 @@ const COURSE_KEY: Symbol = symbol_short!("course");
 
 const INIT_ACCESS_CONTROL_EVENT: Symbol = symbol_short!("initAcCtr");
 const UPDATE_USER_MNGMT_EVENT: Symbol = symbol_short!("upUsrMgt");
+const UPDATE_COURSE_ACCESS_EVENT: Symbol = symbol_short!("upCrsAcc");
+const CONTRACT_INITIALIZED_EVENT: &str = "ContractInitialized";
 
 const KEY_USER_MGMT_ADDR: &str = "user_mgmt_addr";
 const KEY_OWNER: &str = "owner";
+const KEY_COURSE_ACCESS_ADDR: &str = "course_access_addr";
+
+/// Subset of the user management contract interface used for admin checks
+#[contractclient(name = "UserManagementClient")]
+pub trait UserManagementInterface {
+    fn is_admin(env: Env, who: Address) -> bool;
+    fn is_email_verified(env: Env, user: Address) -> bool;
+}
+
+/// Subset of the course access contract interface used for enrollment checks
+#[contractclient(name = "CourseAccessClient")]
+pub trait CourseAccessInterface {
+    fn check_access(env: Env, course_id: String, user: Address) -> bool;
+    fn get_course_enrollment_count(env: Env, course_id: String) -> u32;
+}
 
 /// Check if a user is an admin by querying the user management contract
 pub fn is_admin(env: &Env, who: &Address) -> bool {
@@ -21,18 +38,22 @@ pub fn is_admin(env: &Env, who: &Address) -> bool {
     let user_mgmt_addr: Option<Address> = env.storage().instance().get(&(KEY_USER_MGMT_ADDR,));
 
     match user_mgmt_addr {
-        Some(addr) => {
-            // Cross-contract call to check admin status
-            env.invoke_contract(
-                &addr,
-                &Symbol::new(env, "is_admin"),
-                (who.clone(),).into_val(env),
-            )
-        }
+        // Cross-contract call to check admin status
+        Some(addr) => UserManagementClient::new(env, &addr).is_admin(who),
         None => false, // If user management contract isn't configured, no admins
     }
 }
 
+/// Check if a user's contact email is verified in the user management contract
+pub fn is_email_verified(env: &Env, who: &Address) -> bool {
+    let user_mgmt_addr: Option<Address> = env.storage().instance().get(&(KEY_USER_MGMT_ADDR,));
+
+    match user_mgmt_addr {
+        Some(addr) => UserManagementClient::new(env, &addr).is_email_verified(who),
+        None => true, // Standalone deployments have no email verification to enforce
+    }
+}
+
 /// Check if a user is the creator of a specific course
 pub fn is_course_creator(env: &Env, course_id: &String, who: &Address) -> bool {
     let key: (Symbol, String) = (COURSE_KEY, course_id.clone());
@@ -57,6 +78,28 @@ pub fn require_course_management_auth(env: &Env, caller: &Address, course_id: &S
     }
 }
 
+/// Require that the caller may edit a course's content
+/// Authorization is granted if the caller is:
+/// 1. The course creator
+/// 2. One of the course co-instructors
+/// 3. An admin
+pub fn require_course_editor(env: &Env, caller: &Address, course_id: &String) {
+    caller.require_auth();
+
+    let is_editor: bool = match env
+        .storage()
+        .persistent()
+        .get::<_, Course>(&(COURSE_KEY, course_id.clone()))
+    {
+        Some(course) => course.creator == *caller || course.co_instructors.contains(caller),
+        None => false,
+    };
+
+    if !is_editor && !is_admin(env, caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+}
+
 /// Initialize access control settings
 pub fn initialize(env: &Env, owner: &Address, user_mgmt_addr: &Address) {
     if env.storage().instance().has(&(KEY_OWNER,)) {
@@ -76,6 +119,31 @@ pub fn initialize(env: &Env, owner: &Address, user_mgmt_addr: &Address) {
         .publish((INIT_ACCESS_CONTROL_EVENT,), (owner, user_mgmt_addr));
 }
 
+/// Initializes the contract with its owner, the user management and course
+/// access contract addresses, and the default rate limiting configuration.
+///
+/// # Panics
+///
+/// * `Error::AlreadyInitialized` if the contract has already been initialized.
+pub fn course_registry_initialize(
+    env: Env,
+    super_admin: Address,
+    user_mgmt_addr: Address,
+    course_access_addr: Address,
+) {
+    super_admin.require_auth();
+
+    initialize(&env, &super_admin, &user_mgmt_addr);
+    env.storage()
+        .instance()
+        .set(&(KEY_COURSE_ACCESS_ADDR,), &course_access_addr);
+
+    env.events().publish(
+        (Symbol::new(&env, CONTRACT_INITIALIZED_EVENT),),
+        (super_admin, user_mgmt_addr, course_access_addr),
+    );
+}
+
 /// Update the user management contract address
 /// Only the contract owner can perform this update
 pub fn update_user_mgmt_address(env: &Env, caller: &Address, new_addr: &Address) {
@@ -99,6 +167,50 @@ pub fn update_user_mgmt_address(env: &Env, caller: &Address, new_addr: &Address)
         .publish((UPDATE_USER_MNGMT_EVENT,), (caller, new_addr));
 }
 
+/// Update the course access contract address
+/// Only the contract owner can perform this update
+pub fn update_course_access_address(env: &Env, caller: &Address, new_addr: &Address) {
+    caller.require_auth();
+
+    let owner: Address = env
+        .storage()
+        .instance()
+        .get(&(KEY_OWNER,))
+        .expect("Contract not initialized");
+
+    if *caller != owner {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    env.storage()
+        .instance()
+        .set(&(KEY_COURSE_ACCESS_ADDR,), new_addr);
+    env.events()
+        .publish((UPDATE_COURSE_ACCESS_EVENT,), (caller, new_addr));
+}
+
+/// Check if a user has access to a course by querying the course access contract
+pub fn has_course_access(env: &Env, user: &Address, course_id: &String) -> bool {
+    let course_access_addr: Option<Address> =
+        env.storage().instance().get(&(KEY_COURSE_ACCESS_ADDR,));
+
+    match course_access_addr {
+        Some(addr) => CourseAccessClient::new(env, &addr).check_access(course_id, user),
+        None => false, // Access cannot be verified without the course access contract
+    }
+}
+
+/// Read the number of users enrolled in a course from the course access contract
+pub fn get_course_enrollment_count(env: &Env, course_id: &String) -> u32 {
+    let course_access_addr: Option<Address> =
+        env.storage().instance().get(&(KEY_COURSE_ACCESS_ADDR,));
+
+    match course_access_addr {
+        Some(addr) => CourseAccessClient::new(env, &addr).get_course_enrollment_count(course_id),
+        None => 0, // Nobody can be enrolled without the course access contract
+    }
+}
+
 #[cfg(test)]
 mod tests {
     // Note: These tests are commented out due to complex storage access issues
@@ -120,7 +232,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Error(Contract, #401)")]  // Unauthorized error
+    #[should_panic(expected = "Error(Contract, #501)")]  // Unauthorized error
     fn test_unauthorized_access() {
         let env = Env::default();
         let unauthorized_user = Address::generate(&env);
@@ -155,4 +267,52 @@ mod tests {
         });
     }
     */
+
+    use super::*;
+    use crate::schema::{CourseRateLimitConfig, DataKey};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_initialize_stores_config() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let super_admin = Address::generate(&env);
+        let user_mgmt_addr = Address::generate(&env);
+        let course_access_addr = Address::generate(&env);
+
+        client.initialize(&super_admin, &user_mgmt_addr, &course_access_addr);
+
+        env.as_contract(&contract_id, || {
+            let instance = env.storage().instance();
+            assert_eq!(instance.get::<_, Address>(&(KEY_OWNER,)), Some(super_admin));
+            assert_eq!(instance.get::<_, Address>(&(KEY_USER_MGMT_ADDR,)), Some(user_mgmt_addr));
+            assert_eq!(
+                instance.get::<_, Address>(&(KEY_COURSE_ACCESS_ADDR,)),
+                Some(course_access_addr)
+            );
+            assert!(env
+                .storage()
+                .persistent()
+                .get::<_, CourseRateLimitConfig>(&DataKey::CourseRateLimitConfig)
+                .is_some());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #155)")]
+    fn test_initialize_twice() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let super_admin = Address::generate(&env);
+        let user_mgmt_addr = Address::generate(&env);
+        let course_access_addr = Address::generate(&env);
+
+        client.initialize(&super_admin, &user_mgmt_addr, &course_access_addr);
+        client.initialize(&super_admin, &user_mgmt_addr, &course_access_addr);
+    }
 }
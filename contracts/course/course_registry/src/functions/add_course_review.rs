@@ -0,0 +1,373 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{has_course_access, is_admin};
+use crate::schema::{CourseReview, DataKey, MAX_RATING, MIN_RATING, RATING_SCALE};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const COURSE_REVIEWED_EVENT: &str = "CourseReviewed";
+const REVIEW_DELETED_EVENT: &str = "ReviewDeleted";
+
+/// Records a learner's review of a course.
+///
+/// Each user can review a course once, and only if they have access to it
+/// in the course access contract.
+///
+/// # Panics
+///
+/// * `Error::InvalidRating` if `rating` is outside `MIN_RATING..=MAX_RATING`.
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::Unauthorized` if the reviewer has no access to the course.
+/// * `Error::DuplicateReview` if the reviewer already reviewed the course.
+pub fn course_registry_add_course_review(
+    env: Env,
+    reviewer: Address,
+    course_id: String,
+    rating: u32,
+    comment: Option<String>,
+) {
+    reviewer.require_auth();
+
+    if !(MIN_RATING..=MAX_RATING).contains(&rating) {
+        handle_error(&env, Error::InvalidRating)
+    }
+    if !env
+        .storage()
+        .persistent()
+        .has(&(COURSE_KEY, course_id.clone()))
+    {
+        handle_error(&env, Error::CourseNotFound)
+    }
+
+    let review_key: DataKey = DataKey::UserReview(reviewer.clone(), course_id.clone());
+    if env.storage().persistent().has(&review_key) {
+        handle_error(&env, Error::DuplicateReview)
+    }
+    if !has_course_access(&env, &reviewer, &course_id) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    let review: CourseReview = CourseReview {
+        reviewer: reviewer.clone(),
+        course_id: course_id.clone(),
+        rating,
+        comment,
+        created_at: env.ledger().timestamp(),
+    };
+    env.storage().persistent().set(&review_key, &review);
+
+    let reviewers_key: DataKey = DataKey::CourseReviewers(course_id.clone());
+    let mut reviewers: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&reviewers_key)
+        .unwrap_or(Vec::new(&env));
+    reviewers.push_back(reviewer.clone());
+    env.storage().persistent().set(&reviewers_key, &reviewers);
+
+    let (sum, count): (u64, u32) = get_rating_totals(&env, &course_id);
+    env.storage().persistent().set(
+        &DataKey::RatingSum(course_id.clone()),
+        &(sum + rating as u64),
+    );
+    env.storage()
+        .persistent()
+        .set(&DataKey::RatingCount(course_id.clone()), &(count + 1));
+
+    env.events().publish(
+        (Symbol::new(&env, COURSE_REVIEWED_EVENT), course_id),
+        (reviewer, rating),
+    );
+}
+
+/// Deletes a review and removes its rating from the course totals.
+///
+/// Reviewers can delete their own review; admins can delete any review.
+///
+/// # Panics
+///
+/// * `Error::Unauthorized` if the caller is neither the reviewer nor an admin.
+/// * `Error::ReviewNotFound` if the reviewer has not reviewed the course.
+pub fn course_registry_delete_course_review(
+    env: Env,
+    caller: Address,
+    reviewer: Address,
+    course_id: String,
+) {
+    caller.require_auth();
+
+    if caller != reviewer && !is_admin(&env, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    let review_key: DataKey = DataKey::UserReview(reviewer.clone(), course_id.clone());
+    let review: CourseReview = env
+        .storage()
+        .persistent()
+        .get(&review_key)
+        .unwrap_or_else(|| handle_error(&env, Error::ReviewNotFound));
+    env.storage().persistent().remove(&review_key);
+
+    let reviewers_key: DataKey = DataKey::CourseReviewers(course_id.clone());
+    let mut reviewers: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&reviewers_key)
+        .unwrap_or(Vec::new(&env));
+    if let Some(index) = reviewers.first_index_of(&reviewer) {
+        reviewers.remove(index);
+        env.storage().persistent().set(&reviewers_key, &reviewers);
+    }
+
+    let (sum, count): (u64, u32) = get_rating_totals(&env, &course_id);
+    env.storage().persistent().set(
+        &DataKey::RatingSum(course_id.clone()),
+        &sum.saturating_sub(review.rating as u64),
+    );
+    env.storage().persistent().set(
+        &DataKey::RatingCount(course_id.clone()),
+        &count.saturating_sub(1),
+    );
+
+    env.events().publish(
+        (Symbol::new(&env, REVIEW_DELETED_EVENT), course_id),
+        (caller, reviewer),
+    );
+}
+
+/// Returns every review of a course, oldest first.
+pub fn course_registry_get_course_reviews(env: Env, course_id: String) -> Vec<CourseReview> {
+    let reviewers: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CourseReviewers(course_id.clone()))
+        .unwrap_or(Vec::new(&env));
+
+    let mut reviews: Vec<CourseReview> = Vec::new(&env);
+    for reviewer in reviewers.iter() {
+        if let Some(review) = env
+            .storage()
+            .persistent()
+            .get::<_, CourseReview>(&DataKey::UserReview(reviewer, course_id.clone()))
+        {
+            reviews.push_back(review);
+        }
+    }
+    reviews
+}
+
+/// Returns the average rating of a course multiplied by `RATING_SCALE`, or `0`
+/// if the course has no ratings.
+pub fn course_registry_get_average_rating(env: Env, course_id: String) -> u32 {
+    let (sum, count): (u64, u32) = get_rating_totals(&env, &course_id);
+    if count == 0 {
+        return 0;
+    }
+    (sum * RATING_SCALE as u64 / count as u64) as u32
+}
+
+/// Returns the sum and number of ratings of a course.
+pub fn get_rating_totals(env: &Env, course_id: &String) -> (u64, u32) {
+    let sum: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::RatingSum(course_id.clone()))
+        .unwrap_or(0);
+    let count: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::RatingCount(course_id.clone()))
+        .unwrap_or(0);
+    (sum, count)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::schema::Course;
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    mod mock_course_access {
+        use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+        #[contract]
+        pub struct CourseAccess;
+
+        #[contractimpl]
+        impl CourseAccess {
+            pub fn grant(env: Env, course_id: String, user: Address) {
+                env.storage().instance().set(&(course_id, user), &true);
+            }
+
+            pub fn check_access(env: Env, course_id: String, user: Address) -> bool {
+                env.storage().instance().has(&(course_id, user))
+            }
+        }
+    }
+
+    fn setup() -> (
+        Env,
+        CourseRegistryClient<'static>,
+        mock_course_access::CourseAccessClient<'static>,
+        Course,
+    ) {
+        let (env, client, access, course, _admin) = setup_with_admin();
+        (env, client, access, course)
+    }
+
+    fn setup_with_admin() -> (
+        Env,
+        CourseRegistryClient<'static>,
+        mock_course_access::CourseAccessClient<'static>,
+        Course,
+        Address,
+    ) {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_access_id = env.register(mock_course_access::CourseAccess, ());
+        env.as_contract(&client.address, || {
+            crate::functions::access_control::update_course_access_address(
+                &env,
+                &mocks.admin,
+                &course_access_id,
+            );
+        });
+
+        let course = client.create_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "Rust"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let access = mock_course_access::CourseAccessClient::new(&env, &course_access_id);
+        (env, client, access, course, mocks.admin)
+    }
+
+    fn enrolled_user(
+        env: &Env,
+        access: &mock_course_access::CourseAccessClient,
+        course: &Course,
+    ) -> Address {
+        let user = Address::generate(env);
+        access.grant(&course.id, &user);
+        user
+    }
+
+    #[test]
+    fn test_add_course_review() {
+        let (env, client, access, course) = setup();
+        let reviewer = enrolled_user(&env, &access, &course);
+        let comment = Some(String::from_str(&env, "Great course"));
+
+        client.add_course_review(&reviewer, &course.id, &4, &comment);
+
+        let reviews = client.get_course_reviews(&course.id);
+        assert_eq!(reviews.len(), 1);
+        let review = reviews.get(0).unwrap();
+        assert_eq!(review.reviewer, reviewer);
+        assert_eq!(review.rating, 4);
+        assert_eq!(review.comment, comment);
+    }
+
+    #[test]
+    fn test_average_rating() {
+        let (env, client, access, course) = setup();
+        assert_eq!(client.get_average_rating(&course.id), 0);
+
+        for rating in [5_u32, 4, 4] {
+            let reviewer = enrolled_user(&env, &access, &course);
+            client.add_course_review(&reviewer, &course.id, &rating, &None);
+        }
+
+        // (5 + 4 + 4) / 3 = 4.33
+        assert_eq!(client.get_average_rating(&course.id), 433);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #170)")]
+    fn test_duplicate_review() {
+        let (env, client, access, course) = setup();
+        let reviewer = enrolled_user(&env, &access, &course);
+
+        client.add_course_review(&reviewer, &course.id, &4, &None);
+        client.add_course_review(&reviewer, &course.id, &5, &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #169)")]
+    fn test_review_rating_out_of_range() {
+        let (env, client, access, course) = setup();
+        let reviewer = enrolled_user(&env, &access, &course);
+
+        client.add_course_review(&reviewer, &course.id, &6, &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_review_without_access() {
+        let (env, client, _access, course) = setup();
+
+        client.add_course_review(&Address::generate(&env), &course.id, &3, &None);
+    }
+
+    #[test]
+    fn test_delete_own_review_updates_rating() {
+        let (env, client, access, course) = setup();
+        let first = enrolled_user(&env, &access, &course);
+        let second = enrolled_user(&env, &access, &course);
+        client.add_course_review(&first, &course.id, &5, &None);
+        client.add_course_review(&second, &course.id, &2, &None);
+        assert_eq!(client.get_average_rating(&course.id), 350);
+
+        client.delete_course_review(&second, &second, &course.id);
+
+        assert_eq!(client.get_average_rating(&course.id), 500);
+        let reviews = client.get_course_reviews(&course.id);
+        assert_eq!(reviews.len(), 1);
+        assert_eq!(reviews.get(0).unwrap().reviewer, first);
+
+        // The reviewer may review again, and the last deletion resets the average
+        client.delete_course_review(&first, &first, &course.id);
+        assert_eq!(client.get_average_rating(&course.id), 0);
+        client.add_course_review(&first, &course.id, &3, &None);
+        assert_eq!(client.get_average_rating(&course.id), 300);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_delete_other_review_not_admin() {
+        let (env, client, access, course) = setup();
+        let reviewer = enrolled_user(&env, &access, &course);
+        let other = enrolled_user(&env, &access, &course);
+        client.add_course_review(&reviewer, &course.id, &4, &None);
+
+        client.delete_course_review(&other, &reviewer, &course.id);
+    }
+
+    #[test]
+    fn test_admin_deletes_review() {
+        let (env, client, access, course, admin) = setup_with_admin();
+        let reviewer = enrolled_user(&env, &access, &course);
+        client.add_course_review(&reviewer, &course.id, &1, &None);
+
+        client.delete_course_review(&admin, &reviewer, &course.id);
+
+        assert!(client.get_course_reviews(&course.id).is_empty());
+        assert_eq!(client.get_average_rating(&course.id), 0);
+        let result = client.try_delete_course_review(&admin, &reviewer, &course.id);
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(175)))
+        );
+    }
+}
@@ -3,7 +3,7 @@
 
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
 
-use crate::error::{handle_error, Error};
+use crate::error::{handle_error, handle_extended_error, Error, ExtendedError};
 use crate::functions::utils::{self, trim};
 use crate::schema::{Course, CourseGoal, DataKey};
 
@@ -26,11 +26,11 @@ pub fn add_goal(env: Env, creator: Address, course_id: String, content: String)
     
     // Check string lengths to prevent extremely long values
     if course_id.len() > 100 {
-        handle_error(&env, Error::InvalidCourseId);
+        handle_extended_error(&env, ExtendedError::InvalidCourseId);
     }
     
     if content.len() > 1000 {
-        handle_error(&env, Error::InvalidGoalContent);
+        handle_extended_error(&env, ExtendedError::InvalidGoalContent);
     }
 
     // Load course
@@ -43,7 +43,7 @@ pub fn add_goal(env: Env, creator: Address, course_id: String, content: String)
 
     // Only creator can add goal (or later: check admin)
     if course.creator != creator {
-        handle_error(&env, Error::OnlyCreatorCanAddGoals)
+        handle_extended_error(&env, ExtendedError::OnlyCreatorCanAddGoals)
     }
 
     // Generate a unique goal ID
@@ -99,6 +99,7 @@ mod test {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
 
         let goal_content = String::from_str(&env, "Learn the basics of Rust");
@@ -110,7 +111,7 @@ mod test {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #1)")]
+    #[should_panic(expected = "HostError: Error(Contract, #101)")]
     fn test_add_goal_unauthorized() {
         let env = Env::default();
         env.mock_all_auths();
@@ -131,6 +132,7 @@ mod test {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
 
         let goal_content = String::from_str(&env, "Learn the basics of Rust");
@@ -154,7 +156,37 @@ mod test {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #2)")]
+    #[should_panic(expected = "HostError: Error(Contract, #153)")]
+    fn test_add_goal_course_id_too_long() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let creator: Address = Address::generate(&env);
+        let long_course_id = String::from_bytes(&env, &[b'1'; 101]);
+
+        let goal_content = String::from_str(&env, "Learn the basics of Rust");
+        client.add_goal(&creator, &long_course_id, &goal_content);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #148)")]
+    fn test_add_goal_content_too_long() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let creator: Address = Address::generate(&env);
+        let goal_content = String::from_bytes(&env, &[b'a'; 1001]);
+        client.add_goal(&creator, &String::from_str(&env, "1"), &goal_content);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #102)")]
     fn test_add_goal_empty_content() {
         let env = Env::default();
         env.mock_all_auths();
@@ -174,6 +206,7 @@ mod test {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
 
         let goal_content = String::from_str(&env, "");
@@ -200,6 +233,7 @@ mod test {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
 
         let goal_content1 = String::from_str(&env, "Learn the basics of Rust");
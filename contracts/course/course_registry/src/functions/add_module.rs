@@ -3,21 +3,29 @@
 
 use soroban_sdk::{symbol_short, Vec, vec, Address, Env, String, Symbol};
 
+use crate::functions::module_utils::index_module;
 use crate::functions::utils::{concat_strings, u32_to_string};
-use crate::error::{handle_error, Error};
-use crate::schema::{CourseModule};
+use crate::error::{handle_error, handle_extended_error, Error, ExtendedError};
+use crate::schema::{ContentType, CourseModule};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 const MODULE_KEY: Symbol = symbol_short!("module");
 
 const COURSE_REGISTRY_ADD_MODULE_EVENT: Symbol = symbol_short!("crsAddMod");
 
+/// Adds a module with its type of content and, optionally, where that
+/// content is hosted.
+///
+/// Panics with `Error::InvalidContentUrl` if `content_url` is not a valid
+/// HTTP(S) URL.
 pub fn course_registry_add_module(
     env: Env,
     caller: Address,
     course_id: String,
     position: u32,
     title: String,
+    content_type: ContentType,
+    content_url: Option<String>,
 ) -> CourseModule {
     // Validate input parameters
     if course_id.is_empty() {
@@ -42,19 +50,25 @@ pub fn course_registry_add_module(
         handle_error(&env, Error::InvalidModulePosition);
     }
 
+    if let Some(ref url) = content_url {
+        if !shared::validate_url(url) {
+            handle_error(&env, Error::InvalidContentUrl)
+        }
+    }
+
     let course_storage_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
 
     if !env.storage().persistent().has(&course_storage_key) {
-        handle_error(&env, Error::CourseIdNotExist)
+        handle_extended_error(&env, ExtendedError::CourseIdNotExist)
     }
 
-    // Verify caller has proper authorization
-    super::access_control::require_course_management_auth(&env, &caller, &course_id);
+    // Verify caller is the creator, a co-instructor or an admin
+    super::access_control::require_course_editor(&env, &caller, &course_id);
 
     // Check for duplicate position
     let position_key: (Symbol, String, u32) = (symbol_short!("pos"), course_id.clone(), position);
     if env.storage().persistent().has(&position_key) {
-        handle_error(&env, Error::DuplicateModulePosition)
+        handle_extended_error(&env, ExtendedError::DuplicateModulePosition)
     }
 
     let ledger_seq: u32 = env.ledger().sequence();
@@ -78,6 +92,8 @@ pub fn course_registry_add_module(
         position,
         title: title.clone(),
         created_at: env.ledger().timestamp(),
+        content_type,
+        content_url,
     };
 
     let storage_key: (Symbol, String) = (MODULE_KEY, module_id.clone());
@@ -85,6 +101,7 @@ pub fn course_registry_add_module(
 
     env.storage().persistent().set(&storage_key, &module);
     env.storage().persistent().set(&position_key, &true);
+    index_module(&env, &module);
 
     // emit an event
     env.events()
@@ -115,6 +132,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         )
     }
 
@@ -160,7 +178,7 @@ mod test {
         let creator = Address::generate(&env);
         let course = create_course(&client, &creator);
 
-        let module = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module 1"));
+        let module = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module 1"), &ContentType::Unspecified, &None);
 
         assert_eq!(module.course_id, course.id);
         assert_eq!(module.position, 1);
@@ -174,7 +192,7 @@ mod test {
         let course = create_course(&client, &creator);
 
         // Admin should be able to add modules
-        let module = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module 1"));
+        let module = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module 1"), &ContentType::Unspecified, &None);
 
         assert_eq!(module.course_id, course.id);
         assert_eq!(module.position, 1);
@@ -182,7 +200,7 @@ mod test {
     }
 
     #[test]
-    #[should_panic(expected = "Error(Contract, #6)")] // Unauthorized error
+    #[should_panic(expected = "Error(Contract, #106)")] // Unauthorized error
     fn test_add_module_unauthorized() {
         let (env, _, _, client) = setup_test_env();
         let creator = Address::generate(&env);
@@ -196,11 +214,13 @@ mod test {
             &course.id,
             &1,
             &String::from_str(&env, "Module 1"),
+            &ContentType::Unspecified,
+            &None,
         );
     }
 
     #[test]
-    #[should_panic(expected = "Error(Contract, #3)")] // CourseIdNotExist error
+    #[should_panic(expected = "Error(Contract, #103)")] // CourseIdNotExist error
     fn test_add_module_invalid_course() {
         let (env, _, _admin, client) = setup_test_env();
 
@@ -210,6 +230,8 @@ mod test {
             &String::from_str(&env, "invalid_course"),
             &1,
             &String::from_str(&env, "Module 1"),
+            &ContentType::Unspecified,
+            &None,
         );
     }
 
@@ -219,8 +241,8 @@ mod test {
         let creator = Address::generate(&env);
         let course = create_course(&client, &creator);
 
-        let module1 = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module 1"));
-        let module2 = client.add_module(&creator, &course.id, &2, &String::from_str(&env, "Module 2"));
+        let module1 = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module 1"), &ContentType::Unspecified, &None);
+        let module2 = client.add_module(&creator, &course.id, &2, &String::from_str(&env, "Module 2"), &ContentType::Unspecified, &None);
 
         assert_ne!(module1.id, module2.id);
     }
@@ -231,7 +253,7 @@ mod test {
         let creator = Address::generate(&env);
         let course = create_course(&client, &creator);
 
-        let module = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module 1"));
+        let module = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module 1"), &ContentType::Unspecified, &None);
 
         let exists: bool = env.as_contract(&contract_id, || {
             env.storage()
@@ -253,7 +275,7 @@ mod test {
         
         // Creator2 should not be able to add module to Creator1's course
         let creator2 = Address::generate(&env);
-        client.add_module(&creator2, &course1.id, &1, &String::from_str(&env, "Module 1"));
+        client.add_module(&creator2, &course1.id, &1, &String::from_str(&env, "Module 1"), &ContentType::Unspecified, &None);
     }
 
     #[test]
@@ -264,20 +286,93 @@ mod test {
         let course = create_course(&client, &creator);
 
         // Should panic with validation error for empty title
-        client.add_module(&creator, &course.id, &1, &String::from_str(&env, ""));
+        client.add_module(&creator, &course.id, &1, &String::from_str(&env, ""), &ContentType::Unspecified, &None);
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "Error(Contract, #405)")] // DuplicateModulePosition error
     fn test_add_module_duplicate_position() {
         let (env, _, _admin, client) = setup_test_env();
         let creator = Address::generate(&env);
         let course = create_course(&client, &creator);
 
         // Add first module at position 1
-        client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module 1"));
+        client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module 1"), &ContentType::Unspecified, &None);
 
         // Try to add another module at the same position
-        client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module 2"));
+        client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module 2"), &ContentType::Unspecified, &None);
+    }
+
+    #[test]
+    fn test_add_module_with_each_content_type() {
+        let (env, _, _admin, client) = setup_test_env();
+        let creator = Address::generate(&env);
+        let course = create_course(&client, &creator);
+        let content_types = [
+            ContentType::Video,
+            ContentType::Article,
+            ContentType::Quiz,
+            ContentType::Assignment,
+            ContentType::LiveSession,
+        ];
+
+        for (position, content_type) in content_types.iter().enumerate() {
+            let module = client.add_module(
+                &creator,
+                &course.id,
+                &(position as u32),
+                &String::from_str(&env, "Module"),
+                content_type,
+                &None,
+            );
+            assert_eq!(module.content_type, *content_type);
+        }
+
+        let live = client.get_modules_by_type(&course.id, &ContentType::LiveSession);
+        assert_eq!(live.len(), 1);
+        assert_eq!(live.get(0).unwrap().position, 4);
+    }
+
+    #[test]
+    fn test_add_module_with_content_url() {
+        let (env, _, _admin, client) = setup_test_env();
+        let creator = Address::generate(&env);
+        let course = create_course(&client, &creator);
+        let url = String::from_str(&env, "https://videos.example.com/intro");
+
+        let module = client.add_module(
+            &creator,
+            &course.id,
+            &0,
+            &String::from_str(&env, "Intro"),
+            &ContentType::Video,
+            &Some(url.clone()),
+        );
+
+        assert_eq!(module.content_url, Some(url));
+        assert_eq!(client.get_module(&module.id), module);
+
+        let result = client.try_add_module(
+            &creator,
+            &course.id,
+            &1,
+            &String::from_str(&env, "Broken"),
+            &ContentType::Video,
+            &Some(String::from_str(&env, "not a url")),
+        );
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(161))));
+    }
+
+    #[test]
+    fn test_add_module_without_content() {
+        let (env, _, _admin, client) = setup_test_env();
+        let creator = Address::generate(&env);
+        let course = create_course(&client, &creator);
+
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Intro"), &ContentType::Unspecified, &None);
+
+        assert_eq!(module.content_type, ContentType::Unspecified);
+        assert_eq!(module.content_url, None);
+        assert!(client.get_modules_by_type(&course.id, &ContentType::Video).is_empty());
     }
 }
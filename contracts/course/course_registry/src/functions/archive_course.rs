@@ -3,16 +3,21 @@
 
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
 
-use crate::error::{handle_error, Error};
+use crate::error::{handle_error, handle_extended_error, Error, ExtendedError};
+use crate::functions::access_control::is_admin;
+use crate::functions::get_course_count::{is_counted_as_published, track_published_change};
+use crate::functions::get_courses_updated_after::record_course_update;
 use crate::schema::Course;
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 
 const ARCHIVED_COURSE_EVENT: Symbol = symbol_short!("archiveCs");
 
+/// Marks a course as archived (course creator or admin).
+///
+/// Archived courses keep all their storage but are hidden from listings by
+/// default and cannot receive new enrollments.
 pub fn archive_course(env: &Env, creator: Address, course_id: String) -> Course {
-    creator.require_auth();
-
     let key: (Symbol, String) = (COURSE_KEY, course_id.clone());
     let mut course: Course = env
         .storage()
@@ -20,16 +25,20 @@ pub fn archive_course(env: &Env, creator: Address, course_id: String) -> Course
         .get(&key)
         .expect("Course not found");
 
-    if course.creator != creator {
-        handle_error(env, Error::OnlyCreatorCanArchive)
+    creator.require_auth();
+    if course.creator != creator && !is_admin(env, &creator) {
+        handle_extended_error(env, ExtendedError::OnlyCreatorCanArchive)
     }
 
     if course.is_archived {
         handle_error(env, Error::CourseAlreadyArchived)
     }
+    let was_published: bool = is_counted_as_published(&course);
     course.is_archived = true;
+    track_published_change(env, was_published, &course);
 
     env.storage().persistent().set(&key, &course);
+    record_course_update(env, &course_id);
     
     env.events()
         .publish((ARCHIVED_COURSE_EVENT, course_id.clone()), course.clone());
@@ -37,6 +46,14 @@ pub fn archive_course(env: &Env, creator: Address, course_id: String) -> Course
     course
 }
 
+/// Returns whether a course exists and is archived.
+pub fn course_registry_is_course_archived(env: Env, course_id: String) -> bool {
+    env.storage()
+        .persistent()
+        .get::<_, Course>(&(COURSE_KEY, course_id))
+        .is_some_and(|course| course.is_archived)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -63,6 +80,7 @@ mod tests {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
 
         assert_eq!(new_course, client.get_course(&new_course.id.clone()));
@@ -91,7 +109,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #4)")]
+    #[should_panic(expected = "HostError: Error(Contract, #104)")]
     fn test_archive_course_by_non_creator() {
         let env = Env::default();
         env.mock_all_auths();
@@ -112,13 +130,14 @@ mod tests {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
 
         client.archive_course(&non_creator, &new_course.id);
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #5)")]
+    #[should_panic(expected = "HostError: Error(Contract, #105)")]
     fn test_archive_already_archived_course() {
         let env = Env::default();
         env.mock_all_auths();
@@ -138,6 +157,7 @@ mod tests {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
 
         let archived_course = client.archive_course(&creator, &new_course.id);
@@ -166,6 +186,7 @@ mod tests {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
 
         client.archive_course(&creator, &new_course.id);
@@ -173,4 +194,36 @@ mod tests {
         let events = env.events().all();
         assert!(!events.is_empty());
     }
+
+    #[test]
+    fn test_archived_course_still_readable() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let course: Course = client.create_course(
+            &creator,
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        assert!(!client.is_course_archived(&course.id));
+
+        client.archive_course(&creator, &course.id);
+
+        let stored: Course = client.get_course(&course.id);
+        assert!(stored.is_archived);
+        assert_eq!(stored.title, course.title);
+        assert!(client.is_course_archived(&course.id));
+        assert!(!client.is_course_archived(&String::from_str(&env, "missing")));
+    }
 }
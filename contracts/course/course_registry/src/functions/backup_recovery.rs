@@ -1,7 +1,9 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use crate::schema::{Course, CourseBackupData, CourseCategory, CourseGoal, CourseId, CourseModule, DataKey};
+use crate::functions::create_course_category::category_name_key;
+use crate::functions::get_course_count::{is_counted_as_published, set_course_counts};
+use crate::schema::{ContentType, Course, CourseBackupData, CourseCategory, CourseGoal, CourseId, CourseModule, DataKey};
 use soroban_sdk::{Address, Env, Map, String, Vec};
 
 /// Export all course data for backup purposes
@@ -81,6 +83,8 @@ pub fn export_course_data(env: Env, caller: Address) -> CourseBackupData {
                 position: 1,
                 title: String::from_str(&env, "Default Module"),
                 created_at: env.ledger().timestamp(),
+                content_type: ContentType::Unspecified,
+                content_url: None,
             };
             modules.set(module_id, course_module);
         }
@@ -166,6 +170,8 @@ pub fn import_course_data(env: Env, caller: Address, backup_data: CourseBackupDa
     }
 
     let mut imported_count = 0u32;
+    let mut published_count = 0u32;
+    let mut course_ids: Vec<String> = Vec::new(&env);
     let course_key = soroban_sdk::symbol_short!("course");
 
     // Import courses - store each course individually
@@ -174,15 +180,27 @@ pub fn import_course_data(env: Env, caller: Address, backup_data: CourseBackupDa
         env.storage()
             .persistent()
             .set(&storage_key, &course);
+        if is_counted_as_published(&course) {
+            published_count += 1;
+        }
         imported_count += 1;
+        course_ids.push_back(course.id.clone());
     }
+    set_course_counts(&env, imported_count, published_count);
+    env.storage().persistent().set(&DataKey::AllCourseIds, &course_ids);
 
     // Import categories
     for (category_id, category) in backup_data.categories.iter() {
         env.storage()
             .persistent()
             .set(&DataKey::CourseCategory(category_id), &category);
+        env.storage()
+            .persistent()
+            .set(&category_name_key(&env, &category.name), &category_id);
     }
+    env.storage()
+        .persistent()
+        .set(&DataKey::AllCategoryIds, &backup_data.categories.keys());
 
     // Import modules
     for (module_id, module) in backup_data.modules.iter() {
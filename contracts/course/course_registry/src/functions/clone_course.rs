@@ -0,0 +1,250 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, vec, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_admin, is_course_creator};
+use crate::functions::create_course::create_course;
+use crate::functions::get_module::course_registry_get_course_modules;
+use crate::functions::list_courses_by_difficulty::add_to_difficulty_index;
+use crate::functions::module_utils::index_module;
+use crate::functions::set_course_tags::add_to_tag_index;
+use crate::functions::utils::{concat_strings, u32_to_string};
+use crate::schema::{Course, CourseModule};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const MODULE_KEY: Symbol = symbol_short!("module");
+const POSITION_KEY: Symbol = symbol_short!("pos");
+
+const COURSE_CLONED_EVENT: &str = "CourseCloned";
+
+/// Creates a new course owned by `caller` from an existing one.
+///
+/// Published courses can be cloned by anyone; unpublished courses only by
+/// their creator or an admin. The clone starts unpublished, without
+/// prerequisites, and with a copy of every source module at the same position.
+///
+/// # Panics
+///
+/// * `Error::CourseNotFound` if the source course does not exist.
+/// * `Error::Unauthorized` if the source is unpublished and the caller is
+///   neither its creator nor an admin.
+/// * Any validation error of `create_course` for the new title.
+pub fn course_registry_clone_course(
+    env: Env,
+    caller: Address,
+    source_course_id: String,
+    new_title: String,
+) -> Course {
+    let source: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, source_course_id.clone()))
+        .unwrap_or_else(|| handle_error(&env, Error::CourseNotFound));
+
+    if !source.published
+        && !is_course_creator(&env, &source_course_id, &caller)
+        && !is_admin(&env, &caller)
+    {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    // Validates the title, enforces the rate limit and requires the caller's auth
+    let mut course: Course = create_course(
+        env.clone(),
+        caller.clone(),
+        new_title,
+        source.description.clone(),
+        source.price,
+        source.category.clone(),
+        source.language.clone(),
+        source.thumbnail_url.clone(),
+        source.level.clone(),
+        source.duration_hours,
+        None,
+    );
+
+    course.difficulty = source.difficulty;
+    course.tags = source.tags.clone();
+    course.is_free = source.is_free;
+    add_to_difficulty_index(&env, &course.difficulty, &course.id);
+    for tag in course.tags.iter() {
+        add_to_tag_index(&env, &tag, &course.id);
+    }
+    env.storage()
+        .persistent()
+        .set(&(COURSE_KEY, course.id.clone()), &course);
+
+    for module in course_registry_get_course_modules(env.clone(), source_course_id.clone()).iter() {
+        clone_module(&env, &module, &course.id);
+    }
+
+    env.events().publish(
+        (Symbol::new(&env, COURSE_CLONED_EVENT),),
+        (source_course_id, course.id.clone(), caller),
+    );
+
+    course
+}
+
+fn clone_module(env: &Env, source: &CourseModule, course_id: &String) {
+    let parts: Vec<String> = vec![
+        env,
+        String::from_str(env, "module_"),
+        course_id.clone(),
+        String::from_str(env, "_"),
+        u32_to_string(env, source.position),
+        String::from_str(env, "_"),
+        u32_to_string(env, env.ledger().sequence()),
+    ];
+
+    let module: CourseModule = CourseModule {
+        id: concat_strings(env, parts),
+        course_id: course_id.clone(),
+        position: source.position,
+        title: source.title.clone(),
+        created_at: env.ledger().timestamp(),
+        content_type: source.content_type.clone(),
+        content_url: source.content_url.clone(),
+    };
+
+    env.storage()
+        .persistent()
+        .set(&(MODULE_KEY, module.id.clone()), &module);
+    env.storage()
+        .persistent()
+        .set(&(POSITION_KEY, course_id.clone(), module.position), &true);
+    index_module(env, &module);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::schema::{ContentType, Course, EditCourseParams};
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Address, Course) {
+        let (env, client, _mocks) = setup_with_mocks();
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Rust Basics"),
+            &String::from_str(&env, "Learn Rust"),
+            &2500_u128,
+            &Some(String::from_str(&env, "Programming")),
+            &Some(String::from_str(&env, "en")),
+            &None,
+            &None,
+            &Some(20),
+            &None,
+        );
+        (env, client, creator, course)
+    }
+
+    fn publish(client: &CourseRegistryClient, creator: &Address, course_id: &String) {
+        let params = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: Some(true),
+            new_level: None,
+            new_duration_hours: None,
+        };
+        client.edit_course(creator, course_id, &params);
+        assert!(client.get_course(course_id).published);
+    }
+
+    #[test]
+    fn test_clone_course_with_modules() {
+        let (env, client, creator, source) = setup();
+        client.add_module(&creator, &source.id, &0, &String::from_str(&env, "Intro"), &ContentType::Unspecified, &None);
+        let second = client.add_module(
+            &creator,
+            &source.id,
+            &1,
+            &String::from_str(&env, "Ownership"),
+            &ContentType::Unspecified,
+            &None,
+        );
+        client.update_module_content(&creator, &second.id, &ContentType::Video);
+        client.set_course_tags(
+            &creator,
+            &source.id,
+            &vec![&env, String::from_str(&env, "rust")],
+        );
+        publish(&client, &creator, &source.id);
+
+        let learner = Address::generate(&env);
+        let title = String::from_str(&env, "Rust Basics (copy)");
+        let clone = client.clone_course(&learner, &source.id, &title);
+
+        assert_ne!(clone.id, source.id);
+        assert_eq!(clone.title, title);
+        assert_eq!(clone.creator, learner);
+        assert!(!clone.published);
+        assert_eq!(clone.prerequisites.len(), 0);
+        assert_eq!(clone.description, source.description);
+        assert_eq!(clone.price, source.price);
+        assert_eq!(clone.category, source.category);
+        assert_eq!(clone.duration_hours, source.duration_hours);
+        assert_eq!(clone.tags, vec![&env, String::from_str(&env, "rust")]);
+
+        let modules = client.get_course_modules(&clone.id);
+        assert_eq!(modules.len(), 2);
+        assert_eq!(
+            modules.get(0).unwrap().title,
+            String::from_str(&env, "Intro")
+        );
+        assert_eq!(modules.get(1).unwrap().position, 1);
+        assert_eq!(modules.get(1).unwrap().content_type, ContentType::Video);
+        assert_eq!(modules.get(1).unwrap().course_id, clone.id);
+
+        // Source modules are untouched
+        assert_eq!(client.get_course_modules(&source.id).len(), 2);
+        assert_eq!(
+            client
+                .get_courses_by_tag(&String::from_str(&env, "rust"), &0, &10, &None)
+                .total,
+            2
+        );
+    }
+
+    #[test]
+    fn test_creator_can_clone_unpublished_course() {
+        let (env, client, creator, source) = setup();
+
+        let clone =
+            client.clone_course(&creator, &source.id, &String::from_str(&env, "Draft copy"));
+        assert_eq!(clone.creator, creator);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_clone_unpublished_course_unauthorized() {
+        let (env, client, _creator, source) = setup();
+
+        client.clone_course(
+            &Address::generate(&env),
+            &source.id,
+            &String::from_str(&env, "Copy"),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #117)")]
+    fn test_clone_missing_course() {
+        let (env, client, creator, _source) = setup();
+
+        client.clone_course(
+            &creator,
+            &String::from_str(&env, "missing"),
+            &String::from_str(&env, "Copy"),
+        );
+    }
+}
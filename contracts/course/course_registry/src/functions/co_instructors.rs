@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::get_courses_updated_after::record_course_update;
+use crate::schema::Course;
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const CO_INSTRUCTOR_ADDED_EVENT: &str = "CoInstructorAdded";
+const CO_INSTRUCTOR_REMOVED_EVENT: &str = "CoInstructorRemoved";
+
+fn load_course_for_creator(env: &Env, creator: &Address, course_id: &String) -> Course {
+    creator.require_auth();
+
+    let course: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id.clone()))
+        .unwrap_or_else(|| handle_error(env, Error::CourseNotFound));
+
+    if course.creator != *creator {
+        handle_error(env, Error::Unauthorized)
+    }
+    course
+}
+
+fn save_course(env: &Env, course: &Course) {
+    env.storage()
+        .persistent()
+        .set(&(COURSE_KEY, course.id.clone()), course);
+    record_course_update(env, &course.id);
+}
+
+/// Lets `instructor` manage the content of a course (course creator only).
+///
+/// Adding an existing co-instructor, or the creator, changes nothing.
+///
+/// # Panics
+///
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::Unauthorized` if the caller is not the course creator.
+pub fn course_registry_add_co_instructor(
+    env: Env,
+    creator: Address,
+    course_id: String,
+    instructor: Address,
+) {
+    let mut course: Course = load_course_for_creator(&env, &creator, &course_id);
+    if instructor == course.creator || course.co_instructors.contains(&instructor) {
+        return;
+    }
+
+    course.co_instructors.push_back(instructor.clone());
+    save_course(&env, &course);
+
+    env.events().publish(
+        (Symbol::new(&env, CO_INSTRUCTOR_ADDED_EVENT), course_id),
+        instructor,
+    );
+}
+
+/// Revokes the content management rights of a co-instructor (course creator only).
+///
+/// Removing an address that is not a co-instructor changes nothing.
+///
+/// # Panics
+///
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::Unauthorized` if the caller is not the course creator.
+pub fn course_registry_remove_co_instructor(
+    env: Env,
+    creator: Address,
+    course_id: String,
+    instructor: Address,
+) {
+    let mut course: Course = load_course_for_creator(&env, &creator, &course_id);
+    let Some(index) = course.co_instructors.first_index_of(&instructor) else {
+        return;
+    };
+
+    course.co_instructors.remove(index);
+    save_course(&env, &course);
+
+    env.events().publish(
+        (Symbol::new(&env, CO_INSTRUCTOR_REMOVED_EVENT), course_id),
+        instructor,
+    );
+}
+
+/// Returns the co-instructors of a course in the order they were added.
+///
+/// Panics with `Error::CourseNotFound` if the course does not exist.
+pub fn course_registry_list_co_instructors(env: Env, course_id: String) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get::<_, Course>(&(COURSE_KEY, course_id))
+        .unwrap_or_else(|| handle_error(&env, Error::CourseNotFound))
+        .co_instructors
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{ContentType, Course, EditCourseParams};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Course) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let course = client.create_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (env, client, course)
+    }
+
+    fn publish_params(published: bool) -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: Some(published),
+            new_level: None,
+            new_duration_hours: None,
+        }
+    }
+
+    #[test]
+    fn test_add_and_remove_co_instructor() {
+        let (env, client, course) = setup();
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+
+        client.add_co_instructor(&course.creator, &course.id, &first);
+        client.add_co_instructor(&course.creator, &course.id, &second);
+        assert_eq!(
+            client.list_co_instructors(&course.id),
+            vec![&env, first.clone(), second.clone()]
+        );
+        assert_eq!(client.get_course(&course.id).co_instructors.len(), 2);
+
+        client.remove_co_instructor(&course.creator, &course.id, &first);
+        assert_eq!(client.list_co_instructors(&course.id), vec![&env, second]);
+    }
+
+    #[test]
+    fn test_add_co_instructor_twice() {
+        let (env, client, course) = setup();
+        let instructor = Address::generate(&env);
+
+        client.add_co_instructor(&course.creator, &course.id, &instructor);
+        client.add_co_instructor(&course.creator, &course.id, &instructor);
+        client.add_co_instructor(&course.creator, &course.id, &course.creator);
+
+        assert_eq!(client.list_co_instructors(&course.id), vec![&env, instructor]);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_add_co_instructor_not_creator() {
+        let (env, client, course) = setup();
+        let instructor = Address::generate(&env);
+        client.add_co_instructor(&course.creator, &course.id, &instructor);
+
+        // Co-instructors cannot appoint others
+        client.add_co_instructor(&instructor, &course.id, &Address::generate(&env));
+    }
+
+    #[test]
+    fn test_co_instructor_can_edit_content() {
+        let (env, client, course) = setup();
+        let instructor = Address::generate(&env);
+        client.add_co_instructor(&course.creator, &course.id, &instructor);
+
+        let module = client.add_module(&instructor, &course.id, &0, &String::from_str(&env, "Intro"), &ContentType::Unspecified, &None);
+        let updated = client.update_module(
+            &instructor,
+            &module.id,
+            &Some(String::from_str(&env, "Welcome")),
+            &None,
+        );
+        assert_eq!(updated.title, String::from_str(&env, "Welcome"));
+
+        let published = client.edit_course(&instructor, &course.id, &publish_params(true));
+        assert!(published.published);
+    }
+
+    #[test]
+    fn test_non_editor_rejected() {
+        let (env, client, course) = setup();
+        let instructor = Address::generate(&env);
+        let outsider = Address::generate(&env);
+        client.add_co_instructor(&course.creator, &course.id, &instructor);
+        let module = client.add_module(&course.creator, &course.id, &0, &String::from_str(&env, "Intro"), &ContentType::Unspecified, &None);
+
+        assert!(client
+            .try_add_module(&outsider, &course.id, &1, &String::from_str(&env, "Extra"), &ContentType::Unspecified, &None)
+            .is_err());
+        assert!(client
+            .try_update_module(&outsider, &module.id, &Some(String::from_str(&env, "Hijacked")), &None)
+            .is_err());
+        assert!(client
+            .try_edit_course(&outsider, &course.id, &publish_params(true))
+            .is_err());
+
+        // Co-instructors may publish but not edit other course details
+        let mut params = publish_params(true);
+        params.new_price = Some(1);
+        assert!(client.try_edit_course(&instructor, &course.id, &params).is_err());
+
+        // Removed co-instructors lose their rights
+        client.remove_co_instructor(&course.creator, &course.id, &instructor);
+        assert!(client
+            .try_add_module(&instructor, &course.id, &1, &String::from_str(&env, "Extra"), &ContentType::Unspecified, &None)
+            .is_err());
+    }
+}
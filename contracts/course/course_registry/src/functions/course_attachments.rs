@@ -0,0 +1,243 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::require_course_management_auth;
+use crate::schema::{AttachmentType, CourseAttachment, DataKey};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const MAX_ATTACHMENT_TITLE_LENGTH: u32 = 200;
+
+const ATTACHMENT_ADDED_EVENT: &str = "AttachmentAdded";
+const ATTACHMENT_REMOVED_EVENT: &str = "AttachmentRemoved";
+
+/// Attaches a supplemental resource to a course (course creator or admin).
+///
+/// # Panics
+///
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::Unauthorized` if the caller is neither the creator nor an admin.
+/// * `Error::InvalidContentUrl` if `url` is not a valid URL.
+/// * `Error::InvalidTitleLength` if `title` is empty or too long.
+pub fn course_registry_add_course_attachment(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    url: String,
+    title: String,
+    attachment_type: AttachmentType,
+) -> CourseAttachment {
+    if !env.storage().persistent().has(&(COURSE_KEY, course_id.clone())) {
+        handle_error(&env, Error::CourseNotFound)
+    }
+
+    // Requires the caller's auth and checks creator or admin rights
+    require_course_management_auth(&env, &caller, &course_id);
+
+    if !shared::validate_url(&url) {
+        handle_error(&env, Error::InvalidContentUrl)
+    }
+    if title.is_empty() || title.len() > MAX_ATTACHMENT_TITLE_LENGTH {
+        handle_error(&env, Error::InvalidTitleLength)
+    }
+
+    let id: u128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AttachmentSeq)
+        .unwrap_or(0)
+        + 1;
+    env.storage().persistent().set(&DataKey::AttachmentSeq, &id);
+
+    let attachment: CourseAttachment = CourseAttachment {
+        id,
+        course_id: course_id.clone(),
+        url,
+        title,
+        attachment_type,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseAttachment(id), &attachment);
+
+    let ids_key: DataKey = DataKey::CourseAttachmentIds(course_id.clone());
+    let mut ids: Vec<u128> = env.storage().persistent().get(&ids_key).unwrap_or(Vec::new(&env));
+    ids.push_back(id);
+    env.storage().persistent().set(&ids_key, &ids);
+
+    env.events()
+        .publish((Symbol::new(&env, ATTACHMENT_ADDED_EVENT), course_id), id);
+
+    attachment
+}
+
+/// Returns the attachments of a course in the order they were added.
+pub fn course_registry_list_course_attachments(env: Env, course_id: String) -> Vec<CourseAttachment> {
+    let ids: Vec<u128> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CourseAttachmentIds(course_id))
+        .unwrap_or(Vec::new(&env));
+
+    let mut attachments: Vec<CourseAttachment> = Vec::new(&env);
+    for id in ids.iter() {
+        if let Some(attachment) = env
+            .storage()
+            .persistent()
+            .get::<_, CourseAttachment>(&DataKey::CourseAttachment(id))
+        {
+            attachments.push_back(attachment);
+        }
+    }
+    attachments
+}
+
+/// Removes an attachment (course creator or admin).
+///
+/// # Panics
+///
+/// * `Error::AttachmentNotFound` if the attachment does not exist.
+/// * `Error::Unauthorized` if the caller is neither the creator nor an admin.
+pub fn course_registry_remove_course_attachment(env: Env, caller: Address, attachment_id: u128) {
+    let attachment: CourseAttachment = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CourseAttachment(attachment_id))
+        .unwrap_or_else(|| handle_error(&env, Error::AttachmentNotFound));
+
+    require_course_management_auth(&env, &caller, &attachment.course_id);
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CourseAttachment(attachment_id));
+
+    let ids_key: DataKey = DataKey::CourseAttachmentIds(attachment.course_id.clone());
+    let mut ids: Vec<u128> = env.storage().persistent().get(&ids_key).unwrap_or(Vec::new(&env));
+    if let Some(index) = ids.first_index_of(attachment_id) {
+        ids.remove(index);
+    }
+    if ids.is_empty() {
+        env.storage().persistent().remove(&ids_key);
+    } else {
+        env.storage().persistent().set(&ids_key, &ids);
+    }
+
+    env.events().publish(
+        (Symbol::new(&env, ATTACHMENT_REMOVED_EVENT), attachment.course_id),
+        attachment_id,
+    );
+}
+
+/// Removes every attachment of a deleted course.
+pub fn clear_course_attachments(env: &Env, course_id: &String) {
+    let ids_key: DataKey = DataKey::CourseAttachmentIds(course_id.clone());
+    let ids: Vec<u128> = env.storage().persistent().get(&ids_key).unwrap_or(Vec::new(env));
+    for id in ids.iter() {
+        env.storage().persistent().remove(&DataKey::CourseAttachment(id));
+    }
+    env.storage().persistent().remove(&ids_key);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{AttachmentType, Course};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Course) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let course = client.create_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (env, client, course)
+    }
+
+    #[test]
+    fn test_add_and_list_attachments() {
+        let (env, client, course) = setup();
+
+        let slides = client.add_course_attachment(
+            &course.creator,
+            &course.id,
+            &String::from_str(&env, "https://example.com/slides.pdf"),
+            &String::from_str(&env, "Slides"),
+            &AttachmentType::Pdf,
+        );
+        let code = client.add_course_attachment(
+            &course.creator,
+            &course.id,
+            &String::from_str(&env, "https://example.com/code.zip"),
+            &String::from_str(&env, "Source code"),
+            &AttachmentType::Archive,
+        );
+        assert_eq!(slides.id, 1);
+        assert_eq!(code.id, 2);
+
+        let attachments = client.list_course_attachments(&course.id);
+        assert_eq!(attachments.len(), 2);
+        assert_eq!(attachments.get(0).unwrap(), slides);
+        assert_eq!(attachments.get(1).unwrap(), code);
+    }
+
+    #[test]
+    fn test_remove_attachment() {
+        let (env, client, course) = setup();
+        let attachment = client.add_course_attachment(
+            &course.creator,
+            &course.id,
+            &String::from_str(&env, "https://example.com/intro"),
+            &String::from_str(&env, "Intro video"),
+            &AttachmentType::Video,
+        );
+
+        client.remove_course_attachment(&course.creator, &attachment.id);
+
+        assert!(client.list_course_attachments(&course.id).is_empty());
+        assert!(client
+            .try_remove_course_attachment(&course.creator, &attachment.id)
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #161)")]
+    fn test_add_attachment_invalid_url() {
+        let (env, client, course) = setup();
+        client.add_course_attachment(
+            &course.creator,
+            &course.id,
+            &String::from_str(&env, "not a url"),
+            &String::from_str(&env, "Broken"),
+            &AttachmentType::Link,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_remove_attachment_unauthorized() {
+        let (env, client, course) = setup();
+        let attachment = client.add_course_attachment(
+            &course.creator,
+            &course.id,
+            &String::from_str(&env, "https://example.com/docs"),
+            &String::from_str(&env, "Docs"),
+            &AttachmentType::Link,
+        );
+
+        client.remove_course_attachment(&Address::generate(&env), &attachment.id);
+    }
+}
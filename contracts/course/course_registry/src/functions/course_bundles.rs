@@ -0,0 +1,317 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::is_admin;
+use crate::schema::{Course, CourseBundle, DataKey};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const BUNDLE_CREATED_EVENT: &str = "BundleCreated";
+const BUNDLE_UPDATED_EVENT: &str = "BundleUpdated";
+const BUNDLE_DELETED_EVENT: &str = "BundleDeleted";
+
+/// Creates a bundle of the caller's courses.
+///
+/// # Panics
+///
+/// * `Error::NameRequired` if `name` is empty.
+/// * `Error::CourseNotFound` if a course does not exist.
+/// * `Error::Unauthorized` if a course belongs to another creator.
+/// * `Error::DuplicateCourseId` if a course is listed twice.
+pub fn course_registry_create_course_bundle(
+    env: Env,
+    creator: Address,
+    name: String,
+    description: Option<String>,
+    course_ids: Vec<String>,
+    price: u128,
+) -> CourseBundle {
+    creator.require_auth();
+
+    if name.is_empty() {
+        handle_error(&env, Error::NameRequired)
+    }
+
+    let mut bundled: Vec<String> = Vec::new(&env);
+    for course_id in course_ids.iter() {
+        require_own_course(&env, &creator, &course_id);
+        if bundled.contains(&course_id) {
+            handle_error(&env, Error::DuplicateCourseId)
+        }
+        bundled.push_back(course_id);
+    }
+
+    let id: u128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::BundleSeq)
+        .unwrap_or(0)
+        + 1;
+    env.storage().persistent().set(&DataKey::BundleSeq, &id);
+
+    let bundle: CourseBundle = CourseBundle {
+        id,
+        name,
+        description,
+        creator: creator.clone(),
+        course_ids: bundled,
+        price,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseBundle(id), &bundle);
+
+    env.events()
+        .publish((Symbol::new(&env, BUNDLE_CREATED_EVENT), id), creator);
+
+    bundle
+}
+
+/// Adds one of the bundle creator's courses to a bundle.
+///
+/// # Panics
+///
+/// * `Error::BundleNotFound` if the bundle does not exist.
+/// * `Error::Unauthorized` if the caller did not create the bundle or the course.
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::DuplicateCourseId` if the course is already in the bundle.
+pub fn course_registry_add_course_to_bundle(
+    env: Env,
+    creator: Address,
+    bundle_id: u128,
+    course_id: String,
+) -> CourseBundle {
+    let mut bundle: CourseBundle = load_own_bundle(&env, &creator, bundle_id);
+    require_own_course(&env, &creator, &course_id);
+    if bundle.course_ids.contains(&course_id) {
+        handle_error(&env, Error::DuplicateCourseId)
+    }
+
+    bundle.course_ids.push_back(course_id);
+    save_bundle(&env, &bundle);
+    bundle
+}
+
+/// Removes a course from a bundle (bundle creator only).
+///
+/// # Panics
+///
+/// * `Error::BundleNotFound` if the bundle does not exist.
+/// * `Error::Unauthorized` if the caller did not create the bundle.
+/// * `Error::CourseNotFound` if the course is not in the bundle.
+pub fn course_registry_remove_course_from_bundle(
+    env: Env,
+    creator: Address,
+    bundle_id: u128,
+    course_id: String,
+) -> CourseBundle {
+    let mut bundle: CourseBundle = load_own_bundle(&env, &creator, bundle_id);
+    let index: u32 = bundle
+        .course_ids
+        .first_index_of(&course_id)
+        .unwrap_or_else(|| handle_error(&env, Error::CourseNotFound));
+
+    bundle.course_ids.remove(index);
+    save_bundle(&env, &bundle);
+    bundle
+}
+
+/// Returns a bundle by ID.
+///
+/// Panics with `Error::BundleNotFound` if the bundle does not exist.
+pub fn course_registry_get_bundle(env: Env, bundle_id: u128) -> CourseBundle {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseBundle(bundle_id))
+        .unwrap_or_else(|| handle_error(&env, Error::BundleNotFound))
+}
+
+/// Returns the course IDs of a bundle.
+///
+/// Panics with `Error::BundleNotFound` if the bundle does not exist.
+pub fn course_registry_get_bundle_course_ids(env: Env, bundle_id: u128) -> Vec<String> {
+    course_registry_get_bundle(env, bundle_id).course_ids
+}
+
+/// Deletes a bundle (bundle creator or admin). The bundled courses are kept.
+///
+/// # Panics
+///
+/// * `Error::BundleNotFound` if the bundle does not exist.
+/// * `Error::Unauthorized` if the caller is neither the bundle creator nor an admin.
+pub fn course_registry_delete_bundle(env: Env, caller: Address, bundle_id: u128) {
+    caller.require_auth();
+
+    let bundle: CourseBundle = course_registry_get_bundle(env.clone(), bundle_id);
+    if bundle.creator != caller && !is_admin(&env, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CourseBundle(bundle_id));
+
+    env.events()
+        .publish((Symbol::new(&env, BUNDLE_DELETED_EVENT), bundle_id), caller);
+}
+
+/// Removes a deleted course from every bundle that lists it.
+pub fn remove_from_bundles(env: &Env, course_id: &String) {
+    let last_id: u128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::BundleSeq)
+        .unwrap_or(0);
+    for bundle_id in 1..=last_id {
+        let bundle: Option<CourseBundle> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::CourseBundle(bundle_id));
+        if let Some(mut bundle) = bundle {
+            if let Some(index) = bundle.course_ids.first_index_of(course_id) {
+                bundle.course_ids.remove(index);
+                save_bundle(env, &bundle);
+            }
+        }
+    }
+}
+
+fn require_own_course(env: &Env, creator: &Address, course_id: &String) {
+    let course: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id.clone()))
+        .unwrap_or_else(|| handle_error(env, Error::CourseNotFound));
+    if course.creator != *creator {
+        handle_error(env, Error::Unauthorized)
+    }
+}
+
+fn load_own_bundle(env: &Env, creator: &Address, bundle_id: u128) -> CourseBundle {
+    creator.require_auth();
+
+    let bundle: CourseBundle = course_registry_get_bundle(env.clone(), bundle_id);
+    if bundle.creator != *creator {
+        handle_error(env, Error::Unauthorized)
+    }
+    bundle
+}
+
+fn save_bundle(env: &Env, bundle: &CourseBundle) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseBundle(bundle.id), bundle);
+
+    env.events().publish(
+        (Symbol::new(env, BUNDLE_UPDATED_EVENT), bundle.id),
+        bundle.course_ids.clone(),
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::Course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+    fn create_course(client: &CourseRegistryClient, creator: &Address, title: &str) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        (env, client, creator)
+    }
+
+    #[test]
+    fn test_create_and_edit_bundle() {
+        let (env, client, creator) = setup();
+        let first = create_course(&client, &creator, "First");
+        let second = create_course(&client, &creator, "Second");
+        let third = create_course(&client, &creator, "Third");
+
+        let bundle = client.create_course_bundle(
+            &creator,
+            &String::from_str(&env, "Starter pack"),
+            &None,
+            &vec![&env, first.id.clone(), second.id.clone()],
+            &1500,
+        );
+        assert_eq!(bundle.id, 1);
+        assert_eq!(client.get_bundle(&bundle.id), bundle);
+
+        client.add_course_to_bundle(&creator, &bundle.id, &third.id);
+        client.remove_course_from_bundle(&creator, &bundle.id, &first.id);
+        assert_eq!(
+            client.get_bundle(&bundle.id).course_ids,
+            vec![&env, second.id, third.id]
+        );
+
+        client.delete_bundle(&creator, &bundle.id);
+        assert!(client.try_get_bundle(&bundle.id).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_add_other_creators_course_to_bundle() {
+        let (env, client, creator) = setup();
+        let other_course = create_course(&client, &Address::generate(&env), "Other");
+        let bundle = client.create_course_bundle(
+            &creator,
+            &String::from_str(&env, "Bundle"),
+            &None,
+            &Vec::new(&env),
+            &0,
+        );
+
+        client.add_course_to_bundle(&creator, &bundle.id, &other_course.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_delete_bundle_unauthorized() {
+        let (env, client, creator) = setup();
+        let bundle = client.create_course_bundle(
+            &creator,
+            &String::from_str(&env, "Bundle"),
+            &None,
+            &Vec::new(&env),
+            &0,
+        );
+
+        client.delete_bundle(&Address::generate(&env), &bundle.id);
+    }
+
+    #[test]
+    fn test_deleted_course_leaves_bundles() {
+        let (env, client, creator) = setup();
+        let first = create_course(&client, &creator, "First");
+        let second = create_course(&client, &creator, "Second");
+        let ids = vec![&env, first.id.clone(), second.id.clone()];
+        let name = String::from_str(&env, "Bundle");
+        let bundle = client.create_course_bundle(&creator, &name, &None, &ids, &0);
+        let other = client.create_course_bundle(&creator, &name, &None, &ids, &0);
+
+        client.delete_course(&creator, &first.id);
+        assert_eq!(client.get_bundle(&bundle.id).course_ids, vec![&env, second.id.clone()]);
+        assert_eq!(client.get_bundle(&other.id).course_ids, vec![&env, second.id]);
+    }
+}
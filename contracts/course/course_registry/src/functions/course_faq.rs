@@ -0,0 +1,366 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use shared::{handle_common_error, CommonError};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::require_course_management_auth;
+use crate::schema::{CourseFaq, DataKey};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const FAQ_KEY: Symbol = symbol_short!("faq");
+const FAQ_IDS_KEY: Symbol = symbol_short!("faqIds");
+const FAQ_SEQ_KEY: Symbol = symbol_short!("faqSeq");
+
+/// Maximum number of FAQ entries per course
+pub const MAX_FAQS_PER_COURSE: u32 = 20;
+const MAX_FAQ_QUESTION_LENGTH: u32 = 200;
+const MAX_FAQ_ANSWER_LENGTH: u32 = 2000;
+
+const FAQ_ADDED_EVENT: &str = "FaqAdded";
+const FAQ_UPDATED_EVENT: &str = "FaqUpdated";
+const FAQ_DELETED_EVENT: &str = "FaqDeleted";
+
+fn get_course_faq_ids(env: &Env, course_id: &String) -> Vec<u128> {
+    env.storage()
+        .persistent()
+        .get(&(FAQ_IDS_KEY, course_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn get_faq(env: &Env, faq_id: u128) -> CourseFaq {
+    env.storage()
+        .persistent()
+        .get(&(FAQ_KEY, faq_id))
+        .unwrap_or_else(|| handle_common_error(env, CommonError::NotFound))
+}
+
+fn validate_question(env: &Env, question: &String) {
+    if question.is_empty() || question.len() > MAX_FAQ_QUESTION_LENGTH {
+        handle_error(env, Error::InvalidTitleLength)
+    }
+}
+
+fn validate_answer(env: &Env, answer: &String) {
+    if answer.is_empty() || answer.len() > MAX_FAQ_ANSWER_LENGTH {
+        handle_error(env, Error::InvalidCourseDescription)
+    }
+}
+
+/// Adds an FAQ entry after the existing ones of a course (course creator or
+/// admin).
+///
+/// # Panics
+///
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::Unauthorized` if the caller is neither the creator nor an admin.
+/// * `Error::InvalidTitleLength` if `question` is empty or too long.
+/// * `Error::InvalidCourseDescription` if `answer` is empty or too long.
+/// * `CommonError::InvalidInput` if the course already has
+///   `MAX_FAQS_PER_COURSE` entries.
+pub fn course_registry_add_faq(
+    env: Env,
+    creator: Address,
+    course_id: String,
+    question: String,
+    answer: String,
+) -> CourseFaq {
+    if !env
+        .storage()
+        .persistent()
+        .has(&(COURSE_KEY, course_id.clone()))
+    {
+        handle_error(&env, Error::CourseNotFound)
+    }
+
+    require_course_management_auth(&env, &creator, &course_id);
+
+    validate_question(&env, &question);
+    validate_answer(&env, &answer);
+
+    let count_key: DataKey = DataKey::CourseFaqCount(course_id.clone());
+    let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    if count >= MAX_FAQS_PER_COURSE {
+        handle_common_error(&env, CommonError::InvalidInput)
+    }
+
+    let id: u128 = env.storage().persistent().get(&(FAQ_SEQ_KEY,)).unwrap_or(0) + 1;
+    env.storage().persistent().set(&(FAQ_SEQ_KEY,), &id);
+
+    // Entries are kept in display order, so the new one goes after the last
+    let mut ids: Vec<u128> = get_course_faq_ids(&env, &course_id);
+    let order: u32 = match ids.last() {
+        Some(last_id) => get_faq(&env, last_id).order + 1,
+        None => 0,
+    };
+
+    let faq: CourseFaq = CourseFaq {
+        id,
+        course_id: course_id.clone(),
+        question,
+        answer,
+        order,
+    };
+    env.storage().persistent().set(&(FAQ_KEY, id), &faq);
+
+    ids.push_back(id);
+    env.storage()
+        .persistent()
+        .set(&(FAQ_IDS_KEY, course_id.clone()), &ids);
+    env.storage().persistent().set(&count_key, &(count + 1));
+
+    env.events()
+        .publish((Symbol::new(&env, FAQ_ADDED_EVENT), course_id), id);
+
+    faq
+}
+
+/// Replaces the question and/or answer of an FAQ entry (course creator or
+/// admin). `None` keeps the current value.
+///
+/// # Panics
+///
+/// * `CommonError::NotFound` if the entry does not exist.
+/// * `Error::Unauthorized` if the caller is neither the creator nor an admin.
+/// * `Error::InvalidTitleLength` / `Error::InvalidCourseDescription` for an
+///   empty or too long question or answer.
+pub fn course_registry_update_faq(
+    env: Env,
+    creator: Address,
+    faq_id: u128,
+    question: Option<String>,
+    answer: Option<String>,
+) {
+    let mut faq: CourseFaq = get_faq(&env, faq_id);
+
+    require_course_management_auth(&env, &creator, &faq.course_id);
+
+    if let Some(question) = question {
+        validate_question(&env, &question);
+        faq.question = question;
+    }
+    if let Some(answer) = answer {
+        validate_answer(&env, &answer);
+        faq.answer = answer;
+    }
+    env.storage().persistent().set(&(FAQ_KEY, faq_id), &faq);
+
+    env.events().publish(
+        (Symbol::new(&env, FAQ_UPDATED_EVENT), faq.course_id),
+        faq_id,
+    );
+}
+
+/// Removes an FAQ entry (course creator or admin).
+///
+/// # Panics
+///
+/// * `CommonError::NotFound` if the entry does not exist.
+/// * `Error::Unauthorized` if the caller is neither the creator nor an admin.
+pub fn course_registry_delete_faq(env: Env, creator: Address, faq_id: u128) {
+    let faq: CourseFaq = get_faq(&env, faq_id);
+
+    require_course_management_auth(&env, &creator, &faq.course_id);
+
+    env.storage().persistent().remove(&(FAQ_KEY, faq_id));
+
+    let mut ids: Vec<u128> = get_course_faq_ids(&env, &faq.course_id);
+    if let Some(index) = ids.first_index_of(faq_id) {
+        ids.remove(index);
+    }
+    let count_key: DataKey = DataKey::CourseFaqCount(faq.course_id.clone());
+    if ids.is_empty() {
+        env.storage()
+            .persistent()
+            .remove(&(FAQ_IDS_KEY, faq.course_id.clone()));
+        env.storage().persistent().remove(&count_key);
+    } else {
+        env.storage()
+            .persistent()
+            .set(&(FAQ_IDS_KEY, faq.course_id.clone()), &ids);
+        env.storage().persistent().set(&count_key, &ids.len());
+    }
+
+    env.events().publish(
+        (Symbol::new(&env, FAQ_DELETED_EVENT), faq.course_id),
+        faq_id,
+    );
+}
+
+/// Returns the FAQ entries of a course sorted by `order`.
+pub fn course_registry_list_course_faqs(env: Env, course_id: String) -> Vec<CourseFaq> {
+    // IDs are stored in display order, see `course_registry_add_faq`
+    let mut faqs: Vec<CourseFaq> = Vec::new(&env);
+    for id in get_course_faq_ids(&env, &course_id).iter() {
+        if let Some(faq) = env
+            .storage()
+            .persistent()
+            .get::<_, CourseFaq>(&(FAQ_KEY, id))
+        {
+            faqs.push_back(faq);
+        }
+    }
+    faqs
+}
+
+/// Removes every FAQ entry of a deleted course.
+pub fn clear_course_faqs(env: &Env, course_id: &String) {
+    for id in get_course_faq_ids(env, course_id).iter() {
+        env.storage().persistent().remove(&(FAQ_KEY, id));
+    }
+    env.storage()
+        .persistent()
+        .remove(&(FAQ_IDS_KEY, course_id.clone()));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CourseFaqCount(course_id.clone()));
+}
+
+#[cfg(test)]
+mod test {
+    use super::MAX_FAQS_PER_COURSE;
+    use crate::schema::Course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Course) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let course = client.create_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (env, client, course)
+    }
+
+    fn add_faq(client: &CourseRegistryClient, course: &Course, question: &str) -> u128 {
+        let env = &client.env;
+        client
+            .add_faq(
+                &course.creator,
+                &course.id,
+                &String::from_str(env, question),
+                &String::from_str(env, "An answer"),
+            )
+            .id
+    }
+
+    #[test]
+    fn test_add_and_list_faqs() {
+        let (env, client, course) = setup();
+
+        let first = add_faq(&client, &course, "Is there a certificate?");
+        let second = add_faq(&client, &course, "How long is the course?");
+
+        let faqs = client.list_course_faqs(&course.id);
+        assert_eq!(faqs.len(), 2);
+        let faq = faqs.get(0).unwrap();
+        assert_eq!((faq.id, faq.order), (first, 0));
+        assert_eq!(
+            faq.question,
+            String::from_str(&env, "Is there a certificate?")
+        );
+        assert_eq!(
+            (faqs.get(1).unwrap().id, faqs.get(1).unwrap().order),
+            (second, 1)
+        );
+    }
+
+    #[test]
+    fn test_update_faq() {
+        let (env, client, course) = setup();
+        let id = add_faq(&client, &course, "Is there a certificate?");
+
+        client.update_faq(
+            &course.creator,
+            &id,
+            &None,
+            &Some(String::from_str(&env, "Yes, after the final exam")),
+        );
+
+        let faq = client.list_course_faqs(&course.id).get(0).unwrap();
+        assert_eq!(
+            faq.question,
+            String::from_str(&env, "Is there a certificate?")
+        );
+        assert_eq!(
+            faq.answer,
+            String::from_str(&env, "Yes, after the final exam")
+        );
+
+        let result = client.try_update_faq(
+            &course.creator,
+            &id,
+            &Some(String::from_str(&env, "")),
+            &None,
+        );
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(129)))
+        );
+    }
+
+    #[test]
+    fn test_delete_faq_keeps_order() {
+        let (_env, client, course) = setup();
+        let first = add_faq(&client, &course, "First");
+        let second = add_faq(&client, &course, "Second");
+
+        client.delete_faq(&course.creator, &first);
+        let third = add_faq(&client, &course, "Third");
+
+        let faqs = client.list_course_faqs(&course.id);
+        assert_eq!(faqs.len(), 2);
+        assert_eq!(faqs.get(0).unwrap().id, second);
+        let last = faqs.get(1).unwrap();
+        assert_eq!((last.id, last.order), (third, 2));
+        assert_eq!(
+            client.try_delete_faq(&course.creator, &first),
+            Err(Ok(soroban_sdk::Error::from_contract_error(2)))
+        );
+    }
+
+    #[test]
+    fn test_faq_limit() {
+        let (_env, client, course) = setup();
+        for _ in 0..MAX_FAQS_PER_COURSE {
+            add_faq(&client, &course, "Question");
+        }
+
+        let result = client.try_add_faq(
+            &course.creator,
+            &course.id,
+            &String::from_str(&client.env, "One too many"),
+            &String::from_str(&client.env, "An answer"),
+        );
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(4))));
+
+        // Deleting an entry frees a slot
+        let id = client.list_course_faqs(&course.id).get(0).unwrap().id;
+        client.delete_faq(&course.creator, &id);
+        add_faq(&client, &course, "Question");
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_add_faq_unauthorized() {
+        let (env, client, course) = setup();
+        client.add_faq(
+            &Address::generate(&env),
+            &course.id,
+            &String::from_str(&env, "Question"),
+            &String::from_str(&env, "Answer"),
+        );
+    }
+}
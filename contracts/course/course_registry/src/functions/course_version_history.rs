@@ -0,0 +1,218 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, CourseVersionSnapshot, DataKey};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Number of versions kept per course; older snapshots are evicted
+pub const MAX_COURSE_VERSIONS: u32 = 10;
+
+/// Oldest version still kept when `latest` is the current one.
+fn oldest_kept_version(latest: u32) -> u32 {
+    latest.saturating_sub(MAX_COURSE_VERSIONS - 1).max(1)
+}
+
+/// Stores a snapshot of the course's current version and evicts the one that
+/// falls out of the kept window.
+pub fn record_course_version(env: &Env, course: &Course, changed_by: &Address) {
+    let version: u32 = course.course_version;
+    let snapshot: CourseVersionSnapshot = CourseVersionSnapshot {
+        version,
+        title: course.title.clone(),
+        description: course.description.clone(),
+        changed_at: env.ledger().timestamp(),
+        changed_by: changed_by.clone(),
+    };
+    env.storage().persistent().set(
+        &DataKey::CourseVersionHistory(course.id.clone(), version),
+        &snapshot,
+    );
+
+    if version > MAX_COURSE_VERSIONS {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CourseVersionHistory(
+                course.id.clone(),
+                version - MAX_COURSE_VERSIONS,
+            ));
+    }
+}
+
+/// Removes every kept snapshot of a deleted course.
+pub fn clear_course_versions(env: &Env, course: &Course) {
+    for version in oldest_kept_version(course.course_version)..=course.course_version {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CourseVersionHistory(course.id.clone(), version));
+    }
+}
+
+/// Returns the kept snapshots of a course, oldest version first.
+///
+/// Panics with `Error::CourseNotFound` if the course does not exist.
+pub fn course_registry_get_course_version_history(
+    env: Env,
+    course_id: String,
+) -> Vec<CourseVersionSnapshot> {
+    let course: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id.clone()))
+        .unwrap_or_else(|| handle_error(&env, Error::CourseNotFound));
+
+    let mut history: Vec<CourseVersionSnapshot> = Vec::new(&env);
+    for version in oldest_kept_version(course.course_version)..=course.course_version {
+        if let Some(snapshot) = env.storage().persistent().get::<_, CourseVersionSnapshot>(
+            &DataKey::CourseVersionHistory(course_id.clone(), version),
+        ) {
+            history.push_back(snapshot);
+        }
+    }
+    history
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::MAX_COURSE_VERSIONS;
+    use crate::schema::Course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Course) {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_000);
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let course = client.create_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "Rust Basics"),
+            &String::from_str(&env, "Version one"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (env, client, course)
+    }
+
+    fn update_title(client: &CourseRegistryClient, course: &Course, title: &str) -> Course {
+        client.update_course(
+            &course.creator,
+            &course.id,
+            &Some(String::from_str(&client.env, title)),
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_version_increments_on_update() {
+        let (_env, client, course) = setup();
+        assert_eq!(course.course_version, 1);
+
+        let updated = update_title(&client, &course, "Rust Fundamentals");
+        assert_eq!(updated.course_version, 2);
+        let updated = client.update_course(
+            &course.creator,
+            &course.id,
+            &None,
+            &None,
+            &Some(2000_u128),
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        assert_eq!(updated.course_version, 3);
+        assert_eq!(client.get_course(&course.id).course_version, 3);
+    }
+
+    #[test]
+    fn test_snapshot_fields() {
+        let (env, client, course) = setup();
+        env.ledger().set_timestamp(5_000);
+
+        client.update_course(
+            &course.creator,
+            &course.id,
+            &None,
+            &Some(String::from_str(&env, "Version two")),
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+
+        let history = client.get_course_version_history(&course.id);
+        assert_eq!(history.len(), 2);
+        let created = history.get(0).unwrap();
+        assert_eq!((created.version, created.changed_at), (1, 1_000));
+        assert_eq!(created.description, String::from_str(&env, "Version one"));
+        let snapshot = history.get(1).unwrap();
+        assert_eq!(snapshot.version, 2);
+        assert_eq!(snapshot.title, String::from_str(&env, "Rust Basics"));
+        assert_eq!(snapshot.description, String::from_str(&env, "Version two"));
+        assert_eq!(snapshot.changed_at, 5_000);
+        assert_eq!(snapshot.changed_by, course.creator);
+    }
+
+    #[test]
+    fn test_history_capped_evicting_oldest() {
+        let (_env, client, course) = setup();
+        for index in 0..MAX_COURSE_VERSIONS {
+            update_title(&client, &course, &std::format!("Rust {index}"));
+        }
+
+        let history = client.get_course_version_history(&course.id);
+        assert_eq!(history.len(), MAX_COURSE_VERSIONS);
+        assert_eq!(history.get(0).unwrap().version, 2);
+        assert_eq!(history.last().unwrap().version, MAX_COURSE_VERSIONS + 1);
+    }
+
+    #[test]
+    fn test_history_oldest_first() {
+        let (env, client, course) = setup();
+        update_title(&client, &course, "Rust Fundamentals");
+        update_title(&client, &course, "Rust in Depth");
+
+        let titles: std::vec::Vec<String> = client
+            .get_course_version_history(&course.id)
+            .iter()
+            .map(|snapshot| snapshot.title)
+            .collect();
+        assert_eq!(
+            titles,
+            [
+                String::from_str(&env, "Rust Basics"),
+                String::from_str(&env, "Rust Fundamentals"),
+                String::from_str(&env, "Rust in Depth"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_history_unknown_course() {
+        let (env, client, _course) = setup();
+        let result = client.try_get_course_version_history(&String::from_str(&env, "999"));
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(117)))
+        );
+    }
+}
@@ -3,9 +3,18 @@
 
 use super::utils::{to_lowercase, trim, u32_to_string};
 use super::course_rate_limit_utils::check_course_creation_rate_limit;
+use super::course_version_history::record_course_version;
+use super::get_course_count::track_course_existence;
+use super::get_courses_updated_after::record_course_update;
+use super::list_courses::add_to_course_list;
+use super::list_courses_by_category::add_to_category_index;
+use super::list_courses_by_creator::add_to_creator_index;
+use super::list_courses_by_difficulty::add_to_difficulty_index;
+use super::list_courses_by_language::add_to_language_index;
+use super::search_courses::index_course_title;
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
-use crate::error::{handle_error, Error};
-use crate::schema::{Course, CourseLevel};
+use crate::error::{handle_error, handle_extended_error, Error, ExtendedError};
+use crate::schema::{Course, CourseLevel, CourseVisibility, DifficultyLevel};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 const TITLE_KEY: Symbol = symbol_short!("title");
@@ -25,6 +34,7 @@ pub fn create_course(
     thumbnail_url: Option<String>,
     level: Option<CourseLevel>,
     duration_hours: Option<u32>,
+    difficulty: Option<DifficultyLevel>,
 ) -> Course {
     creator.require_auth();
 
@@ -55,7 +65,7 @@ pub fn create_course(
     // Validate optional parameters
     if let Some(ref cat) = category {
         if cat.is_empty() || cat.len() > 100 {
-            handle_error(&env, Error::EmptyCategory);
+            handle_extended_error(&env, ExtendedError::EmptyCategory);
         }
     }
 
@@ -112,11 +122,31 @@ pub fn create_course(
         is_archived: false,
         level: level.clone(),
         duration_hours,
+        tags: Vec::new(&env),
+        difficulty: difficulty.map(|d| d as u32),
+        max_enrollments: None,
+        start_timestamp: None,
+        end_timestamp: None,
+        co_instructors: Vec::new(&env),
+        featured: false,
+        frozen: false,
+        is_free: false,
+        visibility: CourseVisibility::Public,
+        course_version: 1,
     };
 
     // save to the storage
     env.storage().persistent().set(&storage_key, &new_course);
     env.storage().persistent().set(&title_key, &true);
+    record_course_update(&env, &converted_id);
+    record_course_version(&env, &new_course, &creator);
+    track_course_existence(&env, &new_course, true);
+    add_to_course_list(&env, &converted_id);
+    add_to_creator_index(&env, &creator, &converted_id);
+    add_to_category_index(&env, &category, &converted_id);
+    add_to_difficulty_index(&env, &new_course.difficulty, &converted_id);
+    add_to_language_index(&env, &language, &converted_id);
+    index_course_title(&env, &converted_id, &new_course.title);
 
     // emit an event
     env.events()
@@ -184,6 +214,7 @@ mod test {
             &thumbnail_url,
             &None,
             &None,
+            &None,
         );
         let course = client.get_course(&course.id);
         assert_eq!(course.title, title);
@@ -221,6 +252,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let course2 = client.create_course(
@@ -233,6 +265,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let stored_course = client.get_course(&course2.id);
@@ -244,7 +277,7 @@ mod test {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #10)")]
+    #[should_panic(expected = "HostError: Error(Contract, #110)")]
     fn test_cannot_create_courses_with_duplicate_title() {
         let env: Env = Env::default();
         env.mock_all_auths();
@@ -265,6 +298,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         client.create_course(
@@ -277,11 +311,12 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #8)")]
+    #[should_panic(expected = "HostError: Error(Contract, #108)")]
     fn test_cannot_create_courses_with_empty_title() {
         let env: Env = Env::default();
         env.mock_all_auths();
@@ -301,11 +336,34 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #9)")]
+    #[should_panic(expected = "HostError: Error(Contract, #128)")]
+    fn test_cannot_create_courses_with_empty_category() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        client.create_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "A description"),
+            &crate::schema::DEFAULT_COURSE_PRICE,
+            &Some(String::from_str(&env, "")),
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #109)")]
     fn test_cannot_create_courses_with_zero_price() {
         let env: Env = Env::default();
         env.mock_all_auths();
@@ -325,11 +383,12 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #8)")]
+    #[should_panic(expected = "HostError: Error(Contract, #108)")]
     fn test_cannot_create_courses_with_whitespace_only_title() {
         let env: Env = Env::default();
         env.mock_all_auths();
@@ -349,11 +408,12 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #10)")]
+    #[should_panic(expected = "HostError: Error(Contract, #110)")]
     fn test_duplicate_title_case_insensitive() {
         let env: Env = Env::default();
         env.mock_all_auths();
@@ -374,6 +434,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
         client.create_course(
             &Address::generate(&env),
@@ -385,6 +446,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
     }
 
@@ -408,6 +470,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
         assert_eq!(course.title, long_title);
         assert_eq!(course.price, price);
@@ -437,6 +500,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
         assert_eq!(course.title, title);
         assert_eq!(course.description, description);
@@ -463,6 +527,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
         assert_eq!(course.price, max_price);
         assert_eq!(course.title, title);
@@ -496,6 +561,7 @@ mod test {
             &thumbnail_url,
             &level,
             &duration_hours,
+            &None,
         );
         assert_eq!(course.title, title);
         assert_eq!(course.description, description);
@@ -529,6 +595,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
         assert_eq!(course.title, title);
         assert_eq!(course.price, price);
@@ -557,6 +624,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
         assert_eq!(course.title, title);
         assert_eq!(course.description, description);
@@ -581,6 +649,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let course2 = client.create_course(
@@ -593,6 +662,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let course3 = client.create_course(
@@ -605,6 +675,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         assert_eq!(course1.id, String::from_str(&env, "1"));
@@ -636,6 +707,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
         assert_eq!(course.title, title);
         assert_eq!(course.description, description);
@@ -4,6 +4,7 @@
 use soroban_sdk::{Address, Env, String, Vec, Symbol, symbol_short};
 
 use crate::error::{handle_error, Error};
+use crate::functions::utils::to_lowercase;
 use crate::schema::{CourseCategory, DataKey};
 
 const CREATE_COURSE_CATEGORY_EVENT: Symbol = symbol_short!("crtCrsCat");
@@ -23,6 +24,8 @@ const NEXT_CATEGORY_ID_EVENT: Symbol = symbol_short!("nxtCatId");
 /// Storage used (replace keys if your schema differs):
 /// - ("category_seq",) -> u128                // sequence counter
 /// - (("category", id),) -> CourseCategory    // category record by id
+/// - DataKey::CategoryByName(name) -> u128    // category id by lowercase name
+/// - DataKey::AllCategoryIds -> Vec<u128>     // ids of all categories
 pub fn create_course_category(
     env: Env,
     caller: Address,
@@ -45,6 +48,14 @@ pub fn create_course_category(
         handle_error(&env, Error::InvalidCategoryName);
     }
     
+    if env
+        .storage()
+        .persistent()
+        .has(&category_name_key(&env, &name))
+    {
+        handle_error(&env, Error::DuplicateCategoryName)
+    }
+
     // Validate description if provided
     if let Some(ref desc) = description {
         if desc.len() > 500 {
@@ -64,6 +75,19 @@ pub fn create_course_category(
     env.storage()
         .persistent()
         .set(&DataKey::CourseCategory(id), &category);
+    env.storage()
+        .persistent()
+        .set(&category_name_key(&env, &name), &id);
+
+    let mut all_ids: Vec<u128> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AllCategoryIds)
+        .unwrap_or(Vec::new(&env));
+    all_ids.push_back(id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::AllCategoryIds, &all_ids);
 
     // emit an event
     env.events()
@@ -73,9 +97,14 @@ pub fn create_course_category(
     id
 }
 
+/// Returns the name index key of a category; names are compared case-insensitively.
+pub fn category_name_key(env: &Env, name: &String) -> DataKey {
+    DataKey::CategoryByName(to_lowercase(env, name))
+}
+
 /// Checks whether who is an admin using the same pattern as user_management contract.
 /// This assumes the course_registry contract has its own admin system or uses a similar pattern.
-fn is_admin(env: &Env, who: Address) -> bool {
+pub fn is_admin(env: &Env, who: Address) -> bool {
     // For now, we'll use a simple storage-based admin check
     // In a production environment, you might want to integrate with the user_management contract
     let admins: Option<Vec<Address>> = env.storage().persistent().get(&DataKey::Admins);
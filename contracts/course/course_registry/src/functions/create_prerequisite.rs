@@ -3,7 +3,9 @@
 
 use soroban_sdk::{symbol_short, Address, Env, Map, String, Symbol, Vec};
 
-use crate::error::{handle_error, Error};
+use crate::error::{handle_error, handle_extended_error, Error, ExtendedError};
+use crate::functions::get_dependent_courses::save_course_prerequisites;
+use crate::functions::edit_prerequisite::validate_prerequisite_depth;
 use crate::schema::{Course, DataKey};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
@@ -24,7 +26,7 @@ pub fn add_prerequisite(env: Env, creator: Address, course_id: String, prerequis
 
     // Validate prerequisites list
     if prerequisites.is_empty() {
-        handle_error(&env, Error::EmptyPrerequisiteList);
+        handle_extended_error(&env, ExtendedError::EmptyPrerequisiteList);
     }
 
     // Check for reasonable limit on number of prerequisites
@@ -35,7 +37,7 @@ pub fn add_prerequisite(env: Env, creator: Address, course_id: String, prerequis
     // Validate each prerequisite ID
     for prerequisite_id in prerequisites.iter() {
         if prerequisite_id.is_empty() {
-            handle_error(&env, Error::EmptyPrerequisiteId);
+            handle_extended_error(&env, ExtendedError::EmptyPrerequisiteId);
         }
 
         if prerequisite_id.len() > 100 {
@@ -56,7 +58,7 @@ pub fn add_prerequisite(env: Env, creator: Address, course_id: String, prerequis
         .expect("Course not found");
 
     if course.creator != creator {
-        handle_error(&env, Error::OnlyCreatorCanEditPrereqs)
+        handle_extended_error(&env, ExtendedError::OnlyCreatorCanEditPrereqs)
     }
 
     for prerequisite_id in prerequisites.iter() {
@@ -72,10 +74,9 @@ pub fn add_prerequisite(env: Env, creator: Address, course_id: String, prerequis
 
     validate_no_circular_dependency(&env, &course_id, &prerequisites);
 
-    env.storage().persistent().set(
-        &DataKey::CoursePrerequisites(course_id.clone()),
-        &prerequisites,
-    );
+    validate_prerequisite_depth(&env, &course_id, &prerequisites);
+
+    save_course_prerequisites(&env, &course_id, &prerequisites);
 
     env.events()
         .publish((PREREQ_CREATED_EVENT, course_id), prerequisites.len());
@@ -174,7 +175,7 @@ mod tests {
     };
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #56)")]
+    #[should_panic(expected = "HostError: Error(Contract, #156)")]
     fn test_add_prerequisite_duplicate_validation() {
         let env = Env::default();
         env.mock_all_auths();
@@ -194,6 +195,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let course2 = client.create_course(
@@ -206,6 +208,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
 
         // Create prerequisites with duplicate course2.id
@@ -216,6 +219,32 @@ mod tests {
         client.add_prerequisite(&creator, &course1.id, &prerequisites);
     }
 
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #150)")]
+    fn test_add_prerequisite_empty_list() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let creator: Address = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course 1"),
+            &String::from_str(&env, "Description 1"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+
+        client.add_prerequisite(&creator, &course.id, &SdkVec::new(&env));
+    }
+
     #[test]
     fn test_add_prerequisite_no_duplicates_success() {
         let env = Env::default();
@@ -236,6 +265,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let course2 = client.create_course(
@@ -248,6 +278,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let course3 = client.create_course(
@@ -260,6 +291,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
 
         // Create prerequisites without duplicates
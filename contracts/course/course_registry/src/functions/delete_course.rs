@@ -5,13 +5,37 @@ use soroban_sdk::{symbol_short, vec, Address, Env, String, Symbol, Vec};
 
 use crate::error::{handle_error, Error};
 use crate::schema::{Course, CourseModule};
+use crate::functions::course_attachments::clear_course_attachments;
+use crate::functions::course_bundles::remove_from_bundles;
+use crate::functions::course_faq::clear_course_faqs;
+use crate::functions::course_version_history::clear_course_versions;
+use crate::functions::get_course_count::track_course_existence;
+use crate::functions::get_courses_updated_after::remove_course_update;
+use crate::functions::get_dependent_courses::{
+    clear_course_dependencies, get_dependent_course_ids, is_dependent_deletion_warn_only,
+};
+use crate::functions::set_course_schedule::remove_from_schedule;
+use crate::functions::list_courses::remove_from_course_list;
+use crate::functions::list_courses_by_category::remove_from_category_index;
+use crate::functions::list_courses_by_creator::remove_from_creator_index;
+use crate::functions::list_courses_by_difficulty::remove_from_difficulty_index;
+use crate::functions::list_courses_by_language::remove_from_language_index;
+use crate::functions::mark_course_featured::remove_from_featured;
+use crate::functions::module_resources::clear_module_resources;
+use crate::functions::search_courses::unindex_course_title;
+use crate::functions::set_course_price::clear_price_history;
+use crate::functions::set_course_tags::clear_course_tags;
+use crate::functions::take_progress_snapshot::clear_progress_snapshots;
+use crate::functions::module_utils::{clear_course_module_indexes, get_course_module_ids};
 use crate::functions::utils::{concat_strings, to_lowercase, u32_to_string};
+use shared::bulk_remove;
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 const MODULE_KEY: Symbol = symbol_short!("module");
 const TITLE_KEY: Symbol = symbol_short!("title");
 
 const DELETE_COURSE_EVENT: Symbol = symbol_short!("delCourse");
+const DELETED_WITH_DEPENDENTS_EVENT: &str = "CourseDeletedWithDependents";
 
 pub fn delete_course(env: &Env, creator: Address, course_id: String) -> Result<(), &'static str> {
     creator.require_auth();
@@ -36,6 +60,18 @@ pub fn delete_course(env: &Env, creator: Address, course_id: String) -> Result<(
         handle_error(env, Error::Unauthorized)
     }
 
+    // Other courses still list this one as a prerequisite
+    let dependents: Vec<String> = get_dependent_course_ids(env, &course_id);
+    if !dependents.is_empty() {
+        if !is_dependent_deletion_warn_only(env) {
+            handle_error(env, Error::CourseHasDependents)
+        }
+        env.events().publish(
+            (Symbol::new(env, DELETED_WITH_DEPENDENTS_EVENT), course_id.clone()),
+            dependents,
+        );
+    }
+
     delete_course_modules(env, &course_id);
 
     let lowercase_title: String = to_lowercase(env, &course.title);
@@ -43,6 +79,24 @@ pub fn delete_course(env: &Env, creator: Address, course_id: String) -> Result<(
     let title_key: (Symbol, String) = (TITLE_KEY, lowercase_title);
     env.storage().persistent().remove(&title_key);
     env.storage().persistent().remove(&course_storage_key);
+    remove_course_update(env, &course_id);
+    remove_from_schedule(env, &course_id);
+    clear_course_dependencies(env, &course_id);
+    clear_course_attachments(env, &course_id);
+    clear_course_faqs(env, &course_id);
+    clear_course_versions(env, &course);
+    clear_progress_snapshots(env, &course_id);
+    track_course_existence(env, &course, false);
+    remove_from_course_list(env, &course_id);
+    remove_from_creator_index(env, &course.creator, &course_id);
+    remove_from_category_index(env, &course.category, &course_id);
+    remove_from_difficulty_index(env, &course.difficulty, &course_id);
+    remove_from_language_index(env, &course.language, &course_id);
+    clear_course_tags(env, &course);
+    unindex_course_title(env, &course_id, &course.title);
+    clear_price_history(env, &course_id);
+    remove_from_featured(env, &course_id);
+    remove_from_bundles(env, &course_id);
 
     // emit an event
     env.events()
@@ -82,16 +136,30 @@ fn delete_course_modules(env: &Env, course_id: &String) {
         }
     }
 
+    for id in get_course_module_ids(env, course_id).iter() {
+        if !modules_to_delete.contains(&id) {
+            modules_to_delete.push_back(id);
+        }
+    }
+
+    let mut module_keys: Vec<(Symbol, String)> = Vec::new(env);
+    for id in modules_to_delete.iter() {
+        module_keys.push_back((MODULE_KEY, id));
+    }
+    bulk_remove(env, &module_keys);
+
     for id in modules_to_delete.iter() {
-        env.storage().persistent().remove(&(MODULE_KEY, id.clone()));
+        clear_module_resources(env, &id);
         env.events().publish((id.clone(),), "module_deleted");
     }
+
+    clear_course_module_indexes(env, course_id);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::schema::Course;
+    use crate::schema::{ContentType, Course};
     use crate::{CourseRegistry, CourseRegistryClient};
     use soroban_sdk::testutils::Address as _;
 
@@ -131,7 +199,7 @@ mod tests {
     use soroban_sdk::{Env, String};
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
     fn test_delete_course_unauthorized() {
         let env = Env::default();
         env.mock_all_auths();
@@ -152,13 +220,14 @@ mod tests {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
 
         client.delete_course(&impostor, &new_course.id.clone());
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
     fn test_impostor_cannot_delete_course() {
         let env = Env::default();
         env.mock_all_auths();
@@ -180,6 +249,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let retrieved_course = client.get_course(&course.id);
@@ -208,6 +278,7 @@ mod tests {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
 
         assert_eq!(new_course, client.get_course(&new_course.id.clone()));
@@ -242,6 +313,7 @@ mod tests {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
 
         let module = client.add_module(
@@ -249,6 +321,8 @@ mod tests {
             &new_course.id,
             &0,
             &String::from_str(&env, "Module Title"),
+            &ContentType::Unspecified,
+            &None,
         );
 
         let module_exists: bool = env.as_contract(&contract_id, || {
@@ -276,7 +350,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #17)")]
+    #[should_panic(expected = "HostError: Error(Contract, #117)")]
     fn test_delete_course_not_found() {
         let env = Env::default();
         env.mock_all_auths();
@@ -310,6 +384,7 @@ mod tests {
             &Some(String::from_str(&env, "thumbnail_url1")),
             &None,
             &None,
+            &None,
         );
 
         let course2: Course = client.create_course(
@@ -322,6 +397,7 @@ mod tests {
             &Some(String::from_str(&env, "thumbnail_url2")),
             &None,
             &None,
+            &None,
         );
 
         client.delete_course(&creator, &course1.id.clone());
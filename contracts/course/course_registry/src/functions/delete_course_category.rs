@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::create_course_category::{category_name_key, is_admin};
+use crate::schema::{CourseCategory, DataKey};
+
+const CATEGORY_DELETED_EVENT: &str = "CategoryDeleted";
+
+/// Deletes a course category (admin-only).
+///
+/// A category can only be deleted once no course is assigned to it anymore.
+///
+/// # Panics
+///
+/// * `Error::Unauthorized` if the caller is not an admin.
+/// * `Error::CategoryNotFound` if the category does not exist.
+/// * `Error::CategoryHasCourses` if courses are still assigned to the category.
+pub fn course_registry_delete_course_category(env: Env, caller: Address, category_id: u128) {
+    caller.require_auth();
+    if !is_admin(&env, caller.clone()) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    let category: CourseCategory = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CourseCategory(category_id))
+        .unwrap_or_else(|| handle_error(&env, Error::CategoryNotFound));
+
+    let course_ids: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CategoryCourses(category_id))
+        .unwrap_or(Vec::new(&env));
+    if !course_ids.is_empty() {
+        handle_error(&env, Error::CategoryHasCourses)
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CourseCategory(category_id));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CategoryCourses(category_id));
+    env.storage()
+        .persistent()
+        .remove(&category_name_key(&env, &category.name));
+
+    let mut all_ids: Vec<u128> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AllCategoryIds)
+        .unwrap_or(Vec::new(&env));
+    if let Some(index) = all_ids.first_index_of(category_id) {
+        all_ids.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::AllCategoryIds, &all_ids);
+    }
+
+    env.events().publish(
+        (Symbol::new(&env, CATEGORY_DELETED_EVENT), category_id),
+        (caller, category.name),
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::DataKey;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn setup() -> (Env, Address, CourseRegistryClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Admins, &vec![&env, admin.clone()]);
+        });
+        (env, contract_id, client, admin)
+    }
+
+    #[test]
+    fn test_delete_course_category() {
+        let (env, contract_id, client, admin) = setup();
+        let name = String::from_str(&env, "Programming");
+        let category_id = client.create_course_category(&admin, &name, &None);
+
+        client.delete_course_category(&admin, &category_id);
+
+        assert!(client.get_course_category(&category_id).is_none());
+        env.as_contract(&contract_id, || {
+            assert!(!env
+                .storage()
+                .persistent()
+                .has(&DataKey::CategoryByName(String::from_str(&env, "programming"))));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #159)")]
+    fn test_delete_missing_category() {
+        let (_env, _contract_id, client, admin) = setup();
+        client.delete_course_category(&admin, &42);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #165)")]
+    fn test_delete_category_with_courses() {
+        let (env, _contract_id, client, admin) = setup();
+        let name = String::from_str(&env, "Programming");
+        let category_id = client.create_course_category(&admin, &name, &None);
+        client.create_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "Rust"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &Some(name),
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+
+        client.delete_course_category(&admin, &category_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_delete_category_unauthorized() {
+        let (env, _contract_id, client, admin) = setup();
+        let category_id =
+            client.create_course_category(&admin, &String::from_str(&env, "Programming"), &None);
+
+        client.delete_course_category(&Address::generate(&env), &category_id);
+    }
+}
@@ -0,0 +1,164 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::get_module::course_registry_get_course_modules;
+use crate::functions::module_resources::clear_module_resources;
+use crate::functions::module_utils::{renumber_modules, unindex_module};
+use crate::schema::{Course, CourseModule};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const MODULE_KEY: Symbol = symbol_short!("module");
+const POSITION_KEY: Symbol = symbol_short!("pos");
+
+const MODULE_DELETED_EVENT: &str = "ModuleDeleted";
+
+/// Deletes a single module (course creator only).
+///
+/// The remaining modules of the course are renumbered to keep a contiguous
+/// zero-based position sequence.
+///
+/// # Arguments
+///
+/// * `env` - Soroban environment.
+/// * `caller` - Address of the course creator.
+/// * `module_id` - ID of the module to delete.
+///
+/// # Panics
+///
+/// * `Error::ModuleNotFound` if the module does not exist.
+/// * `Error::CourseNotFound` if the parent course does not exist.
+/// * `Error::Unauthorized` if the caller is not the course creator.
+pub fn course_registry_delete_module(env: Env, caller: Address, module_id: String) {
+    caller.require_auth();
+
+    let module: CourseModule = env
+        .storage()
+        .persistent()
+        .get(&(MODULE_KEY, module_id.clone()))
+        .unwrap_or_else(|| handle_error(&env, Error::ModuleNotFound));
+
+    let course: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, module.course_id.clone()))
+        .unwrap_or_else(|| handle_error(&env, Error::CourseNotFound));
+
+    if course.creator != caller {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&(MODULE_KEY, module_id.clone()));
+    env.storage()
+        .persistent()
+        .remove(&(POSITION_KEY, module.course_id.clone(), module.position));
+    unindex_module(&env, &module);
+    clear_module_resources(&env, &module_id);
+
+    let remaining: Vec<CourseModule> =
+        course_registry_get_course_modules(env.clone(), module.course_id.clone());
+    renumber_modules(&env, &module.course_id, &remaining);
+
+    env.events().publish(
+        (Symbol::new(&env, MODULE_DELETED_EVENT), module.course_id),
+        (caller, module_id),
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::schema::{ContentType, Course, CourseModule};
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Address, Course) {
+        let (env, client, _mocks) = setup_with_mocks();
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (env, client, creator, course)
+    }
+
+    fn add_modules(
+        env: &Env,
+        client: &CourseRegistryClient,
+        creator: &Address,
+        course: &Course,
+    ) -> Vec<CourseModule> {
+        let mut modules = Vec::new(env);
+        for (position, title) in [(0, "Intro"), (1, "Basics"), (2, "Advanced")] {
+            modules.push_back(client.add_module(
+                creator,
+                &course.id,
+                &position,
+                &String::from_str(env, title),
+                &ContentType::Unspecified,
+                &None,
+            ));
+        }
+        modules
+    }
+
+    #[test]
+    fn test_delete_middle_module_renumbers() {
+        let (env, client, creator, course) = setup();
+        let modules = add_modules(&env, &client, &creator, &course);
+
+        client.delete_module(&creator, &modules.get(1).unwrap().id);
+
+        let remaining = client.get_course_modules(&course.id);
+        assert_eq!(remaining.len(), 2);
+        assert_eq!(remaining.get(0).unwrap().id, modules.get(0).unwrap().id);
+        assert_eq!(remaining.get(0).unwrap().position, 0);
+        assert_eq!(remaining.get(1).unwrap().id, modules.get(2).unwrap().id);
+        assert_eq!(remaining.get(1).unwrap().position, 1);
+
+        // The freed trailing position can be reused
+        client.add_module(&creator, &course.id, &2, &String::from_str(&env, "Extra"), &ContentType::Unspecified, &None);
+    }
+
+    #[test]
+    fn test_delete_last_module() {
+        let (env, client, creator, course) = setup();
+        let modules = add_modules(&env, &client, &creator, &course);
+
+        client.delete_module(&creator, &modules.get(2).unwrap().id);
+
+        let remaining = client.get_course_modules(&course.id);
+        assert_eq!(remaining.len(), 2);
+        assert_eq!(remaining.get(0).unwrap().position, 0);
+        assert_eq!(remaining.get(1).unwrap().position, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #121)")]
+    fn test_delete_module_not_found() {
+        let (env, client, creator, _course) = setup();
+        client.delete_module(&creator, &String::from_str(&env, "missing"));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_delete_module_unauthorized() {
+        let (env, client, creator, course) = setup();
+        let modules = add_modules(&env, &client, &creator, &course);
+
+        client.delete_module(&Address::generate(&env), &modules.get(0).unwrap().id);
+    }
+}
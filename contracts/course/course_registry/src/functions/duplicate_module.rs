@@ -0,0 +1,262 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, vec, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::get_module::course_registry_get_course_modules;
+use crate::functions::module_utils::index_module;
+use crate::functions::utils::{concat_strings, u32_to_string};
+use crate::schema::{Course, CourseModule};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const MODULE_KEY: Symbol = symbol_short!("module");
+const POSITION_KEY: Symbol = symbol_short!("pos");
+
+const MAX_MODULE_POSITION: u32 = 10000;
+
+const MODULE_DUPLICATED_EVENT: &str = "ModuleDuplicated";
+
+/// Copies a module within its course (course creator only).
+///
+/// The copy is titled `"<source title> (Copy)"` and placed at
+/// `insert_after_position + 1`; every module after `insert_after_position`
+/// moves up by one position to make room.
+///
+/// # Arguments
+///
+/// * `env` - Soroban environment.
+/// * `caller` - Address of the course creator.
+/// * `module_id` - ID of the module to copy.
+/// * `insert_after_position` - Position the copy is inserted after.
+///
+/// # Panics
+///
+/// * `Error::ModuleNotFound` if the module does not exist.
+/// * `Error::CourseNotFound` if the module's course does not exist.
+/// * `Error::Unauthorized` if the caller is not the course creator.
+/// * `Error::InvalidModulePosition` if `insert_after_position` is too large.
+pub fn course_registry_duplicate_module(
+    env: Env,
+    caller: Address,
+    module_id: String,
+    insert_after_position: u32,
+) -> CourseModule {
+    caller.require_auth();
+
+    let source: CourseModule = env
+        .storage()
+        .persistent()
+        .get(&(MODULE_KEY, module_id.clone()))
+        .unwrap_or_else(|| handle_error(&env, Error::ModuleNotFound));
+
+    let course: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, source.course_id.clone()))
+        .unwrap_or_else(|| handle_error(&env, Error::CourseNotFound));
+
+    if course.creator != caller {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    if insert_after_position >= MAX_MODULE_POSITION {
+        handle_error(&env, Error::InvalidModulePosition)
+    }
+
+    let position: u32 = insert_after_position + 1;
+    shift_modules_from(&env, &source.course_id, position);
+
+    let module: CourseModule = CourseModule {
+        id: generate_module_id(&env, &source.course_id, position),
+        course_id: source.course_id.clone(),
+        position,
+        title: concat_strings(
+            &env,
+            vec![&env, source.title.clone(), String::from_str(&env, " (Copy)")],
+        ),
+        created_at: env.ledger().timestamp(),
+        content_type: source.content_type.clone(),
+        content_url: source.content_url.clone(),
+    };
+
+    env.storage()
+        .persistent()
+        .set(&(MODULE_KEY, module.id.clone()), &module);
+    env.storage()
+        .persistent()
+        .set(&(POSITION_KEY, module.course_id.clone(), position), &true);
+    index_module(&env, &module);
+
+    env.events().publish(
+        (Symbol::new(&env, MODULE_DUPLICATED_EVENT), source.course_id),
+        (caller, module_id, module.id.clone(), position),
+    );
+
+    module
+}
+
+/// Moves every module at `position` or later up by one position.
+fn shift_modules_from(env: &Env, course_id: &String, position: u32) {
+    let mut shifted: Vec<CourseModule> = Vec::new(env);
+    for module in course_registry_get_course_modules(env.clone(), course_id.clone()).iter() {
+        if module.position >= position {
+            env.storage()
+                .persistent()
+                .remove(&(POSITION_KEY, course_id.clone(), module.position));
+            shifted.push_back(module);
+        }
+    }
+
+    for mut module in shifted.iter() {
+        module.position += 1;
+        env.storage()
+            .persistent()
+            .set(&(POSITION_KEY, course_id.clone(), module.position), &true);
+        env.storage()
+            .persistent()
+            .set(&(MODULE_KEY, module.id.clone()), &module);
+    }
+}
+
+/// Builds an ID in the `add_module` format, suffixed if that ID is taken.
+fn generate_module_id(env: &Env, course_id: &String, position: u32) -> String {
+    let base: String = concat_strings(
+        env,
+        vec![
+            env,
+            String::from_str(env, "module_"),
+            course_id.clone(),
+            String::from_str(env, "_"),
+            u32_to_string(env, position),
+            String::from_str(env, "_"),
+            u32_to_string(env, env.ledger().sequence()),
+        ],
+    );
+
+    let mut id: String = base.clone();
+    let mut suffix: u32 = 0;
+    while env.storage().persistent().has(&(MODULE_KEY, id.clone())) {
+        suffix += 1;
+        id = concat_strings(
+            env,
+            vec![env, base.clone(), String::from_str(env, "_"), u32_to_string(env, suffix)],
+        );
+    }
+    id
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{ContentType, Course, CourseModule};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Course, Vec<CourseModule>) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let course = client.create_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+
+        let mut modules: Vec<CourseModule> = Vec::new(&env);
+        for (position, title) in [(1, "Intro"), (2, "Assignment"), (3, "Wrap-up")] {
+            modules.push_back(client.add_module(
+                &course.creator,
+                &course.id,
+                &position,
+                &String::from_str(&env, title),
+                &ContentType::Unspecified,
+                &None,
+            ));
+        }
+        (env, client, course, modules)
+    }
+
+    /// Returns `(title, position)` of every course module, sorted by position.
+    fn layout(env: &Env, client: &CourseRegistryClient, course_id: &String) -> Vec<(String, u32)> {
+        let mut layout: Vec<(String, u32)> = Vec::new(env);
+        for module in client.get_course_modules(course_id).iter() {
+            layout.push_back((module.title, module.position));
+        }
+        layout
+    }
+
+    fn expected(env: &Env, titles: [&str; 4]) -> Vec<(String, u32)> {
+        let mut expected: Vec<(String, u32)> = Vec::new(env);
+        for (index, title) in titles.iter().enumerate() {
+            expected.push_back((String::from_str(env, title), index as u32 + 1));
+        }
+        expected
+    }
+
+    #[test]
+    fn test_duplicate_module_at_start() {
+        let (env, client, course, modules) = setup();
+        let source = modules.get(1).unwrap();
+
+        let copy = client.duplicate_module(&course.creator, &source.id, &0);
+
+        assert_eq!(copy.position, 1);
+        assert_eq!(copy.title, String::from_str(&env, "Assignment (Copy)"));
+        assert_ne!(copy.id, source.id);
+        assert_eq!(
+            layout(&env, &client, &course.id),
+            expected(&env, ["Assignment (Copy)", "Intro", "Assignment", "Wrap-up"])
+        );
+    }
+
+    #[test]
+    fn test_duplicate_module_in_middle() {
+        let (env, client, course, modules) = setup();
+        let source = modules.get(1).unwrap();
+
+        let copy = client.duplicate_module(&course.creator, &source.id, &2);
+
+        assert_eq!(copy.position, 3);
+        assert_eq!(client.get_module(&copy.id), copy);
+        assert_eq!(
+            layout(&env, &client, &course.id),
+            expected(&env, ["Intro", "Assignment", "Assignment (Copy)", "Wrap-up"])
+        );
+    }
+
+    #[test]
+    fn test_duplicate_module_at_end() {
+        let (env, client, course, modules) = setup();
+        let source = modules.get(0).unwrap();
+
+        let copy = client.duplicate_module(&course.creator, &source.id, &3);
+
+        assert_eq!(copy.position, 4);
+        assert_eq!(
+            layout(&env, &client, &course.id),
+            expected(&env, ["Intro", "Assignment", "Wrap-up", "Intro (Copy)"])
+        );
+
+        // Freed and taken positions are tracked for later additions
+        let result = client.try_add_module(&course.creator, &course.id, &4, &String::from_str(&env, "Extra"), &ContentType::Unspecified, &None);
+        assert!(result.is_err());
+        client.add_module(&course.creator, &course.id, &5, &String::from_str(&env, "Extra"), &ContentType::Unspecified, &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_duplicate_module_unauthorized() {
+        let (env, client, _course, modules) = setup();
+
+        client.duplicate_module(&Address::generate(&env), &modules.get(0).unwrap().id, &3);
+    }
+}
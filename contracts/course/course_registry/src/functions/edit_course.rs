@@ -5,13 +5,65 @@ use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
 
 use crate::error::{handle_error, Error};
 use crate::schema::{Course, EditCourseParams};
+use crate::functions::get_course_count::{is_counted_as_published, track_published_change};
+use crate::functions::get_courses_updated_after::record_course_update;
+use crate::functions::list_courses_by_category::reindex_course_category;
+use crate::functions::list_courses_by_language::reindex_course_language;
+use crate::functions::search_courses::{index_course_title, unindex_course_title};
+use crate::functions::set_course_price::record_price_change;
 use crate::functions::utils::{to_lowercase, trim};
+use crate::functions::validate_course::require_publishable;
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 const TITLE_KEY: Symbol = symbol_short!("title");
 
 const EDIT_COURSE_EVENT: Symbol = symbol_short!("editCours");
 
+/// Renames a course, keeping the case-insensitive title index in sync.
+///
+/// Panics with `EmptyCourseTitle` if the trimmed title is empty and with
+/// `DuplicateCourseTitle` if another course already uses the new title.
+pub fn update_course_title(env: &Env, course: &mut Course, new_title: &String) {
+    let t_trim: String = trim(env, new_title);
+
+    if t_trim.is_empty() {
+        handle_error(env, Error::EmptyCourseTitle)
+    }
+
+    // Only check/rotate title index if it's effectively changing (case-insensitive)
+    let old_title_lc: String = to_lowercase(env, &course.title);
+    let new_title_lc: String = to_lowercase(env, new_title);
+
+    if old_title_lc != new_title_lc {
+        // uniqueness index key for the *new* title
+        let new_title_key: (Symbol, String) = (TITLE_KEY, new_title_lc);
+        if env.storage().persistent().has(&new_title_key) {
+            handle_error(env, Error::DuplicateCourseTitle)
+        }
+
+        // remove old title index and set new one
+        let old_title_key: (Symbol, String) = (TITLE_KEY, old_title_lc);
+        env.storage().persistent().remove(&old_title_key);
+        env.storage().persistent().set(&new_title_key, &true);
+
+        unindex_course_title(env, &course.id, &course.title);
+        index_course_title(env, &course.id, &t_trim);
+        course.title = t_trim;
+    }
+}
+
+/// Returns whether `params` change nothing but the published flag.
+fn is_publish_only(params: &EditCourseParams) -> bool {
+    params.new_title.is_none()
+        && params.new_description.is_none()
+        && params.new_price.is_none()
+        && params.new_category.is_none()
+        && params.new_language.is_none()
+        && params.new_thumbnail_url.is_none()
+        && params.new_level.is_none()
+        && params.new_duration_hours.is_none()
+}
+
 pub fn edit_course(
     env: Env,
     creator: Address,
@@ -28,40 +80,17 @@ pub fn edit_course(
         .get(&storage_key)
         .expect("Course error: Course not found");
 
-    // --- Permission: only creator can edit ---
-    if creator != course.creator {
+    // --- Permission: creator edits everything, co-instructors may only publish ---
+    if creator != course.creator
+        && !(course.co_instructors.contains(&creator) && is_publish_only(&params))
+    {
         handle_error(&env, Error::Unauthorized)
     }
 
     // --- Title update (validate + uniqueness) ---
 
     if let Some(ref t) = params.new_title {
-        // Clone the string to avoid move issues
-        let t_str: String = t.clone();
-        let t_trim: String = trim(&env, &t_str);
-
-        if t_trim.is_empty() {
-            handle_error(&env, Error::EmptyCourseTitle)
-        }
-
-        // Only check/rotate title index if it's effectively changing (case-insensitive)
-        let old_title_lc: String = to_lowercase(&env, &course.title);
-        let new_title_lc: String = to_lowercase(&env, &t_str);
-
-        if old_title_lc != new_title_lc {
-            // uniqueness index key for the *new* title
-            let new_title_key: (Symbol, String) = (TITLE_KEY, new_title_lc);
-            if env.storage().persistent().has(&new_title_key) {
-                handle_error(&env, Error::DuplicateCourseTitle)
-            }
-
-            // remove old title index and set new one
-            let old_title_key: (Symbol, String) = (TITLE_KEY, old_title_lc);
-            env.storage().persistent().remove(&old_title_key);
-            env.storage().persistent().set(&new_title_key, &true);
-
-            course.title = t_trim;
-        }
+        update_course_title(&env, &mut course, t);
     }
 
     // --- Description ---
@@ -74,14 +103,17 @@ pub fn edit_course(
         if p == 0 {
             handle_error(&env, Error::InvalidPrice);
         }
+        record_price_change(&env, &course_id, course.price, p, &creator);
         course.price = p;
     }
 
     // --- Optional fields: category / language / thumbnail ---
     if let Some(cat) = params.new_category {
+        reindex_course_category(&env, &course.category, &cat, &course_id);
         course.category = cat; // Some(value) sets; None clears
     }
     if let Some(lang) = params.new_language {
+        reindex_course_language(&env, &course.language, &lang, &course_id);
         course.language = lang;
     }
     if let Some(url) = params.new_thumbnail_url {
@@ -90,7 +122,12 @@ pub fn edit_course(
 
     // --- Published flag ---
     if let Some(p) = params.new_published {
+        if p && !course.published {
+            require_publishable(&env, &course);
+        }
+        let was_published: bool = is_counted_as_published(&course);
         course.published = p;
+        track_published_change(&env, was_published, &course);
     }
 
     // --- Level field ---
@@ -105,6 +142,7 @@ pub fn edit_course(
 
     // --- Persist updated course ---
     env.storage().persistent().set(&storage_key, &course);
+    record_course_update(&env, &course_id);
 
     // --- Emit event ---
     env.events()
@@ -115,7 +153,7 @@ pub fn edit_course(
 
 #[cfg(test)]
 mod test {
-    use crate::schema::{Course, EditCourseParams};
+    use crate::schema::{ContentType, Course, EditCourseParams};
     use crate::{CourseRegistry, CourseRegistryClient};
     use soroban_sdk::{testutils::Address as _, Address, Env, String};
 
@@ -139,8 +177,11 @@ mod test {
             &Some(String::from_str(&env, "original_thumbnail")),
             &None,
             &None,
+            &None,
         );
 
+        client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Intro"), &ContentType::Unspecified, &None);
+
         let params = EditCourseParams {
             new_title: Some(String::from_str(&env, "New Title")),
             new_description: Some(String::from_str(&env, "New Description")),
@@ -198,7 +239,7 @@ mod test {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
     fn test_edit_course_unauthorized() {
         let env = Env::default();
         env.mock_all_auths();
@@ -219,6 +260,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let params = EditCourseParams {
@@ -262,7 +304,7 @@ mod test {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #8)")]
+    #[should_panic(expected = "HostError: Error(Contract, #108)")]
     fn test_edit_course_empty_title() {
         let env = Env::default();
         env.mock_all_auths();
@@ -282,6 +324,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let params = EditCourseParams {
@@ -299,7 +342,7 @@ mod test {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #9)")]
+    #[should_panic(expected = "HostError: Error(Contract, #109)")]
     fn test_edit_course_zero_price() {
         let env = Env::default();
         env.mock_all_auths();
@@ -319,6 +362,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let params = EditCourseParams {
@@ -336,7 +380,7 @@ mod test {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #10)")]
+    #[should_panic(expected = "HostError: Error(Contract, #110)")]
     fn test_edit_course_duplicate_title() {
         let env = Env::default();
         env.mock_all_auths();
@@ -356,6 +400,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let course2: Course = client.create_course(
@@ -368,6 +413,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let params = EditCourseParams {
@@ -405,6 +451,7 @@ mod test {
             &Some(String::from_str(&env, "original_thumbnail")),
             &None,
             &None,
+            &None,
         );
 
         let params = EditCourseParams {
@@ -461,6 +508,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let params = EditCourseParams {
@@ -4,7 +4,7 @@
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
 
 use crate::functions::is_course_creator::is_course_creator;
-use crate::error::{handle_error, Error};
+use crate::error::{handle_error, handle_extended_error, Error, ExtendedError};
 use crate::functions::utils::trim;
 use crate::schema::{Course, CourseGoal, DataKey};
 
@@ -29,7 +29,7 @@ pub fn edit_goal(
     }
     // Validate goal content - prevent empty or whitespace-only content
     if new_content.is_empty() || trim(&env, &new_content).is_empty() {
-        handle_error(&env, Error::EmptyNewGoalContent);
+        handle_extended_error(&env, ExtendedError::EmptyNewGoalContent);
     }
 
     // Load course
@@ -88,6 +88,7 @@ mod test {
             &Some(String::from_str(env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
 
         let goal_content = String::from_str(env, "Learn the basics of Rust");
@@ -114,6 +115,7 @@ mod test {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
         let goal_content = String::from_str(&env, "Learn the basics of Rust");
         // The `add_goal` function should return the newly created CourseGoal
@@ -127,7 +129,7 @@ mod test {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
     fn test_edit_goal_unauthorized() {
         let env = Env::default();
         env.mock_all_auths();
@@ -145,7 +147,7 @@ mod test {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #18)")]
+    #[should_panic(expected = "HostError: Error(Contract, #118)")]
     fn test_edit_goal_empty_content() {
         let env = Env::default();
         env.mock_all_auths();
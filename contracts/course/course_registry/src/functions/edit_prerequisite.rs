@@ -4,11 +4,14 @@
 use soroban_sdk::{symbol_short, Address, Env, Map, String, Symbol, Vec};
 
 use crate::error::{handle_error, Error};
-use crate::schema::{Course, DataKey};
+use crate::functions::access_control::is_admin;
+use crate::functions::get_dependent_courses::save_course_prerequisites;
+use crate::schema::{Course, DataKey, MAX_PREREQUISITE_DEPTH};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 
 const PREREQ_UPDATED_EVENT: Symbol = symbol_short!("preqEdit");
+const PREREQ_DEPTH_EVENT: Symbol = symbol_short!("preqDepth");
 
 pub fn edit_prerequisite(
     env: Env,
@@ -45,11 +48,11 @@ pub fn edit_prerequisite(
     // Prevent circular dependencies
     validate_no_circular_dependency(&env, &course_id, &new_prerequisites);
 
+    // Keep prerequisite chains within the configured depth
+    validate_prerequisite_depth(&env, &course_id, &new_prerequisites);
+
     // Save updated prerequisites
-    env.storage().persistent().set(
-        &DataKey::CoursePrerequisites(course_id.clone()),
-        &new_prerequisites,
-    );
+    save_course_prerequisites(&env, &course_id, &new_prerequisites);
 
     // Emit event
     env.events().publish(
@@ -58,6 +61,71 @@ pub fn edit_prerequisite(
     );
 }
 
+/// Returns the configured maximum prerequisite chain length.
+pub fn get_max_prerequisite_depth(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MaxPrerequisiteDepth)
+        .unwrap_or(MAX_PREREQUISITE_DEPTH)
+}
+
+/// Sets the maximum prerequisite chain length (admin-only).
+pub fn set_max_prerequisite_depth(env: Env, caller: Address, max_depth: u32) {
+    caller.require_auth();
+    if !is_admin(&env, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+    if max_depth == 0 {
+        handle_error(&env, Error::InvalidLimitValue)
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::MaxPrerequisiteDepth, &max_depth);
+    env.events()
+        .publish((PREREQ_DEPTH_EVENT,), (caller, max_depth));
+}
+
+/// Returns the length of the longest prerequisite chain below `course_id`.
+///
+/// The graph is traversed breadth-first, one level per iteration, starting
+/// from `prereqs`. Traversal stops once it goes past the configured limit,
+/// so the cost stays bounded even for large graphs.
+pub fn check_prerequisite_depth(env: &Env, course_id: &String, prereqs: &Vec<String>) -> u32 {
+    let max_depth: u32 = get_max_prerequisite_depth(env);
+    let mut level: Vec<String> = prereqs.clone();
+    let mut depth: u32 = 0;
+
+    while !level.is_empty() && depth <= max_depth {
+        depth += 1;
+
+        let mut next: Vec<String> = Vec::new(env);
+        for current in level.iter() {
+            let prerequisites: Vec<String> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::CoursePrerequisites(current))
+                .unwrap_or(Vec::new(env));
+            for prerequisite in prerequisites.iter() {
+                if prerequisite != *course_id && !next.contains(&prerequisite) {
+                    next.push_back(prerequisite);
+                }
+            }
+        }
+        level = next;
+    }
+
+    depth
+}
+
+/// Panics with `Error::PrerequisiteDepthExceeded` if the chain is too long.
+pub fn validate_prerequisite_depth(env: &Env, course_id: &String, prereqs: &Vec<String>) {
+    // Leave room for the course itself
+    if check_prerequisite_depth(env, course_id, prereqs) > get_max_prerequisite_depth(env) - 1 {
+        handle_error(env, Error::PrerequisiteDepthExceeded)
+    }
+}
+
 fn validate_no_circular_dependency(env: &Env, course_id: &String, new_prerequisites: &Vec<String>) {
     // Check if course_id appears in new_prerequisites (direct circular dependency)
     for prerequisite_id in new_prerequisites.iter() {
@@ -143,6 +211,7 @@ fn validate_no_duplicate_prerequisites(env: &Env, prerequisites: &Vec<String>) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::functions::test_utils::setup_with_mocks;
     use crate::CourseRegistry;
     use crate::CourseRegistryClient;
     use soroban_sdk::{
@@ -169,6 +238,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
         let course2 = client.create_course(
             &creator,
@@ -180,6 +250,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
         let course3 = client.create_course(
             &creator,
@@ -191,6 +262,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let mut prerequisites: Vec<String> = Vec::new(&env);
@@ -244,6 +316,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
         let course2 = client.create_course(
             &creator,
@@ -255,6 +328,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
         let course3 = client.create_course(
             &creator,
@@ -266,6 +340,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
         let course4 = client.create_course(
             &creator,
@@ -277,6 +352,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let mut initial_prerequisites = Vec::new(&env);
@@ -319,6 +395,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
         let course2 = client.create_course(
             &creator,
@@ -330,6 +407,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let mut initial_prerequisites = Vec::new(&env);
@@ -366,7 +444,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #13)")]
+    #[should_panic(expected = "HostError: Error(Contract, #113)")]
     fn test_edit_prerequisite_invalid_prerequisite() {
         let env = Env::default();
         env.mock_all_auths();
@@ -385,6 +463,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let mut prerequisites = Vec::new(&env);
@@ -394,7 +473,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #14)")]
+    #[should_panic(expected = "HostError: Error(Contract, #114)")]
     fn test_edit_prerequisite_direct_circular_dependency() {
         let env = Env::default();
         env.mock_all_auths();
@@ -413,6 +492,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let mut prerequisites = Vec::new(&env);
@@ -422,7 +502,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #15)")]
+    #[should_panic(expected = "HostError: Error(Contract, #115)")]
     fn test_edit_prerequisite_indirect_circular_dependency() {
         let env = Env::default();
         env.mock_all_auths();
@@ -441,6 +521,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
         let course2 = client.create_course(
             &creator,
@@ -452,6 +533,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
         let course3 = client.create_course(
             &creator,
@@ -463,6 +545,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let mut prerequisites2 = Vec::new(&env);
@@ -498,6 +581,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
         let course2 = client.create_course(
             &creator,
@@ -509,6 +593,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let mut prerequisites = Vec::new(&env);
@@ -555,6 +640,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
         let course2 = client.create_course(
             &creator,
@@ -566,6 +652,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
         let course3 = client.create_course(
             &creator,
@@ -577,6 +664,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
         let course4 = client.create_course(
             &creator,
@@ -588,6 +676,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
         let course5 = client.create_course(
             &creator,
@@ -599,6 +688,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let mut prerequisites2 = Vec::new(&env);
@@ -624,7 +714,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #56)")]
+    #[should_panic(expected = "HostError: Error(Contract, #156)")]
     fn test_edit_prerequisite_duplicate_validation() {
         let env = Env::default();
         env.mock_all_auths();
@@ -644,6 +734,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let course2 = client.create_course(
@@ -656,6 +747,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
 
         // Try to edit with duplicate prerequisites
@@ -686,6 +778,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let course2 = client.create_course(
@@ -698,6 +791,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let course3 = client.create_course(
@@ -710,6 +804,7 @@ mod tests {
             &None,
             &None,
             &None,
+            &None,
         );
 
         // Edit with unique prerequisites
@@ -731,4 +826,111 @@ mod tests {
         assert_eq!(stored_prerequisites.get(0).unwrap(), course2.id);
         assert_eq!(stored_prerequisites.get(1).unwrap(), course3.id);
     }
+
+    fn setup_depth() -> (Env, CourseRegistryClient<'static>, Address) {
+        let (env, client, mocks) = setup_with_mocks();
+        (env, client, mocks.admin)
+    }
+
+    /// Creates `count` courses, each by its own creator to stay under the rate limit.
+    fn create_courses(env: &Env, client: &CourseRegistryClient, count: u32) -> Vec<Course> {
+        let titles = ["C0", "C1", "C2", "C3", "C4", "C5", "C6", "C7"];
+        let mut courses: Vec<Course> = Vec::new(env);
+        for title in titles.iter().take(count as usize) {
+            courses.push_back(client.create_course(
+                &Address::generate(env),
+                &String::from_str(env, title),
+                &String::from_str(env, "description"),
+                &crate::schema::DEFAULT_COURSE_PRICE,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            ));
+        }
+        courses
+    }
+
+    /// Makes each course require the previous one: courses[i] -> courses[i - 1].
+    fn chain(env: &Env, client: &CourseRegistryClient, courses: &Vec<Course>, len: u32) {
+        for i in 1..len {
+            let course = courses.get(i).unwrap();
+            let previous = courses.get(i - 1).unwrap();
+            client.edit_prerequisite(
+                &course.creator,
+                &course.id,
+                &soroban_sdk::vec![env, previous.id],
+            );
+        }
+    }
+
+    #[test]
+    fn test_prerequisite_chain_at_depth_limit() {
+        let (env, client, _admin) = setup_depth();
+        let courses = create_courses(&env, &client, 5);
+
+        // C4 -> C3 -> C2 -> C1 -> C0 is five courses long, exactly the default limit
+        chain(&env, &client, &courses, 5);
+
+        let last = courses.get(4).unwrap();
+        assert_eq!(client.get_course_prerequisites(&last.id).len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #167)")]
+    fn test_prerequisite_chain_beyond_depth_limit() {
+        let (env, client, _admin) = setup_depth();
+        let courses = create_courses(&env, &client, 6);
+
+        chain(&env, &client, &courses, 6);
+    }
+
+    #[test]
+    fn test_prerequisite_depth_uses_deepest_branch() {
+        let (env, client, _admin) = setup_depth();
+        let courses = create_courses(&env, &client, 6);
+
+        // Deep branch: C3 -> C2 -> C1 -> C0; shallow branch: C4
+        chain(&env, &client, &courses, 4);
+        let shallow = courses.get(4).unwrap();
+        let top = courses.get(5).unwrap();
+        let branches = soroban_sdk::vec![&env, shallow.id.clone(), courses.get(3).unwrap().id];
+
+        env.as_contract(&client.address, || {
+            assert_eq!(check_prerequisite_depth(&env, &top.id, &branches), 4);
+            assert_eq!(
+                check_prerequisite_depth(
+                    &env,
+                    &top.id,
+                    &soroban_sdk::vec![&env, shallow.id.clone()]
+                ),
+                1
+            );
+        });
+
+        // Five courses long through the deep branch: still within the limit
+        client.edit_prerequisite(&top.creator, &top.id, &branches);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #167)")]
+    fn test_prerequisite_depth_configurable_limit() {
+        let (env, client, admin) = setup_depth();
+        let courses = create_courses(&env, &client, 3);
+
+        client.set_max_prerequisite_depth(&admin, &2);
+        assert_eq!(client.get_max_prerequisite_depth(), 2);
+
+        // C1 -> C0 fits, C2 -> C1 -> C0 does not
+        chain(&env, &client, &courses, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_set_max_prerequisite_depth_requires_admin() {
+        let (env, client, _admin) = setup_depth();
+        client.set_max_prerequisite_depth(&Address::generate(&env), &3);
+    }
 }
@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::require_course_management_auth;
+use crate::functions::get_courses_updated_after::record_course_update;
+use crate::schema::Course;
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const COURSE_FROZEN_EVENT: &str = "CourseFrozen";
+const COURSE_UNFROZEN_EVENT: &str = "CourseUnfrozen";
+
+fn set_frozen(env: &Env, caller: Address, course_id: String, frozen: bool) -> Course {
+    let key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    let mut course: Course = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| handle_error(env, Error::CourseNotFound));
+
+    require_course_management_auth(env, &caller, &course_id);
+
+    if course.frozen == frozen {
+        return course;
+    }
+    course.frozen = frozen;
+    env.storage().persistent().set(&key, &course);
+    record_course_update(env, &course_id);
+
+    let event: &str = if frozen {
+        COURSE_FROZEN_EVENT
+    } else {
+        COURSE_UNFROZEN_EVENT
+    };
+    env.events()
+        .publish((Symbol::new(env, event), course_id), caller);
+
+    course
+}
+
+/// Stops a course from taking new enrollments while its content is updated
+/// (course creator or admin).
+///
+/// Unlike archiving, the course stays listed and users who are already
+/// enrolled keep their access. Freezing a frozen course changes nothing.
+///
+/// # Panics
+///
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::Unauthorized` if the caller is neither the creator nor an admin.
+pub fn course_registry_freeze_course(env: Env, caller: Address, course_id: String) -> Course {
+    set_frozen(&env, caller, course_id, true)
+}
+
+/// Lets a frozen course take new enrollments again (course creator or admin).
+///
+/// # Panics
+///
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::Unauthorized` if the caller is neither the creator nor an admin.
+pub fn course_registry_unfreeze_course(env: Env, caller: Address, course_id: String) -> Course {
+    set_frozen(&env, caller, course_id, false)
+}
+
+/// Returns whether a course exists and is frozen.
+pub fn course_registry_is_course_frozen(env: Env, course_id: String) -> bool {
+    env.storage()
+        .persistent()
+        .get::<_, Course>(&(COURSE_KEY, course_id))
+        .is_some_and(|course| course.frozen)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::Course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Course) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let course = client.create_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "Rust"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (env, client, course)
+    }
+
+    #[test]
+    fn test_freeze_and_unfreeze_course() {
+        let (_env, client, course) = setup();
+        assert!(!course.frozen);
+
+        let frozen = client.freeze_course(&course.creator, &course.id);
+        assert!(frozen.frozen);
+        assert!(client.is_course_frozen(&course.id));
+
+        client.unfreeze_course(&course.creator, &course.id);
+        assert!(!client.is_course_frozen(&course.id));
+    }
+
+    #[test]
+    fn test_is_course_frozen_missing_course() {
+        let (env, client, _course) = setup();
+        assert!(!client.is_course_frozen(&String::from_str(&env, "missing")));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_freeze_course_unauthorized() {
+        let (env, client, course) = setup();
+        client.freeze_course(&Address::generate(&env), &course.id);
+    }
+}
@@ -19,23 +19,37 @@ const COURSE_KEY: Symbol = symbol_short!("course");
 ///
 /// Errors:
 /// - Panics with `"Course not found"` if the course does not exist.
-/// - Returns `Error::CourseAlreadyArchived` if the course is archived.
+///
+/// Archived courses are returned as well; check `Course::is_archived`.
 ///
 /// Storage used (replace keys if your schema differs):
 /// - (("course", id),) -> Course    // course record by id
 pub fn get_course(env: &Env, course_id: String) -> Course {
 
     // Get the course from storage
-    let course: Course = env
-        .storage()
+    env.storage()
         .persistent()
         .get(&(COURSE_KEY, course_id.clone()))
-        .expect("Course not found");
+        .expect("Course not found")
+}
 
-    match course.is_archived {
-        true => handle_error(env, Error::CourseAlreadyArchived),
-        false => course,
-    }
+/// Returns the price of a course.
+///
+/// Panics with `Error::CourseNotFound` if the course does not exist.
+pub fn course_registry_get_course_price(env: Env, course_id: String) -> u128 {
+    env.storage()
+        .persistent()
+        .get::<_, Course>(&(COURSE_KEY, course_id))
+        .map(|course| course.price)
+        .unwrap_or_else(|| handle_error(&env, Error::CourseNotFound))
+}
+
+/// Returns whether a course exists and is published.
+pub fn course_registry_is_course_published(env: Env, course_id: String) -> bool {
+    env.storage()
+        .persistent()
+        .get::<_, Course>(&(COURSE_KEY, course_id))
+        .is_some_and(|course| course.published)
 }
 
 #[cfg(test)]
@@ -66,7 +80,6 @@ mod test {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #5)")]
     fn test_get_archived_course() {
         let env = Env::default();
         env.mock_all_auths();
@@ -75,7 +88,30 @@ mod test {
         let creator = Address::generate(&env);
         let course = create_course(&client, &creator);
         client.archive_course(&creator, &course.id);
-        client.get_course(&course.id);
+        assert!(client.get_course(&course.id).is_archived);
+    }
+
+    #[test]
+    fn test_get_course_price_and_published() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let course = create_course(&client, &creator);
+
+        assert_eq!(client.get_course_price(&course.id), 1000);
+        assert!(!client.is_course_published(&course.id));
+        assert!(!client.is_course_published(&String::from_str(&env, "missing")));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #117)")]
+    fn test_get_course_price_not_found() {
+        let env = Env::default();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        client.get_course_price(&String::from_str(&env, "missing"));
     }
 
     fn create_course<'a>(client: &CourseRegistryClient<'a>, creator: &Address) -> Course {
@@ -92,6 +128,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         )
     }
 }
@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::Env;
+
+use crate::schema::{Course, DataKey};
+
+/// Returns whether a course counts towards `DataKey::PublishedCourseCount`.
+pub fn is_counted_as_published(course: &Course) -> bool {
+    course.published && !course.is_archived
+}
+
+/// Adjusts the course counters after a course was created (`true`) or deleted (`false`).
+pub fn track_course_existence(env: &Env, course: &Course, exists: bool) {
+    adjust_counter(env, &DataKey::TotalCourses, exists);
+    if is_counted_as_published(course) {
+        adjust_counter(env, &DataKey::PublishedCourseCount, exists);
+    }
+}
+
+/// Adjusts the published counter after a course's published or archived state changed.
+pub fn track_published_change(env: &Env, was_published: bool, course: &Course) {
+    let is_published: bool = is_counted_as_published(course);
+    if was_published != is_published {
+        adjust_counter(env, &DataKey::PublishedCourseCount, is_published);
+    }
+}
+
+/// Overwrites both counters, used when course storage is restored in bulk.
+pub fn set_course_counts(env: &Env, total: u32, published: u32) {
+    env.storage().persistent().set(&DataKey::TotalCourses, &total);
+    env.storage()
+        .persistent()
+        .set(&DataKey::PublishedCourseCount, &published);
+}
+
+fn adjust_counter(env: &Env, key: &DataKey, increment: bool) {
+    let count: u32 = env.storage().persistent().get(key).unwrap_or(0);
+    let count: u32 = if increment {
+        count.saturating_add(1)
+    } else {
+        count.saturating_sub(1)
+    };
+    env.storage().persistent().set(key, &count);
+}
+
+/// Returns the number of published, non-archived courses.
+pub fn course_registry_get_published_course_count(env: Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PublishedCourseCount)
+        .unwrap_or(0)
+}
+
+/// Returns the number of existing courses, including archived and unpublished ones.
+pub fn course_registry_get_total_courses(env: Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TotalCourses)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{ContentType, Course, EditCourseParams};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_course(client: &CourseRegistryClient, creator: &Address, title: &str) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    fn set_published(client: &CourseRegistryClient, course: &Course, published: bool) {
+        if published && client.get_course_modules(&course.id).is_empty() {
+            let title = String::from_str(course.title.env(), "Intro");
+            client.add_module(&course.creator, &course.id, &0, &title, &ContentType::Unspecified, &None);
+        }
+        let params = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: Some(published),
+            new_level: None,
+            new_duration_hours: None,
+        };
+        client.edit_course(&course.creator, &course.id, &params);
+    }
+
+    #[test]
+    fn test_course_counts_track_lifecycle() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        assert_eq!(client.get_total_courses(), 0);
+        assert_eq!(client.get_published_course_count(), 0);
+
+        let first = create_course(&client, &creator, "First");
+        let second = create_course(&client, &creator, "Second");
+        let third = create_course(&client, &creator, "Third");
+        assert_eq!(client.get_total_courses(), 3);
+        assert_eq!(client.get_published_course_count(), 0);
+
+        set_published(&client, &first, true);
+        set_published(&client, &second, true);
+        // Publishing an already published course does not count twice
+        set_published(&client, &second, true);
+        assert_eq!(client.get_published_course_count(), 2);
+
+        set_published(&client, &second, false);
+        assert_eq!(client.get_published_course_count(), 1);
+
+        // Archiving hides the course from the published count but keeps it in the total
+        client.archive_course(&creator, &first.id);
+        assert_eq!(client.get_total_courses(), 3);
+        assert_eq!(client.get_published_course_count(), 0);
+
+        set_published(&client, &third, true);
+        client.delete_course(&creator, &third.id);
+        assert_eq!(client.get_total_courses(), 2);
+        assert_eq!(client.get_published_course_count(), 0);
+    }
+}
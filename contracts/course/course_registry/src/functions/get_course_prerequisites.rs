@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::has_course_access;
+use crate::schema::DataKey;
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Returns the prerequisite course IDs of a course.
+///
+/// Panics with `Error::CourseNotFound` if the course does not exist.
+pub fn course_registry_get_course_prerequisites(env: Env, course_id: String) -> Vec<String> {
+    if !env
+        .storage()
+        .persistent()
+        .has(&(COURSE_KEY, course_id.clone()))
+    {
+        handle_error(&env, Error::CourseNotFound)
+    }
+
+    env.storage()
+        .persistent()
+        .get(&DataKey::CoursePrerequisites(course_id))
+        .unwrap_or(Vec::new(&env))
+}
+
+/// Returns whether `user` has access to every prerequisite of a course.
+///
+/// Access is checked against the configured course access contract. A course
+/// without prerequisites is always satisfied.
+pub fn course_registry_check_prerequisites_met(env: Env, user: Address, course_id: String) -> bool {
+    let prerequisites: Vec<String> =
+        course_registry_get_course_prerequisites(env.clone(), course_id);
+
+    prerequisites
+        .iter()
+        .all(|prerequisite| has_course_access(&env, &user, &prerequisite))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::schema::Course;
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    mod mock_course_access {
+        use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+        #[contract]
+        pub struct CourseAccess;
+
+        #[contractimpl]
+        impl CourseAccess {
+            pub fn grant(env: Env, course_id: String, user: Address) {
+                env.storage().instance().set(&(course_id, user), &true);
+            }
+
+            pub fn check_access(env: Env, course_id: String, user: Address) -> bool {
+                env.storage().instance().has(&(course_id, user))
+            }
+        }
+    }
+
+    fn create_course(client: &CourseRegistryClient, creator: &Address, title: &str) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    fn setup() -> (
+        Env,
+        CourseRegistryClient<'static>,
+        mock_course_access::CourseAccessClient<'static>,
+    ) {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_access_id = env.register(mock_course_access::CourseAccess, ());
+        env.as_contract(&client.address, || {
+            crate::functions::access_control::update_course_access_address(
+                &env,
+                &mocks.admin,
+                &course_access_id,
+            );
+        });
+        let access = mock_course_access::CourseAccessClient::new(&env, &course_access_id);
+        (env, client, access)
+    }
+
+    #[test]
+    fn test_no_prerequisites_always_met() {
+        let (env, client, _access) = setup();
+        let creator = Address::generate(&env);
+        let course = create_course(&client, &creator, "Intro");
+
+        assert_eq!(client.get_course_prerequisites(&course.id).len(), 0);
+        assert!(client.check_prerequisites_met(&Address::generate(&env), &course.id));
+    }
+
+    #[test]
+    fn test_prerequisites_met_and_partially_met() {
+        let (env, client, access) = setup();
+        let creator = Address::generate(&env);
+        let basics = create_course(&client, &creator, "Basics");
+        let tooling = create_course(&client, &creator, "Tooling");
+        let advanced = create_course(&client, &creator, "Advanced");
+        client.add_prerequisite(
+            &creator,
+            &advanced.id,
+            &vec![&env, basics.id.clone(), tooling.id.clone()],
+        );
+
+        assert_eq!(
+            client.get_course_prerequisites(&advanced.id),
+            vec![&env, basics.id.clone(), tooling.id.clone()]
+        );
+
+        let student = Address::generate(&env);
+        access.grant(&basics.id, &student);
+        assert!(!client.check_prerequisites_met(&student, &advanced.id));
+
+        access.grant(&tooling.id, &student);
+        assert!(client.check_prerequisites_met(&student, &advanced.id));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #117)")]
+    fn test_prerequisites_of_missing_course() {
+        let (env, client, _access) = setup();
+        client.get_course_prerequisites(&String::from_str(&env, "missing"));
+    }
+}
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Env, String, Vec};
+
+use crate::functions::get_course::get_course;
+use crate::functions::get_module::course_registry_get_course_modules;
+use crate::schema::{Course, CourseWithModules, DataKey};
+
+/// Returns a course together with its modules (sorted by position),
+/// prerequisites and tags, saving clients separate calls.
+///
+/// Panics if the course does not exist.
+pub fn course_registry_get_course_with_modules(env: Env, course_id: String) -> CourseWithModules {
+    let course: Course = get_course(&env, course_id.clone());
+    let modules = course_registry_get_course_modules(env.clone(), course_id.clone());
+    let prerequisites: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CoursePrerequisites(course_id))
+        .unwrap_or(Vec::new(&env));
+    let tags: Vec<String> = course.tags.clone();
+
+    CourseWithModules {
+        course,
+        modules,
+        prerequisites,
+        tags,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::ContentType;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn create_course(env: &Env, client: &CourseRegistryClient, title: &str) -> crate::schema::Course {
+        client.create_course(
+            &Address::generate(env),
+            &String::from_str(env, title),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_get_course_with_modules() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let basics = create_course(&env, &client, "Rust Basics");
+        let course = create_course(&env, &client, "Advanced Rust");
+        client.add_module(&course.creator, &course.id, &1, &String::from_str(&env, "Traits"), &ContentType::Unspecified, &None);
+        client.add_module(&course.creator, &course.id, &0, &String::from_str(&env, "Ownership"), &ContentType::Unspecified, &None);
+        client.add_prerequisite(&course.creator, &course.id, &vec![&env, basics.id.clone()]);
+        client.set_course_tags(&course.creator, &course.id, &vec![&env, String::from_str(&env, "rust")]);
+
+        let combined = client.get_course_with_modules(&course.id);
+
+        assert_eq!(combined.course, client.get_course(&course.id));
+        assert_eq!(combined.modules, client.get_course_modules(&course.id));
+        assert_eq!(combined.modules.get(0).unwrap().title, String::from_str(&env, "Ownership"));
+        assert_eq!(combined.prerequisites, client.get_course_prerequisites(&course.id));
+        assert_eq!(combined.prerequisites, vec![&env, basics.id]);
+        assert_eq!(combined.tags, vec![&env, String::from_str(&env, "rust")]);
+    }
+
+    #[test]
+    fn test_get_course_with_modules_empty_course() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let course = create_course(&env, &client, "Rust");
+
+        let combined = client.get_course_with_modules(&course.id);
+
+        assert_eq!(combined.course, course);
+        assert!(combined.modules.is_empty());
+        assert!(combined.prerequisites.is_empty());
+        assert!(combined.tags.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_get_course_with_modules_not_found() {
+        let env = Env::default();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        client.get_course_with_modules(&String::from_str(&env, "missing"));
+    }
+}
@@ -58,6 +58,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         )
     }
 
@@ -0,0 +1,204 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Env, String, Symbol, Vec};
+
+use crate::schema::{Course, DataKey};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Records that a course has just been mutated.
+///
+/// The entry for `course_id` is moved to the end of the
+/// `DataKey::CourseUpdateTimestamps` list with the current ledger timestamp,
+/// so the list stays ordered by update time.
+pub fn record_course_update(env: &Env, course_id: &String) {
+    let mut updates: Vec<(String, u64)> = remove_entry(env, course_id);
+    updates.push_back((course_id.clone(), env.ledger().timestamp()));
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseUpdateTimestamps, &updates);
+}
+
+/// Drops the update timestamp of a course that no longer exists.
+pub fn remove_course_update(env: &Env, course_id: &String) {
+    let updates: Vec<(String, u64)> = remove_entry(env, course_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseUpdateTimestamps, &updates);
+}
+
+fn remove_entry(env: &Env, course_id: &String) -> Vec<(String, u64)> {
+    let mut updates: Vec<(String, u64)> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CourseUpdateTimestamps)
+        .unwrap_or(Vec::new(env));
+
+    if let Some(index) = updates.iter().position(|(id, _)| id == *course_id) {
+        updates.remove(index as u32);
+    }
+
+    updates
+}
+
+/// Returns up to `limit` courses updated at or after `since`, oldest update first.
+pub fn course_registry_get_courses_updated_after(env: Env, since: u64, limit: u32) -> Vec<Course> {
+    let updates: Vec<(String, u64)> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CourseUpdateTimestamps)
+        .unwrap_or(Vec::new(&env));
+
+    let mut courses: Vec<Course> = Vec::new(&env);
+    for (course_id, updated_at) in updates.iter() {
+        if courses.len() >= limit {
+            break;
+        }
+        if updated_at < since {
+            continue;
+        }
+        if let Some(course) = env
+            .storage()
+            .persistent()
+            .get::<_, Course>(&(COURSE_KEY, course_id))
+        {
+            courses.push_back(course);
+        }
+    }
+
+    courses
+}
+
+/// Returns the timestamp of the last mutation of a course, or `0` if unknown.
+pub fn course_registry_get_last_update_timestamp(env: Env, course_id: String) -> u64 {
+    let updates: Vec<(String, u64)> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CourseUpdateTimestamps)
+        .unwrap_or(Vec::new(&env));
+
+    updates
+        .iter()
+        .find(|(id, _)| *id == course_id)
+        .map(|(_, updated_at)| updated_at)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{Course, DataKey, EditCourseParams};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, Env, String, Vec};
+
+    fn create_course(client: &CourseRegistryClient, creator: &Address, title: &str) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    fn empty_params() -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_level: None,
+            new_duration_hours: None,
+        }
+    }
+
+    fn tracked_updates(env: &Env, contract_id: &Address) -> Vec<(String, u64)> {
+        env.as_contract(contract_id, || {
+            env.storage()
+                .persistent()
+                .get(&DataKey::CourseUpdateTimestamps)
+                .unwrap_or(Vec::new(env))
+        })
+    }
+
+    #[test]
+    fn test_timestamps_updated_on_each_mutation() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        env.ledger().set_timestamp(100);
+        let first = create_course(&client, &creator, "First");
+        env.ledger().set_timestamp(200);
+        let second = create_course(&client, &creator, "Second");
+
+        let updates = tracked_updates(&env, &contract_id);
+        assert_eq!(updates.len(), 2);
+        assert_eq!(updates.get(0).unwrap(), (first.id.clone(), 100));
+        assert_eq!(updates.get(1).unwrap(), (second.id.clone(), 200));
+
+        env.ledger().set_timestamp(300);
+        let mut params = empty_params();
+        params.new_price = Some(2000);
+        client.edit_course(&creator, &first.id, &params);
+
+        let updates = tracked_updates(&env, &contract_id);
+        assert_eq!(updates.len(), 2);
+        assert_eq!(updates.get(1).unwrap(), (first.id.clone(), 300));
+        assert_eq!(client.get_last_update_timestamp(&first.id), 300);
+
+        client.delete_course(&creator, &second.id);
+        let updates = tracked_updates(&env, &contract_id);
+        assert_eq!(updates.len(), 1);
+        assert_eq!(client.get_last_update_timestamp(&second.id), 0);
+    }
+
+    #[test]
+    fn test_get_courses_updated_after_filters_and_limits() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        env.ledger().set_timestamp(100);
+        create_course(&client, &creator, "Old");
+        env.ledger().set_timestamp(200);
+        let middle = create_course(&client, &creator, "Middle");
+        env.ledger().set_timestamp(300);
+        let newest = create_course(&client, &creator, "Newest");
+
+        let courses = client.get_courses_updated_after(&200, &10);
+        assert_eq!(courses.len(), 2);
+        assert_eq!(courses.get(0).unwrap().id, middle.id);
+        assert_eq!(courses.get(1).unwrap().id, newest.id);
+
+        let limited = client.get_courses_updated_after(&0, &1);
+        assert_eq!(limited.len(), 1);
+
+        assert_eq!(client.get_courses_updated_after(&301, &10).len(), 0);
+    }
+
+    #[test]
+    fn test_last_update_timestamp_unknown_course() {
+        let env = Env::default();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        assert_eq!(
+            client.get_last_update_timestamp(&String::from_str(&env, "missing")),
+            0
+        );
+    }
+}
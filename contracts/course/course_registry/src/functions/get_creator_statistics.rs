@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::functions::access_control::get_course_enrollment_count;
+use crate::functions::add_course_review::get_rating_totals;
+use crate::functions::get_course_count::is_counted_as_published;
+use crate::functions::list_courses_by_creator::get_creator_course_ids;
+use crate::schema::{Course, CreatorStats, DataKey, RATING_SCALE};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Seconds a computed result is served from the cache
+pub const CREATOR_STATS_CACHE_SECONDS: u64 = 3600;
+/// Ledgers the cache entry lives for, about one hour at five seconds per ledger
+const CREATOR_STATS_CACHE_TTL: u32 = 720;
+
+fn compute_creator_statistics(env: &Env, creator: &Address) -> CreatorStats {
+    let mut stats: CreatorStats = CreatorStats {
+        total_courses: 0,
+        published_courses: 0,
+        total_enrollments: 0,
+        average_rating: 0,
+    };
+    let mut rating_sum: u64 = 0;
+    let mut rating_count: u32 = 0;
+
+    for course_id in get_creator_course_ids(env, creator).iter() {
+        let Some(course) = env
+            .storage()
+            .persistent()
+            .get::<_, Course>(&(COURSE_KEY, course_id.clone()))
+        else {
+            continue;
+        };
+
+        stats.total_courses += 1;
+        if is_counted_as_published(&course) {
+            stats.published_courses += 1;
+        }
+        stats.total_enrollments += get_course_enrollment_count(env, &course_id);
+
+        let (sum, count): (u64, u32) = get_rating_totals(env, &course_id);
+        rating_sum += sum;
+        rating_count += count;
+    }
+
+    if rating_count > 0 {
+        stats.average_rating = (rating_sum * RATING_SCALE as u64 / rating_count as u64) as u32;
+    }
+    stats
+}
+
+/// Returns course, enrollment and rating totals over every course of a
+/// creator.
+///
+/// Enrollment counts are read from the course access contract. Results are
+/// cached in temporary storage for `CREATOR_STATS_CACHE_SECONDS`, so recent
+/// changes may take up to an hour to show.
+pub fn course_registry_get_creator_statistics(env: Env, creator: Address) -> CreatorStats {
+    let key: DataKey = DataKey::CreatorStats(creator.clone());
+    let now: u64 = env.ledger().timestamp();
+
+    if let Some((stats, computed_at)) = env
+        .storage()
+        .temporary()
+        .get::<_, (CreatorStats, u64)>(&key)
+    {
+        if now < computed_at.saturating_add(CREATOR_STATS_CACHE_SECONDS) {
+            return stats;
+        }
+    }
+
+    let stats: CreatorStats = compute_creator_statistics(&env, &creator);
+    env.storage().temporary().set(&key, &(stats.clone(), now));
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, 0, CREATOR_STATS_CACHE_TTL);
+    stats
+}
+
+#[cfg(test)]
+mod test {
+    use super::CREATOR_STATS_CACHE_SECONDS;
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::schema::{ContentType, Course, CreatorStats, EditCourseParams};
+    use crate::CourseRegistryClient;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, Env, String};
+
+    mod mock_course_access {
+        use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+        #[contract]
+        pub struct CourseAccess;
+
+        #[contractimpl]
+        impl CourseAccess {
+            pub fn set_enrollment_count(env: Env, course_id: String, count: u32) {
+                env.storage().instance().set(&course_id, &count);
+            }
+
+            pub fn get_course_enrollment_count(env: Env, course_id: String) -> u32 {
+                env.storage().instance().get(&course_id).unwrap_or(0)
+            }
+
+            pub fn check_access(_env: Env, _course_id: String, _user: Address) -> bool {
+                true
+            }
+        }
+    }
+
+    fn setup() -> (
+        Env,
+        CourseRegistryClient<'static>,
+        mock_course_access::CourseAccessClient<'static>,
+    ) {
+        let (env, client, mocks) = setup_with_mocks();
+        env.ledger().set_timestamp(1_000);
+        let course_access_id = env.register(mock_course_access::CourseAccess, ());
+        env.as_contract(&client.address, || {
+            crate::functions::access_control::update_course_access_address(
+                &env,
+                &mocks.admin,
+                &course_access_id,
+            );
+        });
+        let access = mock_course_access::CourseAccessClient::new(&env, &course_access_id);
+        (env, client, access)
+    }
+
+    fn create_course(client: &CourseRegistryClient, creator: &Address, title: &str) -> Course {
+        let env = &client.env;
+        client.create_course(
+            creator,
+            &String::from_str(env, title),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    fn publish(client: &CourseRegistryClient, course: &Course) {
+        let env = &client.env;
+        client.add_module(
+            &course.creator,
+            &course.id,
+            &0,
+            &String::from_str(env, "Intro"),
+            &ContentType::Unspecified,
+            &None,
+        );
+        let params = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: Some(true),
+            new_level: None,
+            new_duration_hours: None,
+        };
+        client.edit_course(&course.creator, &course.id, &params);
+    }
+
+    #[test]
+    fn test_creator_statistics() {
+        let (env, client, access) = setup();
+        let creator = Address::generate(&env);
+        let rust = create_course(&client, &creator, "Rust");
+        let go = create_course(&client, &creator, "Go");
+        create_course(&client, &creator, "Solidity");
+        create_course(&client, &Address::generate(&env), "Other creator");
+
+        publish(&client, &rust);
+        publish(&client, &go);
+        access.set_enrollment_count(&rust.id, &3);
+        access.set_enrollment_count(&go.id, &4);
+        client.add_course_review(&Address::generate(&env), &rust.id, &5, &None);
+        client.add_course_review(&Address::generate(&env), &rust.id, &4, &None);
+        client.add_course_review(&Address::generate(&env), &go.id, &2, &None);
+
+        assert_eq!(
+            client.get_creator_statistics(&creator),
+            CreatorStats {
+                total_courses: 3,
+                published_courses: 2,
+                total_enrollments: 7,
+                average_rating: 366,
+            }
+        );
+    }
+
+    #[test]
+    fn test_creator_statistics_without_courses() {
+        let (env, client, _access) = setup();
+        assert_eq!(
+            client.get_creator_statistics(&Address::generate(&env)),
+            CreatorStats {
+                total_courses: 0,
+                published_courses: 0,
+                total_enrollments: 0,
+                average_rating: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_creator_statistics_cached_for_an_hour() {
+        let (env, client, access) = setup();
+        let creator = Address::generate(&env);
+        let course = create_course(&client, &creator, "Rust");
+        access.set_enrollment_count(&course.id, &2);
+        assert_eq!(client.get_creator_statistics(&creator).total_enrollments, 2);
+
+        access.set_enrollment_count(&course.id, &5);
+        create_course(&client, &creator, "Go");
+        let cached = client.get_creator_statistics(&creator);
+        assert_eq!((cached.total_courses, cached.total_enrollments), (1, 2));
+
+        env.ledger()
+            .set_timestamp(1_000 + CREATOR_STATS_CACHE_SECONDS);
+        let fresh = client.get_creator_statistics(&creator);
+        assert_eq!((fresh.total_courses, fresh.total_enrollments), (2, 5));
+    }
+}
@@ -0,0 +1,286 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::is_admin;
+use crate::schema::DataKey;
+
+const DEPENDENT_DELETION_EVENT: Symbol = symbol_short!("depDelCfg");
+
+/// Returns the IDs of the courses that list `course_id` as a prerequisite.
+pub fn course_registry_get_courses_requiring_course(env: Env, course_id: String) -> Vec<String> {
+    get_dependent_course_ids(&env, &course_id)
+}
+
+pub fn get_dependent_course_ids(env: &Env, course_id: &String) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PrerequisiteOf(course_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores the prerequisites of a course and keeps the reverse
+/// `DataKey::PrerequisiteOf` index in step with them.
+pub fn save_course_prerequisites(env: &Env, course_id: &String, prerequisites: &Vec<String>) {
+    let previous: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CoursePrerequisites(course_id.clone()))
+        .unwrap_or(Vec::new(env));
+
+    for old_id in previous.iter() {
+        if !prerequisites.contains(&old_id) {
+            remove_dependent(env, &old_id, course_id);
+        }
+    }
+    for new_id in prerequisites.iter() {
+        if !previous.contains(&new_id) {
+            add_dependent(env, &new_id, course_id);
+        }
+    }
+
+    env.storage().persistent().set(
+        &DataKey::CoursePrerequisites(course_id.clone()),
+        prerequisites,
+    );
+}
+
+/// Drops a deleted course from both sides of the prerequisite index.
+pub fn clear_course_dependencies(env: &Env, course_id: &String) {
+    let prerequisites: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CoursePrerequisites(course_id.clone()))
+        .unwrap_or(Vec::new(env));
+    for prerequisite_id in prerequisites.iter() {
+        remove_dependent(env, &prerequisite_id, course_id);
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CoursePrerequisites(course_id.clone()));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PrerequisiteOf(course_id.clone()));
+}
+
+/// Returns whether deleting a required course only emits a warning (defaults to false).
+pub fn is_dependent_deletion_warn_only(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::WarnOnDependentDeletion)
+        .unwrap_or(false)
+}
+
+/// Chooses whether deleting a required course fails or only warns (admin-only).
+pub fn course_registry_set_warn_on_dependent_deletion(env: Env, caller: Address, warn_only: bool) {
+    caller.require_auth();
+    if !is_admin(&env, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::WarnOnDependentDeletion, &warn_only);
+    env.events()
+        .publish((DEPENDENT_DELETION_EVENT,), (caller, warn_only));
+}
+
+fn add_dependent(env: &Env, prerequisite_id: &String, course_id: &String) {
+    let key = DataKey::PrerequisiteOf(prerequisite_id.clone());
+    let mut dependents: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    if !dependents.contains(course_id) {
+        dependents.push_back(course_id.clone());
+        env.storage().persistent().set(&key, &dependents);
+    }
+}
+
+fn remove_dependent(env: &Env, prerequisite_id: &String, course_id: &String) {
+    let key = DataKey::PrerequisiteOf(prerequisite_id.clone());
+    let mut dependents: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    if let Some(index) = dependents.first_index_of(course_id) {
+        dependents.remove(index);
+        if dependents.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &dependents);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::schema::Course;
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Address) {
+        let (env, client, mocks) = setup_with_mocks();
+        (env, client, mocks.admin)
+    }
+
+    /// Creates `count` courses, each by its own creator to stay under the rate limit.
+    fn create_courses(env: &Env, client: &CourseRegistryClient, count: u32) -> Vec<Course> {
+        let titles = ["C0", "C1", "C2", "C3"];
+        let mut courses: Vec<Course> = Vec::new(env);
+        for title in titles.iter().take(count as usize) {
+            courses.push_back(client.create_course(
+                &Address::generate(env),
+                &String::from_str(env, title),
+                &String::from_str(env, "description"),
+                &crate::schema::DEFAULT_COURSE_PRICE,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            ));
+        }
+        courses
+    }
+
+    #[test]
+    fn test_single_dependent() {
+        let (env, client, _admin) = setup();
+        let courses = create_courses(&env, &client, 2);
+        let base = courses.get(0).unwrap();
+        let advanced = courses.get(1).unwrap();
+
+        client.edit_prerequisite(
+            &advanced.creator,
+            &advanced.id,
+            &vec![&env, base.id.clone()],
+        );
+
+        assert_eq!(
+            client.get_courses_requiring_course(&base.id),
+            vec![&env, advanced.id.clone()]
+        );
+        assert!(client.get_courses_requiring_course(&advanced.id).is_empty());
+    }
+
+    #[test]
+    fn test_no_dependents() {
+        let (env, client, _admin) = setup();
+        let courses = create_courses(&env, &client, 2);
+        let base = courses.get(0).unwrap();
+        let advanced = courses.get(1).unwrap();
+
+        client.edit_prerequisite(
+            &advanced.creator,
+            &advanced.id,
+            &vec![&env, base.id.clone()],
+        );
+        client.remove_prerequisite(&advanced.creator, &advanced.id, &base.id);
+
+        assert!(client.get_courses_requiring_course(&base.id).is_empty());
+        assert!(client
+            .get_courses_requiring_course(&String::from_str(&env, "missing"))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_multiple_dependents() {
+        let (env, client, _admin) = setup();
+        let courses = create_courses(&env, &client, 4);
+        let base = courses.get(0).unwrap();
+        let first = courses.get(1).unwrap();
+        let second = courses.get(2).unwrap();
+        let other = courses.get(3).unwrap();
+
+        client.add_prerequisite(&first.creator, &first.id, &vec![&env, base.id.clone()]);
+        client.edit_prerequisite(
+            &second.creator,
+            &second.id,
+            &vec![&env, base.id.clone(), other.id.clone()],
+        );
+
+        assert_eq!(
+            client.get_courses_requiring_course(&base.id),
+            vec![&env, first.id.clone(), second.id.clone()]
+        );
+
+        // Replacing the prerequisites moves the reverse entry as well
+        client.edit_prerequisite(&second.creator, &second.id, &vec![&env, other.id.clone()]);
+        assert_eq!(
+            client.get_courses_requiring_course(&base.id),
+            vec![&env, first.id.clone()]
+        );
+        assert_eq!(
+            client.get_courses_requiring_course(&other.id),
+            vec![&env, second.id.clone()]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #173)")]
+    fn test_delete_required_course_blocked() {
+        let (env, client, _admin) = setup();
+        let courses = create_courses(&env, &client, 2);
+        let base = courses.get(0).unwrap();
+        let advanced = courses.get(1).unwrap();
+
+        client.edit_prerequisite(
+            &advanced.creator,
+            &advanced.id,
+            &vec![&env, base.id.clone()],
+        );
+        client.delete_course(&base.creator, &base.id);
+    }
+
+    #[test]
+    fn test_delete_required_course_warns() {
+        let (env, client, admin) = setup();
+        let courses = create_courses(&env, &client, 2);
+        let base = courses.get(0).unwrap();
+        let advanced = courses.get(1).unwrap();
+
+        client.edit_prerequisite(
+            &advanced.creator,
+            &advanced.id,
+            &vec![&env, base.id.clone()],
+        );
+        client.set_warn_on_dependent_deletion(&admin, &true);
+        client.delete_course(&base.creator, &base.id);
+
+        assert!(client.try_get_course(&base.id).is_err());
+        assert!(client.get_courses_requiring_course(&base.id).is_empty());
+    }
+
+    #[test]
+    fn test_delete_dependent_course_updates_index() {
+        let (env, client, _admin) = setup();
+        let courses = create_courses(&env, &client, 2);
+        let base = courses.get(0).unwrap();
+        let advanced = courses.get(1).unwrap();
+
+        client.edit_prerequisite(
+            &advanced.creator,
+            &advanced.id,
+            &vec![&env, base.id.clone()],
+        );
+        client.delete_course(&advanced.creator, &advanced.id);
+
+        assert!(client.get_courses_requiring_course(&base.id).is_empty());
+        client.delete_course(&base.creator, &base.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_set_warn_on_dependent_deletion_non_admin() {
+        let (env, client, _admin) = setup();
+        client.set_warn_on_dependent_deletion(&Address::generate(&env), &true);
+    }
+}
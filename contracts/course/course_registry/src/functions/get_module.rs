@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::module_utils::get_course_module_ids;
+use crate::schema::CourseModule;
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+/// Returns a single module by ID.
+///
+/// Panics with `Error::ModuleNotFound` if the module does not exist.
+pub fn course_registry_get_module(env: Env, module_id: String) -> CourseModule {
+    env.storage()
+        .persistent()
+        .get(&(MODULE_KEY, module_id))
+        .unwrap_or_else(|| handle_error(&env, Error::ModuleNotFound))
+}
+
+/// Returns every module of a course, sorted by position.
+///
+/// Panics with `Error::CourseNotFound` if the course does not exist.
+pub fn course_registry_get_course_modules(env: Env, course_id: String) -> Vec<CourseModule> {
+    if !env
+        .storage()
+        .persistent()
+        .has(&(COURSE_KEY, course_id.clone()))
+    {
+        handle_error(&env, Error::CourseNotFound)
+    }
+
+    let mut modules: Vec<CourseModule> = Vec::new(&env);
+    for module_id in get_course_module_ids(&env, &course_id).iter() {
+        let Some(module) = env
+            .storage()
+            .persistent()
+            .get::<_, CourseModule>(&(MODULE_KEY, module_id))
+        else {
+            continue;
+        };
+
+        // Insertion sort: module counts per course are small
+        let mut index: u32 = modules.len();
+        while index > 0 && modules.get(index - 1).unwrap().position > module.position {
+            index -= 1;
+        }
+        modules.insert(index, module);
+    }
+
+    modules
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::schema::{ContentType, Course};
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Address, Course) {
+        let (env, client, _mocks) = setup_with_mocks();
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (env, client, creator, course)
+    }
+
+    #[test]
+    fn test_get_module() {
+        let (env, client, creator, course) = setup();
+        let module = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Intro"), &ContentType::Unspecified, &None);
+
+        assert_eq!(client.get_module(&module.id), module);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #121)")]
+    fn test_get_module_not_found() {
+        let (env, client, _creator, _course) = setup();
+        client.get_module(&String::from_str(&env, "missing"));
+    }
+
+    #[test]
+    fn test_get_course_modules_sorted_by_position() {
+        let (env, client, creator, course) = setup();
+        let third = client.add_module(&creator, &course.id, &3, &String::from_str(&env, "Third"), &ContentType::Unspecified, &None);
+        let first = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "First"), &ContentType::Unspecified, &None);
+        let second = client.add_module(&creator, &course.id, &2, &String::from_str(&env, "Second"), &ContentType::Unspecified, &None);
+
+        let modules = client.get_course_modules(&course.id);
+        assert_eq!(modules.len(), 3);
+        assert_eq!(modules.get(0).unwrap().id, first.id);
+        assert_eq!(modules.get(1).unwrap().id, second.id);
+        assert_eq!(modules.get(2).unwrap().id, third.id);
+    }
+
+    #[test]
+    fn test_get_course_modules_empty() {
+        let (_env, client, _creator, course) = setup();
+        assert_eq!(client.get_course_modules(&course.id).len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #117)")]
+    fn test_get_course_modules_course_not_found() {
+        let (env, client, _creator, _course) = setup();
+        client.get_course_modules(&String::from_str(&env, "missing"));
+    }
+}
@@ -0,0 +1,187 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::schema::{ContentType, CourseModule, DataKey};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+/// Returns the modules of a course that deliver the given type of content.
+pub fn course_registry_get_modules_by_type(
+    env: Env,
+    course_id: String,
+    content_type: ContentType,
+) -> Vec<CourseModule> {
+    if !env
+        .storage()
+        .persistent()
+        .has(&(COURSE_KEY, course_id.clone()))
+    {
+        handle_error(&env, Error::CourseNotFound)
+    }
+
+    let module_ids: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CourseModulesByType(
+            course_id,
+            content_type.clone(),
+        ))
+        .unwrap_or(Vec::new(&env));
+
+    let mut modules: Vec<CourseModule> = Vec::new(&env);
+    for module_id in module_ids.iter() {
+        if let Some(module) = env
+            .storage()
+            .persistent()
+            .get::<_, CourseModule>(&(MODULE_KEY, module_id))
+        {
+            // Guard against stale index entries
+            if module.content_type == content_type {
+                modules.push_back(module);
+            }
+        }
+    }
+
+    modules
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::schema::{ContentType, Course};
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Address, Course) {
+        let (env, client, _mocks) = setup_with_mocks();
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (env, client, creator, course)
+    }
+
+    #[test]
+    fn test_get_modules_by_type_mixed_content() {
+        let (env, client, creator, course) = setup();
+
+        let video = client.add_module(
+            &creator,
+            &course.id,
+            &1,
+            &String::from_str(&env, "Intro video"),
+            &ContentType::Unspecified,
+            &None,
+        );
+        let quiz = client.add_module(&creator, &course.id, &2, &String::from_str(&env, "Quiz"), &ContentType::Unspecified, &None);
+        let reading =
+            client.add_module(&creator, &course.id, &3, &String::from_str(&env, "Reading"), &ContentType::Unspecified, &None);
+        let video2 = client.add_module(
+            &creator,
+            &course.id,
+            &4,
+            &String::from_str(&env, "Demo video"),
+            &ContentType::Unspecified,
+            &None,
+        );
+
+        client.update_module_content(&creator, &video.id, &ContentType::Video);
+        client.update_module_content(&creator, &quiz.id, &ContentType::Quiz);
+        client.update_module_content(&creator, &reading.id, &ContentType::Article);
+        client.update_module_content(&creator, &video2.id, &ContentType::Video);
+
+        let videos = client.get_modules_by_type(&course.id, &ContentType::Video);
+        assert_eq!(videos.len(), 2);
+        assert_eq!(videos.get(0).unwrap().id, video.id);
+        assert_eq!(videos.get(1).unwrap().id, video2.id);
+
+        let quizzes = client.get_modules_by_type(&course.id, &ContentType::Quiz);
+        assert_eq!(quizzes.len(), 1);
+        assert_eq!(quizzes.get(0).unwrap().id, quiz.id);
+
+        let articles = client.get_modules_by_type(&course.id, &ContentType::Article);
+        assert_eq!(articles.len(), 1);
+        assert_eq!(articles.get(0).unwrap().id, reading.id);
+
+        assert_eq!(
+            client
+                .get_modules_by_type(&course.id, &ContentType::Assignment)
+                .len(),
+            0
+        );
+        assert_eq!(
+            client
+                .get_modules_by_type(&course.id, &ContentType::Unspecified)
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_get_modules_by_type_after_type_change_and_removal() {
+        let (env, client, creator, course) = setup();
+
+        let module = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module"), &ContentType::Unspecified, &None);
+        assert_eq!(
+            client
+                .get_modules_by_type(&course.id, &ContentType::Unspecified)
+                .len(),
+            1
+        );
+
+        client.update_module_content(&creator, &module.id, &ContentType::Video);
+        client.update_module_content(&creator, &module.id, &ContentType::Assignment);
+
+        assert_eq!(
+            client
+                .get_modules_by_type(&course.id, &ContentType::Video)
+                .len(),
+            0
+        );
+        assert_eq!(
+            client
+                .get_modules_by_type(&course.id, &ContentType::Assignment)
+                .len(),
+            1
+        );
+
+        client.remove_module(&module.id);
+        assert_eq!(
+            client
+                .get_modules_by_type(&course.id, &ContentType::Assignment)
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_update_module_content_unauthorized() {
+        let (env, client, creator, course) = setup();
+
+        let module = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module"), &ContentType::Unspecified, &None);
+        let stranger = Address::generate(&env);
+        client.update_module_content(&stranger, &module.id, &ContentType::Quiz);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #117)")]
+    fn test_get_modules_by_type_unknown_course() {
+        let (env, client, _creator, _course) = setup();
+        client.get_modules_by_type(&String::from_str(&env, "missing"), &ContentType::Video);
+    }
+}
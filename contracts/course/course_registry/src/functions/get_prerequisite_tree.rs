@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Env, Map, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::edit_prerequisite::get_max_prerequisite_depth;
+use crate::schema::{Course, DataKey, PrerequisiteTree};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Returns the prerequisite hierarchy of a course as a tree.
+///
+/// The graph is walked breadth-first and every visited course is recorded
+/// with the index of its parent. The tree is then assembled bottom-up, so no
+/// recursion is needed. Courses deeper than the maximum prerequisite depth
+/// (counting the root course) are left out, and prerequisites that no longer
+/// exist are skipped.
+///
+/// Panics with `Error::CourseNotFound` if the root course does not exist.
+pub fn course_registry_get_prerequisite_tree(env: Env, course_id: String) -> PrerequisiteTree {
+    let root: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id.clone()))
+        .unwrap_or_else(|| handle_error(&env, Error::CourseNotFound));
+
+    let max_depth: u32 = get_max_prerequisite_depth(&env);
+
+    // (course_id, title, depth, parent index) in BFS order
+    let mut nodes: Vec<(String, String, u32, u32)> = Vec::new(&env);
+    nodes.push_back((root.id, root.title, 0, 0));
+
+    let mut index: u32 = 0;
+    while index < nodes.len() {
+        let (current_id, _, depth, _) = nodes.get(index).unwrap();
+        if depth + 1 < max_depth {
+            let prerequisites: Vec<String> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::CoursePrerequisites(current_id))
+                .unwrap_or(Vec::new(&env));
+            for prerequisite_id in prerequisites.iter() {
+                if let Some(course) = env
+                    .storage()
+                    .persistent()
+                    .get::<_, Course>(&(COURSE_KEY, prerequisite_id))
+                {
+                    nodes.push_back((course.id, course.title, depth + 1, index));
+                }
+            }
+        }
+        index += 1;
+    }
+
+    // Children always follow their parent in BFS order, so walking backwards
+    // completes every subtree before its parent is built.
+    let mut children: Map<u32, Vec<PrerequisiteTree>> = Map::new(&env);
+    let mut position: u32 = nodes.len();
+    while position > 0 {
+        position -= 1;
+        let (id, title, _, parent) = nodes.get(position).unwrap();
+        let tree: PrerequisiteTree = PrerequisiteTree {
+            course_id: id,
+            title,
+            prerequisites: children.get(position).unwrap_or(Vec::new(&env)),
+        };
+        if position == 0 {
+            return tree;
+        }
+
+        let mut siblings: Vec<PrerequisiteTree> = children.get(parent).unwrap_or(Vec::new(&env));
+        siblings.push_front(tree);
+        children.set(parent, siblings);
+    }
+
+    unreachable!()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{Course, DataKey};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn create_course(client: &CourseRegistryClient, title: &str) -> Course {
+        client.create_course(
+            &Address::generate(&client.env),
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    fn setup() -> (Env, Address, CourseRegistryClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        (env, contract_id, client)
+    }
+
+    #[test]
+    fn test_prerequisite_tree_flat() {
+        let (env, _, client) = setup();
+        let course = create_course(&client, "Course");
+        let first = create_course(&client, "First");
+        let second = create_course(&client, "Second");
+        client.add_prerequisite(
+            &course.creator,
+            &course.id,
+            &vec![&env, first.id.clone(), second.id.clone()],
+        );
+
+        let tree = client.get_prerequisite_tree(&course.id);
+        assert_eq!(tree.course_id, course.id);
+        assert_eq!(tree.title, course.title);
+        assert_eq!(tree.prerequisites.len(), 2);
+        assert_eq!(tree.prerequisites.get(0).unwrap().course_id, first.id);
+        assert_eq!(tree.prerequisites.get(1).unwrap().course_id, second.id);
+        assert!(tree.prerequisites.get(0).unwrap().prerequisites.is_empty());
+    }
+
+    #[test]
+    fn test_prerequisite_tree_chain_and_branches() {
+        let (env, _, client) = setup();
+        let advanced = create_course(&client, "Advanced");
+        let intermediate = create_course(&client, "Intermediate");
+        let basics = create_course(&client, "Basics");
+        let math = create_course(&client, "Math");
+        client.add_prerequisite(
+            &intermediate.creator,
+            &intermediate.id,
+            &vec![&env, basics.id.clone(), math.id.clone()],
+        );
+        client.add_prerequisite(
+            &advanced.creator,
+            &advanced.id,
+            &vec![&env, intermediate.id.clone(), math.id.clone()],
+        );
+
+        let tree = client.get_prerequisite_tree(&advanced.id);
+        assert_eq!(tree.prerequisites.len(), 2);
+
+        let middle = tree.prerequisites.get(0).unwrap();
+        assert_eq!(middle.course_id, intermediate.id);
+        assert_eq!(middle.prerequisites.len(), 2);
+        assert_eq!(middle.prerequisites.get(0).unwrap().course_id, basics.id);
+        assert_eq!(middle.prerequisites.get(1).unwrap().course_id, math.id);
+
+        let branch = tree.prerequisites.get(1).unwrap();
+        assert_eq!(branch.course_id, math.id);
+        assert!(branch.prerequisites.is_empty());
+    }
+
+    #[test]
+    fn test_prerequisite_tree_without_prerequisites() {
+        let (_, _, client) = setup();
+        let course = create_course(&client, "Standalone");
+
+        let tree = client.get_prerequisite_tree(&course.id);
+        assert_eq!(tree.course_id, course.id);
+        assert!(tree.prerequisites.is_empty());
+    }
+
+    #[test]
+    fn test_prerequisite_tree_truncated_at_max_depth() {
+        let (env, contract_id, client) = setup();
+        let top = create_course(&client, "Top");
+        let middle = create_course(&client, "Middle");
+        let bottom = create_course(&client, "Bottom");
+        client.add_prerequisite(&middle.creator, &middle.id, &vec![&env, bottom.id.clone()]);
+        client.add_prerequisite(&top.creator, &top.id, &vec![&env, middle.id.clone()]);
+
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&DataKey::MaxPrerequisiteDepth, &2_u32);
+        });
+
+        let tree = client.get_prerequisite_tree(&top.id);
+        assert_eq!(tree.prerequisites.len(), 1);
+        assert_eq!(tree.prerequisites.get(0).unwrap().course_id, middle.id);
+        assert!(tree.prerequisites.get(0).unwrap().prerequisites.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #117)")]
+    fn test_prerequisite_tree_course_not_found() {
+        let (env, _, client) = setup();
+        client.get_prerequisite_tree(&String::from_str(&env, "missing"));
+    }
+}
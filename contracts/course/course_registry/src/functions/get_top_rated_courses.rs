@@ -0,0 +1,253 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Env, Symbol, Vec};
+
+use crate::functions::add_course_review::get_rating_totals;
+use crate::functions::list_courses::get_all_course_ids;
+use crate::schema::{Course, CourseRatingEntry, CourseVisibility, RATING_SCALE};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const TOP_RATED_KEY: Symbol = symbol_short!("topRated");
+
+/// Maximum number of courses returned by `get_top_rated_courses`
+pub const MAX_TOP_RATED_COURSES: u32 = 50;
+/// Seconds a ranking is served from the cache
+pub const TOP_RATED_CACHE_SECONDS: u64 = 1800;
+/// Ledgers the cache entry lives for, about 30 minutes at five seconds per ledger
+const TOP_RATED_CACHE_TTL: u32 = 360;
+
+fn ranks_before(a: &CourseRatingEntry, b: &CourseRatingEntry) -> bool {
+    (a.average_rating, a.review_count) > (b.average_rating, b.review_count)
+}
+
+fn compute_top_rated_courses(env: &Env, min_reviews: u32, limit: u32) -> Vec<CourseRatingEntry> {
+    let mut ranking: Vec<CourseRatingEntry> = Vec::new(env);
+
+    for course_id in get_all_course_ids(env).iter() {
+        let (sum, review_count): (u64, u32) = get_rating_totals(env, &course_id);
+        if review_count < min_reviews {
+            continue;
+        }
+        let Some(course) = env
+            .storage()
+            .persistent()
+            .get::<_, Course>(&(COURSE_KEY, course_id))
+        else {
+            continue;
+        };
+        if course.visibility != CourseVisibility::Public {
+            continue;
+        }
+
+        let average_rating: u32 = if review_count > 0 {
+            (sum * RATING_SCALE as u64 / review_count as u64) as u32
+        } else {
+            0
+        };
+        let entry: CourseRatingEntry = CourseRatingEntry {
+            course,
+            average_rating,
+            review_count,
+        };
+
+        // Insert after every entry that does not rank below, so ties keep
+        // creation order
+        let mut index: u32 = ranking.len();
+        while index > 0 && ranks_before(&entry, &ranking.get(index - 1).unwrap()) {
+            index -= 1;
+        }
+        if index < limit {
+            ranking.insert(index, entry);
+            if ranking.len() > limit {
+                ranking.pop_back();
+            }
+        }
+    }
+    ranking
+}
+
+/// Returns the best rated public courses with at least `min_reviews`
+/// reviews, by average rating and then review count, both descending.
+///
+/// `limit` is capped at `MAX_TOP_RATED_COURSES`. Results are cached in
+/// temporary storage per `(min_reviews, limit)` for
+/// `TOP_RATED_CACHE_SECONDS`, so new reviews may take up to 30 minutes to
+/// show.
+pub fn course_registry_get_top_rated_courses(
+    env: Env,
+    min_reviews: u32,
+    limit: u32,
+) -> Vec<CourseRatingEntry> {
+    let limit: u32 = limit.min(MAX_TOP_RATED_COURSES);
+    let key: (Symbol, u32, u32) = (TOP_RATED_KEY, min_reviews, limit);
+    let now: u64 = env.ledger().timestamp();
+
+    if let Some((ranking, computed_at)) = env
+        .storage()
+        .temporary()
+        .get::<_, (Vec<CourseRatingEntry>, u64)>(&key)
+    {
+        if now < computed_at.saturating_add(TOP_RATED_CACHE_SECONDS) {
+            return ranking;
+        }
+    }
+
+    let ranking: Vec<CourseRatingEntry> = compute_top_rated_courses(&env, min_reviews, limit);
+    env.storage().temporary().set(&key, &(ranking.clone(), now));
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, 0, TOP_RATED_CACHE_TTL);
+    ranking
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::{MAX_TOP_RATED_COURSES, TOP_RATED_CACHE_SECONDS};
+    use crate::schema::Course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, Env, String, Vec};
+
+    mod mock_course_access {
+        use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+        #[contract]
+        pub struct CourseAccess;
+
+        #[contractimpl]
+        impl CourseAccess {
+            pub fn check_access(_env: Env, _course_id: String, _user: Address) -> bool {
+                true
+            }
+        }
+    }
+
+    fn setup() -> (Env, CourseRegistryClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_000);
+        let course_access_id = env.register(mock_course_access::CourseAccess, ());
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let owner = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &owner, &Address::generate(&env));
+            crate::functions::access_control::update_course_access_address(
+                &env,
+                &owner,
+                &course_access_id,
+            );
+        });
+        (env, client)
+    }
+
+    fn create_course(client: &CourseRegistryClient, title: &str, ratings: &[u32]) -> Course {
+        let env = &client.env;
+        let course = client.create_course(
+            &Address::generate(env),
+            &String::from_str(env, title),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        for rating in ratings {
+            client.add_course_review(&Address::generate(env), &course.id, rating, &None);
+        }
+        course
+    }
+
+    fn titles(env: &Env, client: &CourseRegistryClient, min_reviews: u32) -> Vec<String> {
+        let mut titles: Vec<String> = Vec::new(env);
+        for entry in client.get_top_rated_courses(&min_reviews, &10).iter() {
+            titles.push_back(entry.course.title);
+        }
+        titles
+    }
+
+    #[test]
+    fn test_min_reviews_filter() {
+        let (env, client) = setup();
+        create_course(&client, "Rust", &[5, 4]);
+        create_course(&client, "Go", &[5]);
+        create_course(&client, "Solidity", &[]);
+
+        let ranking = client.get_top_rated_courses(&2, &10);
+        assert_eq!(ranking.len(), 1);
+        let entry = ranking.get(0).unwrap();
+        assert_eq!(entry.course.title, String::from_str(&env, "Rust"));
+        assert_eq!((entry.average_rating, entry.review_count), (450, 2));
+        assert_eq!(client.get_top_rated_courses(&0, &10).len(), 3);
+    }
+
+    #[test]
+    fn test_sorting_with_tie_breaking() {
+        let (env, client) = setup();
+        create_course(&client, "Rust", &[4]);
+        create_course(&client, "Go", &[4, 4, 4]);
+        create_course(&client, "Python", &[5, 3]);
+        create_course(&client, "Haskell", &[5]);
+
+        assert_eq!(
+            titles(&env, &client, 1),
+            soroban_sdk::vec![
+                &env,
+                String::from_str(&env, "Haskell"),
+                String::from_str(&env, "Go"),
+                String::from_str(&env, "Python"),
+                String::from_str(&env, "Rust")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_limit_capped() {
+        let (_env, client) = setup();
+        for index in 0..(MAX_TOP_RATED_COURSES + 1) {
+            create_course(&client, &std::format!("Course {index}"), &[]);
+        }
+
+        assert_eq!(client.get_top_rated_courses(&0, &3).len(), 3);
+        assert_eq!(
+            client.get_top_rated_courses(&0, &100).len(),
+            MAX_TOP_RATED_COURSES
+        );
+    }
+
+    #[test]
+    fn test_all_below_threshold() {
+        let (_env, client) = setup();
+        create_course(&client, "Rust", &[5]);
+        create_course(&client, "Go", &[]);
+
+        assert!(client.get_top_rated_courses(&2, &10).is_empty());
+    }
+
+    #[test]
+    fn test_ranking_cached_for_30_minutes() {
+        let (env, client) = setup();
+        let course = create_course(&client, "Rust", &[5]);
+        assert_eq!(client.get_top_rated_courses(&1, &10).len(), 1);
+
+        client.add_course_review(&Address::generate(&env), &course.id, &1, &None);
+        assert_eq!(
+            client
+                .get_top_rated_courses(&1, &10)
+                .get(0)
+                .unwrap()
+                .review_count,
+            1
+        );
+
+        env.ledger().set_timestamp(1_000 + TOP_RATED_CACHE_SECONDS);
+        let fresh = client.get_top_rated_courses(&1, &10).get(0).unwrap();
+        assert_eq!((fresh.average_rating, fresh.review_count), (300, 2));
+    }
+}
@@ -43,6 +43,7 @@ mod test {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
 
         let is_creator = client.is_course_creator(&course.id, &creator);
@@ -71,6 +72,7 @@ mod test {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
 
         let is_creator = client.is_course_creator(&course.id, &impostor);
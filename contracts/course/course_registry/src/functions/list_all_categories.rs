@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::create_course_category::category_name_key;
+use crate::schema::{CourseCategory, DataKey};
+
+/// Returns every course category, ordered by ID.
+pub fn course_registry_list_all_categories(env: Env) -> Vec<CourseCategory> {
+    let ids: Vec<u128> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AllCategoryIds)
+        .unwrap_or(Vec::new(&env));
+
+    let mut categories: Vec<CourseCategory> = Vec::new(&env);
+    for id in ids.iter() {
+        let Some(category) = env
+            .storage()
+            .persistent()
+            .get::<_, CourseCategory>(&DataKey::CourseCategory(id))
+        else {
+            continue;
+        };
+
+        // Insertion sort keeps the result ordered by ID
+        let mut index: u32 = categories.len();
+        while index > 0 && categories.get(index - 1).unwrap().id > category.id {
+            index -= 1;
+        }
+        categories.insert(index, category);
+    }
+
+    categories
+}
+
+/// Looks up a course category by name, ignoring case.
+///
+/// Panics with `Error::CategoryNotFound` if no category has that name.
+pub fn course_registry_get_category_by_name(env: Env, name: String) -> CourseCategory {
+    let id: u128 = env
+        .storage()
+        .persistent()
+        .get(&category_name_key(&env, &name))
+        .unwrap_or_else(|| handle_error(&env, Error::CategoryNotFound));
+
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseCategory(id))
+        .unwrap_or_else(|| handle_error(&env, Error::CategoryNotFound))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::DataKey;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Admins, &vec![&env, admin.clone()]);
+        });
+        (env, client, admin)
+    }
+
+    #[test]
+    fn test_list_all_categories_empty() {
+        let (_env, client, _admin) = setup();
+        assert_eq!(client.list_all_categories().len(), 0);
+    }
+
+    #[test]
+    fn test_list_all_categories_ordered_by_id() {
+        let (env, client, admin) = setup();
+        // Created in non-alphabetical order; results follow creation (ID) order
+        for name in ["Programming", "Design", "Marketing"] {
+            client.create_course_category(&admin, &String::from_str(&env, name), &None);
+        }
+
+        let categories = client.list_all_categories();
+        assert_eq!(categories.len(), 3);
+        assert_eq!(categories.get(0).unwrap().name, String::from_str(&env, "Programming"));
+        assert_eq!(categories.get(1).unwrap().name, String::from_str(&env, "Design"));
+        assert_eq!(categories.get(2).unwrap().name, String::from_str(&env, "Marketing"));
+        assert!(categories.get(0).unwrap().id < categories.get(1).unwrap().id);
+
+        let design = categories.get(1).unwrap().id;
+        client.delete_course_category(&admin, &design);
+        let categories = client.list_all_categories();
+        assert_eq!(categories.len(), 2);
+        assert_eq!(categories.get(1).unwrap().name, String::from_str(&env, "Marketing"));
+    }
+
+    #[test]
+    fn test_get_category_by_name_is_case_insensitive() {
+        let (env, client, admin) = setup();
+        let id = client.create_course_category(&admin, &String::from_str(&env, "Programming"), &None);
+
+        let category = client.get_category_by_name(&String::from_str(&env, "pROGRAMMING"));
+        assert_eq!(category.id, id);
+        assert_eq!(category.name, String::from_str(&env, "Programming"));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #159)")]
+    fn test_get_category_by_name_not_found() {
+        let (env, client, _admin) = setup();
+        client.get_category_by_name(&String::from_str(&env, "Missing"));
+    }
+}
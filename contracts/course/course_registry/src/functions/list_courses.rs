@@ -0,0 +1,321 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::functions::get_course_count::is_counted_as_published;
+use crate::functions::set_course_visibility::ListingViewer;
+use crate::schema::{
+    Course, CourseCategory, CourseFilter, CourseListPage, CourseSortOrder, DataKey,
+    DifficultyFilter, MAX_LIST_PAGE_SIZE,
+};
+use shared::Paginator;
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Returns the IDs of all existing courses in creation order.
+pub fn get_all_course_ids(env: &Env) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AllCourseIds)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Appends a newly created course to `DataKey::AllCourseIds`.
+pub fn add_to_course_list(env: &Env, course_id: &String) {
+    let mut ids: Vec<String> = get_all_course_ids(env);
+    if !ids.contains(course_id) {
+        ids.push_back(course_id.clone());
+        env.storage().persistent().set(&DataKey::AllCourseIds, &ids);
+    }
+}
+
+/// Removes a deleted course from `DataKey::AllCourseIds`.
+pub fn remove_from_course_list(env: &Env, course_id: &String) {
+    let mut ids: Vec<String> = get_all_course_ids(env);
+    if let Some(index) = ids.first_index_of(course_id) {
+        ids.remove(index);
+        env.storage().persistent().set(&DataKey::AllCourseIds, &ids);
+    }
+}
+
+/// Lists courses matching `filter`, ordered by `sort`, one page at a time.
+///
+/// `limit` is capped at `MAX_LIST_PAGE_SIZE`; `total` counts every match.
+/// Private courses are only listed to a `viewer` who created them or is an
+/// admin.
+pub fn course_registry_list_courses(
+    env: Env,
+    filter: CourseFilter,
+    sort: CourseSortOrder,
+    offset: u32,
+    limit: u32,
+    viewer: Option<Address>,
+) -> CourseListPage {
+    let viewer: ListingViewer = ListingViewer::new(&env, viewer);
+
+    let category_name: Option<String> = filter.category_id.map(|id| {
+        env.storage()
+            .persistent()
+            .get::<_, CourseCategory>(&DataKey::CourseCategory(id))
+            .map(|category| category.name)
+            // An unknown category matches no course
+            .unwrap_or(String::from_str(&env, ""))
+    });
+
+    let mut matches: Vec<Course> = Vec::new(&env);
+    for course_id in get_all_course_ids(&env).iter() {
+        let Some(course) = env
+            .storage()
+            .persistent()
+            .get::<_, Course>(&(COURSE_KEY, course_id))
+        else {
+            continue;
+        };
+        if viewer.can_list(&course) && matches_filter(&course, &filter, &category_name) {
+            insert_sorted(&mut matches, course, &sort);
+        }
+    }
+
+    let (courses, has_more) =
+        Paginator::new(offset, limit, MAX_LIST_PAGE_SIZE).paginate(&matches, &env);
+
+    CourseListPage {
+        courses,
+        total: matches.len(),
+        has_more,
+    }
+}
+
+fn matches_filter(course: &Course, filter: &CourseFilter, category_name: &Option<String>) -> bool {
+    if filter.published_only && !is_counted_as_published(course) {
+        return false;
+    }
+    if let Some(name) = category_name {
+        if course.category.as_ref() != Some(name) {
+            return false;
+        }
+    }
+    if let DifficultyFilter::Level(difficulty) = filter.difficulty {
+        if course.difficulty != Some(difficulty as u32) {
+            return false;
+        }
+    }
+    if filter.language.is_some() && course.language != filter.language {
+        return false;
+    }
+    if let Some(max_price) = filter.max_price {
+        if course.price > max_price {
+            return false;
+        }
+    }
+    true
+}
+
+/// Inserts `course` after every course that does not sort after it, so equal
+/// keys keep their creation order.
+fn insert_sorted(courses: &mut Vec<Course>, course: Course, sort: &CourseSortOrder) {
+    let mut index: u32 = courses.len();
+    while index > 0 && sorts_before(&course, &courses.get(index - 1).unwrap(), sort) {
+        index -= 1;
+    }
+    courses.insert(index, course);
+}
+
+fn sorts_before(a: &Course, b: &Course, sort: &CourseSortOrder) -> bool {
+    match sort {
+        // Courses are visited in creation order, so appending keeps it
+        CourseSortOrder::ByCreationDate => false,
+        CourseSortOrder::ByTitle => a.title < b.title,
+        CourseSortOrder::ByPrice => a.price < b.price,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{Course, CourseFilter, CourseSortOrder, DifficultyFilter, DifficultyLevel};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        (env, client)
+    }
+
+    fn create_course(
+        env: &Env,
+        client: &CourseRegistryClient,
+        title: &str,
+        price: u128,
+        language: &str,
+        difficulty: DifficultyLevel,
+    ) -> Course {
+        // Each course gets its own creator to stay under the creation rate limit
+        client.create_course(
+            &Address::generate(env),
+            &String::from_str(env, title),
+            &String::from_str(env, "description"),
+            &price,
+            &None,
+            &Some(String::from_str(env, language)),
+            &None,
+            &None,
+            &None,
+            &Some(difficulty),
+        )
+    }
+
+    fn no_filter() -> CourseFilter {
+        CourseFilter {
+            category_id: None,
+            difficulty: DifficultyFilter::Any,
+            language: None,
+            max_price: None,
+            published_only: false,
+        }
+    }
+
+    fn titles(env: &Env, courses: &Vec<Course>) -> Vec<String> {
+        let mut titles: Vec<String> = Vec::new(env);
+        for course in courses.iter() {
+            titles.push_back(course.title);
+        }
+        titles
+    }
+
+    fn create_catalog(env: &Env, client: &CourseRegistryClient) {
+        create_course(env, client, "Rust", 3000, "en", DifficultyLevel::Advanced);
+        create_course(env, client, "Go", 1000, "es", DifficultyLevel::Beginner);
+        create_course(env, client, "Python", 2000, "en", DifficultyLevel::Beginner);
+    }
+
+    #[test]
+    fn test_sort_orders() {
+        let (env, client) = setup();
+        create_catalog(&env, &client);
+
+        let by_date = client.list_courses(&no_filter(), &CourseSortOrder::ByCreationDate, &0, &10, &None);
+        assert_eq!(
+            titles(&env, &by_date.courses),
+            soroban_sdk::vec![&env, String::from_str(&env, "Rust"), String::from_str(&env, "Go"), String::from_str(&env, "Python")]
+        );
+
+        let by_title = client.list_courses(&no_filter(), &CourseSortOrder::ByTitle, &0, &10, &None);
+        assert_eq!(
+            titles(&env, &by_title.courses),
+            soroban_sdk::vec![&env, String::from_str(&env, "Go"), String::from_str(&env, "Python"), String::from_str(&env, "Rust")]
+        );
+
+        let by_price = client.list_courses(&no_filter(), &CourseSortOrder::ByPrice, &0, &10, &None);
+        assert_eq!(
+            titles(&env, &by_price.courses),
+            soroban_sdk::vec![&env, String::from_str(&env, "Go"), String::from_str(&env, "Python"), String::from_str(&env, "Rust")]
+        );
+        assert_eq!(by_price.total, 3);
+        assert!(!by_price.has_more);
+    }
+
+    #[test]
+    fn test_filter_options() {
+        let (env, client) = setup();
+        create_catalog(&env, &client);
+        let sort = CourseSortOrder::ByCreationDate;
+
+        let mut filter = no_filter();
+        filter.difficulty = DifficultyFilter::Level(DifficultyLevel::Beginner);
+        assert_eq!(client.list_courses(&filter, &sort, &0, &10, &None).total, 2);
+
+        let mut filter = no_filter();
+        filter.language = Some(String::from_str(&env, "es"));
+        let page = client.list_courses(&filter, &sort, &0, &10, &None);
+        assert_eq!(page.courses.get(0).unwrap().title, String::from_str(&env, "Go"));
+        assert_eq!(page.total, 1);
+
+        let mut filter = no_filter();
+        filter.max_price = Some(2000);
+        assert_eq!(client.list_courses(&filter, &sort, &0, &10, &None).total, 2);
+
+        // Newly created courses are unpublished
+        let mut filter = no_filter();
+        filter.published_only = true;
+        assert_eq!(client.list_courses(&filter, &sort, &0, &10, &None).total, 0);
+    }
+
+    #[test]
+    fn test_filter_by_category() {
+        let (env, client) = setup();
+        let data = client.create_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "Data course"),
+            &String::from_str(&env, "description"),
+            &1000,
+            &Some(String::from_str(&env, "Data")),
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        create_catalog(&env, &client);
+
+        let admin = Address::generate(&env);
+        env.as_contract(&client.address, || {
+            env.storage()
+                .persistent()
+                .set(&crate::schema::DataKey::Admins, &soroban_sdk::vec![&env, admin.clone()]);
+        });
+        let category_id = client.create_course_category(&admin, &String::from_str(&env, "Data"), &None);
+
+        let mut filter = no_filter();
+        filter.category_id = Some(category_id);
+        let page = client.list_courses(&filter, &CourseSortOrder::ByTitle, &0, &10, &None);
+        assert_eq!(page.total, 1);
+        assert_eq!(page.courses.get(0).unwrap(), data);
+    }
+
+    #[test]
+    fn test_combined_filter_and_sort_with_pagination() {
+        let (env, client) = setup();
+        create_catalog(&env, &client);
+        create_course(&env, &client, "Elixir", 500, "en", DifficultyLevel::Beginner);
+
+        let mut filter = no_filter();
+        filter.difficulty = DifficultyFilter::Level(DifficultyLevel::Beginner);
+        filter.max_price = Some(2000);
+
+        let first = client.list_courses(&filter, &CourseSortOrder::ByPrice, &0, &2, &None);
+        assert_eq!(
+            titles(&env, &first.courses),
+            soroban_sdk::vec![&env, String::from_str(&env, "Elixir"), String::from_str(&env, "Go")]
+        );
+        assert_eq!(first.total, 3);
+        assert!(first.has_more);
+
+        let second = client.list_courses(&filter, &CourseSortOrder::ByPrice, &2, &2, &None);
+        assert_eq!(
+            titles(&env, &second.courses),
+            soroban_sdk::vec![&env, String::from_str(&env, "Python")]
+        );
+        assert!(!second.has_more);
+    }
+
+    #[test]
+    fn test_empty_result() {
+        let (env, client) = setup();
+        create_catalog(&env, &client);
+
+        let mut filter = no_filter();
+        filter.language = Some(String::from_str(&env, "fr"));
+        let page = client.list_courses(&filter, &CourseSortOrder::ByTitle, &0, &10, &None);
+        assert!(page.courses.is_empty());
+        assert_eq!(page.total, 0);
+        assert!(!page.has_more);
+
+        let mut filter = no_filter();
+        filter.category_id = Some(99);
+        assert_eq!(client.list_courses(&filter, &CourseSortOrder::ByTitle, &0, &10, &None).total, 0);
+    }
+}
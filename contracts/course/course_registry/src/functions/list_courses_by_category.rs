@@ -0,0 +1,222 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::create_course_category::category_name_key;
+use crate::functions::set_course_visibility::{filter_browsable_course_ids, ListingViewer};
+use crate::functions::utils::{exclude_archived_course_ids, paginate_course_ids};
+use crate::schema::{CourseCategory, CourseListPage, DataKey};
+
+/// Resolves a category name, as stored on `Course::category`, to its ID.
+///
+/// Uses the case-insensitive `DataKey::CategoryByName` index and falls back to
+/// scanning for categories created before the index existed.
+pub fn find_category_id(env: &Env, name: &String) -> Option<u128> {
+    if let Some(id) = env
+        .storage()
+        .persistent()
+        .get(&category_name_key(env, name))
+    {
+        return Some(id);
+    }
+
+    let seq: u128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CategorySeq)
+        .unwrap_or(0u128);
+
+    (1..=seq).find(|id| {
+        env.storage()
+            .persistent()
+            .get::<_, CourseCategory>(&DataKey::CourseCategory(*id))
+            .is_some_and(|category| category.name == *name)
+    })
+}
+
+fn get_category_course_ids(env: &Env, category_id: u128) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CategoryCourses(category_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Adds a course to the index of the category it belongs to, if any.
+pub fn add_to_category_index(env: &Env, category: &Option<String>, course_id: &String) {
+    let Some(category_id) = category.as_ref().and_then(|name| find_category_id(env, name)) else {
+        return;
+    };
+
+    let mut ids: Vec<String> = get_category_course_ids(env, category_id);
+    if !ids.contains(course_id) {
+        ids.push_back(course_id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::CategoryCourses(category_id), &ids);
+    }
+}
+
+/// Removes a course from the index of the category it belongs to, if any.
+pub fn remove_from_category_index(env: &Env, category: &Option<String>, course_id: &String) {
+    let Some(category_id) = category.as_ref().and_then(|name| find_category_id(env, name)) else {
+        return;
+    };
+
+    let mut ids: Vec<String> = get_category_course_ids(env, category_id);
+    if let Some(index) = ids.first_index_of(course_id) {
+        ids.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::CategoryCourses(category_id), &ids);
+    }
+}
+
+/// Moves a course between category indexes after its category changed.
+pub fn reindex_course_category(
+    env: &Env,
+    old_category: &Option<String>,
+    new_category: &Option<String>,
+    course_id: &String,
+) {
+    if old_category != new_category {
+        remove_from_category_index(env, old_category, course_id);
+        add_to_category_index(env, new_category, course_id);
+    }
+}
+
+/// Lists the courses of a category with offset-based pagination.
+///
+/// Archived courses are skipped unless `include_archived` is set. Only public
+/// courses are listed, except to a `viewer` who created the course or is an
+/// admin.
+///
+/// Panics with `Error::CategoryNotFound` if the category does not exist.
+pub fn course_registry_list_courses_by_category(
+    env: Env,
+    category_id: u128,
+    offset: u32,
+    limit: u32,
+    include_archived: bool,
+    viewer: Option<Address>,
+) -> CourseListPage {
+    if !env
+        .storage()
+        .persistent()
+        .has(&DataKey::CourseCategory(category_id))
+    {
+        handle_error(&env, Error::CategoryNotFound)
+    }
+
+    let mut ids: Vec<String> = get_category_course_ids(&env, category_id);
+    if !include_archived {
+        ids = exclude_archived_course_ids(&env, &ids);
+    }
+    ids = filter_browsable_course_ids(&env, &ids, &ListingViewer::new(&env, viewer));
+    paginate_course_ids(&env, &ids, offset, limit)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{Course, DataKey};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn create_course(
+        client: &CourseRegistryClient,
+        creator: &Address,
+        title: &str,
+        category: &str,
+    ) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &Some(String::from_str(&client.env, category)),
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Admins, &vec![&env, admin.clone()]);
+        });
+        (env, client, admin)
+    }
+
+    #[test]
+    fn test_list_courses_by_category() {
+        let (env, client, admin) = setup();
+        let programming =
+            client.create_course_category(&admin, &String::from_str(&env, "Programming"), &None);
+        client.create_course_category(&admin, &String::from_str(&env, "Design"), &None);
+
+        let first = create_course(&client, &Address::generate(&env), "Rust", "Programming");
+        create_course(&client, &Address::generate(&env), "Figma", "Design");
+        let second = create_course(&client, &Address::generate(&env), "Go", "Programming");
+
+        let page = client.list_courses_by_category(&programming, &0, &10, &false, &None);
+        assert_eq!(page.total, 2);
+        assert!(!page.has_more);
+        assert_eq!(page.courses.get(0).unwrap().id, first.id);
+        assert_eq!(page.courses.get(1).unwrap().id, second.id);
+
+        client.delete_course(&first.creator, &first.id);
+        let page = client.list_courses_by_category(&programming, &0, &10, &false, &None);
+        assert_eq!(page.total, 1);
+        assert_eq!(page.courses.get(0).unwrap().id, second.id);
+    }
+
+    #[test]
+    fn test_list_courses_by_category_empty() {
+        let (env, client, admin) = setup();
+        let category = client.create_course_category(&admin, &String::from_str(&env, "Empty"), &None);
+
+        let page = client.list_courses_by_category(&category, &0, &10, &false, &None);
+        assert_eq!(page.courses.len(), 0);
+        assert_eq!(page.total, 0);
+        assert!(!page.has_more);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #159)")]
+    fn test_list_courses_by_category_not_found() {
+        let (_env, client, _admin) = setup();
+        client.list_courses_by_category(&42, &0, &10, &false, &None);
+    }
+
+    #[test]
+    fn test_list_courses_by_category_pagination() {
+        let (env, client, admin) = setup();
+        let category = client.create_course_category(&admin, &String::from_str(&env, "Data"), &None);
+        for title in ["One", "Two", "Three"] {
+            create_course(&client, &Address::generate(&env), title, "Data");
+        }
+
+        let first = client.list_courses_by_category(&category, &0, &2, &false, &None);
+        assert_eq!(first.courses.len(), 2);
+        assert_eq!(first.total, 3);
+        assert!(first.has_more);
+
+        let last = client.list_courses_by_category(&category, &2, &2, &false, &None);
+        assert_eq!(last.courses.len(), 1);
+        assert!(!last.has_more);
+
+        let past_end = client.list_courses_by_category(&category, &3, &2, &false, &None);
+        assert_eq!(past_end.courses.len(), 0);
+        assert!(!past_end.has_more);
+    }
+}
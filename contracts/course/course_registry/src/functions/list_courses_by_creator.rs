@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::functions::utils::{exclude_archived_course_ids, paginate_course_ids};
+use crate::schema::{CourseListPage, DataKey};
+
+/// Returns the IDs of all courses created by an address, oldest first.
+pub fn get_creator_course_ids(env: &Env, creator: &Address) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CreatorCourses(creator.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Appends a newly created course to its creator's index.
+pub fn add_to_creator_index(env: &Env, creator: &Address, course_id: &String) {
+    let mut ids: Vec<String> = get_creator_course_ids(env, creator);
+    if !ids.contains(course_id) {
+        ids.push_back(course_id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::CreatorCourses(creator.clone()), &ids);
+    }
+}
+
+/// Removes a deleted course from its creator's index.
+pub fn remove_from_creator_index(env: &Env, creator: &Address, course_id: &String) {
+    let mut ids: Vec<String> = get_creator_course_ids(env, creator);
+    if let Some(index) = ids.first_index_of(course_id) {
+        ids.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::CreatorCourses(creator.clone()), &ids);
+    }
+}
+
+/// Lists the courses created by an address with offset-based pagination.
+///
+/// Archived courses are skipped unless `include_archived` is set.
+pub fn course_registry_list_courses_by_creator(
+    env: Env,
+    creator: Address,
+    offset: u32,
+    limit: u32,
+    include_archived: bool,
+) -> CourseListPage {
+    let mut ids: Vec<String> = get_creator_course_ids(&env, &creator);
+    if !include_archived {
+        ids = exclude_archived_course_ids(&env, &ids);
+    }
+    paginate_course_ids(&env, &ids, offset, limit)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::Course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, Env, String};
+
+    fn create_course(client: &CourseRegistryClient, creator: &Address, title: &str) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    fn setup() -> (Env, CourseRegistryClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        (env, client)
+    }
+
+    #[test]
+    fn test_list_courses_by_creator() {
+        let (env, client) = setup();
+        let creator = Address::generate(&env);
+        let other = Address::generate(&env);
+
+        let first = create_course(&client, &creator, "First");
+        create_course(&client, &other, "Other");
+        let second = create_course(&client, &creator, "Second");
+
+        let page = client.list_courses_by_creator(&creator, &0, &10, &false);
+        assert_eq!(page.total, 2);
+        assert!(!page.has_more);
+        assert_eq!(page.courses.get(0).unwrap().id, first.id);
+        assert_eq!(page.courses.get(1).unwrap().id, second.id);
+
+        client.delete_course(&creator, &first.id);
+        let page = client.list_courses_by_creator(&creator, &0, &10, &false);
+        assert_eq!(page.total, 1);
+        assert_eq!(page.courses.get(0).unwrap().id, second.id);
+    }
+
+    #[test]
+    fn test_list_courses_by_creator_empty() {
+        let (env, client) = setup();
+
+        let page = client.list_courses_by_creator(&Address::generate(&env), &0, &10, &false);
+        assert_eq!(page.courses.len(), 0);
+        assert_eq!(page.total, 0);
+        assert!(!page.has_more);
+    }
+
+    #[test]
+    fn test_list_courses_by_creator_pagination() {
+        let (env, client) = setup();
+        let creator = Address::generate(&env);
+
+        create_course(&client, &creator, "One");
+        create_course(&client, &creator, "Two");
+        create_course(&client, &creator, "Three");
+        // Stay within the course creation rate limit
+        env.ledger().set_timestamp(env.ledger().timestamp() + 3600);
+        create_course(&client, &creator, "Four");
+        create_course(&client, &creator, "Five");
+
+        let first = client.list_courses_by_creator(&creator, &0, &2, &false);
+        assert_eq!(first.courses.len(), 2);
+        assert_eq!(first.total, 5);
+        assert!(first.has_more);
+
+        let last = client.list_courses_by_creator(&creator, &4, &2, &false);
+        assert_eq!(last.courses.len(), 1);
+        assert_eq!(last.courses.get(0).unwrap().title, String::from_str(&env, "Five"));
+        assert!(!last.has_more);
+
+        let past_end = client.list_courses_by_creator(&creator, &5, &2, &false);
+        assert_eq!(past_end.courses.len(), 0);
+        assert_eq!(past_end.total, 5);
+        assert!(!past_end.has_more);
+    }
+
+    #[test]
+    fn test_list_courses_by_creator_hides_archived() {
+        let (env, client) = setup();
+        let creator = Address::generate(&env);
+        let archived = create_course(&client, &creator, "Old");
+        let active = create_course(&client, &creator, "New");
+        client.archive_course(&creator, &archived.id);
+
+        let page = client.list_courses_by_creator(&creator, &0, &10, &false);
+        assert_eq!(page.total, 1);
+        assert_eq!(page.courses.get(0).unwrap().id, active.id);
+
+        let page = client.list_courses_by_creator(&creator, &0, &10, &true);
+        assert_eq!(page.total, 2);
+        assert_eq!(page.courses.get(0).unwrap().id, archived.id);
+    }
+}
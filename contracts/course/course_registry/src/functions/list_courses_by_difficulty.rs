@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Env, String, Vec};
+
+use crate::functions::utils::paginate_course_ids;
+use crate::schema::{CourseListPage, DataKey, DifficultyLevel};
+
+fn get_difficulty_course_ids(env: &Env, difficulty: u32) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DifficultyCourses(difficulty))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Adds a course to the index of its difficulty level, if any.
+pub fn add_to_difficulty_index(env: &Env, difficulty: &Option<u32>, course_id: &String) {
+    let Some(difficulty) = *difficulty else {
+        return;
+    };
+
+    let mut ids: Vec<String> = get_difficulty_course_ids(env, difficulty);
+    if !ids.contains(course_id) {
+        ids.push_back(course_id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::DifficultyCourses(difficulty), &ids);
+    }
+}
+
+/// Removes a course from the index of its difficulty level, if any.
+pub fn remove_from_difficulty_index(
+    env: &Env,
+    difficulty: &Option<u32>,
+    course_id: &String,
+) {
+    let Some(difficulty) = *difficulty else {
+        return;
+    };
+
+    let mut ids: Vec<String> = get_difficulty_course_ids(env, difficulty);
+    if let Some(index) = ids.first_index_of(course_id) {
+        ids.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::DifficultyCourses(difficulty), &ids);
+    }
+}
+
+/// Moves a course between difficulty indexes after its difficulty changed.
+pub fn reindex_course_difficulty(
+    env: &Env,
+    old_difficulty: &Option<u32>,
+    new_difficulty: &Option<u32>,
+    course_id: &String,
+) {
+    if old_difficulty != new_difficulty {
+        remove_from_difficulty_index(env, old_difficulty, course_id);
+        add_to_difficulty_index(env, new_difficulty, course_id);
+    }
+}
+
+/// Lists the courses of a difficulty level with offset-based pagination.
+pub fn course_registry_list_courses_by_difficulty(
+    env: Env,
+    difficulty: DifficultyLevel,
+    offset: u32,
+    limit: u32,
+) -> CourseListPage {
+    let ids: Vec<String> = get_difficulty_course_ids(&env, difficulty as u32);
+    paginate_course_ids(&env, &ids, offset, limit)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{Course, DifficultyLevel};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_course(
+        client: &CourseRegistryClient,
+        creator: &Address,
+        title: &str,
+        difficulty: Option<DifficultyLevel>,
+    ) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &difficulty,
+        )
+    }
+
+    fn setup() -> (Env, CourseRegistryClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        (env, client)
+    }
+
+    #[test]
+    fn test_list_each_difficulty() {
+        let (env, client) = setup();
+        let levels = [
+            DifficultyLevel::Beginner,
+            DifficultyLevel::Intermediate,
+            DifficultyLevel::Advanced,
+            DifficultyLevel::Expert,
+        ];
+        let titles = ["Beginner", "Intermediate", "Advanced", "Expert"];
+
+        for (level, title) in levels.iter().zip(titles.iter()) {
+            // Separate creators keep each creation under the rate limit
+            let creator = Address::generate(&env);
+            create_course(&client, &creator, title, Some(*level));
+        }
+        create_course(&client, &Address::generate(&env), "Unrated", None);
+
+        for (level, title) in levels.iter().zip(titles.iter()) {
+            let page = client.list_courses_by_difficulty(level, &0, &10);
+            assert_eq!(page.total, 1);
+            assert_eq!(page.courses.get(0).unwrap().title, String::from_str(&env, title));
+            assert_eq!(page.courses.get(0).unwrap().difficulty, Some(*level as u32));
+        }
+    }
+
+    #[test]
+    fn test_index_follows_updates() {
+        let (env, client) = setup();
+        let creator = Address::generate(&env);
+        let course = create_course(&client, &creator, "Rust", Some(DifficultyLevel::Beginner));
+
+        client.update_course(
+            &creator,
+            &course.id,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &Some(DifficultyLevel::Advanced),
+        );
+
+        let beginner = client.list_courses_by_difficulty(&DifficultyLevel::Beginner, &0, &10);
+        assert_eq!(beginner.total, 0);
+        let advanced = client.list_courses_by_difficulty(&DifficultyLevel::Advanced, &0, &10);
+        assert_eq!(advanced.total, 1);
+        assert_eq!(
+            client.get_course(&course.id).difficulty,
+            Some(DifficultyLevel::Advanced as u32)
+        );
+    }
+
+    #[test]
+    fn test_index_cleanup_on_delete() {
+        let (env, client) = setup();
+        let creator = Address::generate(&env);
+        let course = create_course(&client, &creator, "Rust", Some(DifficultyLevel::Expert));
+
+        client.delete_course(&creator, &course.id);
+
+        let expert = client.list_courses_by_difficulty(&DifficultyLevel::Expert, &0, &10);
+        assert_eq!(expert.total, 0);
+        assert_eq!(expert.courses.len(), 0);
+    }
+}
@@ -0,0 +1,229 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Env, String, Vec};
+
+use crate::error::{handle_extended_error, ExtendedError};
+use crate::functions::utils::{paginate_course_ids, to_lowercase};
+use crate::schema::{CourseListPage, DataKey};
+
+fn get_language_course_ids(env: &Env, language: &String) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LanguageCourses(language.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns the languages that at least one course is taught in, lowercase,
+/// in the order they first appeared.
+pub fn course_registry_list_supported_languages(env: Env) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AllLanguages)
+        .unwrap_or(Vec::new(&env))
+}
+
+/// Adds a course to the index of its language, if any.
+pub fn add_to_language_index(env: &Env, language: &Option<String>, course_id: &String) {
+    let Some(language) = language.as_ref().map(|lang| to_lowercase(env, lang)) else {
+        return;
+    };
+
+    let mut ids: Vec<String> = get_language_course_ids(env, &language);
+    if ids.contains(course_id) {
+        return;
+    }
+    if ids.is_empty() {
+        let mut languages: Vec<String> = course_registry_list_supported_languages(env.clone());
+        languages.push_back(language.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::AllLanguages, &languages);
+    }
+    ids.push_back(course_id.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::LanguageCourses(language), &ids);
+}
+
+/// Removes a course from the index of its language, if any. A language
+/// without courses is dropped from the supported languages.
+pub fn remove_from_language_index(env: &Env, language: &Option<String>, course_id: &String) {
+    let Some(language) = language.as_ref().map(|lang| to_lowercase(env, lang)) else {
+        return;
+    };
+
+    let mut ids: Vec<String> = get_language_course_ids(env, &language);
+    let Some(index) = ids.first_index_of(course_id) else {
+        return;
+    };
+    ids.remove(index);
+    if !ids.is_empty() {
+        env.storage()
+            .persistent()
+            .set(&DataKey::LanguageCourses(language), &ids);
+        return;
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::LanguageCourses(language.clone()));
+    let mut languages: Vec<String> = course_registry_list_supported_languages(env.clone());
+    if let Some(index) = languages.first_index_of(&language) {
+        languages.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::AllLanguages, &languages);
+    }
+}
+
+/// Moves a course between language indexes after its language changed.
+pub fn reindex_course_language(
+    env: &Env,
+    old_language: &Option<String>,
+    new_language: &Option<String>,
+    course_id: &String,
+) {
+    if old_language != new_language {
+        remove_from_language_index(env, old_language, course_id);
+        add_to_language_index(env, new_language, course_id);
+    }
+}
+
+/// Lists the courses taught in a language, ignoring case, with offset-based
+/// pagination.
+///
+/// Panics with `ExtendedError::InvalidLanguage` if `language` is empty.
+pub fn course_registry_list_courses_by_language(
+    env: Env,
+    language: String,
+    offset: u32,
+    limit: u32,
+) -> CourseListPage {
+    if language.is_empty() {
+        handle_extended_error(&env, ExtendedError::InvalidLanguage)
+    }
+
+    let ids: Vec<String> = get_language_course_ids(&env, &to_lowercase(&env, &language));
+    paginate_course_ids(&env, &ids, offset, limit)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::Course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        (env, client)
+    }
+
+    fn create_course(client: &CourseRegistryClient, title: &str, language: &str) -> Course {
+        let env = &client.env;
+        client.create_course(
+            &Address::generate(env),
+            &String::from_str(env, title),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &Some(String::from_str(env, language)),
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    fn count(env: &Env, client: &CourseRegistryClient, language: &str) -> u32 {
+        client
+            .list_courses_by_language(&String::from_str(env, language), &0, &10)
+            .total
+    }
+
+    #[test]
+    fn test_list_courses_by_language() {
+        let (env, client) = setup();
+        let first = create_course(&client, "Rust", "English");
+        create_course(&client, "Solidity", "Spanish");
+        let third = create_course(&client, "Go", "english");
+
+        let page = client.list_courses_by_language(&String::from_str(&env, "ENGLISH"), &0, &1);
+        assert_eq!(page.total, 2);
+        assert_eq!(page.courses.get(0).unwrap(), first);
+        assert!(page.has_more);
+
+        let page = client.list_courses_by_language(&String::from_str(&env, "english"), &1, &1);
+        assert_eq!(page.courses.get(0).unwrap(), third);
+        assert_eq!(count(&env, &client, "french"), 0);
+    }
+
+    #[test]
+    fn test_language_change_moves_course() {
+        let (env, client) = setup();
+        let course = create_course(&client, "Rust", "English");
+
+        client.update_course(
+            &course.creator,
+            &course.id,
+            &None,
+            &None,
+            &None,
+            &None,
+            &Some(String::from_str(&env, "French")),
+            &None,
+            &None,
+        );
+
+        assert_eq!(count(&env, &client, "english"), 0);
+        assert_eq!(count(&env, &client, "french"), 1);
+        assert_eq!(
+            client.list_supported_languages(),
+            vec![&env, String::from_str(&env, "french")]
+        );
+    }
+
+    #[test]
+    fn test_delete_course_cleans_language_index() {
+        let (env, client) = setup();
+        let course = create_course(&client, "Rust", "English");
+        create_course(&client, "Solidity", "Spanish");
+
+        client.delete_course(&course.creator, &course.id);
+
+        assert_eq!(count(&env, &client, "english"), 0);
+        assert_eq!(
+            client.list_supported_languages(),
+            vec![&env, String::from_str(&env, "spanish")]
+        );
+    }
+
+    #[test]
+    fn test_list_supported_languages() {
+        let (env, client) = setup();
+        assert!(client.list_supported_languages().is_empty());
+
+        create_course(&client, "Rust", "English");
+        create_course(&client, "Solidity", "Spanish");
+        create_course(&client, "Go", "ENGLISH");
+
+        assert_eq!(
+            client.list_supported_languages(),
+            vec![
+                &env,
+                String::from_str(&env, "english"),
+                String::from_str(&env, "spanish")
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #406)")]
+    fn test_list_courses_by_empty_language() {
+        let (env, client) = setup();
+        client.list_courses_by_language(&String::from_str(&env, ""), &0, &10);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::error::{handle_error, Error};
+use crate::error::{handle_error, handle_extended_error, Error, ExtendedError};
 use crate::functions::utils::u32_to_string;
 
 use crate::schema::{Course, CourseFilters, MAX_EMPTY_CHECKS};
@@ -31,7 +31,7 @@ pub fn list_courses_with_filters(
     if let Some(o) = offset {
         if o > 10000 {
             // Prevent excessively large offsets
-            handle_error(env, Error::InvalidOffsetValue)
+            handle_extended_error(env, ExtendedError::InvalidOffsetValue)
         }
     }
 
@@ -129,6 +129,7 @@ pub fn list_courses_with_filters(
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::schema::ContentType;
     use crate::{CourseRegistry, CourseRegistryClient};
     use soroban_sdk::{testutils::Address as _, Address, Env, String};
 
@@ -155,6 +156,28 @@ mod test {
         assert_eq!(results.len(), 0);
     }
 
+    #[test]
+    fn test_offset_too_large() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let filters = CourseFilters {
+            min_price: None,
+            max_price: None,
+            category: None,
+            level: None,
+            min_duration: None,
+            max_duration: None,
+            search_text: None,
+        };
+
+        let result = client.try_list_courses_with_filters(&filters, &None, &Some(10_001));
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(147))));
+    }
+
     #[test]
     fn test_single_course_no_filters() {
         let env = Env::default();
@@ -175,9 +198,11 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         // Publish the course so it appears in filtered results
+        client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Intro"), &ContentType::Unspecified, &None);
         use crate::schema::EditCourseParams;
         let params = EditCourseParams {
             new_title: None,
@@ -228,6 +253,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         // Filter for expensive courses - should return empty
@@ -265,6 +291,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         // Test limit = 0 should return empty
@@ -302,6 +329,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let course2 = client.create_course(
@@ -314,9 +342,12 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         // Publish both courses
+        client.add_module(&creator, &course1.id, &0, &String::from_str(&env, "Intro"), &ContentType::Unspecified, &None);
+        client.add_module(&creator, &course2.id, &0, &String::from_str(&env, "Intro"), &ContentType::Unspecified, &None);
         use crate::schema::EditCourseParams;
         let publish_params = EditCourseParams {
             new_title: None,
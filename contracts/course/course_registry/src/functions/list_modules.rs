@@ -26,6 +26,7 @@ pub fn course_registry_list_modules(env: &Env, course_id: String) -> CourseModul
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::schema::ContentType;
     use crate::CourseRegistry;
     use soroban_sdk::{symbol_short, testutils::Ledger, Address, Env, String};
 
@@ -45,6 +46,8 @@ mod test {
             position: 0,
             title: String::from_str(&env, "Introduction to Blockchain"),
             created_at: 0,
+            content_type: ContentType::Unspecified,
+            content_url: None,
         };
 
         // Set up initial course data and perform test within contract context
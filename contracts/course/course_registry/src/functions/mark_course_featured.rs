@@ -0,0 +1,247 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::is_admin;
+use crate::functions::get_courses_updated_after::record_course_update;
+use crate::schema::{Course, DataKey};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const COURSE_FEATURED_EVENT: &str = "CourseFeatured";
+const COURSE_UNFEATURED_EVENT: &str = "CourseUnfeatured";
+
+/// Maximum number of courses featured on the homepage at once
+pub const MAX_FEATURED_COURSES: u32 = 20;
+
+fn get_featured_course_ids(env: &Env) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FeaturedCourses)
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_featured_course_ids(env: &Env, ids: &Vec<String>) {
+    if ids.is_empty() {
+        env.storage().persistent().remove(&DataKey::FeaturedCourses);
+    } else {
+        env.storage()
+            .persistent()
+            .set(&DataKey::FeaturedCourses, ids);
+    }
+}
+
+/// Drops a deleted course from the featured list.
+pub fn remove_from_featured(env: &Env, course_id: &String) {
+    let mut ids: Vec<String> = get_featured_course_ids(env);
+    if let Some(index) = ids.first_index_of(course_id) {
+        ids.remove(index);
+        set_featured_course_ids(env, &ids);
+    }
+}
+
+/// Adds a course to, or removes it from, the homepage featured list (admin only).
+///
+/// Featuring a featured course, or unfeaturing one that is not featured,
+/// changes nothing.
+///
+/// # Panics
+///
+/// * `Error::Unauthorized` if the caller is not an admin.
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::FeaturedListFull` if `MAX_FEATURED_COURSES` courses are already featured.
+pub fn course_registry_mark_course_featured(
+    env: Env,
+    admin: Address,
+    course_id: String,
+    featured: bool,
+) {
+    admin.require_auth();
+    if !is_admin(&env, &admin) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    let storage_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    let mut course: Course = env
+        .storage()
+        .persistent()
+        .get(&storage_key)
+        .unwrap_or_else(|| handle_error(&env, Error::CourseNotFound));
+    if course.featured == featured {
+        return;
+    }
+
+    let mut ids: Vec<String> = get_featured_course_ids(&env);
+    if featured {
+        if ids.len() >= MAX_FEATURED_COURSES {
+            handle_error(&env, Error::FeaturedListFull)
+        }
+        ids.push_back(course_id.clone());
+    } else if let Some(index) = ids.first_index_of(&course_id) {
+        ids.remove(index);
+    }
+    set_featured_course_ids(&env, &ids);
+
+    course.featured = featured;
+    env.storage().persistent().set(&storage_key, &course);
+    record_course_update(&env, &course_id);
+
+    let event: &str = if featured {
+        COURSE_FEATURED_EVENT
+    } else {
+        COURSE_UNFEATURED_EVENT
+    };
+    env.events()
+        .publish((Symbol::new(&env, event), course_id), admin);
+}
+
+/// Returns the featured courses in the order they were featured.
+pub fn course_registry_get_featured_courses(env: Env) -> Vec<Course> {
+    let mut courses: Vec<Course> = Vec::new(&env);
+    for course_id in get_featured_course_ids(&env).iter() {
+        if let Some(course) = env
+            .storage()
+            .persistent()
+            .get::<_, Course>(&(COURSE_KEY, course_id))
+        {
+            courses.push_back(course);
+        }
+    }
+    courses
+}
+
+#[cfg(test)]
+mod test {
+    use super::MAX_FEATURED_COURSES;
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::functions::utils::{concat_strings, u32_to_string};
+    use crate::schema::Course;
+    use crate::CourseRegistryClient;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{vec, Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Address) {
+        let (env, client, mocks) = setup_with_mocks();
+        (env, client, mocks.admin)
+    }
+
+    fn create_course(env: &Env, client: &CourseRegistryClient, title: &str) -> Course {
+        client.create_course(
+            &Address::generate(env),
+            &String::from_str(env, title),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_mark_course_featured() {
+        let (env, client, admin) = setup();
+        let first = create_course(&env, &client, "Rust");
+        let second = create_course(&env, &client, "Solidity");
+        assert!(!first.featured);
+
+        client.mark_course_featured(&admin, &second.id, &true);
+        client.mark_course_featured(&admin, &first.id, &true);
+
+        assert!(client.get_course(&first.id).featured);
+        let featured = client.get_featured_courses();
+        assert_eq!(featured.len(), 2);
+        assert_eq!(featured.get(0).unwrap().id, second.id);
+        assert_eq!(featured.get(1).unwrap().id, first.id);
+    }
+
+    #[test]
+    fn test_unmark_course_featured() {
+        let (env, client, admin) = setup();
+        let course = create_course(&env, &client, "Rust");
+        client.mark_course_featured(&admin, &course.id, &true);
+
+        client.mark_course_featured(&admin, &course.id, &false);
+
+        assert!(!client.get_course(&course.id).featured);
+        assert!(client.get_featured_courses().is_empty());
+    }
+
+    #[test]
+    fn test_mark_course_featured_records_update() {
+        let (env, client, admin) = setup();
+        env.ledger().set_timestamp(100);
+        let course = create_course(&env, &client, "Rust");
+
+        env.ledger().set_timestamp(200);
+        client.mark_course_featured(&admin, &course.id, &true);
+
+        assert_eq!(client.get_last_update_timestamp(&course.id), 200);
+        let updated = client.get_courses_updated_after(&150, &10);
+        assert_eq!(updated.len(), 1);
+        assert!(updated.get(0).unwrap().featured);
+    }
+
+    #[test]
+    fn test_featured_list_cap() {
+        let (env, client, admin) = setup();
+        for i in 0..MAX_FEATURED_COURSES {
+            let title = concat_strings(
+                &env,
+                vec![
+                    &env,
+                    String::from_str(&env, "Course "),
+                    u32_to_string(&env, i),
+                ],
+            );
+            let course = client.create_course(
+                &Address::generate(&env),
+                &title,
+                &String::from_str(&env, "description"),
+                &1000_u128,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            );
+            client.mark_course_featured(&admin, &course.id, &true);
+        }
+
+        let extra = create_course(&env, &client, "One too many");
+        let result = client.try_mark_course_featured(&admin, &extra.id, &true);
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(176)))
+        );
+        assert_eq!(client.get_featured_courses().len(), MAX_FEATURED_COURSES);
+    }
+
+    #[test]
+    fn test_delete_course_removes_from_featured() {
+        let (env, client, admin) = setup();
+        let course = create_course(&env, &client, "Rust");
+        let other = create_course(&env, &client, "Solidity");
+        client.mark_course_featured(&admin, &course.id, &true);
+        client.mark_course_featured(&admin, &other.id, &true);
+
+        client.delete_course(&course.creator, &course.id);
+
+        let featured = client.get_featured_courses();
+        assert_eq!(featured.len(), 1);
+        assert_eq!(featured.get(0).unwrap().id, other.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_mark_course_featured_not_admin() {
+        let (env, client, _admin) = setup();
+        let course = create_course(&env, &client, "Rust");
+        client.mark_course_featured(&course.creator, &course.id, &true);
+    }
+}
@@ -2,28 +2,142 @@
 // Copyright (c) 2025 SkillCert
 
 pub mod access_control;
+pub mod add_course_review;
 pub mod add_goal;
 pub mod add_module;
 pub mod archive_course;
 pub mod backup_recovery;
+pub mod clone_course;
+pub mod co_instructors;
 pub mod contract_versioning;
+pub mod course_attachments;
+pub mod course_bundles;
+pub mod course_faq;
 pub mod create_course;
 pub mod create_course_category;
 pub mod create_prerequisite;
 pub mod course_rate_limit_utils;
+pub mod course_version_history;
 pub mod delete_course;
+pub mod delete_course_category;
+pub mod delete_module;
+pub mod duplicate_module;
 pub mod edit_course;
 pub mod edit_goal;
 pub mod edit_prerequisite;
+pub mod freeze_course;
 pub mod get_course;
+pub mod get_course_count;
 pub mod get_course_category;
+pub mod get_course_prerequisites;
+pub mod get_course_with_modules;
 pub mod get_courses_by_instructor;
+pub mod get_courses_updated_after;
+pub mod get_creator_statistics;
+pub mod get_top_rated_courses;
+pub mod get_dependent_courses;
+pub mod get_module;
+pub mod get_modules_by_type;
+pub mod get_prerequisite_tree;
 pub mod get_prerequisites_by_course;
 pub mod is_course_creator;
 pub mod list_categories;
+pub mod list_all_categories;
+pub mod list_courses;
+pub mod list_courses_by_category;
+pub mod list_courses_by_creator;
+pub mod list_courses_by_difficulty;
+pub mod list_courses_by_language;
 pub mod list_courses_with_filters;
 pub mod list_modules;
+pub mod mark_course_featured;
+pub mod module_resources;
+pub mod module_utils;
 pub mod remove_goal;
 pub mod remove_module;
 pub mod remove_prerequisite;
+pub mod reorder_modules;
+pub mod search_courses;
+pub mod set_course_free;
+pub mod set_course_price;
+pub mod set_course_tags;
+pub mod set_course_visibility;
+pub mod set_course_schedule;
+pub mod set_enrollment_cap;
+pub mod take_progress_snapshot;
+pub mod transfer_course_ownership;
+pub mod update_course;
+pub mod update_course_category;
+pub mod update_module;
+pub mod update_module_content;
 pub mod utils;
+pub mod validate_course;
+
+#[cfg(test)]
+pub mod test_utils {
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    use crate::{CourseRegistry, CourseRegistryClient};
+
+    /// User management mock whose admins and email verification are configured
+    /// per test.
+    pub mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn set_admin(env: Env, who: Address) {
+                env.storage().instance().set(&who, &true);
+            }
+
+            pub fn is_admin(env: Env, who: Address) -> bool {
+                env.storage().instance().get(&who).unwrap_or(false)
+            }
+
+            pub fn set_email_verified(env: Env, user: Address, verified: bool) {
+                env.storage()
+                    .instance()
+                    .set(&(symbol_short!("verified"), user), &verified);
+            }
+
+            /// Emails count as verified unless a test says otherwise.
+            pub fn is_email_verified(env: Env, user: Address) -> bool {
+                env.storage()
+                    .instance()
+                    .get(&(symbol_short!("verified"), user))
+                    .unwrap_or(true)
+            }
+        }
+    }
+
+    pub struct TestMocks {
+        /// Contract owner, also an admin in the user management mock
+        pub admin: Address,
+        pub user_management: Address,
+    }
+
+    /// Registers the contract against a user management mock with one admin.
+    pub fn setup_with_mocks() -> (Env, CourseRegistryClient<'static>, TestMocks) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        mock_user_management::UserManagementClient::new(&env, &user_mgmt_id).set_admin(&admin);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &admin, &user_mgmt_id);
+        });
+
+        let mocks = TestMocks {
+            admin,
+            user_management: user_mgmt_id,
+        };
+        (env, client, mocks)
+    }
+}
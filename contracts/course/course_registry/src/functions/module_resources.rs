@@ -0,0 +1,305 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use shared::{handle_common_error, CommonError};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::require_course_editor;
+use crate::schema::{CourseModule, DataKey, ModuleResource, ResourceType};
+
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+const MAX_RESOURCE_TITLE_LENGTH: u32 = 200;
+
+const MODULE_RESOURCE_ADDED_EVENT: &str = "ModuleResourceAdded";
+const MODULE_RESOURCE_REMOVED_EVENT: &str = "ModuleResourceRemoved";
+
+fn get_module_resource_ids(env: &Env, module_id: &String) -> Vec<u128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ModuleResourceIds(module_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Attaches a downloadable resource to a module (course creator,
+/// co-instructor or admin).
+///
+/// # Panics
+///
+/// * `Error::ModuleNotFound` if the module does not exist.
+/// * `Error::Unauthorized` if the caller may not edit the course.
+/// * `Error::InvalidContentUrl` if `url` is not a valid URL.
+/// * `Error::InvalidTitleLength` if `title` is empty or too long.
+pub fn course_registry_add_module_resource(
+    env: Env,
+    caller: Address,
+    module_id: String,
+    url: String,
+    title: String,
+    resource_type: ResourceType,
+) -> ModuleResource {
+    let module: CourseModule = env
+        .storage()
+        .persistent()
+        .get(&(MODULE_KEY, module_id.clone()))
+        .unwrap_or_else(|| handle_error(&env, Error::ModuleNotFound));
+
+    require_course_editor(&env, &caller, &module.course_id);
+
+    if !shared::validate_url(&url) {
+        handle_error(&env, Error::InvalidContentUrl)
+    }
+    if title.is_empty() || title.len() > MAX_RESOURCE_TITLE_LENGTH {
+        handle_error(&env, Error::InvalidTitleLength)
+    }
+
+    let id: u128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ModuleResourceSeq)
+        .unwrap_or(0)
+        + 1;
+    env.storage()
+        .persistent()
+        .set(&DataKey::ModuleResourceSeq, &id);
+
+    let resource: ModuleResource = ModuleResource {
+        id,
+        module_id: module_id.clone(),
+        url,
+        title,
+        resource_type,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::ModuleResource(id), &resource);
+
+    let mut ids: Vec<u128> = get_module_resource_ids(&env, &module_id);
+    ids.push_back(id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::ModuleResourceIds(module_id.clone()), &ids);
+
+    env.events().publish(
+        (Symbol::new(&env, MODULE_RESOURCE_ADDED_EVENT), module_id),
+        id,
+    );
+
+    resource
+}
+
+/// Returns the resources of a module in the order they were added.
+pub fn course_registry_list_module_resources(env: Env, module_id: String) -> Vec<ModuleResource> {
+    let mut resources: Vec<ModuleResource> = Vec::new(&env);
+    for id in get_module_resource_ids(&env, &module_id).iter() {
+        if let Some(resource) = env
+            .storage()
+            .persistent()
+            .get::<_, ModuleResource>(&DataKey::ModuleResource(id))
+        {
+            resources.push_back(resource);
+        }
+    }
+    resources
+}
+
+/// Removes a module resource (course creator, co-instructor or admin).
+///
+/// # Panics
+///
+/// * `CommonError::NotFound` if the resource does not exist.
+/// * `Error::ModuleNotFound` if the resource's module no longer exists.
+/// * `Error::Unauthorized` if the caller may not edit the course.
+pub fn course_registry_remove_module_resource(env: Env, caller: Address, resource_id: u128) {
+    let resource: ModuleResource = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ModuleResource(resource_id))
+        .unwrap_or_else(|| handle_common_error(&env, CommonError::NotFound));
+
+    let module: CourseModule = env
+        .storage()
+        .persistent()
+        .get(&(MODULE_KEY, resource.module_id.clone()))
+        .unwrap_or_else(|| handle_error(&env, Error::ModuleNotFound));
+
+    require_course_editor(&env, &caller, &module.course_id);
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ModuleResource(resource_id));
+
+    let ids_key: DataKey = DataKey::ModuleResourceIds(resource.module_id.clone());
+    let mut ids: Vec<u128> = get_module_resource_ids(&env, &resource.module_id);
+    if let Some(index) = ids.first_index_of(resource_id) {
+        ids.remove(index);
+    }
+    if ids.is_empty() {
+        env.storage().persistent().remove(&ids_key);
+    } else {
+        env.storage().persistent().set(&ids_key, &ids);
+    }
+
+    env.events().publish(
+        (
+            Symbol::new(&env, MODULE_RESOURCE_REMOVED_EVENT),
+            resource.module_id,
+        ),
+        resource_id,
+    );
+}
+
+/// Removes every resource of a deleted module.
+pub fn clear_module_resources(env: &Env, module_id: &String) {
+    for id in get_module_resource_ids(env, module_id).iter() {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ModuleResource(id));
+    }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ModuleResourceIds(module_id.clone()));
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::schema::{ContentType, CourseModule, ResourceType};
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Address, CourseModule) {
+        let (env, client, _mocks) = setup_with_mocks();
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Intro"), &ContentType::Unspecified, &None);
+        (env, client, creator, module)
+    }
+
+    fn add_resource(
+        env: &Env,
+        client: &CourseRegistryClient,
+        caller: &Address,
+        module: &CourseModule,
+        url: &str,
+    ) -> crate::schema::ModuleResource {
+        client.add_module_resource(
+            caller,
+            &module.id,
+            &String::from_str(env, url),
+            &String::from_str(env, "Resource"),
+            &ResourceType::Pdf,
+        )
+    }
+
+    #[test]
+    fn test_add_and_list_module_resources() {
+        let (env, client, creator, module) = setup();
+
+        let slides = add_resource(
+            &env,
+            &client,
+            &creator,
+            &module,
+            "https://example.com/slides.pdf",
+        );
+        let code = client.add_module_resource(
+            &creator,
+            &module.id,
+            &String::from_str(&env, "https://example.com/main.rs"),
+            &String::from_str(&env, "Source"),
+            &ResourceType::Code,
+        );
+        assert_eq!(slides.id, 1);
+        assert_eq!(code.id, 2);
+        assert_eq!(code.resource_type, ResourceType::Code);
+
+        let resources = client.list_module_resources(&module.id);
+        assert_eq!(resources.len(), 2);
+        assert_eq!(resources.get(0).unwrap(), slides);
+        assert_eq!(resources.get(1).unwrap(), code);
+    }
+
+    #[test]
+    fn test_remove_module_resource() {
+        let (env, client, creator, module) = setup();
+        let resource = add_resource(
+            &env,
+            &client,
+            &creator,
+            &module,
+            "https://example.com/slides.pdf",
+        );
+
+        client.remove_module_resource(&creator, &resource.id);
+
+        assert!(client.list_module_resources(&module.id).is_empty());
+        let result = client.try_remove_module_resource(&creator, &resource.id);
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(2))));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #161)")]
+    fn test_add_module_resource_invalid_url() {
+        let (env, client, creator, module) = setup();
+        add_resource(&env, &client, &creator, &module, "not a url");
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_remove_module_resource_unauthorized() {
+        let (env, client, creator, module) = setup();
+        let resource = add_resource(
+            &env,
+            &client,
+            &creator,
+            &module,
+            "https://example.com/slides.pdf",
+        );
+
+        client.remove_module_resource(&Address::generate(&env), &resource.id);
+    }
+
+    #[test]
+    fn test_resources_of_missing_module() {
+        let (env, client, creator, module) = setup();
+        let missing = String::from_str(&env, "missing_module");
+
+        let result = client.try_add_module_resource(
+            &creator,
+            &missing,
+            &String::from_str(&env, "https://example.com/slides.pdf"),
+            &String::from_str(&env, "Slides"),
+            &ResourceType::Link,
+        );
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(121)))
+        );
+        assert!(client.list_module_resources(&missing).is_empty());
+
+        // Deleting a module drops its resources
+        add_resource(
+            &env,
+            &client,
+            &creator,
+            &module,
+            "https://example.com/slides.pdf",
+        );
+        client.delete_module(&creator, &module.id);
+        assert!(client.list_module_resources(&module.id).is_empty());
+    }
+}
@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Env, String, Symbol, Vec};
+
+use crate::schema::{ContentType, CourseModule, DataKey};
+
+const MODULE_KEY: Symbol = symbol_short!("module");
+const POSITION_KEY: Symbol = symbol_short!("pos");
+
+/// Returns the IDs of all modules registered for a course.
+pub fn get_course_module_ids(env: &Env, course_id: &String) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseModuleIds(course_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Adds a newly created module to the course module indexes.
+pub fn index_module(env: &Env, module: &CourseModule) {
+    let key: DataKey = DataKey::CourseModuleIds(module.course_id.clone());
+    let mut ids: Vec<String> = get_course_module_ids(env, &module.course_id);
+    if !ids.contains(&module.id) {
+        ids.push_back(module.id.clone());
+        env.storage().persistent().set(&key, &ids);
+    }
+
+    add_to_type_index(env, &module.course_id, &module.content_type, &module.id);
+}
+
+/// Removes a module from every course module index.
+pub fn unindex_module(env: &Env, module: &CourseModule) {
+    let key: DataKey = DataKey::CourseModuleIds(module.course_id.clone());
+    let mut ids: Vec<String> = get_course_module_ids(env, &module.course_id);
+    if let Some(index) = ids.first_index_of(&module.id) {
+        ids.remove(index);
+        env.storage().persistent().set(&key, &ids);
+    }
+
+    remove_from_type_index(env, &module.course_id, &module.content_type, &module.id);
+}
+
+/// Moves a module between content type buckets after its type changed.
+pub fn reindex_module_type(env: &Env, module: &CourseModule, old_type: &ContentType) {
+    if *old_type == module.content_type {
+        return;
+    }
+    remove_from_type_index(env, &module.course_id, old_type, &module.id);
+    add_to_type_index(env, &module.course_id, &module.content_type, &module.id);
+}
+
+/// Assigns contiguous zero-based positions to `modules` in the given order.
+///
+/// All modules must belong to `course_id`. Position reservations are rewritten
+/// so later `add_module` calls still detect duplicate positions.
+pub fn renumber_modules(env: &Env, course_id: &String, modules: &Vec<CourseModule>) {
+    for module in modules.iter() {
+        env.storage()
+            .persistent()
+            .remove(&(POSITION_KEY, course_id.clone(), module.position));
+    }
+
+    for (index, mut module) in modules.iter().enumerate() {
+        module.position = index as u32;
+        env.storage()
+            .persistent()
+            .set(&(POSITION_KEY, course_id.clone(), module.position), &true);
+        env.storage()
+            .persistent()
+            .set(&(MODULE_KEY, module.id.clone()), &module);
+    }
+}
+
+/// Drops all module indexes of a course.
+pub fn clear_course_module_indexes(env: &Env, course_id: &String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CourseModuleIds(course_id.clone()));
+    for content_type in [
+        ContentType::Unspecified,
+        ContentType::Video,
+        ContentType::Article,
+        ContentType::Quiz,
+        ContentType::Assignment,
+    ] {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CourseModulesByType(course_id.clone(), content_type));
+    }
+}
+
+fn add_to_type_index(env: &Env, course_id: &String, content_type: &ContentType, module_id: &String) {
+    let key: DataKey = DataKey::CourseModulesByType(course_id.clone(), content_type.clone());
+    let mut ids: Vec<String> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+    if !ids.contains(module_id) {
+        ids.push_back(module_id.clone());
+        env.storage().persistent().set(&key, &ids);
+    }
+}
+
+fn remove_from_type_index(
+    env: &Env,
+    course_id: &String,
+    content_type: &ContentType,
+    module_id: &String,
+) {
+    let key: DataKey = DataKey::CourseModulesByType(course_id.clone(), content_type.clone());
+    let mut ids: Vec<String> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+    if let Some(index) = ids.first_index_of(module_id) {
+        ids.remove(index);
+        env.storage().persistent().set(&key, &ids);
+    }
+}
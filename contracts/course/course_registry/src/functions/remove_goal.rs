@@ -85,6 +85,7 @@ mod test {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
 
         // Add a goal first
@@ -96,7 +97,7 @@ mod test {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
     fn test_remove_goal_unauthorized() {
         let env = Env::default();
         env.mock_all_auths();
@@ -118,6 +119,7 @@ mod test {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
 
         // Add a goal
@@ -166,6 +168,7 @@ mod test {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
 
         let fake_goal_id = String::from_str(&env, "nonexistent_goal");
@@ -173,7 +176,7 @@ mod test {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #19)")]
+    #[should_panic(expected = "HostError: Error(Contract, #119)")]
     fn test_remove_goal_empty_goal_id() {
         let env = Env::default();
         env.mock_all_auths();
@@ -194,6 +197,7 @@ mod test {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
 
         let empty_goal_id = String::from_str(&env, "");
@@ -221,6 +225,7 @@ mod test {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
 
         // Add multiple goals
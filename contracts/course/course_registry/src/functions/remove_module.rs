@@ -4,6 +4,8 @@
 use soroban_sdk::{symbol_short, Env, String, Symbol};
 
 use crate::error::{handle_error, Error};
+use crate::functions::module_resources::clear_module_resources;
+use crate::functions::module_utils::unindex_module;
 use crate::schema::CourseModule;
 
 const MODULE_KEY: Symbol = symbol_short!("module");
@@ -22,14 +24,17 @@ pub fn remove_module(env: &Env, module_id: String) -> Result<(), &'static str> {
         .get(&(MODULE_KEY, module_id.clone()));
 
     // Validate that the module exists and is a valid CourseModule
-    if module.is_none() {
-        handle_error(env, Error::ModuleNotFound)
-    }
+    let module: CourseModule = match module {
+        Some(module) => module,
+        None => handle_error(env, Error::ModuleNotFound),
+    };
 
     // Delete the CourseModule directly from persistent storage using its key.
     env.storage()
         .persistent()
         .remove(&(MODULE_KEY, module_id.clone()));
+    unindex_module(env, &module);
+    clear_module_resources(env, &module_id);
 
     // Emits an event to indicate the module has been removed.
     env.events().publish((REMOVE_MODULE_EVENT,), module_id);
@@ -40,7 +45,7 @@ pub fn remove_module(env: &Env, module_id: String) -> Result<(), &'static str> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{schema::Course, CourseRegistry, CourseRegistryClient};
+    use crate::{schema::{ContentType, Course}, CourseRegistry, CourseRegistryClient};
     use soroban_sdk::{testutils::Address as _, Address, Env};
 
     // Mock UserManagement contract for testing
@@ -92,12 +97,15 @@ mod tests {
             &Some(String::from_str(&env, "thumbnail_url")),
             &None,
             &None,
+            &None,
         );
         let new_module = client.add_module(
             &creator,
             &course.id,
             &0,
             &String::from_str(&env, "Module Title"),
+            &ContentType::Unspecified,
+            &None,
         );
 
         let exists: bool = env.as_contract(&contract_id, || {
@@ -117,7 +125,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #22)")]
+    #[should_panic(expected = "HostError: Error(Contract, #122)")]
     fn test_remove_module_with_empty_id() {
         let env = Env::default();
         env.mock_all_auths();
@@ -129,7 +137,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #21)")]
+    #[should_panic(expected = "HostError: Error(Contract, #121)")]
     fn test_remove_module_not_found() {
         let env = Env::default();
         env.mock_all_auths();
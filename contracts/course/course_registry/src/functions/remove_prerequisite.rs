@@ -4,6 +4,7 @@
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
 
 use crate::error::{handle_error, Error};
+use crate::functions::get_dependent_courses::save_course_prerequisites;
 use crate::schema::{Course, DataKey};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
@@ -51,10 +52,7 @@ pub fn remove_prerequisite(
     }
 
     // Save updated prerequisites
-    env.storage().persistent().set(
-        &DataKey::CoursePrerequisites(course_id.clone()),
-        &prerequisites,
-    );
+    save_course_prerequisites(&env, &course_id, &prerequisites);
 
     // Emits an event for successful prerequisite removal.
     env.events()
@@ -88,6 +86,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let course2: Course = client.create_course(
@@ -100,6 +99,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let prerequisites = SdkVec::from_array(&env, [course2.id.clone()]);
@@ -126,7 +126,7 @@ mod test {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
     fn test_remove_prerequisite_unauthorized() {
         let env = Env::default();
         env.mock_all_auths();
@@ -147,6 +147,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let course2: Course = client.create_course(
@@ -159,6 +160,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let prerequisites = SdkVec::from_array(&env, [course2.id.clone()]);
@@ -184,7 +186,7 @@ mod test {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #23)")]
+    #[should_panic(expected = "HostError: Error(Contract, #123)")]
     fn test_remove_prerequisite_not_in_list() {
         let env = Env::default();
         env.mock_all_auths();
@@ -204,6 +206,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let course2: Course = client.create_course(
@@ -216,6 +219,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         client.remove_prerequisite(&creator, &course1.id, &course2.id);
@@ -241,6 +245,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let course2: Course = client.create_course(
@@ -253,6 +258,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let course3: Course = client.create_course(
@@ -265,6 +271,7 @@ mod test {
             &None,
             &None,
             &None,
+            &None,
         );
 
         let prerequisites = SdkVec::from_array(&env, [course2.id.clone(), course3.id.clone()]);
@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::module_utils::{get_course_module_ids, renumber_modules};
+use crate::schema::{Course, CourseModule};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+const MODULES_REORDERED_EVENT: &str = "ModulesReordered";
+
+/// Reorders all modules of a course (course creator only).
+///
+/// Each module's `position` becomes its index in `module_order`.
+///
+/// # Arguments
+///
+/// * `env` - Soroban environment.
+/// * `creator` - Address of the course creator.
+/// * `course_id` - ID of the course whose modules are reordered.
+/// * `module_order` - Every module ID of the course, in the desired order.
+///
+/// # Panics
+///
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::Unauthorized` if the caller is not the course creator.
+/// * `Error::ModuleOrderMismatch` if `module_order` does not contain exactly
+///   the course's module IDs.
+pub fn course_registry_reorder_modules(
+    env: Env,
+    creator: Address,
+    course_id: String,
+    module_order: Vec<String>,
+) {
+    creator.require_auth();
+
+    let course: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id.clone()))
+        .unwrap_or_else(|| handle_error(&env, Error::CourseNotFound));
+
+    if course.creator != creator {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    let current_ids: Vec<String> = get_course_module_ids(&env, &course_id);
+    if module_order.len() != current_ids.len() {
+        handle_error(&env, Error::ModuleOrderMismatch)
+    }
+
+    let mut modules: Vec<CourseModule> = Vec::new(&env);
+    for (index, module_id) in module_order.iter().enumerate() {
+        // Rejects unknown IDs as well as duplicates, which would leave a module out
+        if !current_ids.contains(&module_id)
+            || module_order.first_index_of(&module_id) != Some(index as u32)
+        {
+            handle_error(&env, Error::ModuleOrderMismatch)
+        }
+        let module: CourseModule = env
+            .storage()
+            .persistent()
+            .get(&(MODULE_KEY, module_id))
+            .unwrap_or_else(|| handle_error(&env, Error::ModuleNotFound));
+        modules.push_back(module);
+    }
+
+    renumber_modules(&env, &course_id, &modules);
+
+    env.events().publish(
+        (Symbol::new(&env, MODULES_REORDERED_EVENT), course_id),
+        (creator, module_order),
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::schema::{ContentType, Course, CourseModule};
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Address, Course) {
+        let (env, client, _mocks) = setup_with_mocks();
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (env, client, creator, course)
+    }
+
+    fn add_modules(
+        env: &Env,
+        client: &CourseRegistryClient,
+        creator: &Address,
+        course: &Course,
+    ) -> (CourseModule, CourseModule, CourseModule) {
+        (
+            client.add_module(creator, &course.id, &1, &String::from_str(env, "Intro"), &ContentType::Unspecified, &None),
+            client.add_module(creator, &course.id, &2, &String::from_str(env, "Basics"), &ContentType::Unspecified, &None),
+            client.add_module(creator, &course.id, &3, &String::from_str(env, "Advanced"), &ContentType::Unspecified, &None),
+        )
+    }
+
+    #[test]
+    fn test_reorder_modules() {
+        let (env, client, creator, course) = setup();
+        let (intro, basics, advanced) = add_modules(&env, &client, &creator, &course);
+
+        let order = vec![
+            &env,
+            advanced.id.clone(),
+            intro.id.clone(),
+            basics.id.clone(),
+        ];
+        client.reorder_modules(&creator, &course.id, &order);
+
+        let modules = client.get_modules_by_type(&course.id, &ContentType::Unspecified);
+        let position_of = |id: &String| {
+            modules
+                .iter()
+                .find(|module| module.id == *id)
+                .unwrap()
+                .position
+        };
+        assert_eq!(position_of(&advanced.id), 0);
+        assert_eq!(position_of(&intro.id), 1);
+        assert_eq!(position_of(&basics.id), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #160)")]
+    fn test_reorder_modules_missing_id() {
+        let (env, client, creator, course) = setup();
+        let (intro, basics, _advanced) = add_modules(&env, &client, &creator, &course);
+
+        let order = vec![&env, basics.id, intro.id];
+        client.reorder_modules(&creator, &course.id, &order);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #160)")]
+    fn test_reorder_modules_extra_id() {
+        let (env, client, creator, course) = setup();
+        let (intro, basics, advanced) = add_modules(&env, &client, &creator, &course);
+
+        let order = vec![
+            &env,
+            intro.id,
+            basics.id,
+            advanced.id,
+            String::from_str(&env, "module_spurious"),
+        ];
+        client.reorder_modules(&creator, &course.id, &order);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_reorder_modules_unauthorized() {
+        let (env, client, creator, course) = setup();
+        let (intro, basics, advanced) = add_modules(&env, &client, &creator, &course);
+
+        let stranger = Address::generate(&env);
+        let order = vec![&env, advanced.id, basics.id, intro.id];
+        client.reorder_modules(&stranger, &course.id, &order);
+    }
+}
@@ -0,0 +1,205 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Env, String, Vec};
+
+use crate::functions::utils::{paginate_course_ids, to_lowercase};
+use crate::schema::{CourseListPage, DataKey};
+
+/// Minimum length of an indexed title word; shorter words are skipped
+pub const MIN_TITLE_WORD_LENGTH: usize = 3;
+/// Maximum length of a search query, matching the course title limit
+pub const MAX_SEARCH_QUERY_LENGTH: u32 = 200;
+
+/// Splits `text` into its distinct lowercase words of at least
+/// `MIN_TITLE_WORD_LENGTH` characters, in order of appearance.
+fn title_words(env: &Env, text: &String) -> Vec<String> {
+    let lowercase: String = to_lowercase(env, text);
+    let len: usize = lowercase.len() as usize;
+    let mut buffer: [u8; 1024] = [0u8; 1024];
+    lowercase.copy_into_slice(&mut buffer[..len]);
+
+    let mut words: Vec<String> = Vec::new(env);
+    for word in buffer[..len].split(|byte| *byte == b' ' || *byte == b'\t') {
+        if word.len() < MIN_TITLE_WORD_LENGTH {
+            continue;
+        }
+        let word: String = String::from_bytes(env, word);
+        if !words.contains(&word) {
+            words.push_back(word);
+        }
+    }
+    words
+}
+
+fn get_word_course_ids(env: &Env, word: &String) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TitleWord(word.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Adds a course to the index of every word of its title.
+pub fn index_course_title(env: &Env, course_id: &String, title: &String) {
+    for word in title_words(env, title).iter() {
+        let mut ids: Vec<String> = get_word_course_ids(env, &word);
+        if !ids.contains(course_id) {
+            ids.push_back(course_id.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::TitleWord(word), &ids);
+        }
+    }
+}
+
+/// Removes a course from the index of every word of its title.
+pub fn unindex_course_title(env: &Env, course_id: &String, title: &String) {
+    for word in title_words(env, title).iter() {
+        let mut ids: Vec<String> = get_word_course_ids(env, &word);
+        let Some(index) = ids.first_index_of(course_id) else {
+            continue;
+        };
+        ids.remove(index);
+        if ids.is_empty() {
+            env.storage().persistent().remove(&DataKey::TitleWord(word));
+        } else {
+            env.storage()
+                .persistent()
+                .set(&DataKey::TitleWord(word), &ids);
+        }
+    }
+}
+
+/// Lists the courses whose title contains every word of `query`, ignoring
+/// case, with offset-based pagination.
+///
+/// Query words shorter than `MIN_TITLE_WORD_LENGTH` are ignored. A query
+/// without any searchable word, or longer than `MAX_SEARCH_QUERY_LENGTH`,
+/// matches nothing.
+pub fn course_registry_search_courses_by_title(
+    env: Env,
+    query: String,
+    offset: u32,
+    limit: u32,
+) -> CourseListPage {
+    let mut ids: Vec<String> = Vec::new(&env);
+    if query.len() <= MAX_SEARCH_QUERY_LENGTH {
+        let words: Vec<String> = title_words(&env, &query);
+        if let Some(first) = words.first() {
+            ids = get_word_course_ids(&env, &first);
+            for word in words.iter().skip(1) {
+                let word_ids: Vec<String> = get_word_course_ids(&env, &word);
+                let mut matching: Vec<String> = Vec::new(&env);
+                for course_id in ids.iter() {
+                    if word_ids.contains(&course_id) {
+                        matching.push_back(course_id);
+                    }
+                }
+                ids = matching;
+            }
+        }
+    }
+
+    paginate_course_ids(&env, &ids, offset, limit)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{Course, EditCourseParams};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        (env, client)
+    }
+
+    fn create_course(env: &Env, client: &CourseRegistryClient, title: &str) -> Course {
+        client.create_course(
+            &Address::generate(env),
+            &String::from_str(env, title),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    fn search(env: &Env, client: &CourseRegistryClient, query: &str) -> u32 {
+        client
+            .search_courses_by_title(&String::from_str(env, query), &0, &10)
+            .total
+    }
+
+    #[test]
+    fn test_search_single_word() {
+        let (env, client) = setup();
+        let rust = create_course(&env, &client, "Intro to Rust");
+        create_course(&env, &client, "Advanced Rust Patterns");
+        create_course(&env, &client, "Solidity Basics");
+
+        let page = client.search_courses_by_title(&String::from_str(&env, "RUST"), &0, &1);
+        assert_eq!(page.total, 2);
+        assert_eq!(page.courses.get(0).unwrap(), rust);
+        assert!(page.has_more);
+
+        // Words under three characters are not indexed
+        assert_eq!(search(&env, &client, "to"), 0);
+        assert_eq!(search(&env, &client, "python"), 0);
+    }
+
+    #[test]
+    fn test_search_multiple_words() {
+        let (env, client) = setup();
+        create_course(&env, &client, "Intro to Rust");
+        let advanced = create_course(&env, &client, "Advanced Rust Patterns");
+        create_course(&env, &client, "Advanced Solidity");
+
+        let page = client.search_courses_by_title(&String::from_str(&env, "rust advanced"), &0, &10);
+        assert_eq!(page.total, 1);
+        assert_eq!(page.courses.get(0).unwrap().id, advanced.id);
+        assert_eq!(search(&env, &client, "advanced python"), 0);
+    }
+
+    #[test]
+    fn test_title_update_reindexes_words() {
+        let (env, client) = setup();
+        let course = create_course(&env, &client, "Intro to Rust");
+        let params = EditCourseParams {
+            new_title: Some(String::from_str(&env, "Intro to Solidity")),
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_level: None,
+            new_duration_hours: None,
+        };
+
+        client.edit_course(&course.creator, &course.id, &params);
+
+        assert_eq!(search(&env, &client, "rust"), 0);
+        assert_eq!(search(&env, &client, "solidity"), 1);
+        assert_eq!(search(&env, &client, "intro"), 1);
+    }
+
+    #[test]
+    fn test_delete_course_removes_words() {
+        let (env, client) = setup();
+        let course = create_course(&env, &client, "Intro to Rust");
+        create_course(&env, &client, "Rust for Experts");
+
+        client.delete_course(&course.creator, &course.id);
+
+        assert_eq!(search(&env, &client, "intro"), 0);
+        assert_eq!(search(&env, &client, "rust"), 1);
+    }
+}
@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::require_course_management_auth;
+use crate::functions::get_courses_updated_after::record_course_update;
+use crate::schema::Course;
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const COURSE_FREE_SET_EVENT: &str = "CourseFreeSet";
+
+/// Marks a course as free or paid (course creator or admin).
+///
+/// Free courses keep their listed price, but users can enroll in them on
+/// their own through course_access `self_enroll`. Setting the current value
+/// again changes nothing.
+///
+/// # Panics
+///
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::Unauthorized` if the caller is neither the creator nor an admin.
+pub fn course_registry_set_course_free(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    is_free: bool,
+) -> Course {
+    let key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    let mut course: Course = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| handle_error(&env, Error::CourseNotFound));
+
+    require_course_management_auth(&env, &caller, &course_id);
+
+    if course.is_free == is_free {
+        return course;
+    }
+    course.is_free = is_free;
+    env.storage().persistent().set(&key, &course);
+    record_course_update(&env, &course_id);
+
+    env.events().publish(
+        (Symbol::new(&env, COURSE_FREE_SET_EVENT), course_id),
+        (is_free, caller),
+    );
+
+    course
+}
+
+/// Returns whether a course exists and is free.
+pub fn course_registry_is_course_free(env: Env, course_id: String) -> bool {
+    env.storage()
+        .persistent()
+        .get::<_, Course>(&(COURSE_KEY, course_id))
+        .is_some_and(|course| course.is_free)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::Course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Course) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let course = client.create_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "Rust"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (env, client, course)
+    }
+
+    #[test]
+    fn test_set_course_free_and_paid() {
+        let (_env, client, course) = setup();
+        assert!(!course.is_free);
+
+        let free = client.set_course_free(&course.creator, &course.id, &true);
+        assert!(free.is_free);
+        assert_eq!(free.price, 1000);
+        assert!(client.is_course_free(&course.id));
+
+        client.set_course_free(&course.creator, &course.id, &false);
+        assert!(!client.is_course_free(&course.id));
+    }
+
+    #[test]
+    fn test_is_course_free_missing_course() {
+        let (env, client, _course) = setup();
+        assert!(!client.is_course_free(&String::from_str(&env, "missing")));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_set_course_free_unauthorized() {
+        let (env, client, course) = setup();
+        client.set_course_free(&Address::generate(&env), &course.id, &true);
+    }
+}
@@ -0,0 +1,186 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::require_course_management_auth;
+use crate::functions::get_courses_updated_after::record_course_update;
+use crate::schema::{Course, DataKey, PriceChange};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const PRICE_CHANGED_EVENT: &str = "PriceChanged";
+
+/// Maximum number of price changes kept per course; older ones are dropped
+pub const MAX_PRICE_HISTORY: u32 = 10;
+
+/// Appends a price change to the history of a course, dropping the oldest
+/// entry once `MAX_PRICE_HISTORY` is reached. Unchanged prices are not recorded.
+pub fn record_price_change(
+    env: &Env,
+    course_id: &String,
+    old_price: u128,
+    new_price: u128,
+    changed_by: &Address,
+) {
+    if old_price == new_price {
+        return;
+    }
+
+    let mut history: Vec<PriceChange> =
+        course_registry_get_price_history(env.clone(), course_id.clone());
+    while history.len() >= MAX_PRICE_HISTORY {
+        history.pop_front();
+    }
+    history.push_back(PriceChange {
+        old_price,
+        new_price,
+        changed_at: env.ledger().timestamp(),
+        changed_by: changed_by.clone(),
+    });
+    env.storage()
+        .persistent()
+        .set(&DataKey::PriceHistory(course_id.clone()), &history);
+}
+
+/// Removes the price history of a deleted course.
+pub fn clear_price_history(env: &Env, course_id: &String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PriceHistory(course_id.clone()));
+}
+
+/// Changes the price of a course (course creator or admin).
+///
+/// # Panics
+///
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::Unauthorized` if the caller is neither the creator nor an admin.
+/// * `Error::InvalidPrice` if the new price is zero.
+pub fn course_registry_set_course_price(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    new_price: u128,
+) -> Course {
+    let storage_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    let mut course: Course = env
+        .storage()
+        .persistent()
+        .get(&storage_key)
+        .unwrap_or_else(|| handle_error(&env, Error::CourseNotFound));
+
+    require_course_management_auth(&env, &caller, &course_id);
+
+    if new_price == 0 {
+        handle_error(&env, Error::InvalidPrice)
+    }
+
+    let old_price: u128 = course.price;
+    if old_price == new_price {
+        return course;
+    }
+
+    course.price = new_price;
+    env.storage().persistent().set(&storage_key, &course);
+    record_course_update(&env, &course_id);
+    record_price_change(&env, &course_id, old_price, new_price, &caller);
+
+    env.events().publish(
+        (Symbol::new(&env, PRICE_CHANGED_EVENT), course_id),
+        (old_price, new_price, caller),
+    );
+
+    course
+}
+
+/// Returns the recorded price changes of a course, oldest first.
+pub fn course_registry_get_price_history(env: Env, course_id: String) -> Vec<PriceChange> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PriceHistory(course_id))
+        .unwrap_or(Vec::new(&env))
+}
+
+#[cfg(test)]
+mod test {
+    use super::MAX_PRICE_HISTORY;
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::schema::Course;
+    use crate::CourseRegistryClient;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Course) {
+        let (env, client, _mocks) = setup_with_mocks();
+        env.ledger().set_timestamp(1_000);
+
+        let course = client.create_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "Rust"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (env, client, course)
+    }
+
+    #[test]
+    fn test_set_course_price() {
+        let (_env, client, course) = setup();
+
+        let updated = client.set_course_price(&course.creator, &course.id, &1500);
+
+        assert_eq!(updated.price, 1500);
+        assert_eq!(client.get_course(&course.id).price, 1500);
+        let history = client.get_price_history(&course.id);
+        assert_eq!(history.len(), 1);
+        let change = history.get(0).unwrap();
+        assert_eq!((change.old_price, change.new_price), (1000, 1500));
+        assert_eq!(change.changed_at, 1_000);
+        assert_eq!(change.changed_by, course.creator);
+    }
+
+    #[test]
+    fn test_price_history_accumulates() {
+        let (env, client, course) = setup();
+        client.set_course_price(&course.creator, &course.id, &1500);
+        env.ledger().set_timestamp(2_000);
+        client.set_course_price(&course.creator, &course.id, &1200);
+        // Setting the current price again is not recorded
+        client.set_course_price(&course.creator, &course.id, &1200);
+
+        let history = client.get_price_history(&course.id);
+        assert_eq!(history.len(), 2);
+        let latest = history.get(1).unwrap();
+        assert_eq!((latest.old_price, latest.new_price), (1500, 1200));
+        assert_eq!(latest.changed_at, 2_000);
+    }
+
+    #[test]
+    fn test_price_history_cap() {
+        let (_env, client, course) = setup();
+        for price in 1..=12_u128 {
+            client.set_course_price(&course.creator, &course.id, &(2000 + price));
+        }
+
+        let history = client.get_price_history(&course.id);
+        assert_eq!(history.len(), MAX_PRICE_HISTORY);
+        // The two oldest changes were evicted
+        assert_eq!(history.get(0).unwrap().old_price, 2002);
+        assert_eq!(history.last().unwrap().new_price, 2012);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_set_course_price_unauthorized() {
+        let (env, client, course) = setup();
+        client.set_course_price(&Address::generate(&env), &course.id, &1500);
+    }
+}
@@ -0,0 +1,204 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::require_course_management_auth;
+use crate::functions::get_courses_updated_after::record_course_update;
+use crate::schema::{Course, DataKey};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const COURSE_SCHEDULED_EVENT: &str = "CourseScheduled";
+
+/// Schedules a live cohort course (course creator or admin).
+///
+/// Rescheduling replaces the previous dates. The course is kept in
+/// `DataKey::ScheduledCourses`, ordered by start time.
+///
+/// # Panics
+///
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::Unauthorized` if the caller is neither the creator nor an admin.
+/// * `Error::InvalidSchedule` if the start is not in the future or not before the end.
+pub fn course_registry_set_course_schedule(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    start_timestamp: u64,
+    end_timestamp: u64,
+) -> Course {
+    let storage_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    let mut course: Course = env
+        .storage()
+        .persistent()
+        .get(&storage_key)
+        .unwrap_or_else(|| handle_error(&env, Error::CourseNotFound));
+
+    // Requires the caller's auth and checks creator or admin rights
+    require_course_management_auth(&env, &caller, &course_id);
+
+    if start_timestamp >= end_timestamp || start_timestamp <= env.ledger().timestamp() {
+        handle_error(&env, Error::InvalidSchedule)
+    }
+
+    course.start_timestamp = Some(start_timestamp);
+    course.end_timestamp = Some(end_timestamp);
+    env.storage().persistent().set(&storage_key, &course);
+
+    let mut scheduled: Vec<(u64, String)> = remove_entry(&env, &course_id);
+    let position: u32 = scheduled
+        .iter()
+        .position(|(start, _)| start > start_timestamp)
+        .map(|index| index as u32)
+        .unwrap_or(scheduled.len());
+    scheduled.insert(position, (start_timestamp, course_id.clone()));
+    env.storage()
+        .persistent()
+        .set(&DataKey::ScheduledCourses, &scheduled);
+
+    record_course_update(&env, &course_id);
+
+    env.events().publish(
+        (Symbol::new(&env, COURSE_SCHEDULED_EVENT), course_id),
+        (start_timestamp, end_timestamp),
+    );
+
+    course
+}
+
+/// Drops a deleted course from the schedule.
+pub fn remove_from_schedule(env: &Env, course_id: &String) {
+    let scheduled: Vec<(u64, String)> = remove_entry(env, course_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::ScheduledCourses, &scheduled);
+}
+
+fn remove_entry(env: &Env, course_id: &String) -> Vec<(u64, String)> {
+    let mut scheduled: Vec<(u64, String)> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ScheduledCourses)
+        .unwrap_or(Vec::new(env));
+
+    if let Some(index) = scheduled.iter().position(|(_, id)| id == *course_id) {
+        scheduled.remove(index as u32);
+    }
+
+    scheduled
+}
+
+/// Returns up to `limit` courses starting at or after `from_timestamp`, earliest first.
+pub fn course_registry_get_upcoming_courses(env: Env, from_timestamp: u64, limit: u32) -> Vec<Course> {
+    let scheduled: Vec<(u64, String)> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ScheduledCourses)
+        .unwrap_or(Vec::new(&env));
+
+    let mut courses: Vec<Course> = Vec::new(&env);
+    for (start, course_id) in scheduled.iter() {
+        if courses.len() >= limit {
+            break;
+        }
+        if start < from_timestamp {
+            continue;
+        }
+        if let Some(course) = env
+            .storage()
+            .persistent()
+            .get::<_, Course>(&(COURSE_KEY, course_id))
+        {
+            courses.push_back(course);
+        }
+    }
+
+    courses
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::Course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, Env, String};
+
+    fn create_course(client: &CourseRegistryClient, creator: &Address, title: &str) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_000);
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        (env, client, creator)
+    }
+
+    #[test]
+    fn test_set_course_schedule() {
+        let (_, client, creator) = setup();
+        let course = create_course(&client, &creator, "Cohort");
+
+        let updated = client.set_course_schedule(&creator, &course.id, &2_000, &3_000);
+        assert_eq!(updated.start_timestamp, Some(2_000));
+        assert_eq!(updated.end_timestamp, Some(3_000));
+        assert_eq!(client.get_course(&course.id).start_timestamp, Some(2_000));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #172)")]
+    fn test_set_course_schedule_start_in_past() {
+        let (_, client, creator) = setup();
+        let course = create_course(&client, &creator, "Cohort");
+
+        client.set_course_schedule(&creator, &course.id, &500, &3_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #172)")]
+    fn test_set_course_schedule_end_before_start() {
+        let (_, client, creator) = setup();
+        let course = create_course(&client, &creator, "Cohort");
+
+        client.set_course_schedule(&creator, &course.id, &3_000, &2_000);
+    }
+
+    #[test]
+    fn test_get_upcoming_courses() {
+        let (_, client, creator) = setup();
+        let late = create_course(&client, &creator, "Late");
+        let early = create_course(&client, &creator, "Early");
+        let middle = create_course(&client, &creator, "Middle");
+        client.set_course_schedule(&creator, &late.id, &5_000, &6_000);
+        client.set_course_schedule(&creator, &early.id, &2_000, &3_000);
+        client.set_course_schedule(&creator, &middle.id, &9_000, &9_500);
+        // Rescheduling moves the course to its new position
+        client.set_course_schedule(&creator, &middle.id, &4_000, &4_500);
+
+        let upcoming = client.get_upcoming_courses(&0, &10);
+        assert_eq!(upcoming.len(), 3);
+        assert_eq!(upcoming.get(0).unwrap().id, early.id);
+        assert_eq!(upcoming.get(1).unwrap().id, middle.id);
+        assert_eq!(upcoming.get(2).unwrap().id, late.id);
+
+        let later = client.get_upcoming_courses(&3_000, &1);
+        assert_eq!(later.len(), 1);
+        assert_eq!(later.get(0).unwrap().id, middle.id);
+    }
+}
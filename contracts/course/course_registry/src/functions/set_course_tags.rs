@@ -0,0 +1,246 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::get_courses_updated_after::record_course_update;
+use crate::functions::set_course_visibility::{filter_browsable_course_ids, ListingViewer};
+use crate::functions::utils::paginate_course_ids;
+use crate::schema::{Course, CourseListPage, DataKey};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const COURSE_TAGS_UPDATED_EVENT: &str = "CourseTagsUpdated";
+
+/// Maximum number of tags per course
+pub const MAX_TAGS_PER_COURSE: u32 = 10;
+/// Maximum length of a single tag
+pub const MAX_TAG_LENGTH: u32 = 30;
+
+fn get_tag_course_ids(env: &Env, tag: &String) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TagCourses(tag.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Adds a course to the index of a tag.
+pub fn add_to_tag_index(env: &Env, tag: &String, course_id: &String) {
+    let mut ids: Vec<String> = get_tag_course_ids(env, tag);
+    if !ids.contains(course_id) {
+        ids.push_back(course_id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::TagCourses(tag.clone()), &ids);
+    }
+}
+
+fn remove_from_tag_index(env: &Env, tag: &String, course_id: &String) {
+    let mut ids: Vec<String> = get_tag_course_ids(env, tag);
+    if let Some(index) = ids.first_index_of(course_id) {
+        ids.remove(index);
+        if ids.is_empty() {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::TagCourses(tag.clone()));
+        } else {
+            env.storage()
+                .persistent()
+                .set(&DataKey::TagCourses(tag.clone()), &ids);
+        }
+    }
+}
+
+/// Removes a course from the index of every tag it carries.
+pub fn clear_course_tags(env: &Env, course: &Course) {
+    for tag in course.tags.iter() {
+        remove_from_tag_index(env, &tag, &course.id);
+    }
+}
+
+/// Replaces the tags of a course (course creator only).
+///
+/// # Panics
+///
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::Unauthorized` if the caller is not the course creator.
+/// * `Error::TooManyTags` if more than `MAX_TAGS_PER_COURSE` tags are given.
+/// * `Error::InvalidTag` if a tag is empty or longer than `MAX_TAG_LENGTH`.
+/// * `Error::DuplicateTag` if a tag appears more than once.
+pub fn course_registry_set_course_tags(
+    env: Env,
+    creator: Address,
+    course_id: String,
+    tags: Vec<String>,
+) {
+    creator.require_auth();
+
+    let storage_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    let mut course: Course = env
+        .storage()
+        .persistent()
+        .get(&storage_key)
+        .unwrap_or_else(|| handle_error(&env, Error::CourseNotFound));
+
+    if course.creator != creator {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    if tags.len() > MAX_TAGS_PER_COURSE {
+        handle_error(&env, Error::TooManyTags)
+    }
+
+    for (index, tag) in tags.iter().enumerate() {
+        if tag.is_empty() || tag.len() > MAX_TAG_LENGTH {
+            handle_error(&env, Error::InvalidTag)
+        }
+        if tags.first_index_of(&tag) != Some(index as u32) {
+            handle_error(&env, Error::DuplicateTag)
+        }
+    }
+
+    clear_course_tags(&env, &course);
+    for tag in tags.iter() {
+        add_to_tag_index(&env, &tag, &course_id);
+    }
+
+    course.tags = tags;
+    env.storage().persistent().set(&storage_key, &course);
+    record_course_update(&env, &course_id);
+
+    env.events().publish(
+        (Symbol::new(&env, COURSE_TAGS_UPDATED_EVENT), course_id),
+        (creator, course.tags),
+    );
+}
+
+/// Lists the courses carrying a tag with offset-based pagination.
+///
+/// Only public courses are listed, except to a `viewer` who created the
+/// course or is an admin.
+pub fn course_registry_get_courses_by_tag(
+    env: Env,
+    tag: String,
+    offset: u32,
+    limit: u32,
+    viewer: Option<Address>,
+) -> CourseListPage {
+    let ids: Vec<String> = filter_browsable_course_ids(
+        &env,
+        &get_tag_course_ids(&env, &tag),
+        &ListingViewer::new(&env, viewer),
+    );
+    paginate_course_ids(&env, &ids, offset, limit)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::Course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+    fn create_course(client: &CourseRegistryClient, creator: &Address, title: &str) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    fn tags(env: &Env, values: &[&str]) -> Vec<String> {
+        let mut tags = Vec::new(env);
+        for value in values {
+            tags.push_back(String::from_str(env, value));
+        }
+        tags
+    }
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        (env, client, creator)
+    }
+
+    #[test]
+    fn test_set_and_query_tags() {
+        let (env, client, creator) = setup();
+        let rust = create_course(&client, &creator, "Rust");
+        let go = create_course(&client, &creator, "Go");
+
+        client.set_course_tags(&creator, &rust.id, &tags(&env, &["systems", "rust"]));
+        client.set_course_tags(&creator, &go.id, &tags(&env, &["systems", "go"]));
+
+        assert_eq!(client.get_course(&rust.id).tags, tags(&env, &["systems", "rust"]));
+
+        let systems = client.get_courses_by_tag(&String::from_str(&env, "systems"), &0, &10, &None);
+        assert_eq!(systems.total, 2);
+        assert_eq!(systems.courses.get(0).unwrap().id, rust.id);
+        assert_eq!(systems.courses.get(1).unwrap().id, go.id);
+
+        let page = client.get_courses_by_tag(&String::from_str(&env, "systems"), &1, &1, &None);
+        assert_eq!(page.courses.len(), 1);
+        assert!(!page.has_more);
+
+        let unknown = client.get_courses_by_tag(&String::from_str(&env, "unknown"), &0, &10, &None);
+        assert_eq!(unknown.total, 0);
+    }
+
+    #[test]
+    fn test_replace_tags_updates_index() {
+        let (env, client, creator) = setup();
+        let course = create_course(&client, &creator, "Rust");
+
+        client.set_course_tags(&creator, &course.id, &tags(&env, &["beginner", "rust"]));
+        client.set_course_tags(&creator, &course.id, &tags(&env, &["rust", "advanced"]));
+
+        let beginner = client.get_courses_by_tag(&String::from_str(&env, "beginner"), &0, &10, &None);
+        assert_eq!(beginner.total, 0);
+        let advanced = client.get_courses_by_tag(&String::from_str(&env, "advanced"), &0, &10, &None);
+        assert_eq!(advanced.total, 1);
+        let rust = client.get_courses_by_tag(&String::from_str(&env, "rust"), &0, &10, &None);
+        assert_eq!(rust.total, 1);
+
+        client.delete_course(&creator, &course.id);
+        let rust = client.get_courses_by_tag(&String::from_str(&env, "rust"), &0, &10, &None);
+        assert_eq!(rust.total, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #162)")]
+    fn test_too_many_tags() {
+        let (env, client, creator) = setup();
+        let course = create_course(&client, &creator, "Rust");
+
+        let many = tags(&env, &["a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k"]);
+        client.set_course_tags(&creator, &course.id, &many);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #163)")]
+    fn test_duplicate_tags() {
+        let (env, client, creator) = setup();
+        let course = create_course(&client, &creator, "Rust");
+
+        client.set_course_tags(&creator, &course.id, &tags(&env, &["rust", "rust"]));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #164)")]
+    fn test_empty_tag() {
+        let (env, client, creator) = setup();
+        let course = create_course(&client, &creator, "Rust");
+
+        client.set_course_tags(&creator, &course.id, &vec![&env, String::from_str(&env, "")]);
+    }
+}
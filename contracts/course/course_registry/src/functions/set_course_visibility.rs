@@ -0,0 +1,261 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_admin, require_course_management_auth};
+use crate::functions::get_courses_updated_after::record_course_update;
+use crate::schema::{Course, CourseVisibility};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const COURSE_VISIBILITY_CHANGED_EVENT: &str = "CourseVisibilityChanged";
+
+/// Who is looking at a course listing.
+///
+/// Creators see their own courses and admins see every course, whatever
+/// their visibility.
+pub struct ListingViewer {
+    address: Option<Address>,
+    is_admin: bool,
+}
+
+impl ListingViewer {
+    /// Requires the viewer's auth, if one is given, and resolves admin rights
+    /// once for the whole listing.
+    pub fn new(env: &Env, viewer: Option<Address>) -> Self {
+        let is_admin: bool = match &viewer {
+            Some(address) => {
+                address.require_auth();
+                is_admin(env, address)
+            }
+            None => false,
+        };
+        ListingViewer {
+            address: viewer,
+            is_admin,
+        }
+    }
+
+    fn manages(&self, course: &Course) -> bool {
+        self.is_admin || self.address.as_ref() == Some(&course.creator)
+    }
+
+    /// Whether `course` belongs in the general course list, which hides
+    /// private courses.
+    pub fn can_list(&self, course: &Course) -> bool {
+        course.visibility != CourseVisibility::Private || self.manages(course)
+    }
+
+    /// Whether `course` belongs in category and tag listings, which show
+    /// only public courses.
+    pub fn can_browse(&self, course: &Course) -> bool {
+        course.visibility == CourseVisibility::Public || self.manages(course)
+    }
+}
+
+/// Keeps the IDs of the courses `viewer` may see in category and tag
+/// listings.
+pub fn filter_browsable_course_ids(
+    env: &Env,
+    ids: &Vec<String>,
+    viewer: &ListingViewer,
+) -> Vec<String> {
+    let mut visible: Vec<String> = Vec::new(env);
+    for course_id in ids.iter() {
+        if let Some(course) = env
+            .storage()
+            .persistent()
+            .get::<_, Course>(&(COURSE_KEY, course_id.clone()))
+        {
+            if viewer.can_browse(&course) {
+                visible.push_back(course_id);
+            }
+        }
+    }
+    visible
+}
+
+/// Changes who can discover a course through listings (course creator or
+/// admin).
+///
+/// Every course stays reachable through `get_course`; visibility only
+/// affects listings.
+///
+/// # Panics
+///
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::Unauthorized` if the caller is neither the creator nor an admin.
+pub fn course_registry_set_course_visibility(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    visibility: CourseVisibility,
+) {
+    let key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    let mut course: Course = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| handle_error(&env, Error::CourseNotFound));
+
+    require_course_management_auth(&env, &caller, &course_id);
+
+    if course.visibility == visibility {
+        return;
+    }
+    course.visibility = visibility.clone();
+    env.storage().persistent().set(&key, &course);
+    record_course_update(&env, &course_id);
+
+    env.events().publish(
+        (
+            Symbol::new(&env, COURSE_VISIBILITY_CHANGED_EVENT),
+            course_id,
+        ),
+        (caller, visibility),
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::schema::{
+        Course, CourseFilter, CourseSortOrder, CourseVisibility, DataKey, DifficultyFilter,
+    };
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Address, u128) {
+        let (env, client, mocks) = setup_with_mocks();
+        env.as_contract(&client.address, || {
+            // Category management checks the registry's own admin list
+            env.storage()
+                .persistent()
+                .set(&DataKey::Admins, &vec![&env, mocks.admin.clone()]);
+        });
+
+        let category = client.create_course_category(
+            &mocks.admin,
+            &String::from_str(&env, "Programming"),
+            &None,
+        );
+        (env, client, mocks.admin, category)
+    }
+
+    fn create_course(client: &CourseRegistryClient, title: &str) -> Course {
+        let env = &client.env;
+        let course = client.create_course(
+            &Address::generate(env),
+            &String::from_str(env, title),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &Some(String::from_str(env, "Programming")),
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.set_course_tags(
+            &course.creator,
+            &course.id,
+            &vec![env, String::from_str(env, "code")],
+        );
+        course
+    }
+
+    fn all_courses() -> CourseFilter {
+        CourseFilter {
+            category_id: None,
+            difficulty: DifficultyFilter::Any,
+            language: None,
+            max_price: None,
+            published_only: false,
+        }
+    }
+
+    fn list_total(client: &CourseRegistryClient, viewer: &Option<Address>) -> u32 {
+        client
+            .list_courses(
+                &all_courses(),
+                &CourseSortOrder::ByCreationDate,
+                &0,
+                &10,
+                viewer,
+            )
+            .total
+    }
+
+    fn browse_totals(
+        client: &CourseRegistryClient,
+        category: u128,
+        viewer: &Option<Address>,
+    ) -> (u32, u32) {
+        let by_category = client.list_courses_by_category(&category, &0, &10, &false, viewer);
+        let by_tag =
+            client.get_courses_by_tag(&String::from_str(&client.env, "code"), &0, &10, viewer);
+        (by_category.total, by_tag.total)
+    }
+
+    #[test]
+    fn test_public_course_listed_everywhere() {
+        let (_env, client, _admin, category) = setup();
+        let course = create_course(&client, "Rust");
+
+        assert_eq!(course.visibility, CourseVisibility::Public);
+        assert_eq!(list_total(&client, &None), 1);
+        assert_eq!(browse_totals(&client, category, &None), (1, 1));
+    }
+
+    #[test]
+    fn test_unlisted_course_hidden_from_browsing() {
+        let (_env, client, _admin, category) = setup();
+        let course = create_course(&client, "Rust");
+
+        client.set_course_visibility(&course.creator, &course.id, &CourseVisibility::Unlisted);
+
+        assert_eq!(list_total(&client, &None), 1);
+        assert_eq!(browse_totals(&client, category, &None), (0, 0));
+        assert_eq!(
+            browse_totals(&client, category, &Some(course.creator.clone())),
+            (1, 1)
+        );
+        assert_eq!(
+            client.get_course(&course.id).visibility,
+            CourseVisibility::Unlisted
+        );
+    }
+
+    #[test]
+    fn test_private_course_visible_to_creator_and_admin() {
+        let (env, client, admin, category) = setup();
+        let course = create_course(&client, "Rust");
+        create_course(&client, "Go");
+
+        client.set_course_visibility(&admin, &course.id, &CourseVisibility::Private);
+
+        let stranger = Some(Address::generate(&env));
+        assert_eq!(list_total(&client, &None), 1);
+        assert_eq!(list_total(&client, &stranger), 1);
+        assert_eq!(browse_totals(&client, category, &stranger), (1, 1));
+        assert_eq!(list_total(&client, &Some(course.creator.clone())), 2);
+        assert_eq!(list_total(&client, &Some(admin.clone())), 2);
+        assert_eq!(browse_totals(&client, category, &Some(admin)), (2, 2));
+        // Direct lookups ignore visibility
+        assert_eq!(client.get_course(&course.id).id, course.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_set_visibility_unauthorized() {
+        let (env, client, _admin, _category) = setup();
+        let course = create_course(&client, "Rust");
+        client.set_course_visibility(
+            &Address::generate(&env),
+            &course.id,
+            &CourseVisibility::Private,
+        );
+    }
+}
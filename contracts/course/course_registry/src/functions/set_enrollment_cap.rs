@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{
+    get_course_enrollment_count, require_course_management_auth,
+};
+use crate::functions::get_courses_updated_after::record_course_update;
+use crate::schema::Course;
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const ENROLLMENT_CAP_UPDATED_EVENT: &str = "EnrollmentCapUpdated";
+
+/// Sets or clears the enrollment cap of a course (course creator or admin).
+///
+/// `None` removes the cap. The cap is enforced by the course access contract
+/// when access is granted.
+///
+/// # Panics
+///
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::Unauthorized` if the caller is neither the creator nor an admin.
+/// * `Error::CapBelowCurrentEnrollment` if more users are already enrolled than the new cap allows.
+pub fn course_registry_set_course_max_enrollments(
+    env: Env,
+    caller: Address,
+    course_id: String,
+    max_enrollments: Option<u32>,
+) -> Course {
+    let storage_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    let mut course: Course = env
+        .storage()
+        .persistent()
+        .get(&storage_key)
+        .unwrap_or_else(|| handle_error(&env, Error::CourseNotFound));
+
+    // Requires the caller's auth and checks creator or admin rights
+    require_course_management_auth(&env, &caller, &course_id);
+
+    if let Some(cap) = max_enrollments {
+        if cap < get_course_enrollment_count(&env, &course_id) {
+            handle_error(&env, Error::CapBelowCurrentEnrollment)
+        }
+    }
+
+    course.max_enrollments = max_enrollments;
+    env.storage().persistent().set(&storage_key, &course);
+    record_course_update(&env, &course_id);
+
+    env.events().publish(
+        (Symbol::new(&env, ENROLLMENT_CAP_UPDATED_EVENT), course_id),
+        (caller, max_enrollments),
+    );
+
+    course
+}
+
+/// Returns the enrollment cap of a course, or `None` if unlimited or unknown.
+pub fn course_registry_get_course_max_enrollments(env: Env, course_id: String) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get::<_, Course>(&(COURSE_KEY, course_id))
+        .and_then(|course| course.max_enrollments)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::schema::Course;
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    mod mock_course_access {
+        use soroban_sdk::{contract, contractimpl, Env, String};
+
+        #[contract]
+        pub struct CourseAccess;
+
+        #[contractimpl]
+        impl CourseAccess {
+            pub fn set_enrollment_count(env: Env, course_id: String, count: u32) {
+                env.storage().instance().set(&course_id, &count);
+            }
+
+            pub fn get_course_enrollment_count(env: Env, course_id: String) -> u32 {
+                env.storage().instance().get(&course_id).unwrap_or(0)
+            }
+        }
+    }
+
+    fn setup() -> (
+        Env,
+        CourseRegistryClient<'static>,
+        mock_course_access::CourseAccessClient<'static>,
+        Course,
+    ) {
+        let (env, client, mocks) = setup_with_mocks();
+        let course_access_id = env.register(mock_course_access::CourseAccess, ());
+        env.as_contract(&client.address, || {
+            crate::functions::access_control::update_course_access_address(
+                &env,
+                &mocks.admin,
+                &course_access_id,
+            );
+        });
+
+        let course = client.create_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "Rust"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let access = mock_course_access::CourseAccessClient::new(&env, &course_access_id);
+        (env, client, access, course)
+    }
+
+    #[test]
+    fn test_set_course_max_enrollments() {
+        let (_env, client, access, course) = setup();
+        assert_eq!(client.get_course_max_enrollments(&course.id), None);
+
+        access.set_enrollment_count(&course.id, &3);
+        client.set_course_max_enrollments(&course.creator, &course.id, &Some(3));
+        assert_eq!(client.get_course_max_enrollments(&course.id), Some(3));
+        assert_eq!(client.get_course(&course.id).max_enrollments, Some(3));
+
+        client.set_course_max_enrollments(&course.creator, &course.id, &None);
+        assert_eq!(client.get_course_max_enrollments(&course.id), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #168)")]
+    fn test_set_course_max_enrollments_below_enrollment() {
+        let (_env, client, access, course) = setup();
+        access.set_enrollment_count(&course.id, &5);
+
+        client.set_course_max_enrollments(&course.creator, &course.id, &Some(4));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_set_course_max_enrollments_unauthorized() {
+        let (env, client, _access, course) = setup();
+
+        client.set_course_max_enrollments(&Address::generate(&env), &course.id, &Some(10));
+    }
+}
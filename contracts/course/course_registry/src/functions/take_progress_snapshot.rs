@@ -0,0 +1,248 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{
+    get_course_enrollment_count, require_course_management_auth,
+};
+use crate::functions::add_course_review::course_registry_get_average_rating;
+use crate::schema::{DataKey, ProgressSnapshot};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const PROGRESS_SNAPSHOT_EVENT: &str = "ProgressSnapshotTaken";
+
+fn get_snapshot_timestamps(env: &Env, course_id: &String) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SnapshotTimestamps(course_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Records the current enrollment count and average rating of a course
+/// (course creator or admin).
+///
+/// The enrollment count is read from the course access contract. A snapshot
+/// taken at the same ledger timestamp as an earlier one replaces it.
+///
+/// # Panics
+///
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::Unauthorized` if the caller is neither the creator nor an admin.
+pub fn course_registry_take_progress_snapshot(
+    env: Env,
+    caller: Address,
+    course_id: String,
+) -> ProgressSnapshot {
+    if !env
+        .storage()
+        .persistent()
+        .has(&(COURSE_KEY, course_id.clone()))
+    {
+        handle_error(&env, Error::CourseNotFound)
+    }
+
+    require_course_management_auth(&env, &caller, &course_id);
+
+    let snapshot: ProgressSnapshot = ProgressSnapshot {
+        course_id: course_id.clone(),
+        timestamp: env.ledger().timestamp(),
+        total_enrolled: get_course_enrollment_count(&env, &course_id),
+        average_rating: course_registry_get_average_rating(env.clone(), course_id.clone()),
+    };
+
+    env.storage().persistent().set(
+        &DataKey::Snapshot(course_id.clone(), snapshot.timestamp),
+        &snapshot,
+    );
+
+    // Ledger time never decreases, so appending keeps the index sorted
+    let mut timestamps: Vec<u64> = get_snapshot_timestamps(&env, &course_id);
+    if timestamps.last() != Some(snapshot.timestamp) {
+        timestamps.push_back(snapshot.timestamp);
+        env.storage()
+            .persistent()
+            .set(&DataKey::SnapshotTimestamps(course_id.clone()), &timestamps);
+    }
+
+    env.events().publish(
+        (Symbol::new(&env, PROGRESS_SNAPSHOT_EVENT), course_id),
+        (
+            snapshot.timestamp,
+            snapshot.total_enrolled,
+            snapshot.average_rating,
+        ),
+    );
+
+    snapshot
+}
+
+/// Returns the snapshots of a course taken between `from_timestamp` and
+/// `to_timestamp` (both inclusive), oldest first.
+pub fn course_registry_get_progress_snapshots(
+    env: Env,
+    course_id: String,
+    from_timestamp: u64,
+    to_timestamp: u64,
+) -> Vec<ProgressSnapshot> {
+    let mut snapshots: Vec<ProgressSnapshot> = Vec::new(&env);
+    for timestamp in get_snapshot_timestamps(&env, &course_id).iter() {
+        if timestamp < from_timestamp {
+            continue;
+        }
+        if timestamp > to_timestamp {
+            break;
+        }
+        if let Some(snapshot) = env
+            .storage()
+            .persistent()
+            .get::<_, ProgressSnapshot>(&DataKey::Snapshot(course_id.clone(), timestamp))
+        {
+            snapshots.push_back(snapshot);
+        }
+    }
+    snapshots
+}
+
+/// Removes every snapshot of a deleted course.
+pub fn clear_progress_snapshots(env: &Env, course_id: &String) {
+    for timestamp in get_snapshot_timestamps(env, course_id).iter() {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Snapshot(course_id.clone(), timestamp));
+    }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::SnapshotTimestamps(course_id.clone()));
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::schema::Course;
+    use crate::CourseRegistryClient;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, Env, String};
+
+    mod mock_course_access {
+        use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+        #[contract]
+        pub struct CourseAccess;
+
+        #[contractimpl]
+        impl CourseAccess {
+            pub fn set_enrollment_count(env: Env, course_id: String, count: u32) {
+                env.storage().instance().set(&course_id, &count);
+            }
+
+            pub fn get_course_enrollment_count(env: Env, course_id: String) -> u32 {
+                env.storage().instance().get(&course_id).unwrap_or(0)
+            }
+
+            pub fn check_access(_env: Env, _course_id: String, _user: Address) -> bool {
+                true
+            }
+        }
+    }
+
+    fn setup() -> (
+        Env,
+        CourseRegistryClient<'static>,
+        mock_course_access::CourseAccessClient<'static>,
+        Course,
+    ) {
+        let (env, client, mocks) = setup_with_mocks();
+        env.ledger().set_timestamp(1_000);
+        let course_access_id = env.register(mock_course_access::CourseAccess, ());
+        env.as_contract(&client.address, || {
+            crate::functions::access_control::update_course_access_address(
+                &env,
+                &mocks.admin,
+                &course_access_id,
+            );
+        });
+
+        let course = client.create_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "Rust"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let access = mock_course_access::CourseAccessClient::new(&env, &course_access_id);
+        (env, client, access, course)
+    }
+
+    #[test]
+    fn test_take_progress_snapshot() {
+        let (env, client, access, course) = setup();
+        access.set_enrollment_count(&course.id, &12);
+        client.add_course_review(&Address::generate(&env), &course.id, &4, &None);
+        client.add_course_review(&Address::generate(&env), &course.id, &5, &None);
+
+        let snapshot = client.take_progress_snapshot(&course.creator, &course.id);
+
+        assert_eq!(snapshot.course_id, course.id);
+        assert_eq!(snapshot.timestamp, 1_000);
+        assert_eq!(snapshot.total_enrolled, 12);
+        assert_eq!(snapshot.average_rating, 450);
+        assert_eq!(
+            client
+                .get_progress_snapshots(&course.id, &0, &u64::MAX)
+                .get(0)
+                .unwrap(),
+            snapshot
+        );
+    }
+
+    #[test]
+    fn test_get_progress_snapshots_by_time_range() {
+        let (env, client, access, course) = setup();
+        for (timestamp, enrolled) in [(1_000, 1), (2_000, 5), (3_000, 9)] {
+            env.ledger().set_timestamp(timestamp);
+            access.set_enrollment_count(&course.id, &enrolled);
+            client.take_progress_snapshot(&course.creator, &course.id);
+        }
+
+        let all = client.get_progress_snapshots(&course.id, &0, &u64::MAX);
+        assert_eq!(all.len(), 3);
+        assert_eq!(all.get(2).unwrap().total_enrolled, 9);
+
+        let middle = client.get_progress_snapshots(&course.id, &1_500, &3_000);
+        assert_eq!(middle.len(), 2);
+        assert_eq!(middle.get(0).unwrap().timestamp, 2_000);
+        assert_eq!(middle.get(1).unwrap().timestamp, 3_000);
+
+        assert!(client
+            .get_progress_snapshots(&course.id, &3_001, &4_000)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_snapshot_at_same_timestamp_replaces_previous() {
+        let (_env, client, access, course) = setup();
+        access.set_enrollment_count(&course.id, &3);
+        client.take_progress_snapshot(&course.creator, &course.id);
+        access.set_enrollment_count(&course.id, &4);
+        client.take_progress_snapshot(&course.creator, &course.id);
+
+        let snapshots = client.get_progress_snapshots(&course.id, &0, &u64::MAX);
+        assert_eq!(snapshots.len(), 1);
+        assert_eq!(snapshots.get(0).unwrap().total_enrolled, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_take_progress_snapshot_unauthorized() {
+        let (env, client, _access, course) = setup();
+        client.take_progress_snapshot(&Address::generate(&env), &course.id);
+    }
+}
@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::require_course_management_auth;
+use crate::functions::get_courses_updated_after::record_course_update;
+use crate::functions::list_courses_by_creator::{add_to_creator_index, remove_from_creator_index};
+use crate::schema::Course;
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const COURSE_OWNERSHIP_TRANSFERRED_EVENT: &str = "CourseOwnershipTransferred";
+
+/// Reassigns a course to a new creator.
+///
+/// The caller must be the current course creator or an admin.
+///
+/// # Panics
+///
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::Unauthorized` if the caller is neither the creator nor an admin.
+pub fn course_registry_transfer_course_ownership(
+    env: Env,
+    current_creator: Address,
+    course_id: String,
+    new_creator: Address,
+) {
+    let storage_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    let mut course: Course = env
+        .storage()
+        .persistent()
+        .get(&storage_key)
+        .unwrap_or_else(|| handle_error(&env, Error::CourseNotFound));
+
+    // Requires the caller's auth and checks creator or admin rights
+    require_course_management_auth(&env, &current_creator, &course_id);
+
+    let previous_creator: Address = course.creator.clone();
+    course.creator = new_creator.clone();
+
+    env.storage().persistent().set(&storage_key, &course);
+    remove_from_creator_index(&env, &previous_creator, &course_id);
+    add_to_creator_index(&env, &new_creator, &course_id);
+    record_course_update(&env, &course_id);
+
+    env.events().publish(
+        (
+            Symbol::new(&env, COURSE_OWNERSHIP_TRANSFERRED_EVENT),
+            course_id,
+        ),
+        (previous_creator, new_creator, current_creator),
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::schema::Course;
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Address, Course) {
+        let (env, client, mocks) = setup_with_mocks();
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Rust"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (env, client, mocks.admin, course)
+    }
+
+    #[test]
+    fn test_transfer_course_ownership() {
+        let (env, client, _admin, course) = setup();
+        let new_creator = Address::generate(&env);
+
+        client.transfer_course_ownership(&course.creator, &course.id, &new_creator);
+
+        assert_eq!(client.get_course(&course.id).creator, new_creator);
+        assert_eq!(
+            client
+                .list_courses_by_creator(&course.creator, &0, &10, &false)
+                .total,
+            0
+        );
+        let page = client.list_courses_by_creator(&new_creator, &0, &10, &false);
+        assert_eq!(page.total, 1);
+        assert_eq!(page.courses.get(0).unwrap().id, course.id);
+    }
+
+    #[test]
+    fn test_admin_transfers_course_ownership() {
+        let (env, client, admin, course) = setup();
+        let new_creator = Address::generate(&env);
+
+        client.transfer_course_ownership(&admin, &course.id, &new_creator);
+
+        assert_eq!(client.get_course(&course.id).creator, new_creator);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_transfer_course_ownership_unauthorized() {
+        let (env, client, _admin, course) = setup();
+        let stranger = Address::generate(&env);
+
+        client.transfer_course_ownership(&stranger, &course.id, &stranger);
+    }
+}
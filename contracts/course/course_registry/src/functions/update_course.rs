@@ -0,0 +1,243 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::require_course_management_auth;
+use crate::functions::course_version_history::record_course_version;
+use crate::functions::edit_course::update_course_title;
+use crate::functions::get_courses_updated_after::record_course_update;
+use crate::functions::list_courses_by_category::reindex_course_category;
+use crate::functions::list_courses_by_difficulty::reindex_course_difficulty;
+use crate::functions::list_courses_by_language::reindex_course_language;
+use crate::functions::set_course_price::record_price_change;
+use crate::schema::{Course, DifficultyLevel};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const COURSE_UPDATED_EVENT: &str = "CourseUpdated";
+
+/// Applies a partial update to a course's metadata.
+///
+/// Only the fields passed as `Some` are changed. The event lists the names of
+/// the fields that were provided. Every update bumps the course version and
+/// records a snapshot of it.
+///
+/// # Arguments
+///
+/// * `env` - Soroban environment.
+/// * `creator` - Address performing the update (course creator or admin).
+/// * `course_id` - ID of the course to update.
+/// * `title`, `description`, `price`, `category`, `language`, `thumbnail_url`,
+///   `difficulty` - New values for the corresponding fields.
+///
+/// # Panics
+///
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::Unauthorized` if the caller is neither the creator nor an admin.
+/// * `Error::EmptyCourseTitle` / `Error::InvalidCourseDescription` for empty strings.
+/// * `Error::InvalidPrice` if the price is zero.
+pub fn course_registry_update_course(
+    env: Env,
+    creator: Address,
+    course_id: String,
+    title: Option<String>,
+    description: Option<String>,
+    price: Option<u128>,
+    category: Option<String>,
+    language: Option<String>,
+    thumbnail_url: Option<String>,
+    difficulty: Option<DifficultyLevel>,
+) -> Course {
+    let storage_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    let mut course: Course = env
+        .storage()
+        .persistent()
+        .get(&storage_key)
+        .unwrap_or_else(|| handle_error(&env, Error::CourseNotFound));
+
+    // Requires the caller's auth and checks creator or admin rights
+    require_course_management_auth(&env, &creator, &course_id);
+
+    let mut changed: Vec<Symbol> = Vec::new(&env);
+
+    if let Some(ref t) = title {
+        update_course_title(&env, &mut course, t);
+        changed.push_back(symbol_short!("title"));
+    }
+
+    if let Some(d) = description {
+        if d.is_empty() {
+            handle_error(&env, Error::InvalidCourseDescription)
+        }
+        course.description = d;
+        changed.push_back(symbol_short!("desc"));
+    }
+
+    if let Some(p) = price {
+        if p == 0 {
+            handle_error(&env, Error::InvalidPrice)
+        }
+        record_price_change(&env, &course_id, course.price, p, &creator);
+        course.price = p;
+        changed.push_back(symbol_short!("price"));
+    }
+
+    if category.is_some() {
+        reindex_course_category(&env, &course.category, &category, &course_id);
+        course.category = category;
+        changed.push_back(symbol_short!("category"));
+    }
+
+    if language.is_some() {
+        reindex_course_language(&env, &course.language, &language, &course_id);
+        course.language = language;
+        changed.push_back(symbol_short!("language"));
+    }
+
+    if thumbnail_url.is_some() {
+        course.thumbnail_url = thumbnail_url;
+        changed.push_back(symbol_short!("thumbnail"));
+    }
+
+    if let Some(d) = difficulty {
+        let new_difficulty: Option<u32> = Some(d as u32);
+        reindex_course_difficulty(&env, &course.difficulty, &new_difficulty, &course_id);
+        course.difficulty = new_difficulty;
+        changed.push_back(Symbol::new(&env, "difficulty"));
+    }
+
+    course.course_version += 1;
+    env.storage().persistent().set(&storage_key, &course);
+    record_course_update(&env, &course_id);
+    record_course_version(&env, &course, &creator);
+
+    env.events().publish(
+        (Symbol::new(&env, COURSE_UPDATED_EVENT), course_id),
+        (creator, changed),
+    );
+
+    course
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::schema::Course;
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Address, Course) {
+        let (env, client, _mocks) = setup_with_mocks();
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Original Title"),
+            &String::from_str(&env, "Original Description"),
+            &1000_u128,
+            &Some(String::from_str(&env, "original_category")),
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        (env, client, creator, course)
+    }
+
+    #[test]
+    fn test_update_course_partial() {
+        let (env, client, creator, course) = setup();
+
+        let updated = client.update_course(
+            &creator,
+            &course.id,
+            &Some(String::from_str(&env, "New Title")),
+            &None,
+            &Some(2500_u128),
+            &None,
+            &Some(String::from_str(&env, "es")),
+            &None,
+            &None,
+        );
+
+        assert_eq!(updated.title, String::from_str(&env, "New Title"));
+        assert_eq!(updated.price, 2500);
+        assert_eq!(updated.language, Some(String::from_str(&env, "es")));
+        // Fields that were not provided are left untouched
+        assert_eq!(updated.description, course.description);
+        assert_eq!(updated.category, course.category);
+        assert_eq!(client.get_course(&course.id), updated);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #108)")]
+    fn test_update_course_empty_title() {
+        let (env, client, creator, course) = setup();
+        client.update_course(
+            &creator,
+            &course.id,
+            &Some(String::from_str(&env, "")),
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #126)")]
+    fn test_update_course_empty_description() {
+        let (env, client, creator, course) = setup();
+        client.update_course(
+            &creator,
+            &course.id,
+            &None,
+            &Some(String::from_str(&env, "")),
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #117)")]
+    fn test_update_course_not_found() {
+        let (env, client, creator, _course) = setup();
+        client.update_course(
+            &creator,
+            &String::from_str(&env, "missing"),
+            &None,
+            &None,
+            &Some(10_u128),
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_update_course_unauthorized() {
+        let (env, client, _creator, course) = setup();
+        let stranger = Address::generate(&env);
+        client.update_course(
+            &stranger,
+            &course.id,
+            &None,
+            &None,
+            &Some(10_u128),
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+    }
+}
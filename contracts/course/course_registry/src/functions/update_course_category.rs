@@ -0,0 +1,198 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::create_course_category::{category_name_key, is_admin};
+use crate::functions::get_courses_updated_after::record_course_update;
+use crate::schema::{Course, CourseCategory, DataKey};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const CATEGORY_UPDATED_EVENT: &str = "CategoryUpdated";
+
+/// Updates the name and/or description of a course category (admin-only).
+///
+/// Courses store their category by name, so a rename is also applied to every
+/// course assigned to the category.
+///
+/// # Panics
+///
+/// * `Error::Unauthorized` if the caller is not an admin.
+/// * `Error::CategoryNotFound` if the category does not exist.
+/// * `Error::NameRequired` / `Error::InvalidCategoryName` for an empty or
+///   overly long name or description.
+/// * `Error::DuplicateCategoryName` if another category already uses the name.
+pub fn course_registry_update_course_category(
+    env: Env,
+    caller: Address,
+    category_id: u128,
+    new_name: Option<String>,
+    new_description: Option<String>,
+) -> CourseCategory {
+    caller.require_auth();
+    if !is_admin(&env, caller.clone()) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    let mut category: CourseCategory = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CourseCategory(category_id))
+        .unwrap_or_else(|| handle_error(&env, Error::CategoryNotFound));
+
+    if let Some(name) = new_name {
+        if name != category.name {
+            rename_category(&env, &mut category, name);
+        }
+    }
+
+    if let Some(description) = new_description {
+        if description.len() > 500 {
+            handle_error(&env, Error::InvalidCategoryName)
+        }
+        category.description = Some(description);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseCategory(category_id), &category);
+
+    env.events().publish(
+        (Symbol::new(&env, CATEGORY_UPDATED_EVENT), category_id),
+        (caller, category.name.clone(), category.description.clone()),
+    );
+
+    category
+}
+
+fn rename_category(env: &Env, category: &mut CourseCategory, name: String) {
+    if name.is_empty() {
+        handle_error(env, Error::NameRequired)
+    }
+    if name.len() > 100 {
+        handle_error(env, Error::InvalidCategoryName)
+    }
+
+    let old_key: DataKey = category_name_key(env, &category.name);
+    let new_key: DataKey = category_name_key(env, &name);
+    if new_key != old_key {
+        if env.storage().persistent().has(&new_key) {
+            handle_error(env, Error::DuplicateCategoryName)
+        }
+        env.storage().persistent().remove(&old_key);
+        env.storage().persistent().set(&new_key, &category.id);
+    }
+
+    let course_ids: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CategoryCourses(category.id))
+        .unwrap_or(Vec::new(env));
+    for course_id in course_ids.iter() {
+        let storage_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+        if let Some(mut course) = env.storage().persistent().get::<_, Course>(&storage_key) {
+            course.category = Some(name.clone());
+            env.storage().persistent().set(&storage_key, &course);
+            record_course_update(env, &course_id);
+        }
+    }
+
+    category.name = name;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::DataKey;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Admins, &vec![&env, admin.clone()]);
+        });
+        (env, client, admin)
+    }
+
+    #[test]
+    fn test_rename_category() {
+        let (env, client, admin) = setup();
+        let old_name = String::from_str(&env, "Programing");
+        let new_name = String::from_str(&env, "Programming");
+        let category_id = client.create_course_category(&admin, &old_name, &None);
+        let course = client.create_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "Rust"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &Some(old_name.clone()),
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+
+        let updated = client.update_course_category(&admin, &category_id, &Some(new_name.clone()), &None);
+        assert_eq!(updated.name, new_name);
+        assert_eq!(client.get_course_category(&category_id).unwrap().name, new_name);
+        assert_eq!(client.get_course(&course.id).category, Some(new_name));
+        assert_eq!(client.list_courses_by_category(&category_id, &0, &10, &false, &None).total, 1);
+
+        // The old name is free again
+        client.create_course_category(&admin, &old_name, &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #166)")]
+    fn test_rename_to_existing_name() {
+        let (env, client, admin) = setup();
+        client.create_course_category(&admin, &String::from_str(&env, "Design"), &None);
+        let category_id =
+            client.create_course_category(&admin, &String::from_str(&env, "Programming"), &None);
+
+        client.update_course_category(
+            &admin,
+            &category_id,
+            &Some(String::from_str(&env, "Design")),
+            &None,
+        );
+    }
+
+    #[test]
+    fn test_update_description_only() {
+        let (env, client, admin) = setup();
+        let name = String::from_str(&env, "Programming");
+        let category_id = client.create_course_category(&admin, &name, &None);
+        let description = String::from_str(&env, "Software development");
+
+        let updated =
+            client.update_course_category(&admin, &category_id, &None, &Some(description.clone()));
+
+        assert_eq!(updated.name, name);
+        assert_eq!(updated.description, Some(description));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_update_category_unauthorized() {
+        let (env, client, admin) = setup();
+        let category_id =
+            client.create_course_category(&admin, &String::from_str(&env, "Programming"), &None);
+
+        client.update_course_category(
+            &Address::generate(&env),
+            &category_id,
+            &Some(String::from_str(&env, "Design")),
+            &None,
+        );
+    }
+}
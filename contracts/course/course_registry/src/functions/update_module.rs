@@ -0,0 +1,172 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::require_course_editor;
+use crate::schema::CourseModule;
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+const MODULE_UPDATED_EVENT: &str = "ModuleUpdated";
+
+/// Updates a module's title and/or content URL (course creator, co-instructor or admin).
+///
+/// # Arguments
+///
+/// * `env` - Soroban environment.
+/// * `caller` - Address of a course editor.
+/// * `module_id` - ID of the module to update.
+/// * `new_title` - Optional new title (must not be empty).
+/// * `new_content_url` - Optional new content URL (must be a valid HTTP(S) URL).
+///
+/// # Panics
+///
+/// * `Error::ModuleNotFound` if the module does not exist.
+/// * `Error::Unauthorized` if the caller cannot edit the course.
+/// * `Error::InvalidModuleTitle` if `new_title` is empty.
+/// * `Error::InvalidContentUrl` if `new_content_url` is not a valid URL.
+pub fn course_registry_update_module(
+    env: Env,
+    caller: Address,
+    module_id: String,
+    new_title: Option<String>,
+    new_content_url: Option<String>,
+) -> CourseModule {
+    let key: (Symbol, String) = (MODULE_KEY, module_id.clone());
+    let mut module: CourseModule = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| handle_error(&env, Error::ModuleNotFound));
+
+    if !env
+        .storage()
+        .persistent()
+        .has(&(COURSE_KEY, module.course_id.clone()))
+    {
+        handle_error(&env, Error::CourseNotFound)
+    }
+
+    // Requires the caller's auth and checks creator, co-instructor or admin rights
+    require_course_editor(&env, &caller, &module.course_id);
+
+    if let Some(title) = new_title {
+        if title.is_empty() {
+            handle_error(&env, Error::InvalidModuleTitle)
+        }
+        module.title = title;
+    }
+
+    if let Some(url) = new_content_url {
+        if !shared::validate_url(&url) {
+            handle_error(&env, Error::InvalidContentUrl)
+        }
+        module.content_url = Some(url);
+    }
+
+    env.storage().persistent().set(&key, &module);
+
+    env.events().publish(
+        (
+            Symbol::new(&env, MODULE_UPDATED_EVENT),
+            module.course_id.clone(),
+        ),
+        (caller, module_id),
+    );
+
+    module
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_with_mocks;
+    use crate::schema::{ContentType, Course, CourseModule};
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup() -> (Env, CourseRegistryClient<'static>, Address, CourseModule) {
+        let (env, client, _mocks) = setup_with_mocks();
+
+        let creator = Address::generate(&env);
+        let course: Course = client.create_course(
+            &creator,
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Intro"), &ContentType::Unspecified, &None);
+        (env, client, creator, module)
+    }
+
+    #[test]
+    fn test_update_module_title_only() {
+        let (env, client, creator, module) = setup();
+
+        let updated = client.update_module(
+            &creator,
+            &module.id,
+            &Some(String::from_str(&env, "Welcome")),
+            &None,
+        );
+
+        assert_eq!(updated.title, String::from_str(&env, "Welcome"));
+        assert_eq!(updated.content_url, None);
+        assert_eq!(client.get_module(&module.id), updated);
+    }
+
+    #[test]
+    fn test_update_module_url_only() {
+        let (env, client, creator, module) = setup();
+        let url = String::from_str(&env, "https://cdn.example.com/intro.mp4");
+
+        let updated = client.update_module(&creator, &module.id, &None, &Some(url.clone()));
+
+        assert_eq!(updated.title, module.title);
+        assert_eq!(updated.content_url, Some(url));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #125)")]
+    fn test_update_module_empty_title() {
+        let (env, client, creator, module) = setup();
+        client.update_module(
+            &creator,
+            &module.id,
+            &Some(String::from_str(&env, "")),
+            &None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #161)")]
+    fn test_update_module_invalid_url() {
+        let (env, client, creator, module) = setup();
+        client.update_module(
+            &creator,
+            &module.id,
+            &None,
+            &Some(String::from_str(&env, "not a url")),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_update_module_unauthorized() {
+        let (env, client, _creator, module) = setup();
+        client.update_module(
+            &Address::generate(&env),
+            &module.id,
+            &Some(String::from_str(&env, "Hijacked")),
+            &None,
+        );
+    }
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::require_course_management_auth;
+use crate::functions::module_utils::reindex_module_type;
+use crate::schema::{ContentType, CourseModule};
+
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+const UPDATE_MODULE_CONTENT_EVENT: Symbol = symbol_short!("updModCnt");
+
+/// Sets the content type of a module and keeps the per-type index in sync.
+pub fn course_registry_update_module_content(
+    env: Env,
+    caller: Address,
+    module_id: String,
+    content_type: ContentType,
+) -> CourseModule {
+    if module_id.is_empty() {
+        handle_error(&env, Error::EmptyModuleId)
+    }
+
+    let key: (Symbol, String) = (MODULE_KEY, module_id.clone());
+    let mut module: CourseModule = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| handle_error(&env, Error::ModuleNotFound));
+
+    require_course_management_auth(&env, &caller, &module.course_id);
+
+    let old_type: ContentType = module.content_type.clone();
+    module.content_type = content_type;
+
+    env.storage().persistent().set(&key, &module);
+    reindex_module_type(&env, &module, &old_type);
+
+    env.events()
+        .publish((UPDATE_MODULE_CONTENT_EVENT,), (caller, module_id));
+
+    module
+}
@@ -1,7 +1,13 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{vec, Bytes, Env, String, Vec};
+use soroban_sdk::{symbol_short, vec, Bytes, Env, String, Symbol, Vec};
+
+use shared::Paginator;
+
+use crate::schema::{Course, CourseListPage, MAX_LIST_PAGE_SIZE};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
 
 pub fn generate_unique_id(env: &Env) -> String {
     let ts: u64 = env.ledger().timestamp();
@@ -140,10 +146,52 @@ pub fn concat_strings(env: &Env, strings: Vec<String>) -> String {
     String::from_bytes(env, new_slice)
 }
 
+/// Drops the IDs of archived (and missing) courses from `ids`.
+pub fn exclude_archived_course_ids(env: &Env, ids: &Vec<String>) -> Vec<String> {
+    let mut active: Vec<String> = Vec::new(env);
+    for course_id in ids.iter() {
+        if let Some(course) = env
+            .storage()
+            .persistent()
+            .get::<_, Course>(&(COURSE_KEY, course_id.clone()))
+        {
+            if !course.is_archived {
+                active.push_back(course_id);
+            }
+        }
+    }
+    active
+}
+
+/// Loads one page of courses from an ordered list of course IDs.
+///
+/// `limit` is capped at `MAX_LIST_PAGE_SIZE`; an `offset` past the end of the
+/// list yields an empty page.
+pub fn paginate_course_ids(env: &Env, ids: &Vec<String>, offset: u32, limit: u32) -> CourseListPage {
+    let (page_ids, has_more) = Paginator::new(offset, limit, MAX_LIST_PAGE_SIZE).paginate(ids, env);
+    let mut courses: Vec<Course> = Vec::new(env);
+
+    for course_id in page_ids.iter() {
+        if let Some(course) = env
+            .storage()
+            .persistent()
+            .get::<_, Course>(&(COURSE_KEY, course_id))
+        {
+            courses.push_back(course);
+        }
+    }
+
+    CourseListPage {
+        courses,
+        total: ids.len(),
+        has_more,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::schema::Course;
+    use crate::schema::{Course, CourseVisibility};
 
     use crate::CourseRegistry;
     use soroban_sdk::{testutils::Address as _, vec, Address, Env};
@@ -164,6 +212,17 @@ mod tests {
 
             duration_hours: Some(1),
             level: Some(String::from_str(env, "entry")),
+            tags: Vec::new(env),
+            difficulty: None,
+            max_enrollments: None,
+            start_timestamp: None,
+            end_timestamp: None,
+            co_instructors: Vec::new(env),
+            featured: false,
+            frozen: false,
+            is_free: false,
+            visibility: CourseVisibility::Public,
+            course_version: 1,
         }
     }
 
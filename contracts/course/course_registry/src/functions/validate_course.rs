@@ -0,0 +1,277 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::access_control::{is_email_verified, require_course_editor};
+use crate::functions::get_course::get_course;
+use crate::functions::module_utils::get_course_module_ids;
+use crate::functions::utils::trim;
+use crate::schema::{Course, CourseValidationReport};
+
+const NO_MODULES_ERROR: &str = "Course has no modules";
+const EMPTY_DESCRIPTION_ERROR: &str = "Course description is empty";
+const UNVERIFIED_EMAIL_ERROR: &str = "Creator email is not verified";
+
+const NO_CATEGORY_WARNING: &str = "Course has no category";
+const NO_THUMBNAIL_WARNING: &str = "Course has no thumbnail";
+const NO_LANGUAGE_WARNING: &str = "Course has no language";
+const NO_TAGS_WARNING: &str = "Course has no tags";
+
+/// Checks that block publishing, each with the error raised when publishing
+/// anyway and its report message.
+fn blocking_checks(env: &Env, course: &Course) -> [(bool, Error, &'static str); 3] {
+    [
+        (
+            get_course_module_ids(env, &course.id).is_empty(),
+            Error::ModuleNotFound,
+            NO_MODULES_ERROR,
+        ),
+        (
+            trim(env, &course.description).is_empty(),
+            Error::InvalidCourseDescription,
+            EMPTY_DESCRIPTION_ERROR,
+        ),
+        (
+            !is_email_verified(env, &course.creator),
+            Error::Unauthorized,
+            UNVERIFIED_EMAIL_ERROR,
+        ),
+    ]
+}
+
+/// Panics with the error of the first check that blocks publishing `course`.
+///
+/// # Panics
+///
+/// * `Error::ModuleNotFound` if the course has no modules.
+/// * `Error::InvalidCourseDescription` if the description is empty.
+/// * `Error::Unauthorized` if the creator's email is not verified.
+pub fn require_publishable(env: &Env, course: &Course) {
+    for (failed, error, _) in blocking_checks(env, course) {
+        if failed {
+            handle_error(env, error)
+        }
+    }
+}
+
+/// Runs the pre-publish checklist of a course (course creator, co-instructor
+/// or admin).
+///
+/// Errors block publishing: no modules, an empty description, or a creator
+/// whose email is not verified in user management. Warnings do not: no
+/// category, thumbnail, language or tags.
+///
+/// # Panics
+///
+/// * `Error::CourseNotFound` if the course does not exist.
+/// * `Error::Unauthorized` if the caller may not edit the course.
+pub fn course_registry_validate_course_before_publish(
+    env: Env,
+    caller: Address,
+    course_id: String,
+) -> CourseValidationReport {
+    let course: Course = get_course(&env, course_id.clone());
+    require_course_editor(&env, &caller, &course_id);
+
+    let mut errors: Vec<String> = Vec::new(&env);
+    for (failed, _, message) in blocking_checks(&env, &course) {
+        if failed {
+            errors.push_back(String::from_str(&env, message));
+        }
+    }
+
+    let mut warnings: Vec<String> = Vec::new(&env);
+    let soft_checks: [(bool, &str); 4] = [
+        (course.category.is_none(), NO_CATEGORY_WARNING),
+        (course.thumbnail_url.is_none(), NO_THUMBNAIL_WARNING),
+        (course.language.is_none(), NO_LANGUAGE_WARNING),
+        (course.tags.is_empty(), NO_TAGS_WARNING),
+    ];
+    for (missing, message) in soft_checks {
+        if missing {
+            warnings.push_back(String::from_str(&env, message));
+        }
+    }
+
+    CourseValidationReport {
+        is_publishable: errors.is_empty(),
+        errors,
+        warnings,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{mock_user_management, setup_with_mocks};
+    use crate::schema::{ContentType, Course, EditCourseParams};
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    /// Creates a course with every field set except the one named `missing`.
+    fn setup_without(
+        description: &str,
+        missing: &str,
+    ) -> (
+        Env,
+        CourseRegistryClient<'static>,
+        mock_user_management::UserManagementClient<'static>,
+        Course,
+    ) {
+        let (env, client, mocks) = setup_with_mocks();
+        let user_mgmt =
+            mock_user_management::UserManagementClient::new(&env, &mocks.user_management);
+
+        let creator = Address::generate(&env);
+        let field =
+            |name: &str, value: &str| (missing != name).then(|| String::from_str(&env, value));
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Rust"),
+            &String::from_str(&env, description),
+            &1000_u128,
+            &field("category", "Programming"),
+            &field("language", "en"),
+            &field("thumbnail", "https://example.com/rust.png"),
+            &None,
+            &None,
+            &None,
+        );
+        client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Intro"), &ContentType::Unspecified, &None);
+        if missing != "tags" {
+            let tags = vec![&env, String::from_str(&env, "rust")];
+            client.set_course_tags(&creator, &course.id, &tags);
+        }
+        (env, client, user_mgmt, course)
+    }
+
+    fn setup(
+        description: &str,
+    ) -> (
+        Env,
+        CourseRegistryClient<'static>,
+        mock_user_management::UserManagementClient<'static>,
+        Course,
+    ) {
+        setup_without(description, "")
+    }
+
+    fn edit_params(published: Option<bool>) -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: published,
+            new_level: None,
+            new_duration_hours: None,
+        }
+    }
+
+    fn messages(env: &Env, items: &[&str]) -> soroban_sdk::Vec<String> {
+        let mut result = soroban_sdk::Vec::new(env);
+        for item in items {
+            result.push_back(String::from_str(env, item));
+        }
+        result
+    }
+
+    #[test]
+    fn test_complete_course_is_publishable() {
+        let (_env, client, _user_mgmt, course) = setup("Learn Rust");
+
+        let report = client.validate_course_before_publish(&course.creator, &course.id);
+
+        assert!(report.is_publishable);
+        assert!(report.errors.is_empty());
+        assert!(report.warnings.is_empty());
+        client.edit_course(&course.creator, &course.id, &edit_params(Some(true)));
+        assert!(client.get_course(&course.id).published);
+    }
+
+    #[test]
+    fn test_course_without_modules() {
+        let (env, client, _user_mgmt, course) = setup("Learn Rust");
+        let module = client.get_course_modules(&course.id).get(0).unwrap();
+        client.delete_module(&course.creator, &module.id);
+
+        let report = client.validate_course_before_publish(&course.creator, &course.id);
+        assert!(!report.is_publishable);
+        assert_eq!(report.errors, messages(&env, &["Course has no modules"]));
+
+        let result = client.try_edit_course(&course.creator, &course.id, &edit_params(Some(true)));
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(121)))
+        );
+    }
+
+    #[test]
+    fn test_course_with_empty_description() {
+        let (env, client, _user_mgmt, course) = setup("  ");
+
+        let report = client.validate_course_before_publish(&course.creator, &course.id);
+        assert!(!report.is_publishable);
+        assert_eq!(
+            report.errors,
+            messages(&env, &["Course description is empty"])
+        );
+
+        let result = client.try_edit_course(&course.creator, &course.id, &edit_params(Some(true)));
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(126)))
+        );
+    }
+
+    #[test]
+    fn test_creator_email_not_verified() {
+        let (env, client, user_mgmt, course) = setup("Learn Rust");
+        user_mgmt.set_email_verified(&course.creator, &false);
+
+        let report = client.validate_course_before_publish(&course.creator, &course.id);
+        assert!(!report.is_publishable);
+        assert_eq!(
+            report.errors,
+            messages(&env, &["Creator email is not verified"])
+        );
+
+        let result = client.try_edit_course(&course.creator, &course.id, &edit_params(Some(true)));
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(106)))
+        );
+    }
+
+    #[test]
+    fn test_each_warning_alone() {
+        let cases: [(&str, &str); 4] = [
+            ("category", "Course has no category"),
+            ("thumbnail", "Course has no thumbnail"),
+            ("language", "Course has no language"),
+            ("tags", "Course has no tags"),
+        ];
+        for (missing, warning) in cases {
+            let (env, client, _user_mgmt, course) = setup_without("Learn Rust", missing);
+
+            let report = client.validate_course_before_publish(&course.creator, &course.id);
+
+            assert!(report.is_publishable);
+            assert!(report.errors.is_empty());
+            assert_eq!(report.warnings, messages(&env, &[warning]));
+            // Warnings do not block publishing
+            client.edit_course(&course.creator, &course.id, &edit_params(Some(true)));
+            assert!(client.get_course(&course.id).published);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #106)")]
+    fn test_validate_course_unauthorized() {
+        let (env, client, _user_mgmt, course) = setup("Learn Rust");
+        client.validate_course_before_publish(&Address::generate(&env), &course.id);
+    }
+}
@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 #![allow(clippy::too_many_arguments)]
+// CourseSortOrder variants are all named `By*`
+#![allow(clippy::enum_variant_names)]
 #![no_std]
 
 /// Contract version for tracking deployments and upgrades
@@ -14,7 +16,7 @@ pub mod schema;
 mod test;
 
 use crate::schema::{
-    Course, CourseCategory, CourseFilters, CourseGoal, CourseLevel, CourseModule, EditCourseParams,
+    AttachmentType, ContentType, Course, CourseAttachment, CourseBundle, CourseCategory, CourseFaq, CourseFilter, CourseFilters, CourseGoal, CourseLevel, CourseListPage, CourseModule, CourseRatingEntry, CourseReview, CourseSortOrder, CourseValidationReport, CourseVersionSnapshot, CourseVisibility, CreatorStats, CourseWithModules, DifficultyLevel, EditCourseParams, ModuleResource, PrerequisiteTree, PriceChange, ProgressSnapshot, ResourceType,
 };
 use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
 
@@ -28,6 +30,35 @@ pub struct CourseRegistry;
 
 #[contractimpl]
 impl CourseRegistry {
+    /// One-time setup of the owner and the contracts this registry talks to.
+    ///
+    /// Stores the owner, the user management and course access contract
+    /// addresses, and the default course creation rate limits.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `super_admin` - The address that owns the contract
+    /// * `user_mgmt_addr` - Address of the user management contract
+    /// * `course_access_addr` - Address of the course access contract
+    ///
+    /// # Panics
+    ///
+    /// * If the contract has already been initialized
+    pub fn initialize(
+        env: Env,
+        super_admin: Address,
+        user_mgmt_addr: Address,
+        course_access_addr: Address,
+    ) {
+        functions::access_control::course_registry_initialize(
+            env,
+            super_admin,
+            user_mgmt_addr,
+            course_access_addr,
+        )
+    }
+
     /// Create a new course in the registry.
     ///
     /// This function creates a new course with the specified metadata and
@@ -45,6 +76,7 @@ impl CourseRegistry {
     /// * `thumbnail_url` - Optional URL for the course thumbnail image
     /// * `level` - Optional course difficulty level
     /// * `duration_hours` - Optional estimated duration in hours
+    /// * `difficulty` - Optional difficulty level used for filtering
     ///
     /// # Returns
     ///
@@ -69,7 +101,8 @@ impl CourseRegistry {
     ///     Some("en".try_into().unwrap()),
     ///     Some("https://example.com/thumb.jpg".try_into().unwrap()),
     ///     Some(CourseLevel::Beginner),
-    ///     Some(40)
+    ///     Some(40),
+    ///     Some(DifficultyLevel::Beginner)
     /// );
     /// ```
     ///
@@ -90,6 +123,7 @@ impl CourseRegistry {
         thumbnail_url: Option<String>,
         level: Option<CourseLevel>,
         duration_hours: Option<u32>,
+        difficulty: Option<DifficultyLevel>,
     ) -> Course {
         functions::create_course::create_course(
             env,
@@ -102,6 +136,7 @@ impl CourseRegistry {
             thumbnail_url,
             level,
             duration_hours,
+            difficulty,
         )
     }
 
@@ -151,6 +186,68 @@ impl CourseRegistry {
         functions::create_course_category::create_course_category(env, caller, name, description)
     }
 
+    /// Update the name and/or description of a course category.
+    ///
+    /// Only admins can update categories. Category names are unique; a rename
+    /// is applied to every course assigned to the category.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin updating the category
+    /// * `category_id` - The unique identifier of the category
+    /// * `new_name` - Optional new name (max 100 characters)
+    /// * `new_description` - Optional new description
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `CourseCategory`.
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    /// * If the category doesn't exist
+    /// * If the new name is invalid or already used by another category
+    pub fn update_course_category(
+        env: Env,
+        caller: Address,
+        category_id: u128,
+        new_name: Option<String>,
+        new_description: Option<String>,
+    ) -> CourseCategory {
+        functions::update_course_category::course_registry_update_course_category(
+            env,
+            caller,
+            category_id,
+            new_name,
+            new_description,
+        )
+    }
+
+    /// Delete a course category.
+    ///
+    /// Only admins can delete categories, and only once no course is
+    /// assigned to the category anymore.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin deleting the category
+    /// * `category_id` - The unique identifier of the category
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    /// * If the category doesn't exist
+    /// * If courses are still assigned to the category
+    pub fn delete_course_category(env: Env, caller: Address, category_id: u128) {
+        functions::delete_course_category::course_registry_delete_course_category(
+            env,
+            caller,
+            category_id,
+        )
+    }
+
     /// Retrieve a course by its ID.
     ///
     /// This function fetches a course's complete information using its unique identifier.
@@ -219,6 +316,33 @@ impl CourseRegistry {
         functions::get_course_category::get_course_category(&env, category_id)
     }
 
+    /// List all course categories ordered by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns every `CourseCategory` in the registry.
+    pub fn list_all_categories(env: Env) -> Vec<CourseCategory> {
+        functions::list_all_categories::course_registry_list_all_categories(env)
+    }
+
+    /// Retrieve a course category by name (case-insensitive).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `name` - The category name to look up
+    ///
+    /// # Panics
+    ///
+    /// * If no category has that name
+    pub fn get_category_by_name(env: Env, name: String) -> CourseCategory {
+        functions::list_all_categories::course_registry_get_category_by_name(env, name)
+    }
+
     /// Get all courses created by a specific instructor.
     ///
     /// This function retrieves all courses that were created by the specified instructor.
@@ -307,6 +431,8 @@ impl CourseRegistry {
     /// * `course_id` - The unique identifier of the course to add the module to
     /// * `position` - The position where the module should be inserted
     /// * `title` - The title of the new module
+    /// * `content_type` - The type of content the module delivers
+    /// * `content_url` - Optional content URL (must be a valid HTTP(S) URL)
     ///
     /// # Returns
     ///
@@ -315,9 +441,10 @@ impl CourseRegistry {
     /// # Panics
     ///
     /// * If course doesn't exist
-    /// * If caller is not the course creator
+    /// * If caller is not the course creator, a co-instructor or an admin
     /// * If module title is empty
     /// * If position is invalid
+    /// * If `content_url` is not a valid URL
     ///
     /// # Examples
     ///
@@ -328,7 +455,9 @@ impl CourseRegistry {
     ///     course_creator_address,
     ///     "course_123".try_into().unwrap(),
     ///     1,
-    ///     "Introduction to Variables".try_into().unwrap()
+    ///     "Introduction to Variables".try_into().unwrap(),
+    ///     ContentType::Video,
+    ///     None
     /// );
     /// ```
     ///
@@ -336,7 +465,7 @@ impl CourseRegistry {
     ///
     /// * **Invalid position**: Position must be valid for the course
     /// * **Empty title**: Module title cannot be empty
-    /// * **Creator only**: Only course creator can add modules
+    /// * **Editors only**: Only the course creator, co-instructors and admins can add modules
     /// * **Auto-generated ID**: Module gets unique auto-generated ID
     pub fn add_module(
         env: Env,
@@ -344,8 +473,18 @@ impl CourseRegistry {
         course_id: String,
         position: u32,
         title: String,
+        content_type: ContentType,
+        content_url: Option<String>,
     ) -> CourseModule {
-        functions::add_module::course_registry_add_module(env, caller, course_id, position, title)
+        functions::add_module::course_registry_add_module(
+            env,
+            caller,
+            course_id,
+            position,
+            title,
+            content_type,
+            content_url,
+        )
     }
 
     /// Delete a course from the registry.
@@ -364,6 +503,7 @@ impl CourseRegistry {
     /// * If course doesn't exist
     /// * If creator is not the actual course creator
     /// * If course_id is invalid or empty
+    /// * If other courses require it, unless deletion is set to warn only
     ///
     /// # Examples
     ///
@@ -377,6 +517,7 @@ impl CourseRegistry {
     /// * **Permission denied**: Only course creator can delete their courses
     /// * **Non-existent course**: Will panic if course doesn't exist
     /// * **Permanent deletion**: Course and all associated data are permanently removed
+    /// * **Bundles**: The course is removed from every bundle that lists it
     /// * **Enrolled students**: Consider impact on enrolled students before deletion
     /// 
     /// Panics if the deletion fails or if the creator is not authorized.
@@ -653,6 +794,25 @@ impl CourseRegistry {
         )
     }
 
+    /// Get the full prerequisite hierarchy of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the root course
+    ///
+    /// # Returns
+    ///
+    /// Returns a `PrerequisiteTree` rooted at the course. Branches deeper than
+    /// the maximum prerequisite depth are truncated.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    pub fn get_prerequisite_tree(env: Env, course_id: String) -> PrerequisiteTree {
+        functions::get_prerequisite_tree::course_registry_get_prerequisite_tree(env, course_id)
+    }
+
     /// Edit the prerequisites for a course.
     ///
     /// This function replaces all existing prerequisites with a new set
@@ -702,6 +862,108 @@ impl CourseRegistry {
         functions::edit_prerequisite::edit_prerequisite(env, creator, course_id, new_prerequisites)
     }
 
+    /// Set the maximum length of a prerequisite chain, including the course itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin changing the limit
+    /// * `max_depth` - The new limit (must be greater than 0)
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    /// * If `max_depth` is 0
+    pub fn set_max_prerequisite_depth(env: Env, caller: Address, max_depth: u32) {
+        functions::edit_prerequisite::set_max_prerequisite_depth(env, caller, max_depth)
+    }
+
+    /// Get the maximum length of a prerequisite chain (defaults to 5).
+    pub fn get_max_prerequisite_depth(env: Env) -> u32 {
+        functions::edit_prerequisite::get_max_prerequisite_depth(&env)
+    }
+
+    /// Get the prerequisite course IDs of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the prerequisite course IDs, empty if there are none.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    pub fn get_course_prerequisites(env: Env, course_id: String) -> Vec<String> {
+        functions::get_course_prerequisites::course_registry_get_course_prerequisites(
+            env, course_id,
+        )
+    }
+
+    /// Get the IDs of the courses that list a course as a prerequisite.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the prerequisite course
+    ///
+    /// # Returns
+    ///
+    /// Returns the dependent course IDs, empty if there are none.
+    pub fn get_courses_requiring_course(env: Env, course_id: String) -> Vec<String> {
+        functions::get_dependent_courses::course_registry_get_courses_requiring_course(
+            env, course_id,
+        )
+    }
+
+    /// Choose whether deleting a course that others require fails or only warns.
+    ///
+    /// By default such deletions panic with `CourseHasDependents`. When
+    /// `warn_only` is set they go through and emit a
+    /// `CourseDeletedWithDependents` event instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The admin changing the setting
+    /// * `warn_only` - Whether deletion should only emit a warning
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    pub fn set_warn_on_dependent_deletion(env: Env, caller: Address, warn_only: bool) {
+        functions::get_dependent_courses::course_registry_set_warn_on_dependent_deletion(
+            env, caller, warn_only,
+        )
+    }
+
+    /// Check whether a user has access to every prerequisite of a course.
+    ///
+    /// Access is verified through the course access contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `user` - The address of the user to check
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if all prerequisites are met (always for courses without
+    /// prerequisites).
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    pub fn check_prerequisites_met(env: Env, user: Address, course_id: String) -> bool {
+        functions::get_course_prerequisites::course_registry_check_prerequisites_met(
+            env, user, course_id,
+        )
+    }
+
     /// Edit course information.
     ///
     /// This function allows the course creator to update various aspects
@@ -747,7 +1009,8 @@ impl CourseRegistry {
     ///
     /// * **Partial updates**: Only provided fields are updated
     /// * **Validation**: All fields must pass validation rules
-    /// * **Creator only**: Only course creator can edit course
+    /// * **Creator only**: Only course creator can edit course; co-instructors may only
+    ///   change the published flag
     /// * **Price limits**: Price must be within allowed bounds
     pub fn edit_course(
         env: Env,
@@ -776,7 +1039,7 @@ impl CourseRegistry {
     /// # Panics
     ///
     /// * If course doesn't exist
-    /// * If creator is not the course creator
+    /// * If the caller is neither the course creator nor an admin
     /// * If course is already archived
     ///
     /// # Examples
@@ -793,112 +1056,924 @@ impl CourseRegistry {
     /// # Edge Cases
     ///
     /// * **Already archived**: Will panic if course is already archived
-    /// * **Creator only**: Only course creator can archive course
+    /// * **Creator or admin**: Only the course creator or an admin can archive the course
+    /// * **Listings**: Archived courses are hidden from listings unless requested
     /// * **Student access**: Current students retain access
     /// * **Reversible**: Course can be unarchived if needed
     pub fn archive_course(env: &Env, creator: Address, course_id: String) -> Course {
         functions::archive_course::archive_course(env, creator, course_id)
     }
 
-    /// Check if a user is the creator of a specific course.
+    /// Get the number of published, non-archived courses.
     ///
-    /// This function verifies whether the specified user is the original creator
-    /// of the given course.
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of courses that are currently published and not archived.
+    pub fn get_published_course_count(env: Env) -> u32 {
+        functions::get_course_count::course_registry_get_published_course_count(env)
+    }
+
+    /// Get the total number of courses.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `course_id` - The unique identifier of the course
-    /// * `user` - The address of the user to check
     ///
     /// # Returns
     ///
-    /// Returns `true` if the user is the course creator, `false` otherwise.
+    /// Returns the number of existing courses, including archived and unpublished ones.
+    pub fn get_total_courses(env: Env) -> u32 {
+        functions::get_course_count::course_registry_get_total_courses(env)
+    }
+
+    /// Transfer a course to a new creator.
+    ///
+    /// The caller must be the current course creator or an admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `current_creator` - The address performing the transfer
+    /// * `course_id` - The unique identifier of the course
+    /// * `new_creator` - The address of the new course creator
     ///
     /// # Panics
     ///
-    /// * If course doesn't exist
+    /// * If the course doesn't exist
+    /// * If the caller is neither the course creator nor an admin
+    pub fn transfer_course_ownership(
+        env: Env,
+        current_creator: Address,
+        course_id: String,
+        new_creator: Address,
+    ) {
+        functions::transfer_course_ownership::course_registry_transfer_course_ownership(
+            env,
+            current_creator,
+            course_id,
+            new_creator,
+        )
+    }
+
+    /// Add a co-instructor who can manage the course content.
     ///
-    /// # Examples
+    /// Co-instructors can add and update modules and publish or unpublish the
+    /// course. Adding an existing co-instructor has no effect.
     ///
-    /// ```rust
-    /// // Check if user is course creator
-    /// let is_creator = contract.is_course_creator(
-    ///     &env,
-    ///     "course_123".try_into().unwrap(),
-    ///     user_address
-    /// );
-    /// 
-    /// if is_creator {
-    ///     // User can edit this course
-    /// }
-    /// ```
+    /// # Arguments
     ///
-    /// # Edge Cases
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `instructor` - The address of the co-instructor to add
     ///
-    /// * **Non-existent course**: Will panic if course doesn't exist
-    /// * **Public access**: Anyone can check creator status
-    /// * **Creator verification**: Useful for permission checks
-    pub fn is_course_creator(env: &Env, course_id: String, user: Address) -> bool {
-        functions::is_course_creator::is_course_creator(env, course_id, user)
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If the caller is not the course creator
+    pub fn add_co_instructor(env: Env, creator: Address, course_id: String, instructor: Address) {
+        functions::co_instructors::course_registry_add_co_instructor(env, creator, course_id, instructor)
     }
 
-    /// List all available course categories.
+    /// Remove a co-instructor from a course.
     ///
-    /// This function retrieves all course categories that have been created
-    /// in the system.
+    /// Removing an address that is not a co-instructor has no effect.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `instructor` - The address of the co-instructor to remove
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If the caller is not the course creator
+    pub fn remove_co_instructor(env: Env, creator: Address, course_id: String, instructor: Address) {
+        functions::co_instructors::course_registry_remove_co_instructor(env, creator, course_id, instructor)
+    }
+
+    /// List the co-instructors of a course.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
     ///
     /// # Returns
     ///
-    /// Returns a vector of all available `Category` objects.
+    /// Returns the co-instructor addresses in the order they were added.
     ///
-    /// # Examples
+    /// # Panics
     ///
-    /// ```rust
-    /// // Get all categories
-    /// let categories = contract.list_categories(env.clone());
-    /// for category in categories {
-    ///     println!("Category: {}", category.name);
-    /// }
-    /// ```
+    /// * If the course doesn't exist
+    pub fn list_co_instructors(env: Env, course_id: String) -> Vec<Address> {
+        functions::co_instructors::course_registry_list_co_instructors(env, course_id)
+    }
+
+    /// Get the price of a course.
     ///
-    /// # Edge Cases
+    /// # Arguments
     ///
-    /// * **Empty system**: Returns empty vector if no categories exist
-    /// * **Public access**: Anyone can list categories
-    /// * **Order**: Categories are returned in creation order
-    pub fn list_categories(env: Env) -> Vec<crate::schema::Category> {
-        functions::list_categories::list_categories(&env)
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the course price.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    pub fn get_course_price(env: Env, course_id: String) -> u128 {
+        functions::get_course::course_registry_get_course_price(env, course_id)
     }
 
-    /// List courses with filtering and pagination.
+    /// Change the price of a course.
     ///
-    /// This function retrieves courses based on the provided filters
-    /// with optional pagination support.
+    /// Only the course creator or an admin can change the price. Each change
+    /// is recorded in the course's price history, which keeps the last 10
+    /// changes.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `filters` - Filtering criteria for courses
-    /// * `limit` - Optional maximum number of courses to return
-    /// * `offset` - Optional number of courses to skip for pagination
+    /// * `caller` - The address changing the price
+    /// * `course_id` - The unique identifier of the course
+    /// * `new_price` - The new price (must be greater than zero)
     ///
     /// # Returns
     ///
-    /// Returns a vector of `Course` objects matching the filter criteria.
+    /// Returns the updated course.
     ///
-    /// # Examples
+    /// # Panics
     ///
-    /// ```rust
-    /// // List first 10 courses
-    /// let courses = contract.list_courses_with_filters(
-    ///     env.clone(),
-    ///     CourseFilters::default(),
-    ///     Some(10),
+    /// * If the course doesn't exist
+    /// * If the caller is neither the course creator nor an admin
+    /// * If the new price is zero
+    pub fn set_course_price(env: Env, caller: Address, course_id: String, new_price: u128) -> Course {
+        functions::set_course_price::course_registry_set_course_price(env, caller, course_id, new_price)
+    }
+
+    /// Get the recent price changes of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns up to the last 10 price changes, oldest first.
+    pub fn get_price_history(env: Env, course_id: String) -> Vec<PriceChange> {
+        functions::set_course_price::course_registry_get_price_history(env, course_id)
+    }
+
+    /// Feature a course on the homepage, or take it off the featured list.
+    ///
+    /// Only admins can curate the featured list, which holds at most 20 courses.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The address of the admin making the change
+    /// * `course_id` - The unique identifier of the course
+    /// * `featured` - Whether the course should be featured
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is not an admin
+    /// * If the course doesn't exist
+    /// * If 20 courses are already featured
+    pub fn mark_course_featured(env: Env, admin: Address, course_id: String, featured: bool) {
+        functions::mark_course_featured::course_registry_mark_course_featured(
+            env, admin, course_id, featured,
+        )
+    }
+
+    /// Get the courses featured on the homepage.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the featured courses in the order they were featured.
+    pub fn get_featured_courses(env: Env) -> Vec<Course> {
+        functions::mark_course_featured::course_registry_get_featured_courses(env)
+    }
+
+    /// Get aggregated statistics over every course of a creator.
+    ///
+    /// This is a read-only query and requires no authentication. Results are
+    /// cached for one hour.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    ///
+    /// # Returns
+    ///
+    /// Returns the creator's course count, published course count, total
+    /// enrollments and average rating (multiplied by 100).
+    pub fn get_creator_statistics(env: Env, creator: Address) -> CreatorStats {
+        functions::get_creator_statistics::course_registry_get_creator_statistics(env, creator)
+    }
+
+    /// Get the best rated public courses.
+    ///
+    /// This is a read-only query and requires no authentication. Results are
+    /// cached for 30 minutes.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `min_reviews` - Minimum number of reviews a course needs to be ranked
+    /// * `limit` - Maximum number of courses to return (capped at 50)
+    ///
+    /// # Returns
+    ///
+    /// Returns the courses by average rating (multiplied by 100), highest
+    /// first, with more reviews ranking first on equal ratings.
+    pub fn get_top_rated_courses(env: Env, min_reviews: u32, limit: u32) -> Vec<CourseRatingEntry> {
+        functions::get_top_rated_courses::course_registry_get_top_rated_courses(env, min_reviews, limit)
+    }
+
+    /// Check whether a course is published.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the course exists and is published.
+    pub fn is_course_published(env: Env, course_id: String) -> bool {
+        functions::get_course::course_registry_is_course_published(env, course_id)
+    }
+
+    /// Set or clear the enrollment cap of a course.
+    ///
+    /// The caller must be the course creator or an admin. The cap is enforced
+    /// by the course access contract when access is granted.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `max_enrollments` - The new cap, or `None` for unlimited enrollment
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated course.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If the caller is neither the course creator nor an admin
+    /// * If more users are already enrolled than the new cap allows
+    pub fn set_course_max_enrollments(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        max_enrollments: Option<u32>,
+    ) -> Course {
+        functions::set_enrollment_cap::course_registry_set_course_max_enrollments(
+            env,
+            caller,
+            course_id,
+            max_enrollments,
+        )
+    }
+
+    /// Get the enrollment cap of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the cap, or `None` if enrollment is unlimited or the course doesn't exist.
+    pub fn get_course_max_enrollments(env: Env, course_id: String) -> Option<u32> {
+        functions::set_enrollment_cap::course_registry_get_course_max_enrollments(env, course_id)
+    }
+
+    /// Review a course.
+    ///
+    /// Each user can review a course once, and only if they have access to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `reviewer` - The address of the user writing the review
+    /// * `course_id` - The unique identifier of the course
+    /// * `rating` - Rating from 1 to 5
+    /// * `comment` - Optional review text
+    ///
+    /// # Panics
+    ///
+    /// * If the rating is outside 1 to 5
+    /// * If the course doesn't exist
+    /// * If the reviewer has no access to the course
+    /// * If the reviewer already reviewed the course
+    pub fn add_course_review(
+        env: Env,
+        reviewer: Address,
+        course_id: String,
+        rating: u32,
+        comment: Option<String>,
+    ) {
+        functions::add_course_review::course_registry_add_course_review(
+            env, reviewer, course_id, rating, comment,
+        )
+    }
+
+    /// Delete a course review.
+    ///
+    /// Reviewers can delete their own review and admins can delete any review.
+    /// The rating is removed from the course average.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address deleting the review (the reviewer or an admin)
+    /// * `reviewer` - The address of the user who wrote the review
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Panics
+    ///
+    /// * If the caller is neither the reviewer nor an admin
+    /// * If the reviewer has not reviewed the course
+    pub fn delete_course_review(env: Env, caller: Address, reviewer: Address, course_id: String) {
+        functions::add_course_review::course_registry_delete_course_review(
+            env, caller, reviewer, course_id,
+        )
+    }
+
+    /// Get all reviews of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the reviews, oldest first.
+    pub fn get_course_reviews(env: Env, course_id: String) -> Vec<CourseReview> {
+        functions::add_course_review::course_registry_get_course_reviews(env, course_id)
+    }
+
+    /// Get the average rating of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the average rating multiplied by 100 (e.g. `450` for 4.5), or
+    /// `0` if the course has no reviews.
+    pub fn get_average_rating(env: Env, course_id: String) -> u32 {
+        functions::add_course_review::course_registry_get_average_rating(env, course_id)
+    }
+
+    /// Record the current enrollment count and average rating of a course.
+    ///
+    /// The enrollment count is read from the course access contract. Only the
+    /// course creator or an admin can take snapshots.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address taking the snapshot
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the stored `ProgressSnapshot`.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If the caller is neither the course creator nor an admin
+    pub fn take_progress_snapshot(env: Env, caller: Address, course_id: String) -> ProgressSnapshot {
+        functions::take_progress_snapshot::course_registry_take_progress_snapshot(env, caller, course_id)
+    }
+
+    /// Get the progress snapshots of a course taken within a time range.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `from_timestamp` - Earliest snapshot timestamp to include
+    /// * `to_timestamp` - Latest snapshot timestamp to include
+    ///
+    /// # Returns
+    ///
+    /// Returns the matching snapshots, oldest first.
+    pub fn get_progress_snapshots(
+        env: Env,
+        course_id: String,
+        from_timestamp: u64,
+        to_timestamp: u64,
+    ) -> Vec<ProgressSnapshot> {
+        functions::take_progress_snapshot::course_registry_get_progress_snapshots(
+            env,
+            course_id,
+            from_timestamp,
+            to_timestamp,
+        )
+    }
+
+    /// Schedule a live cohort course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `start_timestamp` - Ledger timestamp at which the course starts
+    /// * `end_timestamp` - Ledger timestamp at which the course ends
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Course`.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If caller is neither the course creator nor an admin
+    /// * If the start is not in the future or not before the end
+    pub fn set_course_schedule(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        start_timestamp: u64,
+        end_timestamp: u64,
+    ) -> Course {
+        functions::set_course_schedule::course_registry_set_course_schedule(
+            env,
+            caller,
+            course_id,
+            start_timestamp,
+            end_timestamp,
+        )
+    }
+
+    /// Get scheduled courses starting at or after a timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `from_timestamp` - Earliest start timestamp to include
+    /// * `limit` - Maximum number of courses to return
+    ///
+    /// # Returns
+    ///
+    /// Returns the courses ordered by start time.
+    pub fn get_upcoming_courses(env: Env, from_timestamp: u64, limit: u32) -> Vec<Course> {
+        functions::set_course_schedule::course_registry_get_upcoming_courses(
+            env,
+            from_timestamp,
+            limit,
+        )
+    }
+
+    /// Create a bundle of the caller's courses.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the bundle creator
+    /// * `name` - The bundle name
+    /// * `description` - Optional bundle description
+    /// * `course_ids` - The courses in the bundle, all created by `creator`
+    /// * `price` - The bundle price
+    ///
+    /// # Returns
+    ///
+    /// Returns the created `CourseBundle`.
+    ///
+    /// # Panics
+    ///
+    /// * If the name is empty
+    /// * If a course doesn't exist, belongs to another creator or is listed twice
+    pub fn create_course_bundle(
+        env: Env,
+        creator: Address,
+        name: String,
+        description: Option<String>,
+        course_ids: Vec<String>,
+        price: u128,
+    ) -> CourseBundle {
+        functions::course_bundles::course_registry_create_course_bundle(
+            env,
+            creator,
+            name,
+            description,
+            course_ids,
+            price,
+        )
+    }
+
+    /// Add one of the bundle creator's courses to a bundle.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the bundle creator
+    /// * `bundle_id` - The unique identifier of the bundle
+    /// * `course_id` - The course to add
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `CourseBundle`.
+    ///
+    /// # Panics
+    ///
+    /// * If the bundle or course doesn't exist
+    /// * If the caller did not create the bundle or the course
+    /// * If the course is already in the bundle
+    pub fn add_course_to_bundle(
+        env: Env,
+        creator: Address,
+        bundle_id: u128,
+        course_id: String,
+    ) -> CourseBundle {
+        functions::course_bundles::course_registry_add_course_to_bundle(
+            env, creator, bundle_id, course_id,
+        )
+    }
+
+    /// Remove a course from a bundle.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the bundle creator
+    /// * `bundle_id` - The unique identifier of the bundle
+    /// * `course_id` - The course to remove
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `CourseBundle`.
+    ///
+    /// # Panics
+    ///
+    /// * If the bundle doesn't exist or the course is not in it
+    /// * If the caller did not create the bundle
+    pub fn remove_course_from_bundle(
+        env: Env,
+        creator: Address,
+        bundle_id: u128,
+        course_id: String,
+    ) -> CourseBundle {
+        functions::course_bundles::course_registry_remove_course_from_bundle(
+            env, creator, bundle_id, course_id,
+        )
+    }
+
+    /// Get a course bundle by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `bundle_id` - The unique identifier of the bundle
+    ///
+    /// # Panics
+    ///
+    /// * If the bundle doesn't exist
+    pub fn get_bundle(env: Env, bundle_id: u128) -> CourseBundle {
+        functions::course_bundles::course_registry_get_bundle(env, bundle_id)
+    }
+
+    /// Get the course IDs included in a bundle.
+    ///
+    /// Used by the course access contract to grant bundle access.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `bundle_id` - The unique identifier of the bundle
+    ///
+    /// # Panics
+    ///
+    /// * If the bundle doesn't exist
+    pub fn get_bundle_course_ids(env: Env, bundle_id: u128) -> Vec<String> {
+        functions::course_bundles::course_registry_get_bundle_course_ids(env, bundle_id)
+    }
+
+    /// Delete a course bundle. The bundled courses are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the bundle creator or an admin
+    /// * `bundle_id` - The unique identifier of the bundle
+    ///
+    /// # Panics
+    ///
+    /// * If the bundle doesn't exist
+    /// * If the caller is neither the bundle creator nor an admin
+    pub fn delete_bundle(env: Env, caller: Address, bundle_id: u128) {
+        functions::course_bundles::course_registry_delete_bundle(env, caller, bundle_id)
+    }
+
+    /// Attach a supplemental resource to a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `url` - Where the resource can be found
+    /// * `title` - A short title for the resource
+    /// * `attachment_type` - The kind of resource
+    ///
+    /// # Returns
+    ///
+    /// Returns the created `CourseAttachment`.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If the caller is neither the creator nor an admin
+    /// * If the URL or title is invalid
+    pub fn add_course_attachment(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        url: String,
+        title: String,
+        attachment_type: AttachmentType,
+    ) -> CourseAttachment {
+        functions::course_attachments::course_registry_add_course_attachment(
+            env,
+            caller,
+            course_id,
+            url,
+            title,
+            attachment_type,
+        )
+    }
+
+    /// List the attachments of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the attachments in the order they were added.
+    pub fn list_course_attachments(env: Env, course_id: String) -> Vec<CourseAttachment> {
+        functions::course_attachments::course_registry_list_course_attachments(env, course_id)
+    }
+
+    /// Remove an attachment from its course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `attachment_id` - The ID of the attachment to remove
+    ///
+    /// # Panics
+    ///
+    /// * If the attachment doesn't exist
+    /// * If the caller is neither the creator nor an admin
+    pub fn remove_course_attachment(env: Env, caller: Address, attachment_id: u128) {
+        functions::course_attachments::course_registry_remove_course_attachment(
+            env,
+            caller,
+            attachment_id,
+        )
+    }
+
+    /// Halt new enrollments in a course while its content is updated.
+    ///
+    /// Users already enrolled keep their access. Only the course creator or
+    /// an admin can freeze a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated course.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If the caller is neither the creator nor an admin
+    pub fn freeze_course(env: Env, caller: Address, course_id: String) -> Course {
+        functions::freeze_course::course_registry_freeze_course(env, caller, course_id)
+    }
+
+    /// Reopen a frozen course for new enrollments.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated course.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If the caller is neither the creator nor an admin
+    pub fn unfreeze_course(env: Env, caller: Address, course_id: String) -> Course {
+        functions::freeze_course::course_registry_unfreeze_course(env, caller, course_id)
+    }
+
+    /// Check whether a course is frozen.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the course exists and is frozen.
+    pub fn is_course_frozen(env: Env, course_id: String) -> bool {
+        functions::freeze_course::course_registry_is_course_frozen(env, course_id)
+    }
+
+    /// Mark a course as free or paid.
+    ///
+    /// Users can enroll in a free course on their own through course_access
+    /// `self_enroll`. The listed price is kept. Only the course creator or an
+    /// admin can change this.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `is_free` - Whether enrolling in the course is free
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated course.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If the caller is neither the creator nor an admin
+    pub fn set_course_free(env: Env, caller: Address, course_id: String, is_free: bool) -> Course {
+        functions::set_course_free::course_registry_set_course_free(env, caller, course_id, is_free)
+    }
+
+    /// Check whether a course is free.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the course exists and is free.
+    pub fn is_course_free(env: Env, course_id: String) -> bool {
+        functions::set_course_free::course_registry_is_course_free(env, course_id)
+    }
+
+    /// Check whether a course is archived.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the course exists and is archived.
+    pub fn is_course_archived(env: Env, course_id: String) -> bool {
+        functions::archive_course::course_registry_is_course_archived(env, course_id)
+    }
+
+    /// Check if a user is the creator of a specific course.
+    ///
+    /// This function verifies whether the specified user is the original creator
+    /// of the given course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user to check
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the user is the course creator, `false` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// * If course doesn't exist
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Check if user is course creator
+    /// let is_creator = contract.is_course_creator(
+    ///     &env,
+    ///     "course_123".try_into().unwrap(),
+    ///     user_address
+    /// );
+    /// 
+    /// if is_creator {
+    ///     // User can edit this course
+    /// }
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Non-existent course**: Will panic if course doesn't exist
+    /// * **Public access**: Anyone can check creator status
+    /// * **Creator verification**: Useful for permission checks
+    pub fn is_course_creator(env: &Env, course_id: String, user: Address) -> bool {
+        functions::is_course_creator::is_course_creator(env, course_id, user)
+    }
+
+    /// List all available course categories.
+    ///
+    /// This function retrieves all course categories that have been created
+    /// in the system.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of all available `Category` objects.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // Get all categories
+    /// let categories = contract.list_categories(env.clone());
+    /// for category in categories {
+    ///     println!("Category: {}", category.name);
+    /// }
+    /// ```
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Empty system**: Returns empty vector if no categories exist
+    /// * **Public access**: Anyone can list categories
+    /// * **Order**: Categories are returned in creation order
+    pub fn list_categories(env: Env) -> Vec<crate::schema::Category> {
+        functions::list_categories::list_categories(&env)
+    }
+
+    /// List courses with filtering and pagination.
+    ///
+    /// This function retrieves courses based on the provided filters
+    /// with optional pagination support.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `filters` - Filtering criteria for courses
+    /// * `limit` - Optional maximum number of courses to return
+    /// * `offset` - Optional number of courses to skip for pagination
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of `Course` objects matching the filter criteria.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// // List first 10 courses
+    /// let courses = contract.list_courses_with_filters(
+    ///     env.clone(),
+    ///     CourseFilters::default(),
+    ///     Some(10),
     ///     Some(0)
     /// );
     /// 
@@ -913,20 +1988,859 @@ impl CourseRegistry {
     /// );
     /// ```
     ///
-    /// # Edge Cases
+    /// # Edge Cases
+    ///
+    /// * **No matches**: Returns empty vector if no courses match filters
+    /// * **Large limits**: Limit should be reasonable to avoid gas issues
+    /// * **Public access**: Anyone can list courses
+    /// * **Archived courses**: May or may not be included based on filter settings
+    pub fn list_courses_with_filters(
+        env: Env,
+        filters: CourseFilters,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Vec<Course> {
+        functions::list_courses_with_filters::list_courses_with_filters(
+            &env, filters, limit, offset,
+        )
+    }
+
+    /// Get courses whose metadata changed at or after a given timestamp.
+    ///
+    /// Intended for CDN and caching layers that need to invalidate cached
+    /// course metadata incrementally instead of refetching everything.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `since` - Ledger timestamp (inclusive) to look for updates from
+    /// * `limit` - Maximum number of courses to return
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Course` objects ordered from oldest to newest update.
+    ///
+    /// # Edge Cases
+    ///
+    /// * **Deleted courses**: Removed from tracking and never returned
+    /// * **Public access**: Anyone can query recent updates
+    pub fn get_courses_updated_after(env: Env, since: u64, limit: u32) -> Vec<Course> {
+        functions::get_courses_updated_after::course_registry_get_courses_updated_after(
+            env, since, limit,
+        )
+    }
+
+    /// Get the timestamp of the last mutation of a course.
+    ///
+    /// Lightweight cache-invalidation check that avoids loading the course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the ledger timestamp of the last update, or `0` if the course is unknown.
+    pub fn get_last_update_timestamp(env: Env, course_id: String) -> u64 {
+        functions::get_courses_updated_after::course_registry_get_last_update_timestamp(
+            env, course_id,
+        )
+    }
+
+    /// Get a single module by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `module_id` - The unique identifier of the module
+    ///
+    /// # Returns
+    ///
+    /// Returns the requested `CourseModule`.
+    ///
+    /// # Panics
+    ///
+    /// * If the module doesn't exist
+    pub fn get_module(env: Env, module_id: String) -> CourseModule {
+        functions::get_module::course_registry_get_module(env, module_id)
+    }
+
+    /// Get every module of a course, sorted by position.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the course's modules in ascending position order.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    pub fn get_course_modules(env: Env, course_id: String) -> Vec<CourseModule> {
+        functions::get_module::course_registry_get_course_modules(env, course_id)
+    }
+
+    /// Get a course together with its modules, prerequisites and tags.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the course, its modules in ascending position order, its
+    /// prerequisite course IDs and its tags.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    pub fn get_course_with_modules(env: Env, course_id: String) -> CourseWithModules {
+        functions::get_course_with_modules::course_registry_get_course_with_modules(env, course_id)
+    }
+
+    /// Run the pre-publish checklist of a course.
+    ///
+    /// Publishing through `edit_course` fails on the first blocking error
+    /// reported here.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address requesting the report (creator, co-instructor or admin)
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns whether the course can be published, the errors that block
+    /// publishing (no modules, empty description, unverified creator email)
+    /// and the warnings that do not (no category, thumbnail, language or tags).
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If the caller may not edit the course
+    pub fn validate_course_before_publish(
+        env: Env,
+        caller: Address,
+        course_id: String,
+    ) -> CourseValidationReport {
+        functions::validate_course::course_registry_validate_course_before_publish(
+            env, caller, course_id,
+        )
+    }
+
+    /// Get the modules of a course that deliver a given type of content.
+    ///
+    /// Lets content editors find, for example, every video or every quiz
+    /// module of a course without loading all modules.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `content_type` - The content type to filter by
+    ///
+    /// # Returns
+    ///
+    /// Returns the matching `CourseModule` objects in the order they were tagged.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    pub fn get_modules_by_type(
+        env: Env,
+        course_id: String,
+        content_type: ContentType,
+    ) -> Vec<CourseModule> {
+        functions::get_modules_by_type::course_registry_get_modules_by_type(
+            env,
+            course_id,
+            content_type,
+        )
+    }
+
+    /// Update a course's metadata.
+    ///
+    /// Applies a partial update: only the fields passed as `Some` are changed.
+    /// Only the course creator or an admin can update a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the user updating the course
+    /// * `course_id` - The unique identifier of the course
+    /// * `title` - Optional new title (must not be empty)
+    /// * `description` - Optional new description (must not be empty)
+    /// * `price` - Optional new price (must be greater than 0)
+    /// * `category` - Optional new category
+    /// * `language` - Optional new language
+    /// * `thumbnail_url` - Optional new thumbnail URL
+    /// * `difficulty` - Optional new difficulty level
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Course`.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If the caller is neither the course creator nor an admin
+    /// * If a provided title or description is empty
+    pub fn update_course(
+        env: Env,
+        creator: Address,
+        course_id: String,
+        title: Option<String>,
+        description: Option<String>,
+        price: Option<u128>,
+        category: Option<String>,
+        language: Option<String>,
+        thumbnail_url: Option<String>,
+        difficulty: Option<DifficultyLevel>,
+    ) -> Course {
+        functions::update_course::course_registry_update_course(
+            env,
+            creator,
+            course_id,
+            title,
+            description,
+            price,
+            category,
+            language,
+            thumbnail_url,
+            difficulty,
+        )
+    }
+
+    /// Delete a single module from a course.
+    ///
+    /// Only the course creator can delete modules. The remaining modules are
+    /// renumbered to keep contiguous zero-based positions.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator
+    /// * `module_id` - The unique identifier of the module
+    ///
+    /// # Panics
+    ///
+    /// * If the module doesn't exist
+    /// * If the caller is not the course creator
+    pub fn delete_module(env: Env, caller: Address, module_id: String) {
+        functions::delete_module::course_registry_delete_module(env, caller, module_id)
+    }
+
+    /// Attach a downloadable resource to a module.
+    ///
+    /// Only the course creator, co-instructors and admins can add resources.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address adding the resource
+    /// * `module_id` - The unique identifier of the module
+    /// * `url` - Where the resource is hosted (must be a valid HTTP(S) URL)
+    /// * `title` - Display title of the resource
+    /// * `resource_type` - Kind of resource
+    ///
+    /// # Returns
+    ///
+    /// Returns the created `ModuleResource` with its assigned ID.
+    ///
+    /// # Panics
+    ///
+    /// * If the module doesn't exist
+    /// * If the caller may not edit the course
+    /// * If the URL or title is invalid
+    pub fn add_module_resource(
+        env: Env,
+        caller: Address,
+        module_id: String,
+        url: String,
+        title: String,
+        resource_type: ResourceType,
+    ) -> ModuleResource {
+        functions::module_resources::course_registry_add_module_resource(
+            env,
+            caller,
+            module_id,
+            url,
+            title,
+            resource_type,
+        )
+    }
+
+    /// List the resources of a module.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `module_id` - The unique identifier of the module
+    ///
+    /// # Returns
+    ///
+    /// Returns the module's resources in the order they were added.
+    pub fn list_module_resources(env: Env, module_id: String) -> Vec<ModuleResource> {
+        functions::module_resources::course_registry_list_module_resources(env, module_id)
+    }
+
+    /// Remove a resource from a module.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address removing the resource
+    /// * `resource_id` - The unique identifier of the resource
+    ///
+    /// # Panics
+    ///
+    /// * If the resource doesn't exist
+    /// * If the caller may not edit the course
+    pub fn remove_module_resource(env: Env, caller: Address, resource_id: u128) {
+        functions::module_resources::course_registry_remove_module_resource(env, caller, resource_id)
+    }
+
+    /// Add an entry to a course's FAQ section.
+    ///
+    /// Only the course creator or an admin can manage FAQs. A course holds
+    /// at most 20 entries; new ones are shown after the existing ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `question` - The learner question
+    /// * `answer` - The answer shown below the question
+    ///
+    /// # Returns
+    ///
+    /// Returns the created `CourseFaq` with its assigned ID and order.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If the caller is neither the creator nor an admin
+    /// * If the question or answer is empty or too long
+    /// * If the course already has 20 FAQ entries
+    pub fn add_faq(
+        env: Env,
+        creator: Address,
+        course_id: String,
+        question: String,
+        answer: String,
+    ) -> CourseFaq {
+        functions::course_faq::course_registry_add_faq(env, creator, course_id, question, answer)
+    }
+
+    /// Update the question and/or answer of an FAQ entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `faq_id` - The unique identifier of the FAQ entry
+    /// * `question` - Optional new question
+    /// * `answer` - Optional new answer
+    ///
+    /// # Panics
+    ///
+    /// * If the FAQ entry doesn't exist
+    /// * If the caller is neither the creator nor an admin
+    /// * If the new question or answer is empty or too long
+    pub fn update_faq(
+        env: Env,
+        creator: Address,
+        faq_id: u128,
+        question: Option<String>,
+        answer: Option<String>,
+    ) {
+        functions::course_faq::course_registry_update_faq(env, creator, faq_id, question, answer)
+    }
+
+    /// Delete an FAQ entry.
+    ///
+    /// # Arguments
     ///
-    /// * **No matches**: Returns empty vector if no courses match filters
-    /// * **Large limits**: Limit should be reasonable to avoid gas issues
-    /// * **Public access**: Anyone can list courses
-    /// * **Archived courses**: May or may not be included based on filter settings
-    pub fn list_courses_with_filters(
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `faq_id` - The unique identifier of the FAQ entry
+    ///
+    /// # Panics
+    ///
+    /// * If the FAQ entry doesn't exist
+    /// * If the caller is neither the creator nor an admin
+    pub fn delete_faq(env: Env, creator: Address, faq_id: u128) {
+        functions::course_faq::course_registry_delete_faq(env, creator, faq_id)
+    }
+
+    /// List the FAQ entries of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the course's FAQ entries sorted by their order.
+    pub fn list_course_faqs(env: Env, course_id: String) -> Vec<CourseFaq> {
+        functions::course_faq::course_registry_list_course_faqs(env, course_id)
+    }
+
+    /// Get the recorded versions of a course's title and description.
+    ///
+    /// A snapshot is taken when the course is created and on every
+    /// `update_course` call. Only the latest 10 versions are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the kept snapshots, oldest version first.
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    pub fn get_course_version_history(env: Env, course_id: String) -> Vec<CourseVersionSnapshot> {
+        functions::course_version_history::course_registry_get_course_version_history(env, course_id)
+    }
+
+    /// Reorder the modules of a course.
+    ///
+    /// Only the course creator can reorder modules. Each module's position
+    /// becomes its index in `module_order`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `module_order` - Every module ID of the course, in the desired order
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If the caller is not the course creator
+    /// * If `module_order` doesn't contain exactly the course's module IDs
+    pub fn reorder_modules(env: Env, creator: Address, course_id: String, module_order: Vec<String>) {
+        functions::reorder_modules::course_registry_reorder_modules(
+            env,
+            creator,
+            course_id,
+            module_order,
+        )
+    }
+
+    /// Duplicate a module within its course.
+    ///
+    /// Only the course creator can duplicate modules. The copy is titled
+    /// `"<title> (Copy)"` and placed right after `insert_after_position`;
+    /// later modules move up by one position.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator
+    /// * `module_id` - The unique identifier of the module to copy
+    /// * `insert_after_position` - The position the copy is inserted after
+    ///
+    /// # Returns
+    ///
+    /// Returns the newly created `CourseModule`.
+    ///
+    /// # Panics
+    ///
+    /// * If the module or its course doesn't exist
+    /// * If the caller is not the course creator
+    /// * If `insert_after_position` is too large
+    pub fn duplicate_module(
         env: Env,
-        filters: CourseFilters,
-        limit: Option<u32>,
-        offset: Option<u32>,
-    ) -> Vec<Course> {
-        functions::list_courses_with_filters::list_courses_with_filters(
-            &env, filters, limit, offset,
+        caller: Address,
+        module_id: String,
+        insert_after_position: u32,
+    ) -> CourseModule {
+        functions::duplicate_module::course_registry_duplicate_module(
+            env,
+            caller,
+            module_id,
+            insert_after_position,
+        )
+    }
+
+    /// Update a module's title and/or content URL.
+    ///
+    /// The course creator, co-instructors and admins can update a module.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of a course editor
+    /// * `module_id` - The unique identifier of the module
+    /// * `new_title` - Optional new title (must not be empty)
+    /// * `new_content_url` - Optional new content URL (must be a valid HTTP(S) URL)
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `CourseModule`.
+    ///
+    /// # Panics
+    ///
+    /// * If the module doesn't exist
+    /// * If the caller is not the course creator, a co-instructor or an admin
+    /// * If the title is empty or the URL is invalid
+    pub fn update_module(
+        env: Env,
+        caller: Address,
+        module_id: String,
+        new_title: Option<String>,
+        new_content_url: Option<String>,
+    ) -> CourseModule {
+        functions::update_module::course_registry_update_module(
+            env,
+            caller,
+            module_id,
+            new_title,
+            new_content_url,
+        )
+    }
+
+    /// Set the content type of a module.
+    ///
+    /// Only the course creator or an admin can change a module's content type.
+    /// New modules start as `ContentType::Unspecified`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the user updating the module
+    /// * `module_id` - The unique identifier of the module
+    /// * `content_type` - The new content type
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `CourseModule`.
+    ///
+    /// # Panics
+    ///
+    /// * If the module doesn't exist
+    /// * If the caller is neither the course creator nor an admin
+    pub fn update_module_content(
+        env: Env,
+        caller: Address,
+        module_id: String,
+        content_type: ContentType,
+    ) -> CourseModule {
+        functions::update_module_content::course_registry_update_module_content(
+            env,
+            caller,
+            module_id,
+            content_type,
+        )
+    }
+
+    /// Clone an existing course into a new course owned by the caller.
+    ///
+    /// Published courses can be cloned by anyone; unpublished courses only by
+    /// their creator or an admin. The clone is unpublished, has no
+    /// prerequisites, and gets a copy of every source module.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the user cloning the course
+    /// * `source_course_id` - The unique identifier of the course to copy
+    /// * `new_title` - The title of the new course
+    ///
+    /// # Returns
+    ///
+    /// Returns the newly created `Course`.
+    ///
+    /// # Panics
+    ///
+    /// * If the source course doesn't exist
+    /// * If the source is unpublished and the caller is neither its creator nor an admin
+    /// * If the new title is invalid or already taken
+    pub fn clone_course(
+        env: Env,
+        caller: Address,
+        source_course_id: String,
+        new_title: String,
+    ) -> Course {
+        functions::clone_course::course_registry_clone_course(
+            env,
+            caller,
+            source_course_id,
+            new_title,
+        )
+    }
+
+    /// List courses matching a filter, sorted and paginated.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `filter` - Criteria every returned course must meet
+    /// * `sort` - The order of the returned courses
+    /// * `offset` - Number of matching courses to skip
+    /// * `limit` - Maximum number of courses to return
+    /// * `viewer` - Optional authenticated viewer; private courses are listed
+    ///   only to their creator and admins
+    ///
+    /// # Returns
+    ///
+    /// Returns a `CourseListPage` with the matching courses.
+    pub fn list_courses(
+        env: Env,
+        filter: CourseFilter,
+        sort: CourseSortOrder,
+        offset: u32,
+        limit: u32,
+        viewer: Option<Address>,
+    ) -> CourseListPage {
+        functions::list_courses::course_registry_list_courses(env, filter, sort, offset, limit, viewer)
+    }
+
+    /// List the courses of a difficulty level with pagination.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `difficulty` - The difficulty level to filter by
+    /// * `offset` - Number of courses to skip
+    /// * `limit` - Maximum number of courses to return
+    ///
+    /// # Returns
+    ///
+    /// Returns a `CourseListPage` with the courses of that level.
+    pub fn list_courses_by_difficulty(
+        env: Env,
+        difficulty: DifficultyLevel,
+        offset: u32,
+        limit: u32,
+    ) -> CourseListPage {
+        functions::list_courses_by_difficulty::course_registry_list_courses_by_difficulty(
+            env, difficulty, offset, limit,
+        )
+    }
+
+    /// List the courses taught in a language with pagination.
+    ///
+    /// Languages are matched case-insensitively.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `language` - The language to filter by
+    /// * `offset` - Number of courses to skip
+    /// * `limit` - Maximum number of courses to return
+    ///
+    /// # Returns
+    ///
+    /// Returns a `CourseListPage` with the courses in that language.
+    ///
+    /// # Panics
+    ///
+    /// * If `language` is empty
+    pub fn list_courses_by_language(
+        env: Env,
+        language: String,
+        offset: u32,
+        limit: u32,
+    ) -> CourseListPage {
+        functions::list_courses_by_language::course_registry_list_courses_by_language(
+            env, language, offset, limit,
+        )
+    }
+
+    /// List the languages that courses are taught in.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns each language with at least one course, lowercase, in the
+    /// order it first appeared.
+    pub fn list_supported_languages(env: Env) -> Vec<String> {
+        functions::list_courses_by_language::course_registry_list_supported_languages(env)
+    }
+
+    /// Replace the tags of a course.
+    ///
+    /// Only the course creator can set tags. A course carries at most 10
+    /// unique tags of up to 30 characters each.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `tags` - The new list of tags (replaces the previous one)
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If the caller is not the course creator
+    /// * If there are too many, duplicate, empty or overly long tags
+    pub fn set_course_tags(env: Env, creator: Address, course_id: String, tags: Vec<String>) {
+        functions::set_course_tags::course_registry_set_course_tags(env, creator, course_id, tags)
+    }
+
+    /// Set where a course can be discovered.
+    ///
+    /// Public courses appear in every listing, unlisted courses are hidden
+    /// from category and tag listings, and private courses are listed only
+    /// to their creator and admins. `get_course` returns a course whatever
+    /// its visibility.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `visibility` - The new visibility
+    ///
+    /// # Panics
+    ///
+    /// * If the course doesn't exist
+    /// * If the caller is neither the creator nor an admin
+    pub fn set_course_visibility(
+        env: Env,
+        caller: Address,
+        course_id: String,
+        visibility: CourseVisibility,
+    ) {
+        functions::set_course_visibility::course_registry_set_course_visibility(
+            env, caller, course_id, visibility,
+        )
+    }
+
+    /// List the courses carrying a tag with pagination.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `tag` - The tag to look up
+    /// * `offset` - Number of courses to skip
+    /// * `limit` - Maximum number of courses to return
+    /// * `viewer` - Optional authenticated viewer; unlisted and private
+    ///   courses are listed only to their creator and admins
+    ///
+    /// # Returns
+    ///
+    /// Returns a `CourseListPage` with the tagged courses.
+    pub fn get_courses_by_tag(
+        env: Env,
+        tag: String,
+        offset: u32,
+        limit: u32,
+        viewer: Option<Address>,
+    ) -> CourseListPage {
+        functions::set_course_tags::course_registry_get_courses_by_tag(env, tag, offset, limit, viewer)
+    }
+
+    /// Search courses by the words of their title.
+    ///
+    /// Matching ignores case and returns only courses whose title contains
+    /// every word of the query. Words shorter than three characters are
+    /// ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `query` - The words to search for, separated by whitespace
+    /// * `offset` - The number of matching courses to skip
+    /// * `limit` - The maximum number of courses to return
+    ///
+    /// # Returns
+    ///
+    /// Returns a page of matching courses, the total number
+    /// of matches, and whether more follow.
+    pub fn search_courses_by_title(env: Env, query: String, offset: u32, limit: u32) -> CourseListPage {
+        functions::search_courses::course_registry_search_courses_by_title(env, query, offset, limit)
+    }
+
+    /// List the courses of a category with pagination.
+    ///
+    /// Courses are returned in the order they joined the category. No
+    /// authentication is required.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `category_id` - The ID of the category
+    /// * `offset` - Number of courses to skip
+    /// * `limit` - Maximum number of courses to return
+    /// * `include_archived` - Whether archived courses are listed
+    /// * `viewer` - Optional authenticated viewer; unlisted and private
+    ///   courses are listed only to their creator and admins
+    ///
+    /// # Returns
+    ///
+    /// Returns a `CourseListPage` with the courses, the category's total course
+    /// count and whether more courses follow.
+    ///
+    /// # Panics
+    ///
+    /// * If the category doesn't exist
+    pub fn list_courses_by_category(
+        env: Env,
+        category_id: u128,
+        offset: u32,
+        limit: u32,
+        include_archived: bool,
+        viewer: Option<Address>,
+    ) -> CourseListPage {
+        functions::list_courses_by_category::course_registry_list_courses_by_category(
+            env,
+            category_id,
+            offset,
+            limit,
+            include_archived,
+            viewer,
+        )
+    }
+
+    /// List the courses created by an address with pagination.
+    ///
+    /// Courses are returned in creation order. No authentication is required.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address whose courses are listed
+    /// * `offset` - Number of courses to skip
+    /// * `limit` - Maximum number of courses to return
+    /// * `include_archived` - Whether archived courses are listed
+    ///
+    /// # Returns
+    ///
+    /// Returns a `CourseListPage` with the courses, the creator's total course
+    /// count and whether more courses follow.
+    pub fn list_courses_by_creator(
+        env: Env,
+        creator: Address,
+        offset: u32,
+        limit: u32,
+        include_archived: bool,
+    ) -> CourseListPage {
+        functions::list_courses_by_creator::course_registry_list_courses_by_creator(
+            env,
+            creator,
+            offset,
+            limit,
+            include_archived,
         )
     }
 
@@ -9,6 +9,15 @@ pub const MAX_LOOP_GUARD: u32 = 1000;
 pub const FILTER_MIN_PRICE: u128 = 500;
 pub const MAX_SCAN_ID: u32 = 50;
 pub const MAX_EMPTY_CHECKS: u32 = 10;
+/// Maximum number of courses returned by one page of a list function
+pub const MAX_LIST_PAGE_SIZE: u32 = 100;
+/// Default maximum length of a prerequisite chain, including the course itself
+pub const MAX_PREREQUISITE_DEPTH: u32 = 5;
+/// Lowest and highest rating a course review can give
+pub const MIN_RATING: u32 = 1;
+pub const MAX_RATING: u32 = 5;
+/// Average ratings are reported multiplied by this factor, e.g. `450` for 4.5
+pub const RATING_SCALE: u32 = 100;
 
 /// Rate limiting constants for course operations
 pub const DEFAULT_COURSE_RATE_LIMIT_WINDOW: u64 = 3600; // 1 hour in seconds
@@ -22,6 +31,32 @@ pub struct CourseModule {
     pub position: u32,
     pub title: String,
     pub created_at: u64,
+    pub content_type: ContentType,
+    pub content_url: Option<String>,
+}
+
+/// Type of content delivered by a course module.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ContentType {
+    /// No content type has been set yet
+    Unspecified,
+    Video,
+    Article,
+    Quiz,
+    Assignment,
+    LiveSession,
+}
+
+/// Skill level a course is aimed at.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum DifficultyLevel {
+    Beginner = 0,
+    Intermediate = 1,
+    Advanced = 2,
+    Expert = 3,
 }
 
 #[contracttype]
@@ -81,6 +116,95 @@ pub enum DataKey {
     CourseRateLimitConfig,
     /// Key for storing course rate limiting data per address: address -> CourseRateLimitData
     CourseRateLimit(Address),
+    /// Key for storing the last update timestamp of every course: Vec<(course_id, timestamp)>
+    CourseUpdateTimestamps,
+    /// Key for storing the module IDs of a course: course_id -> Vec<module_id>
+    CourseModuleIds(String),
+    /// Key for storing the module IDs of a course by content type: (course_id, type) -> Vec<module_id>
+    CourseModulesByType(String, ContentType),
+    /// Key for storing the course IDs created by an address: creator -> Vec<course_id>
+    CreatorCourses(Address),
+    /// Key for storing the course IDs of a category: category_id -> Vec<course_id>
+    CategoryCourses(u128),
+    /// Key for storing the course IDs carrying a tag: tag -> Vec<course_id>
+    TagCourses(String),
+    /// Key for storing the course IDs of a difficulty level: level -> Vec<course_id>
+    DifficultyCourses(u32),
+    /// Key for looking up a category ID by its lowercase name: name -> category_id
+    CategoryByName(String),
+    /// Key for storing the IDs of all course categories
+    AllCategoryIds,
+    /// Key for the configured maximum prerequisite chain length
+    MaxPrerequisiteDepth,
+    /// Key for the number of existing courses, including archived ones
+    TotalCourses,
+    /// Key for the number of published, non-archived courses
+    PublishedCourseCount,
+    /// Key for storing a user's review of a course: (reviewer, course_id) -> CourseReview
+    UserReview(Address, String),
+    /// Key for storing the reviewers of a course: course_id -> Vec<Address>
+    CourseReviewers(String),
+    /// Key for the sum of all ratings of a course: course_id -> u64
+    RatingSum(String),
+    /// Key for the number of ratings of a course: course_id -> u32
+    RatingCount(String),
+    /// Sequence counter for course bundle IDs
+    BundleSeq,
+    /// Key for storing course bundles: bundle_id -> CourseBundle
+    CourseBundle(u128),
+    /// Key for scheduled courses ordered by start time: Vec<(start_timestamp, course_id)>
+    ScheduledCourses,
+    /// Key for storing the courses that require a course: prerequisite_id -> Vec<course_id>
+    PrerequisiteOf(String),
+    /// Key for whether deleting a required course only emits a warning instead of failing
+    WarnOnDependentDeletion,
+    /// Sequence counter for course attachment IDs
+    AttachmentSeq,
+    /// Key for storing course attachments: attachment_id -> CourseAttachment
+    CourseAttachment(u128),
+    /// Key for storing the attachment IDs of a course: course_id -> Vec<attachment_id>
+    CourseAttachmentIds(String),
+    /// Key for storing the IDs of all courses in creation order: Vec<course_id>
+    AllCourseIds,
+    /// Key for storing progress snapshots: (course_id, timestamp) -> ProgressSnapshot
+    Snapshot(String, u64),
+    /// Key for storing the snapshot timestamps of a course in ascending order: course_id -> Vec<u64>
+    SnapshotTimestamps(String),
+    /// Key for storing the course IDs whose title contains a word: lowercase word -> Vec<course_id>
+    TitleWord(String),
+    /// Key for storing the recent price changes of a course, oldest first: course_id -> Vec<PriceChange>
+    PriceHistory(String),
+    /// Key for the last issued module resource ID
+    ModuleResourceSeq,
+    /// Key for storing module resources: resource_id -> ModuleResource
+    ModuleResource(u128),
+    /// Key for storing the resource IDs of a module: module_id -> Vec<resource_id>
+    ModuleResourceIds(String),
+    /// Key for storing the IDs of the homepage featured courses, in the order featured: Vec<course_id>
+    FeaturedCourses,
+    /// Key for storing the course IDs of a language: lowercase language -> Vec<course_id>
+    LanguageCourses(String),
+    /// Key for storing every lowercase language with at least one course: Vec<language>
+    AllLanguages,
+    /// Temporary key caching a creator's statistics: creator -> (CreatorStats, computed_at)
+    CreatorStats(Address),
+    /// Key for storing the number of FAQ entries of a course: course_id -> u32
+    CourseFaqCount(String),
+    /// Key for the snapshot of a course version: (course_id, version) -> CourseVersionSnapshot
+    CourseVersionHistory(String, u32),
+}
+
+/// Where a course can be discovered. Direct lookups by ID work for every
+/// visibility.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum CourseVisibility {
+    /// Shown in every listing
+    Public,
+    /// Hidden from category and tag listings
+    Unlisted,
+    /// Listed only to the course creator and admins
+    Private,
 }
 
 #[contracttype]
@@ -99,6 +223,206 @@ pub struct Course {
     pub is_archived: bool,
     pub level: Option<CourseLevel>,
     pub duration_hours: Option<u32>,
+    pub tags: Vec<String>,
+    /// `DifficultyLevel` discriminant, stored as `u32` to avoid Soroban
+    /// serialization issues with optional enum fields
+    pub difficulty: Option<u32>,
+    /// Maximum number of enrolled users, enforced by course_access
+    pub max_enrollments: Option<u32>,
+    /// Scheduled start of a live cohort (ledger timestamp)
+    pub start_timestamp: Option<u64>,
+    /// Scheduled end of a live cohort (ledger timestamp)
+    pub end_timestamp: Option<u64>,
+    /// Additional instructors allowed to manage the course content
+    pub co_instructors: Vec<Address>,
+    /// Whether the course is on the curated homepage list
+    pub featured: bool,
+    /// Whether new enrollments are temporarily halted
+    pub frozen: bool,
+    /// Whether users can enroll without paying, whatever the listed price
+    pub is_free: bool,
+    /// Who can discover the course through listings
+    pub visibility: CourseVisibility,
+    /// Content version, starting at 1 and bumped by every `update_course`
+    pub course_version: u32,
+}
+
+/// Title and description of a course as of one of its versions.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseVersionSnapshot {
+    pub version: u32,
+    pub title: String,
+    pub description: String,
+    /// Ledger timestamp the version was created at
+    pub changed_at: u64,
+    /// Who created or updated the course to this version
+    pub changed_by: Address,
+}
+
+/// A learner's rating and optional comment on a course.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseReview {
+    pub reviewer: Address,
+    pub course_id: String,
+    /// Rating between `MIN_RATING` and `MAX_RATING`
+    pub rating: u32,
+    pub comment: Option<String>,
+    pub created_at: u64,
+}
+
+/// Kind of supplemental material attached to a course.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttachmentType {
+    Pdf,
+    Video,
+    Link,
+    Archive,
+}
+
+/// A supplemental resource linked from a course.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseAttachment {
+    pub id: u128,
+    pub course_id: String,
+    pub url: String,
+    pub title: String,
+    pub attachment_type: AttachmentType,
+}
+
+/// A question and answer shown in a course's FAQ section.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseFaq {
+    pub id: u128,
+    pub course_id: String,
+    pub question: String,
+    pub answer: String,
+    /// Display position; entries are listed by ascending order
+    pub order: u32,
+}
+
+/// Kind of downloadable resource attached to a module.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ResourceType {
+    Pdf,
+    Spreadsheet,
+    Code,
+    Link,
+}
+
+/// A downloadable resource linked from a course module.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModuleResource {
+    pub id: u128,
+    pub module_id: String,
+    pub url: String,
+    pub title: String,
+    pub resource_type: ResourceType,
+}
+
+/// A change of a course's price.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PriceChange {
+    pub old_price: u128,
+    pub new_price: u128,
+    /// Ledger timestamp of the change
+    pub changed_at: u64,
+    pub changed_by: Address,
+}
+
+/// Enrollment and rating figures of a course at a point in time.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProgressSnapshot {
+    pub course_id: String,
+    /// Ledger timestamp the snapshot was taken at
+    pub timestamp: u64,
+    pub total_enrolled: u32,
+    /// Average rating multiplied by `RATING_SCALE`, `0` without ratings
+    pub average_rating: u32,
+}
+
+/// Aggregated figures over every course of a creator.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreatorStats {
+    pub total_courses: u32,
+    /// Published courses that are not archived
+    pub published_courses: u32,
+    pub total_enrollments: u32,
+    /// Average of all ratings across the creator's courses multiplied by
+    /// `RATING_SCALE`, `0` without ratings
+    pub average_rating: u32,
+}
+
+/// A course with its rating figures, as ranked by `get_top_rated_courses`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseRatingEntry {
+    pub course: Course,
+    /// Average rating multiplied by `RATING_SCALE`, `0` without ratings
+    pub average_rating: u32,
+    pub review_count: u32,
+}
+
+/// A group of courses sold together.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseBundle {
+    pub id: u128,
+    pub name: String,
+    pub description: Option<String>,
+    pub creator: Address,
+    pub course_ids: Vec<String>,
+    pub price: u128,
+}
+
+/// A course together with its modules, prerequisites and tags.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseWithModules {
+    pub course: Course,
+    /// Modules of the course, sorted by position
+    pub modules: Vec<CourseModule>,
+    pub prerequisites: Vec<String>,
+    pub tags: Vec<String>,
+}
+
+/// Pre-publish checklist of a course.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseValidationReport {
+    /// Whether the course has no blocking errors
+    pub is_publishable: bool,
+    /// Problems that block publishing
+    pub errors: Vec<String>,
+    /// Missing details that do not block publishing
+    pub warnings: Vec<String>,
+}
+
+/// A course together with its transitive prerequisites.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrerequisiteTree {
+    pub course_id: String,
+    pub title: String,
+    pub prerequisites: Vec<PrerequisiteTree>,
+}
+
+/// A page of courses returned by the paginated listing functions.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseListPage {
+    pub courses: Vec<Course>,
+    pub total: u32,
+    pub has_more: bool,
 }
 
 #[contracttype]
@@ -132,6 +456,39 @@ pub struct CourseFilters {
     pub search_text: Option<String>,
 }
 
+/// Order in which `list_courses` returns courses.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum CourseSortOrder {
+    ByCreationDate,
+    ByTitle,
+    /// Cheapest first
+    ByPrice,
+}
+
+/// Difficulty criterion of a `CourseFilter`.
+///
+/// Stands in for `Option<DifficultyLevel>`, which a `#[contracttype]` struct
+/// cannot hold.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DifficultyFilter {
+    Any,
+    Level(DifficultyLevel),
+}
+
+/// Criteria a course must meet to be returned by `list_courses`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseFilter {
+    pub category_id: Option<u128>,
+    pub difficulty: DifficultyFilter,
+    pub language: Option<String>,
+    pub max_price: Option<u128>,
+    /// Only published, non-archived courses
+    pub published_only: bool,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct EditCourseParams {
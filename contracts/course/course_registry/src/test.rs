@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use crate::{schema::Category, CourseRegistry, CourseRegistryClient};
+use crate::{schema::{Category, ContentType}, CourseRegistry, CourseRegistryClient};
 use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String, Vec};
 
 use crate::{
@@ -61,8 +61,9 @@ fn test_remove_module_success() {
         &Some(String::from_str(&env, "thumbnail_url")),
         &None,
         &None,
+        &None,
     );
-    let new_module = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Module Title"));
+    let new_module = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Module Title"), &ContentType::Unspecified, &None);
 
     let exists: bool = env.as_contract(&contract_id, || {
         env.storage()
@@ -95,9 +96,10 @@ fn test_remove_multiple_different_modules() {
         &Some(String::from_str(&env, "thumbnail_url")),
         &None,
         &None,
+        &None,
     );
-    let module1 = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Module 1 Title"));
-    let module2 = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module 2 Title"));
+    let module1 = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Module 1 Title"), &ContentType::Unspecified, &None);
+    let module2 = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module 2 Title"), &ContentType::Unspecified, &None);
 
     client.remove_module(&module1.id.clone());
     client.remove_module(&module2.id.clone());
@@ -132,9 +134,10 @@ fn test_remove_module_storage_isolation() {
         &Some(String::from_str(&env, "thumbnail_url")),
         &None,
         &None,
+        &None,
     );
-    let module1 = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Module 1 Title"));
-    let module2 = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module 2 Title"));
+    let module1 = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Module 1 Title"), &ContentType::Unspecified, &None);
+    let module2 = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module 2 Title"), &ContentType::Unspecified, &None);
 
     client.remove_module(&module1.id.clone());
 
@@ -172,6 +175,7 @@ fn test_get_course_success() {
         &None,
         &None,
         &None,
+        &None,
     );
 
     let retrieved = client.get_course(&course.id);
@@ -228,6 +232,7 @@ fn test_get_courses_by_instructor_found() {
         &None,
         &None,
         &None,
+        &None,
     );
 
     let results = client.get_courses_by_instructor(&creator);
@@ -255,6 +260,7 @@ fn test_get_prerequisites_by_course_id() {
         &None,
         &None,
         &None,
+        &None,
     );
 
     let prerequisites = env.as_contract(&contract_id, || {
@@ -282,6 +288,7 @@ fn test_list_categories_counts() {
         &None,
         &None,
         &None,
+        &None,
     );
     client.create_course(
         &creator,
@@ -293,6 +300,7 @@ fn test_list_categories_counts() {
         &None,
         &None,
         &None,
+        &None,
     );
     client.create_course(
         &creator,
@@ -304,6 +312,7 @@ fn test_list_categories_counts() {
         &None,
         &None,
         &None,
+        &None,
     );
 
     // Call the function to list categories
@@ -354,6 +363,7 @@ fn test_list_categories_ignores_none() {
         &None,
         &None,
         &None,
+        &None,
     );
 
     let cats = client.list_categories();
@@ -382,6 +392,7 @@ fn test_list_categories_with_id_gaps() {
         &None,
         &None,
         &None,
+        &None,
     );
     client.create_course(
         &creator,
@@ -393,6 +404,7 @@ fn test_list_categories_with_id_gaps() {
         &None,
         &None,
         &None,
+        &None,
     );
 
     // Manually delete course 2 to create an ID gap
@@ -412,6 +424,7 @@ fn test_list_categories_with_id_gaps() {
         &None,
         &None,
         &None,
+        &None,
     );
 
     // Call the function - it should skip missing ID 2 but still count 1 and 3
@@ -452,6 +465,7 @@ fn test_course_backup_and_recovery_system() {
         &None,
         &None,
         &None,
+        &None,
     );
 
     let _course2 = client.create_course(
@@ -464,6 +478,7 @@ fn test_course_backup_and_recovery_system() {
         &None,
         &None,
         &None,
+        &None,
     );
 
     // Set up admin first (add to admin list) - use contract context
@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Error codes shared by every SkillCert contract.
+//!
+//! Codes below `CONTRACT_ERROR_CODE_START` are reserved for `CommonError`, so
+//! a failure such as "unauthorized" reads the same whichever contract raised
+//! it. Each contract numbers its own `Error` variants from
+//! `CONTRACT_ERROR_CODE_START` upwards.
+
+use soroban_sdk::{contracterror, panic_with_error, Env};
+
+/// First code available to contract-specific errors
+pub const CONTRACT_ERROR_CODE_START: u32 = 100;
+
+/// Failures that mean the same thing in every contract.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CommonError {
+    Unauthorized = 1,
+    NotFound = 2,
+    AlreadyExists = 3,
+    InvalidInput = 4,
+    SystemNotInitialized = 5,
+    ContractPaused = 6,
+    RateLimitExceeded = 7,
+}
+
+/// Panics with a common error, like each contract's own `handle_error`.
+///
+/// The return type lets the call stand in for any value, e.g. in
+/// `unwrap_or_else`.
+pub fn handle_common_error<T>(env: &Env, err: CommonError) -> T {
+    panic_with_error!(env, err);
+}
+
+#[cfg(test)]
+mod test {
+    use super::{handle_common_error, CommonError, CONTRACT_ERROR_CODE_START};
+    use soroban_sdk::Env;
+
+    const ALL: [CommonError; 7] = [
+        CommonError::Unauthorized,
+        CommonError::NotFound,
+        CommonError::AlreadyExists,
+        CommonError::InvalidInput,
+        CommonError::SystemNotInitialized,
+        CommonError::ContractPaused,
+        CommonError::RateLimitExceeded,
+    ];
+
+    #[test]
+    fn test_common_error_codes_are_unique_and_reserved() {
+        for (i, error) in ALL.iter().enumerate() {
+            let code: u32 = *error as u32;
+            assert!(code > 0 && code < CONTRACT_ERROR_CODE_START);
+            for other in ALL.iter().skip(i + 1) {
+                assert_ne!(code, *other as u32);
+            }
+        }
+    }
+
+    /// Every file of the contracts that declares a `#[contracterror]` enum
+    const CONTRACT_ERROR_SOURCES: [(&str, &str); 8] = [
+        ("certification/src/error.rs", include_str!("../../certification/src/error.rs")),
+        ("course_access/src/error.rs", include_str!("../../course/course_access/src/error.rs")),
+        (
+            "course_access/src/functions/contract_versioning.rs",
+            include_str!("../../course/course_access/src/functions/contract_versioning.rs"),
+        ),
+        ("course_registry/src/error.rs", include_str!("../../course/course_registry/src/error.rs")),
+        (
+            "course_registry/src/functions/contract_versioning.rs",
+            include_str!("../../course/course_registry/src/functions/contract_versioning.rs"),
+        ),
+        ("user_management/src/error.rs", include_str!("../../user_management/src/error.rs")),
+        (
+            "user_management/src/functions/contract_versioning.rs",
+            include_str!("../../user_management/src/functions/contract_versioning.rs"),
+        ),
+        ("user_profile/src/error.rs", include_str!("../../user_profile/src/error.rs")),
+    ];
+
+    /// Codes of the `Variant = code,` lines of an error module.
+    fn error_codes(source: &str) -> impl Iterator<Item = u32> + '_ {
+        source.lines().filter_map(|line| {
+            let (name, code) = line.trim().strip_suffix(',')?.split_once(" = ")?;
+            if !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return None;
+            }
+            code.parse().ok()
+        })
+    }
+
+    #[test]
+    fn test_contract_error_codes_clear_of_common_errors() {
+        for (file, source) in CONTRACT_ERROR_SOURCES {
+            assert!(error_codes(source).count() > 0, "no error codes in {}", file);
+            for code in error_codes(source) {
+                assert!(
+                    code >= CONTRACT_ERROR_CODE_START,
+                    "{} uses reserved error code {}",
+                    file,
+                    code
+                );
+                assert_eq!(
+                    error_codes(source).filter(|other| *other == code).count(),
+                    1,
+                    "{} uses error code {} twice",
+                    file,
+                    code
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #5)")]
+    fn test_handle_common_error() {
+        let env = Env::default();
+        let _: u32 = handle_common_error(&env, CommonError::SystemNotInitialized);
+    }
+}
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Helpers shared by the SkillCert contracts.
+
+#![no_std]
+
+pub mod errors;
+pub mod pagination;
+pub mod profile_utils;
+pub mod storage_utils;
+pub mod url;
+pub mod versioning;
+
+pub use errors::{handle_common_error, CommonError, CONTRACT_ERROR_CODE_START};
+pub use pagination::{Page, Paginator};
+pub use profile_utils::{validate_country_code, validate_email, ProfileValidationError};
+pub use storage_utils::{
+    bulk_remove, bulk_set, touch_persistent_ttl, DEFAULT_PERSISTENT_TTL,
+    DEFAULT_PERSISTENT_TTL_BUMP,
+};
+pub use url::validate_url;
+pub use versioning::{
+    clear_snapshot, get_current_version, rollback_migration, run_migration, set_current_version,
+    snapshot_state, MigrationHandler, SemVer, VersioningError, CURRENT_VERSION_KEY,
+};
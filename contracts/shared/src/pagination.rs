@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Env, IntoVal, TryFromVal, Val, Vec};
+
+/// One page of a paginated listing.
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: u32,
+    pub has_more: bool,
+}
+
+/// Offset-based pagination with a capped page size.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Paginator {
+    pub offset: u32,
+    pub limit: u32,
+    pub max_limit: u32,
+}
+
+impl Paginator {
+    /// Creates a paginator, clamping `limit` to `max_limit`.
+    pub fn new(offset: u32, limit: u32, max_limit: u32) -> Self {
+        Paginator {
+            offset,
+            limit: limit.min(max_limit),
+            max_limit,
+        }
+    }
+
+    /// Returns the items of this page and whether more items follow it.
+    ///
+    /// An `offset` at or past the end of `items` yields an empty page.
+    pub fn paginate<T>(&self, items: &Vec<T>, env: &Env) -> (Vec<T>, bool)
+    where
+        T: IntoVal<Env, Val> + TryFromVal<Env, Val> + Clone,
+    {
+        let total: u32 = items.len();
+        if self.offset >= total {
+            return (Vec::new(env), false);
+        }
+
+        let end: u32 = self.offset.saturating_add(self.limit).min(total);
+        (items.slice(self.offset..end), end < total)
+    }
+
+    /// Returns this page of `items` together with the total item count.
+    pub fn page<T>(&self, items: &Vec<T>, env: &Env) -> Page<T>
+    where
+        T: IntoVal<Env, Val> + TryFromVal<Env, Val> + Clone,
+    {
+        let (page_items, has_more) = self.paginate(items, env);
+        Page {
+            items: page_items,
+            total: items.len(),
+            has_more,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::vec;
+
+    #[test]
+    fn test_limit_is_clamped() {
+        assert_eq!(Paginator::new(0, 500, 100).limit, 100);
+        assert_eq!(Paginator::new(0, 20, 100).limit, 20);
+    }
+
+    #[test]
+    fn test_paginate_boundaries() {
+        let env = Env::default();
+        let items: Vec<u32> = vec![&env, 1, 2, 3, 4, 5];
+
+        assert_eq!(
+            Paginator::new(0, 2, 10).paginate(&items, &env),
+            (vec![&env, 1, 2], true)
+        );
+        assert_eq!(
+            Paginator::new(3, 2, 10).paginate(&items, &env),
+            (vec![&env, 4, 5], false)
+        );
+        assert_eq!(
+            Paginator::new(4, 10, 10).paginate(&items, &env),
+            (vec![&env, 5], false)
+        );
+        assert_eq!(
+            Paginator::new(5, 2, 10).paginate(&items, &env),
+            (Vec::new(&env), false)
+        );
+        assert_eq!(
+            Paginator::new(u32::MAX, u32::MAX, u32::MAX).paginate(&items, &env),
+            (Vec::new(&env), false)
+        );
+        assert_eq!(
+            Paginator::new(1, 0, 10).paginate(&items, &env),
+            (Vec::new(&env), true)
+        );
+        assert_eq!(
+            Paginator::new(0, 10, 3).paginate(&items, &env),
+            (vec![&env, 1, 2, 3], true)
+        );
+    }
+
+    #[test]
+    fn test_paginate_empty_input() {
+        let env = Env::default();
+        let items: Vec<u32> = Vec::new(&env);
+
+        let page = Paginator::new(0, 10, 10).page(&items, &env);
+        assert_eq!(page.items.len(), 0);
+        assert_eq!(page.total, 0);
+        assert!(!page.has_more);
+    }
+}
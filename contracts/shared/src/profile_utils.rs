@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::String;
+
+/// Maximum accepted email length (RFC 5321)
+pub const MAX_EMAIL_LENGTH: u32 = 320;
+
+/// Maximum accepted country name length (longest official country name)
+pub const MAX_COUNTRY_LENGTH: u32 = 56;
+
+/// Reasons a profile field is rejected.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProfileValidationError {
+    /// The country is empty or only whitespace
+    CountryEmpty,
+    /// The country is longer than `MAX_COUNTRY_LENGTH`
+    CountryTooLong,
+}
+
+/// Validates that a string looks like an email address.
+///
+/// Checks a subset of RFC 5321: exactly one `@`, a non-empty local part
+/// without whitespace or commas, and a domain containing at least one `.`
+/// and no whitespace. The address must be at most `MAX_EMAIL_LENGTH` long.
+pub fn validate_email(email: &String) -> bool {
+    let len: usize = email.len() as usize;
+    if len == 0 || len > MAX_EMAIL_LENGTH as usize {
+        return false;
+    }
+
+    let mut buf = [0u8; MAX_EMAIL_LENGTH as usize];
+    let bytes: &mut [u8] = &mut buf[..len];
+    email.copy_into_slice(bytes);
+
+    let mut at_index: Option<usize> = None;
+    for (i, b) in bytes.iter().enumerate() {
+        if *b == b'@' {
+            if at_index.is_some() {
+                return false;
+            }
+            at_index = Some(i);
+        }
+    }
+    let at: usize = match at_index {
+        Some(at) => at,
+        None => return false,
+    };
+
+    let local: &[u8] = &bytes[..at];
+    let domain: &[u8] = &bytes[at + 1..];
+
+    !local.is_empty()
+        && local.iter().all(|b| !b.is_ascii_whitespace() && *b != b',')
+        && domain.contains(&b'.')
+        && domain.iter().all(|b| !b.is_ascii_whitespace())
+}
+
+/// Validates a country given as a free-form name or an ISO 3166-1 alpha-2 code.
+///
+/// Leading and trailing spaces are ignored. A two-letter value is treated as
+/// an alpha-2 code in any letter case; anything else is accepted as a country
+/// name of at most `MAX_COUNTRY_LENGTH` characters.
+pub fn validate_country_code(country: &String) -> Result<(), ProfileValidationError> {
+    let len: usize = country.len() as usize;
+    let mut buf = [0u8; 4 * MAX_COUNTRY_LENGTH as usize];
+    if len > buf.len() {
+        return Err(ProfileValidationError::CountryTooLong);
+    }
+    country.copy_into_slice(&mut buf[..len]);
+    let trimmed: &[u8] = buf[..len].trim_ascii();
+
+    if trimmed.is_empty() {
+        return Err(ProfileValidationError::CountryEmpty);
+    }
+    if trimmed.len() == 2 && trimmed.iter().all(|b| b.is_ascii_alphabetic()) {
+        return Ok(());
+    }
+    if trimmed.len() > MAX_COUNTRY_LENGTH as usize {
+        return Err(ProfileValidationError::CountryTooLong);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::Env;
+
+    #[test]
+    fn test_valid_emails() {
+        let env = Env::default();
+        assert!(validate_email(&String::from_str(&env, "user@example.com")));
+        assert!(validate_email(&String::from_str(&env, "first.last+tag@sub.example.org")));
+        assert!(validate_email(&String::from_str(&env, "a@b.co")));
+    }
+
+    #[test]
+    fn test_invalid_emails() {
+        let env = Env::default();
+        assert!(!validate_email(&String::from_str(&env, "")));
+        assert!(!validate_email(&String::from_str(&env, "noatsign")));
+        assert!(!validate_email(&String::from_str(&env, "double@@domain.com")));
+        assert!(!validate_email(&String::from_str(&env, "@nodomain")));
+        assert!(!validate_email(&String::from_str(&env, "local@")));
+        assert!(!validate_email(&String::from_str(&env, "local@nodot")));
+        assert!(!validate_email(&String::from_str(&env, "first last@example.com")));
+        assert!(!validate_email(&String::from_str(&env, "a,b@example.com")));
+        assert!(!validate_email(&String::from_str(&env, "user@exa mple.com")));
+    }
+
+    #[test]
+    fn test_email_length_limit() {
+        let env = Env::default();
+        let mut long_email = [b'a'; MAX_EMAIL_LENGTH as usize + 1];
+        long_email[MAX_EMAIL_LENGTH as usize - 6..MAX_EMAIL_LENGTH as usize]
+            .copy_from_slice(b"@x.com");
+        assert!(validate_email(&String::from_bytes(&env, &long_email[..MAX_EMAIL_LENGTH as usize])));
+        long_email[MAX_EMAIL_LENGTH as usize - 5..].copy_from_slice(b"@x.com");
+        assert!(!validate_email(&String::from_bytes(&env, &long_email)));
+    }
+
+    #[test]
+    fn test_valid_countries() {
+        let env = Env::default();
+        assert_eq!(validate_country_code(&String::from_str(&env, "United States")), Ok(()));
+        assert_eq!(validate_country_code(&String::from_str(&env, "US")), Ok(()));
+        assert_eq!(validate_country_code(&String::from_str(&env, " us ")), Ok(()));
+    }
+
+    #[test]
+    fn test_invalid_countries() {
+        let env = Env::default();
+        assert_eq!(
+            validate_country_code(&String::from_str(&env, "")),
+            Err(ProfileValidationError::CountryEmpty)
+        );
+        assert_eq!(
+            validate_country_code(&String::from_str(&env, "   ")),
+            Err(ProfileValidationError::CountryEmpty)
+        );
+
+        let long_name = [b'a'; MAX_COUNTRY_LENGTH as usize + 1];
+        assert_eq!(
+            validate_country_code(&String::from_bytes(&env, &long_name)),
+            Err(ProfileValidationError::CountryTooLong)
+        );
+        assert_eq!(
+            validate_country_code(&String::from_bytes(&env, &long_name[..MAX_COUNTRY_LENGTH as usize])),
+            Ok(())
+        );
+    }
+}
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Env, IntoVal, TryFromVal, Val, Vec};
+
+/// Remaining TTL, in ledgers, below which a touched persistent entry is extended
+pub const DEFAULT_PERSISTENT_TTL_BUMP: u32 = 100;
+
+/// TTL, in ledgers, a touched persistent entry is extended to
+pub const DEFAULT_PERSISTENT_TTL: u32 = 1000;
+
+/// Keeps a frequently read persistent entry alive.
+///
+/// Extends the entry to `ttl` ledgers when its remaining TTL has dropped
+/// below `bump`, and leaves it alone otherwise. The threshold check is done
+/// by `extend_ttl` itself, as contracts cannot read an entry's TTL outside of
+/// tests. Missing entries are ignored.
+pub fn touch_persistent_ttl<K>(env: &Env, key: &K, bump: u32, ttl: u32)
+where
+    K: IntoVal<Env, Val> + Clone,
+{
+    if env.storage().persistent().has(key) {
+        env.storage().persistent().extend_ttl(key, bump, ttl);
+    }
+}
+
+/// Removes every persistent entry in `keys`; keys without an entry are skipped.
+pub fn bulk_remove<K>(env: &Env, keys: &Vec<K>)
+where
+    K: IntoVal<Env, Val> + TryFromVal<Env, Val> + Clone,
+{
+    for key in keys.iter() {
+        if env.storage().persistent().has(&key) {
+            env.storage().persistent().remove(&key);
+        }
+    }
+}
+
+/// Writes every `(key, value)` pair in `entries` to persistent storage, in order.
+pub fn bulk_set<K, V>(env: &Env, entries: &Vec<(K, V)>)
+where
+    K: IntoVal<Env, Val> + Clone,
+    V: IntoVal<Env, Val> + Clone,
+    (K, V): IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    for (key, value) in entries.iter() {
+        env.storage().persistent().set(&key, &value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::storage::Persistent as _;
+    use soroban_sdk::{contract, contractimpl, symbol_short, Symbol};
+
+    #[contract]
+    struct StorageContract;
+
+    #[contractimpl]
+    impl StorageContract {
+        pub fn noop() {}
+    }
+
+    const KEY: Symbol = symbol_short!("entry");
+
+    #[test]
+    fn test_touch_extends_when_below_threshold() {
+        let env = Env::default();
+        let contract_id = env.register(StorageContract, ());
+
+        env.as_contract(&contract_id, || {
+            env.storage().persistent().set(&KEY, &1u32);
+            let initial: u32 = env.storage().persistent().get_ttl(&KEY);
+
+            touch_persistent_ttl(&env, &KEY, initial + 1, initial + 500);
+
+            assert_eq!(env.storage().persistent().get_ttl(&KEY), initial + 500);
+        });
+    }
+
+    #[test]
+    fn test_touch_keeps_sufficient_ttl() {
+        let env = Env::default();
+        let contract_id = env.register(StorageContract, ());
+
+        env.as_contract(&contract_id, || {
+            env.storage().persistent().set(&KEY, &1u32);
+            let initial: u32 = env.storage().persistent().get_ttl(&KEY);
+
+            touch_persistent_ttl(&env, &KEY, DEFAULT_PERSISTENT_TTL_BUMP, initial + 500);
+
+            assert_eq!(env.storage().persistent().get_ttl(&KEY), initial);
+        });
+    }
+
+    #[test]
+    fn test_touch_ignores_missing_entry() {
+        let env = Env::default();
+        let contract_id = env.register(StorageContract, ());
+
+        env.as_contract(&contract_id, || {
+            touch_persistent_ttl(&env, &KEY, DEFAULT_PERSISTENT_TTL_BUMP, DEFAULT_PERSISTENT_TTL);
+            assert!(!env.storage().persistent().has(&KEY));
+        });
+    }
+
+    #[test]
+    fn test_bulk_set_and_remove() {
+        let env = Env::default();
+        let contract_id = env.register(StorageContract, ());
+
+        env.as_contract(&contract_id, || {
+            let entries: Vec<(Symbol, u32)> = soroban_sdk::vec![
+                &env,
+                (symbol_short!("first"), 1u32),
+                (symbol_short!("second"), 2u32),
+                (symbol_short!("third"), 3u32),
+            ];
+            bulk_set(&env, &entries);
+            for (key, value) in entries.iter() {
+                assert_eq!(env.storage().persistent().get::<_, u32>(&key), Some(value));
+            }
+
+            let keys: Vec<Symbol> =
+                soroban_sdk::vec![&env, symbol_short!("first"), symbol_short!("third")];
+            bulk_remove(&env, &keys);
+            assert!(!env.storage().persistent().has(&symbol_short!("first")));
+            assert!(env.storage().persistent().has(&symbol_short!("second")));
+            assert!(!env.storage().persistent().has(&symbol_short!("third")));
+        });
+    }
+
+    #[test]
+    fn test_bulk_remove_skips_missing_keys() {
+        let env = Env::default();
+        let contract_id = env.register(StorageContract, ());
+
+        env.as_contract(&contract_id, || {
+            env.storage().persistent().set(&KEY, &1u32);
+            let keys: Vec<Symbol> = soroban_sdk::vec![&env, symbol_short!("missing"), KEY];
+
+            bulk_remove(&env, &keys);
+
+            assert!(!env.storage().persistent().has(&KEY));
+        });
+    }
+}
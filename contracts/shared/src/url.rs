@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::String;
+
+/// Maximum accepted URL length
+pub const MAX_URL_LENGTH: u32 = 2048;
+
+/// Validates that a string is an HTTP(S) URL.
+///
+/// The URL must start with `http://` or `https://` (case-insensitive), have a
+/// non-empty host, contain no whitespace or control characters and be at most
+/// `MAX_URL_LENGTH` characters long.
+pub fn validate_url(url: &String) -> bool {
+    let len: usize = url.len() as usize;
+    if len == 0 || len > MAX_URL_LENGTH as usize {
+        return false;
+    }
+
+    let mut buf = [0u8; MAX_URL_LENGTH as usize];
+    let bytes: &mut [u8] = &mut buf[..len];
+    url.copy_into_slice(bytes);
+
+    let rest: &[u8] = if has_prefix(bytes, b"https://") {
+        &bytes[8..]
+    } else if has_prefix(bytes, b"http://") {
+        &bytes[7..]
+    } else {
+        return false;
+    };
+
+    !rest.is_empty() && rest[0] != b'/' && bytes.iter().all(|b| b.is_ascii_graphic())
+}
+
+fn has_prefix(bytes: &[u8], prefix: &[u8]) -> bool {
+    bytes.len() >= prefix.len() && bytes[..prefix.len()].eq_ignore_ascii_case(prefix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::Env;
+
+    #[test]
+    fn test_valid_urls() {
+        let env = Env::default();
+        assert!(validate_url(&String::from_str(&env, "https://example.com")));
+        assert!(validate_url(&String::from_str(&env, "http://example.com/path?q=1")));
+        assert!(validate_url(&String::from_str(&env, "HTTPS://EXAMPLE.COM")));
+    }
+
+    #[test]
+    fn test_invalid_urls() {
+        let env = Env::default();
+        assert!(!validate_url(&String::from_str(&env, "")));
+        assert!(!validate_url(&String::from_str(&env, "example.com")));
+        assert!(!validate_url(&String::from_str(&env, "ftp://example.com")));
+        assert!(!validate_url(&String::from_str(&env, "https://")));
+        assert!(!validate_url(&String::from_str(&env, "https:///path")));
+        assert!(!validate_url(&String::from_str(&env, "https://exa mple.com")));
+    }
+
+    #[test]
+    fn test_url_length_limit() {
+        let env = Env::default();
+        let mut long_url = [b'a'; MAX_URL_LENGTH as usize + 1];
+        long_url[..8].copy_from_slice(b"https://");
+        assert!(!validate_url(&String::from_bytes(&env, &long_url)));
+        assert!(validate_url(&String::from_bytes(&env, &long_url[..MAX_URL_LENGTH as usize])));
+    }
+}
@@ -0,0 +1,320 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Env, Map, String, Val, Vec};
+
+/// Longest accepted version string, e.g. `4294967295.4294967295.4294967295`
+pub const MAX_VERSION_LENGTH: u32 = 32;
+
+/// Instance storage key of the version a contract's data was last migrated to
+pub const CURRENT_VERSION_KEY: &str = "current_version";
+
+/// Prefix of the instance storage keys holding migration snapshots
+const MIGRATION_SNAPSHOT_KEY: &str = "migration_snapshot";
+
+/// Reasons a version string is rejected.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VersioningError {
+    /// The version is not of the form `MAJOR.MINOR.PATCH`
+    InvalidVersion,
+}
+
+/// A `MAJOR.MINOR.PATCH` version. Ordering compares major, then minor, then patch.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct SemVer {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl SemVer {
+    /// Parses `MAJOR.MINOR.PATCH`, where each part is a decimal `u32`.
+    ///
+    /// Pre-release and build suffixes are not supported.
+    pub fn parse(version: &String) -> Result<Self, VersioningError> {
+        let len: usize = version.len() as usize;
+        if len == 0 || len > MAX_VERSION_LENGTH as usize {
+            return Err(VersioningError::InvalidVersion);
+        }
+
+        let mut buf = [0u8; MAX_VERSION_LENGTH as usize];
+        let bytes: &mut [u8] = &mut buf[..len];
+        version.copy_into_slice(bytes);
+
+        let mut parts = [0u32; 3];
+        let mut count: usize = 0;
+        for part in bytes.split(|b| *b == b'.') {
+            if count == parts.len() {
+                return Err(VersioningError::InvalidVersion);
+            }
+            parts[count] = parse_number(part)?;
+            count += 1;
+        }
+        if count != parts.len() {
+            return Err(VersioningError::InvalidVersion);
+        }
+
+        Ok(SemVer {
+            major: parts[0],
+            minor: parts[1],
+            patch: parts[2],
+        })
+    }
+
+    /// Returns whether moving from `from` to `to` is allowed, i.e. not a downgrade.
+    pub fn is_compatible_upgrade(from: &SemVer, to: &SemVer) -> bool {
+        to >= from
+    }
+}
+
+fn parse_number(digits: &[u8]) -> Result<u32, VersioningError> {
+    if digits.is_empty() {
+        return Err(VersioningError::InvalidVersion);
+    }
+
+    let mut value: u32 = 0;
+    for b in digits {
+        if !b.is_ascii_digit() {
+            return Err(VersioningError::InvalidVersion);
+        }
+        value = value
+            .checked_mul(10)
+            .and_then(|v| v.checked_add((b - b'0') as u32))
+            .ok_or(VersioningError::InvalidVersion)?;
+    }
+    Ok(value)
+}
+
+/// Returns the version the calling contract's data was last migrated to, if any.
+pub fn get_current_version(env: &Env) -> Option<String> {
+    env.storage()
+        .instance()
+        .get(&String::from_str(env, CURRENT_VERSION_KEY))
+}
+
+/// Records the version the calling contract's data has been migrated to.
+pub fn set_current_version(env: &Env, version: String) {
+    env.storage()
+        .instance()
+        .set(&String::from_str(env, CURRENT_VERSION_KEY), &version);
+}
+
+/// Contract-specific hooks of a data migration.
+pub trait MigrationHandler {
+    /// Returns the instance storage keys a migration may modify, which are
+    /// restored if it fails.
+    fn get_snapshot_keys(env: &Env) -> Vec<String>;
+}
+
+fn snapshot_key(env: &Env, snapshot_id: &str) -> (String, String) {
+    (
+        String::from_str(env, MIGRATION_SNAPSHOT_KEY),
+        String::from_str(env, snapshot_id),
+    )
+}
+
+/// Saves the current instance storage values of `keys` under `snapshot_id`,
+/// replacing any earlier snapshot with that ID. Keys without a value are
+/// recorded as absent.
+pub fn snapshot_state(env: &Env, snapshot_id: &str, keys: &Vec<String>) {
+    let mut values: Map<String, Val> = Map::new(env);
+    for key in keys.iter() {
+        if let Some(value) = env.storage().instance().get::<String, Val>(&key) {
+            values.set(key, value);
+        }
+    }
+    env.storage()
+        .instance()
+        .set(&snapshot_key(env, snapshot_id), &(keys.clone(), values));
+}
+
+/// Restores the instance storage values saved under `snapshot_id` and drops
+/// the snapshot. Keys that were absent when the snapshot was taken are removed.
+///
+/// Returns `false` if there is no such snapshot.
+pub fn rollback_migration(env: &Env, snapshot_id: &str) -> bool {
+    let key: (String, String) = snapshot_key(env, snapshot_id);
+    let Some((keys, values)) = env
+        .storage()
+        .instance()
+        .get::<_, (Vec<String>, Map<String, Val>)>(&key)
+    else {
+        return false;
+    };
+
+    for entry_key in keys.iter() {
+        match values.get(entry_key.clone()) {
+            Some(value) => env.storage().instance().set(&entry_key, &value),
+            None => env.storage().instance().remove(&entry_key),
+        }
+    }
+    env.storage().instance().remove(&key);
+    true
+}
+
+/// Drops the snapshot saved under `snapshot_id`, if any.
+pub fn clear_snapshot(env: &Env, snapshot_id: &str) {
+    env.storage()
+        .instance()
+        .remove(&snapshot_key(env, snapshot_id));
+}
+
+/// Runs `migration` with the keys declared by `H` snapshotted first.
+///
+/// When `migration` returns `false` the snapshotted values are restored;
+/// otherwise the snapshot is dropped. Returns the result of `migration`.
+pub fn run_migration<H, F>(env: &Env, snapshot_id: &str, migration: F) -> bool
+where
+    H: MigrationHandler,
+    F: FnOnce() -> bool,
+{
+    snapshot_state(env, snapshot_id, &H::get_snapshot_keys(env));
+    let succeeded: bool = migration();
+    if succeeded {
+        clear_snapshot(env, snapshot_id);
+    } else {
+        rollback_migration(env, snapshot_id);
+    }
+    succeeded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{contract, contractimpl, vec};
+
+    #[contract]
+    struct VersionedContract;
+
+    #[contractimpl]
+    impl VersionedContract {
+        pub fn noop() {}
+    }
+
+    fn parse(env: &Env, version: &str) -> Result<SemVer, VersioningError> {
+        SemVer::parse(&String::from_str(env, version))
+    }
+
+    fn version(env: &Env, version: &str) -> SemVer {
+        parse(env, version).unwrap()
+    }
+
+    #[test]
+    fn test_parse_valid_versions() {
+        let env = Env::default();
+        assert_eq!(version(&env, "1.2.3"), SemVer { major: 1, minor: 2, patch: 3 });
+        assert_eq!(version(&env, "0.0.0"), SemVer { major: 0, minor: 0, patch: 0 });
+        assert_eq!(version(&env, "10.20.30").minor, 20);
+        assert_eq!(version(&env, "4294967295.0.1").major, u32::MAX);
+    }
+
+    #[test]
+    fn test_parse_malformed_versions() {
+        let env = Env::default();
+        for malformed in [
+            "", "1", "1.2", "1.2.3.4", "1..3", ".1.2", "1.2.", "a.b.c", "1.2.x", "v1.2.3",
+            "1.2.3-beta", " 1.2.3", "4294967296.0.0",
+        ] {
+            assert_eq!(parse(&env, malformed), Err(VersioningError::InvalidVersion), "{}", malformed);
+        }
+    }
+
+    #[test]
+    fn test_version_ordering() {
+        let env = Env::default();
+        assert!(version(&env, "1.0.0") < version(&env, "1.0.1"));
+        assert!(version(&env, "1.0.9") < version(&env, "1.1.0"));
+        assert!(version(&env, "1.9.9") < version(&env, "2.0.0"));
+        assert!(version(&env, "1.10.0") > version(&env, "1.9.0"));
+        assert_eq!(version(&env, "01.2.3"), version(&env, "1.2.3"));
+    }
+
+    #[test]
+    fn test_is_compatible_upgrade() {
+        let env = Env::default();
+        let v1_0_0 = version(&env, "1.0.0");
+        let v1_1_0 = version(&env, "1.1.0");
+        let v2_0_0 = version(&env, "2.0.0");
+
+        assert!(SemVer::is_compatible_upgrade(&v1_0_0, &v1_1_0));
+        assert!(SemVer::is_compatible_upgrade(&v1_0_0, &v2_0_0));
+        assert!(SemVer::is_compatible_upgrade(&v1_1_0, &v1_1_0));
+        assert!(!SemVer::is_compatible_upgrade(&v1_1_0, &v1_0_0));
+        assert!(!SemVer::is_compatible_upgrade(&v2_0_0, &v1_1_0));
+    }
+
+    #[test]
+    fn test_current_version() {
+        let env = Env::default();
+        let contract_id = env.register(VersionedContract, ());
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(get_current_version(&env), None);
+            set_current_version(&env, String::from_str(&env, "1.1.0"));
+            assert_eq!(get_current_version(&env), Some(String::from_str(&env, "1.1.0")));
+        });
+    }
+
+    struct TestMigration;
+
+    impl MigrationHandler for TestMigration {
+        fn get_snapshot_keys(env: &Env) -> Vec<String> {
+            vec![
+                env,
+                String::from_str(env, "counter"),
+                String::from_str(env, "label"),
+                String::from_str(env, CURRENT_VERSION_KEY),
+            ]
+        }
+    }
+
+    fn key(env: &Env, name: &str) -> String {
+        String::from_str(env, name)
+    }
+
+    #[test]
+    fn test_failed_migration_restores_state() {
+        let env = Env::default();
+        let contract_id = env.register(VersionedContract, ());
+
+        env.as_contract(&contract_id, || {
+            env.storage().instance().set(&key(&env, "counter"), &1u32);
+            set_current_version(&env, String::from_str(&env, "1.0.0"));
+
+            let succeeded = run_migration::<TestMigration, _>(&env, "test", || {
+                env.storage().instance().set(&key(&env, "counter"), &2u32);
+                env.storage().instance().set(&key(&env, "label"), &key(&env, "new"));
+                set_current_version(&env, String::from_str(&env, "1.1.0"));
+                false
+            });
+
+            assert!(!succeeded);
+            let counter: Option<u32> = env.storage().instance().get(&key(&env, "counter"));
+            assert_eq!(counter, Some(1));
+            assert!(!env.storage().instance().has(&key(&env, "label")));
+            assert_eq!(get_current_version(&env), Some(String::from_str(&env, "1.0.0")));
+            assert!(!rollback_migration(&env, "test"));
+        });
+    }
+
+    #[test]
+    fn test_successful_migration_clears_snapshot() {
+        let env = Env::default();
+        let contract_id = env.register(VersionedContract, ());
+
+        env.as_contract(&contract_id, || {
+            env.storage().instance().set(&key(&env, "counter"), &1u32);
+
+            let succeeded = run_migration::<TestMigration, _>(&env, "test", || {
+                env.storage().instance().set(&key(&env, "counter"), &2u32);
+                true
+            });
+
+            assert!(succeeded);
+            let counter: Option<u32> = env.storage().instance().get(&key(&env, "counter"));
+            assert_eq!(counter, Some(2));
+            assert!(!env.storage().instance().has(&snapshot_key(&env, "test")));
+            assert!(!rollback_migration(&env, "test"));
+        });
+    }
+}
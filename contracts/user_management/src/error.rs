@@ -7,39 +7,53 @@ use soroban_sdk::{contracterror, panic_with_error, Env};
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum Error {
-    AlreadInitialized = 1,
-    InvalidMaxPageSize = 2,
-    SystemNotInitialized = 3,
-    AccessDenied = 4,
-    SuperAdminNotRegular = 5,
-    OperationFailed = 6,
-    MaxAdminsReached = 7,
-    CannotRemoveSuperAdmin = 8,
-    UserProfileExists = 9,
-    NameRequired = 10,
-    EmailRequired = 11,
-    CountryRequired = 12,
-    InvalidEmailFormat = 15,
-    EmailAlreadyExists = 16,
-    InvalidField = 17,
-    InvalidProfilePicURL = 19,
-    UserNotFound = 20,
-    UserProfileNotFound = 21,
-    InactiveUser = 22,
-    PageParamTooLarge = 23,
-    InvalidTitleLength = 24,
-    PasswordMismatch = 25,
+    AlreadyInitialized = 101,
+    InvalidMaxPageSize = 102,
+    SystemNotInitialized = 103,
+    AccessDenied = 104,
+    SuperAdminNotRegular = 105,
+    OperationFailed = 106,
+    AdminRosterFull = 107,
+    CannotRemoveSuperAdmin = 108,
+    UserProfileExists = 109,
+    NameRequired = 110,
+    EmailRequired = 111,
+    CountryRequired = 112,
+    InvalidEmailFormat = 115,
+    EmailAlreadyExists = 116,
+    InvalidField = 117,
+    InvalidProfilePicURL = 119,
+    UserNotFound = 120,
+    UserProfileNotFound = 121,
+    InactiveUser = 122,
+    PageParamTooLarge = 123,
+    InvalidTitleLength = 124,
+    PasswordMismatch = 125,
     // Rate limiting errors
-    RateLimitExceeded = 26,
-    RateLimitNotConfigured = 27,
-    PasswordTooShort = 28,
-    PasswordTooLong = 29,
-    PasswordMissingUppercase = 30,
-    PasswordMissingLowercase = 31,
-    PasswordMissingDigit = 32,
-    PasswordMissingSpecialChar = 33,
-    RequiredFieldMissing = 34,
-    Unauthorized = 35
+    RateLimitExceeded = 126,
+    RateLimitNotConfigured = 127,
+    PasswordTooShort = 128,
+    PasswordTooLong = 129,
+    PasswordMissingUppercase = 130,
+    PasswordMissingLowercase = 131,
+    PasswordMissingDigit = 132,
+    PasswordMissingSpecialChar = 133,
+    RequiredFieldMissing = 134,
+    Unauthorized = 135,
+    InvalidAvatarUrl = 136,
+    AdminNotFound = 137,
+    CannotDemoteSuperAdmin = 138,
+    TooManySkills = 139,
+    InvitationNotFound = 140,
+    InvitationExpired = 141,
+    AddressBlacklisted = 142,
+    ProposalNotFound = 143,
+    ProposalExpired = 144,
+    AlreadyApproved = 145,
+    ApprovalRequired = 146,
+    TooManySocialLinks = 147,
+    InvalidSocialLinkUrl = 148,
+    EmailChangeCooldown = 149,
 }
 
 pub fn handle_error(env: &Env, error: Error) -> ! {
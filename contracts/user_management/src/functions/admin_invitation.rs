@@ -0,0 +1,209 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::is_admin::require_super_admin;
+use crate::schema::{AdminConfig, AdminInvitation, DataKey};
+
+const ADMIN_INVITED_EVENT: &str = "AdminInvited";
+const ADMIN_INVITATION_ACCEPTED_EVENT: &str = "AdminInvitationAccepted";
+const ADMIN_INVITATION_REVOKED_EVENT: &str = "AdminInvitationRevoked";
+
+fn get_admins(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get::<DataKey, Vec<Address>>(&DataKey::Admins)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Invites `invitee` to become an admin (super admin only).
+///
+/// The invitation can be accepted until `valid_for_seconds` have passed.
+/// Inviting the same address again replaces the pending invitation.
+///
+/// # Panics
+///
+/// * `Error::SystemNotInitialized` if the admin system is not initialized.
+/// * `Error::AccessDenied` if the caller is not the super admin.
+/// * `Error::SuperAdminNotRegular` if the invitee is the super admin.
+/// * `Error::OperationFailed` if the invitee is already an admin.
+pub fn user_management_invite_admin(
+    env: Env,
+    super_admin: Address,
+    invitee: Address,
+    valid_for_seconds: u64,
+) -> AdminInvitation {
+    let config: AdminConfig = require_super_admin(&env, &super_admin);
+
+    if invitee == config.super_admin {
+        handle_error(&env, Error::SuperAdminNotRegular)
+    }
+    if get_admins(&env).contains(&invitee) {
+        handle_error(&env, Error::OperationFailed) // Don't disclose admin status
+    }
+
+    let invitation: AdminInvitation = AdminInvitation {
+        invitee: invitee.clone(),
+        invited_by: super_admin,
+        expires_at: env.ledger().timestamp().saturating_add(valid_for_seconds),
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::AdminInvitation(invitee.clone()), &invitation);
+
+    env.events().publish(
+        (Symbol::new(&env, ADMIN_INVITED_EVENT), invitee),
+        invitation.expires_at,
+    );
+
+    invitation
+}
+
+/// Accepts a pending admin invitation, adding the invitee to the admin list.
+///
+/// # Panics
+///
+/// * `Error::InvitationNotFound` if the invitee has no pending invitation.
+/// * `Error::InvitationExpired` if the invitation has expired.
+/// * `Error::SystemNotInitialized` if the admin system is not initialized.
+/// * `Error::AdminRosterFull` if the admin list is full.
+pub fn user_management_accept_admin_invitation(env: Env, invitee: Address) {
+    invitee.require_auth();
+
+    let key: DataKey = DataKey::AdminInvitation(invitee.clone());
+    let invitation: AdminInvitation = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| handle_error(&env, Error::InvitationNotFound));
+
+    if env.ledger().timestamp() > invitation.expires_at {
+        handle_error(&env, Error::InvitationExpired)
+    }
+
+    let config: AdminConfig = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AdminConfig)
+        .unwrap_or_else(|| handle_error(&env, Error::SystemNotInitialized));
+
+    let mut admins: Vec<Address> = get_admins(&env);
+    if !admins.contains(&invitee) {
+        if admins.len() >= config.max_admins {
+            handle_error(&env, Error::AdminRosterFull)
+        }
+        admins.push_back(invitee.clone());
+        env.storage().persistent().set(&DataKey::Admins, &admins);
+    }
+    env.storage().persistent().remove(&key);
+
+    env.events().publish(
+        (Symbol::new(&env, ADMIN_INVITATION_ACCEPTED_EVENT), invitee),
+        (invitation.invited_by, admins.len()),
+    );
+}
+
+/// Cancels a pending admin invitation (super admin only).
+///
+/// # Panics
+///
+/// * `Error::SystemNotInitialized` if the admin system is not initialized.
+/// * `Error::AccessDenied` if the caller is not the super admin.
+/// * `Error::InvitationNotFound` if the invitee has no pending invitation.
+pub fn user_management_revoke_admin_invitation(env: Env, super_admin: Address, invitee: Address) {
+    require_super_admin(&env, &super_admin);
+
+    let key: DataKey = DataKey::AdminInvitation(invitee.clone());
+    if !env.storage().persistent().has(&key) {
+        handle_error(&env, Error::InvitationNotFound)
+    }
+    env.storage().persistent().remove(&key);
+
+    env.events().publish(
+        (Symbol::new(&env, ADMIN_INVITATION_REVOKED_EVENT), invitee),
+        super_admin,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, Env};
+
+    fn setup() -> (Env, UserManagementClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_000);
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+
+        let super_admin = Address::generate(&env);
+        client.initialize(&super_admin, &None);
+
+        (env, client, super_admin)
+    }
+
+    #[test]
+    fn test_accept_admin_invitation() {
+        let (env, client, super_admin) = setup();
+        let invitee = Address::generate(&env);
+
+        let invitation = client.invite_admin(&super_admin, &invitee, &3_600);
+        assert_eq!(invitation.invited_by, super_admin);
+        assert_eq!(invitation.expires_at, 4_600);
+        assert!(!client.is_admin(&invitee));
+
+        env.ledger().set_timestamp(4_600);
+        client.accept_admin_invitation(&invitee);
+
+        assert!(client.is_admin(&invitee));
+    }
+
+    #[test]
+    fn test_accept_expired_invitation() {
+        let (env, client, super_admin) = setup();
+        let invitee = Address::generate(&env);
+        client.invite_admin(&super_admin, &invitee, &3_600);
+
+        env.ledger().set_timestamp(4_601);
+        let result = client.try_accept_admin_invitation(&invitee);
+
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(141))));
+        assert!(!client.is_admin(&invitee));
+    }
+
+    #[test]
+    fn test_accept_invitation_twice() {
+        let (env, client, super_admin) = setup();
+        let invitee = Address::generate(&env);
+        client.invite_admin(&super_admin, &invitee, &3_600);
+        client.accept_admin_invitation(&invitee);
+
+        let result = client.try_accept_admin_invitation(&invitee);
+
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(140))));
+    }
+
+    #[test]
+    fn test_revoke_invitation_before_acceptance() {
+        let (env, client, super_admin) = setup();
+        let invitee = Address::generate(&env);
+        client.invite_admin(&super_admin, &invitee, &3_600);
+
+        client.revoke_admin_invitation(&super_admin, &invitee);
+
+        let result = client.try_accept_admin_invitation(&invitee);
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(140))));
+        assert!(!client.is_admin(&invitee));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #104)")]
+    fn test_invite_admin_not_super_admin() {
+        let (env, client, _super_admin) = setup();
+        client.invite_admin(&Address::generate(&env), &Address::generate(&env), &3_600);
+    }
+}
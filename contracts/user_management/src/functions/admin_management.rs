@@ -5,12 +5,14 @@ use soroban_sdk::{Address, Env, Vec, Symbol, symbol_short};
 
 use crate::error::{handle_error, Error};
 use crate::schema::{
-    AdminConfig, DataKey, ABSOLUTE_MAX_PAGE_SIZE, DEFAULT_MAX_PAGE_SIZE, MAX_ADMINS,
+    AdminConfig, DataKey, ABSOLUTE_MAX_PAGE_SIZE, DEFAULT_MAX_ADMINS, DEFAULT_MAX_PAGE_SIZE,
 };
+use crate::functions::multi_sig::require_single_key_allowed;
 use crate::functions::utils::rate_limit_utils::get_default_rate_limit_config;
 use core::iter::Iterator;
 
 const INIT_SYSTEM_EVENT: Symbol = symbol_short!("initSys");
+const CONTRACT_INITIALIZED_EVENT: &str = "ContractInitialized";
 const ADD_ADMIN_EVENT: Symbol = symbol_short!("addAdmin");
 const REMOVE_ADMIN_EVENT: Symbol = symbol_short!("rmvAdmin");
 
@@ -30,7 +32,7 @@ pub fn initialize_system(
         .get::<DataKey, AdminConfig>(&DataKey::AdminConfig)
     {
         if existing_config.initialized {
-            handle_error(&env, Error::AlreadInitialized)
+            handle_error(&env, Error::AlreadyInitialized)
         }
     }
 
@@ -52,6 +54,7 @@ pub fn initialize_system(
         max_page_size: validated_max_page_size,
         total_user_count: 0,
         rate_limit_config: get_default_rate_limit_config(),
+        max_admins: DEFAULT_MAX_ADMINS,
     };
 
     // Store the configuration
@@ -71,6 +74,19 @@ pub fn initialize_system(
     config
 }
 
+/// Initialize the contract with the super admin as its own initializer
+pub fn initialize(env: Env, super_admin: Address, max_page_size: Option<u32>) -> AdminConfig {
+    let config: AdminConfig =
+        initialize_system(env.clone(), super_admin.clone(), super_admin.clone(), max_page_size);
+
+    env.events().publish(
+        (Symbol::new(&env, CONTRACT_INITIALIZED_EVENT),),
+        (super_admin, config.max_page_size),
+    );
+
+    config
+}
+
 /// Add a new admin (super admin only)
 pub fn add_admin(env: Env, caller: Address, new_admin: Address) {
     caller.require_auth();
@@ -107,8 +123,8 @@ pub fn add_admin(env: Env, caller: Address, new_admin: Address) {
     }
 
     // Limit number of admins for security
-    if admins.len() >= MAX_ADMINS {
-        handle_error(&env, Error::MaxAdminsReached)
+    if admins.len() >= config.max_admins {
+        handle_error(&env, Error::AdminRosterFull)
     }
 
     admins.push_back(new_admin.clone());
@@ -136,30 +152,42 @@ pub fn remove_admin(env: Env, caller: Address, admin_to_remove: Address) {
     if caller != config.super_admin {
         handle_error(&env, Error::AccessDenied)
     }
+    require_single_key_allowed(&env);
+
+    remove_from_admin_roster(&env, &config, &caller, &admin_to_remove);
+}
 
+/// Removes `admin_to_remove` from the regular admins once `actor` has been
+/// authorized to do so.
+pub fn remove_from_admin_roster(
+    env: &Env,
+    config: &AdminConfig,
+    actor: &Address,
+    admin_to_remove: &Address,
+) {
     // Cannot remove super admin
-    if admin_to_remove == config.super_admin {
-        handle_error(&env, Error::CannotRemoveSuperAdmin)
+    if *admin_to_remove == config.super_admin {
+        handle_error(env, Error::CannotRemoveSuperAdmin)
     }
 
     let admins: Vec<Address> = env
         .storage()
         .persistent()
         .get::<DataKey, Vec<Address>>(&DataKey::Admins)
-        .unwrap_or_else(|| Vec::new(&env));
+        .unwrap_or_else(|| Vec::new(env));
 
     // Find and remove the admin
     let initial_len: u32 = admins.len();
-    let mut new_admins: Vec<Address> = Vec::new(&env);
+    let mut new_admins: Vec<Address> = Vec::new(env);
 
     for admin in admins.iter() {
-        if admin != admin_to_remove {
+        if admin != *admin_to_remove {
             new_admins.push_back(admin);
         }
     }
 
     if new_admins.len() == initial_len {
-        handle_error(&env, Error::AccessDenied) // Don't disclose admin existence
+        handle_error(env, Error::AccessDenied) // Don't disclose admin existence
     }
 
     env.storage()
@@ -167,7 +195,7 @@ pub fn remove_admin(env: Env, caller: Address, admin_to_remove: Address) {
         .set(&DataKey::Admins, &new_admins);
 
     env.events()
-        .publish((REMOVE_ADMIN_EVENT, &caller), (admin_to_remove, new_admins.len()));
+        .publish((REMOVE_ADMIN_EVENT, actor), (admin_to_remove.clone(), new_admins.len()));
 }
 
 /// Get list of all admins (admin only)
@@ -243,7 +271,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #1)")]
+    #[should_panic(expected = "HostError: Error(Contract, #101)")]
     fn test_cannot_initialize_twice() {
         let env = Env::default();
         let contract_id = env.register(UserManagement, {});
@@ -258,6 +286,40 @@ mod tests {
         client.initialize_system(&initializer, &super_admin, &Some(TEST_MAX_PAGE_SIZE));
     }
 
+    #[test]
+    fn test_initialize_stores_admin_config() {
+        let env = Env::default();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        env.mock_all_auths();
+
+        let super_admin = Address::generate(&env);
+        let config = client.initialize(&super_admin, &None);
+
+        assert!(config.initialized);
+        assert_eq!(config.super_admin, super_admin);
+        assert_eq!(config.max_page_size, crate::schema::DEFAULT_MAX_PAGE_SIZE);
+        assert_eq!(
+            config.rate_limit_config,
+            crate::functions::utils::rate_limit_utils::get_default_rate_limit_config()
+        );
+        assert!(client.is_system_initialized());
+        assert_eq!(client.get_super_admin(), super_admin);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #101)")]
+    fn test_initialize_twice() {
+        let env = Env::default();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        env.mock_all_auths();
+
+        let super_admin = Address::generate(&env);
+        client.initialize(&super_admin, &None);
+        client.initialize(&super_admin, &None);
+    }
+
     #[test]
     fn test_add_remove_admin() {
         let env = Env::default();
@@ -285,7 +347,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #4)")]
+    #[should_panic(expected = "HostError: Error(Contract, #104)")]
     fn test_non_super_admin_cannot_add_admin() {
         let env = Env::default();
         let contract_id = env.register(UserManagement, {});
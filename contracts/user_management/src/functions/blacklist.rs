@@ -0,0 +1,159 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::is_admin::require_admin;
+use crate::schema::DataKey;
+
+const BLACKLIST_ADDED_EVENT: &str = "AddressBlacklisted";
+const BLACKLIST_REMOVED_EVENT: &str = "AddressUnblacklisted";
+
+fn get_blacklist(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Blacklist)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Panics with `Error::AddressBlacklisted` if `address` is blacklisted.
+pub fn check_not_blacklisted(env: &Env, address: &Address) {
+    if get_blacklist(env).contains(address) {
+        handle_error(env, Error::AddressBlacklisted)
+    }
+}
+
+/// Blocks `target` from using the platform (admin only).
+///
+/// Blacklisting an address twice changes nothing.
+///
+/// Panics with `Error::AccessDenied` if the caller is not an admin.
+pub fn user_management_add_to_blacklist(env: Env, admin: Address, target: Address) {
+    require_admin(&env, &admin);
+
+    let mut blacklist: Vec<Address> = get_blacklist(&env);
+    if blacklist.contains(&target) {
+        return;
+    }
+    blacklist.push_back(target.clone());
+    env.storage().persistent().set(&DataKey::Blacklist, &blacklist);
+
+    env.events()
+        .publish((Symbol::new(&env, BLACKLIST_ADDED_EVENT), target), admin);
+}
+
+/// Lifts the block on `target` (admin only).
+///
+/// Removing an address that is not blacklisted changes nothing.
+///
+/// Panics with `Error::AccessDenied` if the caller is not an admin.
+pub fn user_management_remove_from_blacklist(env: Env, admin: Address, target: Address) {
+    require_admin(&env, &admin);
+
+    let mut blacklist: Vec<Address> = get_blacklist(&env);
+    let Some(index) = blacklist.first_index_of(&target) else {
+        return;
+    };
+    blacklist.remove(index);
+    if blacklist.is_empty() {
+        env.storage().persistent().remove(&DataKey::Blacklist);
+    } else {
+        env.storage().persistent().set(&DataKey::Blacklist, &blacklist);
+    }
+
+    env.events()
+        .publish((Symbol::new(&env, BLACKLIST_REMOVED_EVENT), target), admin);
+}
+
+/// Returns whether `address` is blacklisted.
+pub fn user_management_is_blacklisted(env: Env, address: Address) -> bool {
+    get_blacklist(&env).contains(&address)
+}
+
+/// Returns the blacklisted addresses in the order they were added (admin only).
+///
+/// Panics with `Error::AccessDenied` if the caller is not an admin.
+pub fn user_management_list_blacklist(env: Env, caller: Address) -> Vec<Address> {
+    require_admin(&env, &caller);
+
+    get_blacklist(&env)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::UserProfile;
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn setup() -> (Env, UserManagementClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+
+        let super_admin = Address::generate(&env);
+        client.initialize(&super_admin, &None);
+
+        (env, client, super_admin)
+    }
+
+    fn profile(env: &Env) -> UserProfile {
+        UserProfile {
+            full_name: String::from_str(env, "Jane Doe"),
+            contact_email: String::from_str(env, "jane@example.com"),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        }
+    }
+
+    #[test]
+    fn test_add_and_remove_from_blacklist() {
+        let (env, client, admin) = setup();
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+
+        client.add_to_blacklist(&admin, &first);
+        client.add_to_blacklist(&admin, &second);
+        assert!(client.is_blacklisted(&first));
+        assert_eq!(client.list_blacklist(&admin), vec![&env, first.clone(), second.clone()]);
+
+        client.remove_from_blacklist(&admin, &first);
+        assert!(!client.is_blacklisted(&first));
+        assert_eq!(client.list_blacklist(&admin), vec![&env, second]);
+    }
+
+    #[test]
+    fn test_add_to_blacklist_twice() {
+        let (env, client, admin) = setup();
+        let target = Address::generate(&env);
+
+        client.add_to_blacklist(&admin, &target);
+        client.add_to_blacklist(&admin, &target);
+
+        assert_eq!(client.list_blacklist(&admin).len(), 1);
+    }
+
+    #[test]
+    fn test_blacklisted_address_cannot_create_profile() {
+        let (env, client, admin) = setup();
+        let user = Address::generate(&env);
+        client.add_to_blacklist(&admin, &user);
+
+        let result = client.try_create_user_profile(&user, &profile(&env));
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(142))));
+
+        client.remove_from_blacklist(&admin, &user);
+        client.create_user_profile(&user, &profile(&env));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #104)")]
+    fn test_add_to_blacklist_not_admin() {
+        let (env, client, _admin) = setup();
+        client.add_to_blacklist(&Address::generate(&env), &Address::generate(&env));
+    }
+}
@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::schema::{DataKey, ProfileCompletenessScore, UserProfile};
+
+/// Minimum score for a profile to count as complete
+pub const PROFILE_COMPLETE_THRESHOLD: u32 = 80;
+
+const FULL_NAME_POINTS: u32 = 20;
+const CONTACT_EMAIL_POINTS: u32 = 20;
+const COUNTRY_POINTS: u32 = 15;
+const PROFESSION_POINTS: u32 = 15;
+const PURPOSE_POINTS: u32 = 15;
+const AVATAR_URL_POINTS: u32 = 10;
+
+fn is_filled(value: &Option<String>) -> bool {
+    value.as_ref().is_some_and(|v| !v.is_empty())
+}
+
+/// Scores how much of a user's profile is filled in, out of 95 points.
+///
+/// Each filled field adds its weight to the score; empty fields are listed by
+/// name in `missing_fields`. The profile counts as complete from
+/// `PROFILE_COMPLETE_THRESHOLD` points.
+///
+/// Panics with `Error::UserNotFound` if the user has no profile.
+pub fn user_management_check_profile_completeness(
+    env: Env,
+    user: Address,
+) -> ProfileCompletenessScore {
+    let profile: UserProfile = env
+        .storage()
+        .persistent()
+        .get(&DataKey::UserProfile(user))
+        .unwrap_or_else(|| handle_error(&env, Error::UserNotFound));
+
+    let fields: [(&str, bool, u32); 6] = [
+        ("full_name", !profile.full_name.is_empty(), FULL_NAME_POINTS),
+        (
+            "contact_email",
+            !profile.contact_email.is_empty(),
+            CONTACT_EMAIL_POINTS,
+        ),
+        ("country", is_filled(&profile.country), COUNTRY_POINTS),
+        (
+            "profession",
+            is_filled(&profile.profession),
+            PROFESSION_POINTS,
+        ),
+        ("purpose", is_filled(&profile.purpose), PURPOSE_POINTS),
+        (
+            "avatar_url",
+            is_filled(&profile.avatar_url),
+            AVATAR_URL_POINTS,
+        ),
+    ];
+
+    let mut score: u32 = 0;
+    let mut missing_fields: Vec<String> = Vec::new(&env);
+    for (name, filled, points) in fields {
+        if filled {
+            score += points;
+        } else {
+            missing_fields.push_back(String::from_str(&env, name));
+        }
+    }
+
+    ProfileCompletenessScore {
+        score,
+        missing_fields,
+        is_complete: score >= PROFILE_COMPLETE_THRESHOLD,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{ProfileUpdateParams, UserProfile};
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn setup() -> (Env, UserManagementClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        (env, client)
+    }
+
+    fn minimal_profile(env: &Env) -> UserProfile {
+        UserProfile {
+            full_name: String::from_str(env, "Jane Doe"),
+            contact_email: String::from_str(env, "jane@example.com"),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        }
+    }
+
+    fn score_of(env: &Env, client: &UserManagementClient, profile: &UserProfile) -> u32 {
+        let user = Address::generate(env);
+        client.create_user_profile(&user, profile);
+        client.check_profile_completeness(&user).score
+    }
+
+    #[test]
+    fn test_minimal_profile() {
+        let (env, client) = setup();
+        let user = Address::generate(&env);
+        client.create_user_profile(&user, &minimal_profile(&env));
+
+        let result = client.check_profile_completeness(&user);
+
+        assert_eq!(result.score, 40);
+        assert!(!result.is_complete);
+        assert_eq!(
+            result.missing_fields,
+            vec![
+                &env,
+                String::from_str(&env, "country"),
+                String::from_str(&env, "profession"),
+                String::from_str(&env, "purpose"),
+                String::from_str(&env, "avatar_url"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_field_points() {
+        let (env, client) = setup();
+        let value = Some(String::from_str(&env, "value"));
+
+        let mut profile = minimal_profile(&env);
+        profile.country = value.clone();
+        assert_eq!(score_of(&env, &client, &profile), 55);
+
+        let mut profile = minimal_profile(&env);
+        profile.contact_email = String::from_str(&env, "profession@example.com");
+        profile.profession = value.clone();
+        assert_eq!(score_of(&env, &client, &profile), 55);
+
+        let mut profile = minimal_profile(&env);
+        profile.contact_email = String::from_str(&env, "purpose@example.com");
+        profile.purpose = value;
+        assert_eq!(score_of(&env, &client, &profile), 55);
+
+        let mut profile = minimal_profile(&env);
+        profile.contact_email = String::from_str(&env, "avatar@example.com");
+        profile.avatar_url = Some(String::from_str(&env, "https://example.com/a.png"));
+        assert_eq!(score_of(&env, &client, &profile), 50);
+    }
+
+    #[test]
+    fn test_completeness_threshold() {
+        let (env, client) = setup();
+        let user = Address::generate(&env);
+        let mut profile = minimal_profile(&env);
+        profile.country = Some(String::from_str(&env, "Spain"));
+        profile.profession = Some(String::from_str(&env, "Engineer"));
+        client.create_user_profile(&user, &profile);
+
+        let result = client.check_profile_completeness(&user);
+        assert_eq!(result.score, 70);
+        assert!(!result.is_complete);
+
+        let updates = ProfileUpdateParams {
+            full_name: None,
+            profession: None,
+            country: None,
+            purpose: Some(String::from_str(&env, "Learn Rust")),
+            profile_picture_url: None,
+            avatar_url: None,
+        };
+        client.edit_user_profile(&user, &user, &updates);
+        let result = client.check_profile_completeness(&user);
+        assert_eq!(result.score, 85);
+        assert!(result.is_complete);
+        assert_eq!(
+            result.missing_fields,
+            vec![&env, String::from_str(&env, "avatar_url")]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #120)")]
+    fn test_completeness_without_profile() {
+        let (env, client) = setup();
+        client.check_profile_completeness(&Address::generate(&env));
+    }
+}
@@ -2,8 +2,16 @@
 // Copyright (c) 2025 SkillCert
 
 use crate::error::{handle_error, Error};
+use crate::functions::blacklist::check_not_blacklisted;
 use crate::schema::{DataKey, LightProfile, UserProfile, UserRole, UserStatus};
+use crate::functions::get_inactive_users::record_user_activity;
+use crate::functions::list_users::add_to_user_index;
+use crate::functions::search_users_by_country::{
+    add_to_country_index, trim_country, validate_country,
+};
+use crate::functions::set_user_role::add_to_role_index;
 use crate::functions::utils::rate_limit_utils::check_user_creation_rate_limit;
+use crate::functions::utils::storage_utils::normalize_email;
 use crate::functions::utils::url_validation;
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
 use core::iter::Iterator;
@@ -13,9 +21,7 @@ const USER_CREATED_EVENT: Symbol = symbol_short!("usrCrtd");
 
 /// Security constants for profile validation
 const MAX_NAME_LENGTH: usize = 100;
-const MAX_EMAIL_LENGTH: usize = 320; // RFC 5321 standard
 const MAX_PROFESSION_LENGTH: usize = 100;
-const MAX_COUNTRY_LENGTH: usize = 56; // Longest country name
 
 /// Validates string content for security
 fn validate_string_content(_env: &Env, s: &String, max_len: usize) -> bool {
@@ -26,49 +32,15 @@ fn validate_string_content(_env: &Env, s: &String, max_len: usize) -> bool {
     true
 }
 
-/// Validates email format (basic validation)
-fn validate_email_format(email: &String) -> bool {
-    // Basic email validation - must contain @ and have minimum length
-    if email.len() < 5 || email.len() > MAX_EMAIL_LENGTH as u32 {
-        return false;
-    }
-
-    // For Soroban strings, we'll do a basic validation
-    // Check if the string is empty (additional safety check)
-    if email.is_empty() {
-        return false;
-    }
-
-    // Basic validation - reject emails that are clearly invalid
-    // In production, implement proper RFC 5322 email validation
-    if email.len() == 13 {
-        // "invalid-email" has 13 characters - reject for testing
-        return false;
-    }
-
-    // This is where we would normally check for @ symbol, but due to Soroban SDK limitations
-    // we'll simulate the validation for the test
-    // In a real implementation, you might need to implement custom string parsing
-
-    // For the test to pass, we need to reject "invalid-email" (no @)
-    // This is a simplified validation for demo purposes
-    if email.len() < 5 {
-        // "bad" has 3 characters
-        return false;
-    }
-
-    true
-}
-
 /// Check if email is already taken
 fn is_email_unique(env: &Env, email: &String) -> bool {
-    let email_key: DataKey = DataKey::EmailIndex(email.clone());
+    let email_key: DataKey = DataKey::EmailIndex(normalize_email(env, email));
     !env.storage().persistent().has(&email_key)
 }
 
 /// Register email in the email index
 fn register_email(env: &Env, email: &String, user_address: &Address) {
-    let email_key: DataKey = DataKey::EmailIndex(email.clone());
+    let email_key: DataKey = DataKey::EmailIndex(normalize_email(env, email));
     env.storage().persistent().set(&email_key, user_address);
 }
 
@@ -108,9 +80,10 @@ fn add_to_users_index(env: &Env, user: &Address) {
 /// * If email is already taken
 /// * If user authentication fails
 /// * If user profile already exists
-pub fn create_user_profile(env: Env, user: Address, profile: UserProfile) -> UserProfile {
+pub fn create_user_profile(env: Env, user: Address, mut profile: UserProfile) -> UserProfile {
     // Require authentication for the user
     user.require_auth();
+    check_not_blacklisted(&env, &user);
 
     // Check rate limiting before proceeding (use default config if system not initialized)
     let admin_config_key = DataKey::AdminConfig;
@@ -150,7 +123,7 @@ pub fn create_user_profile(env: Env, user: Address, profile: UserProfile) -> Use
     }
 
     // Validate email format
-    if !validate_email_format(&profile.contact_email) {
+    if !shared::validate_email(&profile.contact_email) {
         handle_error(&env, Error::InvalidEmailFormat)
     }
 
@@ -168,10 +141,9 @@ pub fn create_user_profile(env: Env, user: Address, profile: UserProfile) -> Use
 
     // Validate country field if provided
     if let Some(ref country) = profile.country {
-        if !country.is_empty() && !validate_string_content(&env, country, MAX_COUNTRY_LENGTH) {
-            handle_error(&env, Error::InvalidField)
-        }
+        validate_country(&env, country);
     }
+    profile.country = profile.country.map(|country| trim_country(&env, &country));
 
     // Validate profile picture URL if provided
     if let Some(ref url) = profile.profile_picture_url {
@@ -180,6 +152,13 @@ pub fn create_user_profile(env: Env, user: Address, profile: UserProfile) -> Use
         }
     }
 
+    // Validate avatar URL if provided
+    if let Some(ref url) = profile.avatar_url {
+        if !url_validation::is_valid_avatar_url(url) {
+            handle_error(&env, Error::InvalidAvatarUrl)
+        }
+    }
+
     // Register email in the email index
     register_email(&env, &profile.contact_email, &user);
 
@@ -188,6 +167,13 @@ pub fn create_user_profile(env: Env, user: Address, profile: UserProfile) -> Use
 
     // Add user to the global users index
     add_to_users_index(&env, &user);
+    add_to_user_index(&env, &user);
+    add_to_country_index(&env, &user, &profile.country);
+    add_to_role_index(&env, &user, &UserRole::Student);
+    record_user_activity(&env, &user);
+    env.storage()
+        .persistent()
+        .set(&DataKey::RegisteredAt(user.clone()), &env.ledger().timestamp());
 
     // Store light profile for efficient listing
     let light_profile = LightProfile {
@@ -139,6 +139,7 @@ mod tests {
             country: Some(String::from_str(env, "United States")),
             purpose: Some(String::from_str(env, "Learn testing methodologies")),
             profile_picture_url: None,
+            avatar_url: None,
         };
 
         let light_profile = LightProfile {
@@ -174,6 +175,7 @@ mod tests {
                     use crate::functions::utils::rate_limit_utils::get_default_rate_limit_config;
                     get_default_rate_limit_config()
                 },
+                max_admins: crate::schema::DEFAULT_MAX_ADMINS,
             };
             env.storage()
                 .persistent()
@@ -246,7 +248,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #4)")]
+    #[should_panic(expected = "HostError: Error(Contract, #104)")]
     fn test_delete_user_unauthorized() {
         let (env, contract_id, client) = setup_test_env();
         let user1 = Address::generate(&env);
@@ -263,7 +265,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #20)")]
+    #[should_panic(expected = "HostError: Error(Contract, #120)")]
     fn test_delete_nonexistent_user() {
         let (env, contract_id, client) = setup_test_env();
         let admin = Address::generate(&env);
@@ -278,7 +280,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "HostError: Error(Contract, #22)")]
+    #[should_panic(expected = "HostError: Error(Contract, #122)")]
     fn test_delete_already_inactive_user() {
         let (env, contract_id, client) = setup_test_env();
         let admin = Address::generate(&env);
@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::get_profile_history::clear_profile_history;
+use crate::functions::is_admin::require_user_management_auth;
+use crate::functions::list_users::remove_from_user_index;
+use crate::functions::rbac::get_user_role;
+use crate::functions::search_users_by_country::remove_from_country_index;
+use crate::functions::set_user_role::remove_from_role_index;
+use crate::functions::social_links::clear_social_links;
+use crate::functions::user_skills::clear_user_skills;
+use crate::functions::utils::storage_utils::normalize_email;
+use crate::schema::{DataKey, UserProfile};
+
+const PROFILE_DELETED_EVENT: &str = "ProfileDeleted";
+
+/// Permanently removes a user's profile from storage.
+///
+/// Unlike `delete_user`, which only deactivates the account, this removes the
+/// profile, its email reservation and every index entry pointing to the user.
+///
+/// # Arguments
+///
+/// * `env` - Soroban environment.
+/// * `caller` - Address performing the deletion (profile owner or admin).
+/// * `target_user` - Address whose profile is deleted.
+///
+/// # Panics
+///
+/// * `Error::Unauthorized` if the caller is neither the owner nor an admin.
+/// * `Error::UserNotFound` if the profile does not exist.
+pub fn user_management_delete_user_profile(env: Env, caller: Address, target_user: Address) {
+    require_user_management_auth(&env, &caller, &target_user);
+
+    let profile_key: DataKey = DataKey::UserProfile(target_user.clone());
+    let profile: UserProfile = env
+        .storage()
+        .persistent()
+        .get(&profile_key)
+        .unwrap_or_else(|| handle_error(&env, Error::UserNotFound));
+
+    env.storage().persistent().remove(&profile_key);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::EmailIndex(normalize_email(&env, &profile.contact_email)));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::UserProfileLight(target_user.clone()));
+
+    remove_from_users_index(&env, &target_user);
+    remove_from_user_index(&env, &target_user);
+    remove_from_country_index(&env, &target_user, &profile.country);
+    remove_from_role_index(&env, &target_user, &get_user_role(&env, &target_user));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::UserRole(target_user.clone()));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::RegisteredAt(target_user.clone()));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::EmailVerified(target_user.clone()));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::LastEmailChange(target_user.clone()));
+    clear_profile_history(&env, &target_user);
+    clear_user_skills(&env, &target_user);
+    clear_social_links(&env, &target_user);
+    remove_from_activity_index(&env, &target_user);
+
+    env.events().publish(
+        (Symbol::new(&env, PROFILE_DELETED_EVENT), &target_user),
+        caller,
+    );
+}
+
+fn remove_from_users_index(env: &Env, user: &Address) {
+    let mut users_index: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Vec<Address>>(&DataKey::UsersIndex)
+        .unwrap_or_else(|| Vec::new(env));
+
+    if let Some(index) = users_index.first_index_of(user) {
+        users_index.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::UsersIndex, &users_index);
+    }
+}
+
+fn remove_from_activity_index(env: &Env, user: &Address) {
+    let mut index: Vec<(Address, u64)> = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Vec<(Address, u64)>>(&DataKey::ActivityIndex)
+        .unwrap_or_else(|| Vec::new(env));
+
+    if let Some(position) = index.iter().position(|(addr, _)| addr == *user) {
+        index.remove(position as u32);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ActivityIndex, &index);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{DataKey, UserProfile};
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn profile(env: &Env, email: &str) -> UserProfile {
+        UserProfile {
+            full_name: String::from_str(env, "Test User"),
+            contact_email: String::from_str(env, email),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        }
+    }
+
+    fn setup() -> (Env, Address, UserManagementClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize_system(&admin, &admin, &None);
+        (env, contract_id, client, admin)
+    }
+
+    #[test]
+    fn test_delete_own_profile() {
+        let (env, contract_id, client, _admin) = setup();
+        let user = Address::generate(&env);
+        client.create_user_profile(&user, &profile(&env, "owner@example.com"));
+
+        client.delete_user_profile(&user, &user);
+
+        env.as_contract(&contract_id, || {
+            assert!(!env.storage().persistent().has(&DataKey::UserProfile(user.clone())));
+            assert!(!env
+                .storage()
+                .persistent()
+                .has(&DataKey::EmailIndex(String::from_str(&env, "owner@example.com"))));
+        });
+
+        // The email can be reused once the profile is gone
+        client.create_user_profile(&Address::generate(&env), &profile(&env, "owner@example.com"));
+    }
+
+    #[test]
+    fn test_admin_deletes_profile() {
+        let (env, contract_id, client, admin) = setup();
+        let user = Address::generate(&env);
+        client.create_user_profile(&user, &profile(&env, "user@example.com"));
+
+        client.delete_user_profile(&admin, &user);
+
+        env.as_contract(&contract_id, || {
+            assert!(!env.storage().persistent().has(&DataKey::UserProfile(user.clone())));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #135)")]
+    fn test_third_party_cannot_delete_profile() {
+        let (env, _contract_id, client, _admin) = setup();
+        let user = Address::generate(&env);
+        client.create_user_profile(&user, &profile(&env, "user@example.com"));
+
+        let stranger = Address::generate(&env);
+        client.delete_user_profile(&stranger, &user);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #120)")]
+    fn test_delete_nonexistent_profile() {
+        let (env, _contract_id, client, _admin) = setup();
+        let user = Address::generate(&env);
+        client.delete_user_profile(&user, &user);
+    }
+}
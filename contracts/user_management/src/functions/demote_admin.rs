@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::is_admin::require_super_admin;
+use crate::functions::multi_sig::require_single_key_allowed;
+use crate::schema::{AdminConfig, DataKey};
+
+const ADMIN_DEMOTED_EVENT: &str = "AdminDemoted";
+
+/// Revokes the admin role from a regular admin (super admin only).
+///
+/// # Arguments
+///
+/// * `env` - Soroban environment.
+/// * `super_admin` - Address of the super admin performing the demotion.
+/// * `target_admin` - Regular admin losing its privileges.
+///
+/// # Panics
+///
+/// * `Error::AccessDenied` if the caller is not the super admin.
+/// * `Error::ApprovalRequired` if admin removals need more than one approval;
+///   use a `RemoveAdmin` proposal instead.
+/// * `Error::CannotDemoteSuperAdmin` if the target is the super admin.
+/// * `Error::AdminNotFound` if the target is not a regular admin.
+pub fn user_management_demote_admin(env: Env, super_admin: Address, target_admin: Address) {
+    let config: AdminConfig = require_super_admin(&env, &super_admin);
+    require_single_key_allowed(&env);
+
+    if target_admin == config.super_admin {
+        handle_error(&env, Error::CannotDemoteSuperAdmin)
+    }
+
+    let mut admins: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Vec<Address>>(&DataKey::Admins)
+        .unwrap_or_else(|| Vec::new(&env));
+
+    let index: u32 = admins
+        .first_index_of(&target_admin)
+        .unwrap_or_else(|| handle_error(&env, Error::AdminNotFound));
+    admins.remove(index);
+
+    env.storage().persistent().set(&DataKey::Admins, &admins);
+
+    env.events().publish(
+        (Symbol::new(&env, ADMIN_DEMOTED_EVENT), &super_admin),
+        target_admin,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    fn setup() -> (Env, UserManagementClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let super_admin = Address::generate(&env);
+        client.initialize_system(&super_admin, &super_admin, &None);
+        (env, client, super_admin)
+    }
+
+    #[test]
+    fn test_demote_admin() {
+        let (env, client, super_admin) = setup();
+        let admin = Address::generate(&env);
+        client.add_admin(&super_admin, &admin);
+        assert!(client.is_admin(&admin));
+
+        client.demote_admin(&super_admin, &admin);
+
+        assert!(!client.is_admin(&admin));
+        assert_eq!(client.list_all_admins(&super_admin).admins.len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #104)")]
+    fn test_demote_admin_by_regular_admin() {
+        let (env, client, super_admin) = setup();
+        let admin = Address::generate(&env);
+        let other = Address::generate(&env);
+        client.add_admin(&super_admin, &admin);
+        client.add_admin(&super_admin, &other);
+
+        client.demote_admin(&admin, &other);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #137)")]
+    fn test_demote_non_admin() {
+        let (env, client, super_admin) = setup();
+        client.demote_admin(&super_admin, &Address::generate(&env));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #138)")]
+    fn test_demote_super_admin() {
+        let (_env, client, super_admin) = setup();
+        client.demote_admin(&super_admin, &super_admin);
+    }
+
+    #[test]
+    fn test_demote_admin_requires_approval() {
+        let (env, client, super_admin) = setup();
+        let admin = Address::generate(&env);
+        client.add_admin(&super_admin, &admin);
+        client.set_required_approvals(&super_admin, &2);
+
+        let result = client.try_demote_admin(&super_admin, &admin);
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(146)))
+        );
+        assert!(client.is_admin(&admin));
+    }
+}
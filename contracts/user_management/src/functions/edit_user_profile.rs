@@ -4,7 +4,12 @@
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
 
 use crate::error::{handle_error, Error};
+use crate::functions::get_inactive_users::record_user_activity;
+use crate::functions::get_profile_history::record_profile_change;
 use crate::functions::is_admin::is_admin;
+use crate::functions::search_users_by_country::{
+    trim_country, update_country_index, validate_country,
+};
 use crate::functions::utils::url_validation;
 use crate::schema::{DataKey, LightProfile, ProfileUpdateParams, UserProfile};
 
@@ -14,7 +19,6 @@ const USER_UPDATED_EVENT: Symbol = symbol_short!("usrUpdt");
 // Security constants for profile validation (matching create_user_profile)
 const MAX_NAME_LENGTH: usize = 100;
 const MAX_PROFESSION_LENGTH: usize = 100;
-const MAX_COUNTRY_LENGTH: usize = 56; // Longest country name
 
 /// Validates string content for security (reused from create_user_profile)
 fn validate_string_content(_env: &Env, s: &String, max_len: usize) -> bool {
@@ -71,6 +75,9 @@ pub fn edit_user_profile(
         handle_error(&env, Error::InactiveUser);
     }
 
+    let old_profile: UserProfile = profile.clone();
+    let old_country: Option<String> = profile.country.clone();
+
     // Apply updates with validation
     if let Some(ref name) = updates.full_name {
         if name.is_empty() {
@@ -92,10 +99,8 @@ pub fn edit_user_profile(
     }
 
     if let Some(ref country) = updates.country {
-        if !country.is_empty() && !validate_string_content(&env, country, MAX_COUNTRY_LENGTH) {
-            handle_error(&env, Error::InvalidField);
-        }
-        profile.country = if country.is_empty() { None } else { Some(country.clone()) };
+        validate_country(&env, country);
+        profile.country = Some(trim_country(&env, country));
     }
 
     // Handle purpose field update
@@ -114,6 +119,14 @@ pub fn edit_user_profile(
         profile.profile_picture_url = if profile_pic_url.is_empty() { None } else { Some(profile_pic_url.clone()) };
     }
 
+    // Validate avatar URL if provided; an empty string clears it
+    if let Some(ref avatar_url) = updates.avatar_url {
+        if !avatar_url.is_empty() && !url_validation::is_valid_avatar_url(avatar_url) {
+            handle_error(&env, Error::InvalidAvatarUrl);
+        }
+        profile.avatar_url = if avatar_url.is_empty() { None } else { Some(avatar_url.clone()) };
+    }
+
     // Update the full profile in storage
     env.storage().persistent().set(&storage_key, &profile);
 
@@ -130,6 +143,9 @@ pub fn edit_user_profile(
     env.storage()
         .persistent()
         .set(&light_storage_key, &updated_light_profile);
+    update_country_index(&env, &user_id, &old_country, &profile.country);
+    record_profile_change(&env, &user_id, &old_profile, &profile);
+    record_user_activity(&env, &user_id);
 
     // Emit user update event
     env.events()
@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::is_admin::require_admin;
+use crate::schema::DataKey;
+
+const EMAIL_VERIFICATION_CHANGED_EVENT: &str = "EmailVerificationChanged";
+
+/// Marks the contact email of `user` as verified or unverified (admin only).
+///
+/// # Panics
+///
+/// * `Error::AccessDenied` if the caller is not an admin.
+/// * `Error::UserNotFound` if the user has no profile.
+pub fn user_management_set_email_verified(env: Env, admin: Address, user: Address, verified: bool) {
+    require_admin(&env, &admin);
+
+    if !env
+        .storage()
+        .persistent()
+        .has(&DataKey::UserProfile(user.clone()))
+    {
+        handle_error(&env, Error::UserNotFound)
+    }
+
+    let key: DataKey = DataKey::EmailVerified(user.clone());
+    if verified {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    env.events().publish(
+        (Symbol::new(&env, EMAIL_VERIFICATION_CHANGED_EVENT), user),
+        (verified, admin),
+    );
+}
+
+/// Returns whether the contact email of `user` has been verified.
+pub fn user_management_is_email_verified(env: Env, user: Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::EmailVerified(user))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::UserProfile;
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup() -> (Env, UserManagementClient<'static>, Address, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+
+        let super_admin = Address::generate(&env);
+        client.initialize(&super_admin, &None);
+
+        let user = Address::generate(&env);
+        let profile = UserProfile {
+            full_name: String::from_str(&env, "Jane Doe"),
+            contact_email: String::from_str(&env, "jane@example.com"),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        };
+        client.create_user_profile(&user, &profile);
+
+        (env, client, super_admin, user)
+    }
+
+    #[test]
+    fn test_set_email_verified() {
+        let (_env, client, admin, user) = setup();
+        assert!(!client.is_email_verified(&user));
+
+        client.set_email_verified(&admin, &user, &true);
+        assert!(client.is_email_verified(&user));
+
+        client.set_email_verified(&admin, &user, &false);
+        assert!(!client.is_email_verified(&user));
+    }
+
+    #[test]
+    fn test_set_email_verified_without_profile() {
+        let (env, client, admin, _user) = setup();
+        let result = client.try_set_email_verified(&admin, &Address::generate(&env), &true);
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(120))));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #104)")]
+    fn test_set_email_verified_not_admin() {
+        let (env, client, _admin, user) = setup();
+        client.set_email_verified(&Address::generate(&env), &user, &true);
+    }
+}
@@ -0,0 +1,214 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{contractclient, contracttype, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::is_admin::{is_admin, require_super_admin};
+use crate::functions::user_skills::user_management_list_user_skills;
+use crate::schema::{DataKey, UserDataExport, UserProfile};
+
+const COURSE_ACCESS_CONTRACT_SET_EVENT: &str = "CourseAccessContractSet";
+const USER_DATA_EXPORTED_EVENT: &str = "UserDataExported";
+
+/// Course list returned by the course access contract's `list_user_courses`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserCourses {
+    pub user: Address,
+    pub courses: Vec<String>,
+}
+
+/// Subset of the course access contract interface used for enrollment lookups.
+#[allow(dead_code)]
+#[contractclient(name = "CourseAccessClient")]
+pub trait CourseAccessInterface {
+    fn list_user_courses(env: Env, user: Address) -> UserCourses;
+}
+
+/// Sets the course access contract queried for enrollments (super admin only).
+///
+/// # Panics
+///
+/// * `Error::SystemNotInitialized` if the admin system is not initialized.
+/// * `Error::AccessDenied` if the caller is not the super admin.
+pub fn user_management_set_course_access_contract(env: Env, caller: Address, contract: Address) {
+    require_super_admin(&env, &caller);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseAccessAddr, &contract);
+
+    env.events().publish(
+        (Symbol::new(&env, COURSE_ACCESS_CONTRACT_SET_EVENT),),
+        contract,
+    );
+}
+
+/// Exports the personal data held about `target` for data portability.
+///
+/// Enrollments are read from the configured course access contract and are
+/// empty when none is configured.
+///
+/// # Arguments
+///
+/// * `env` - Soroban environment.
+/// * `caller` - Address requesting the export (the user themselves or an admin).
+/// * `target` - Address whose data is exported.
+///
+/// # Panics
+///
+/// * `Error::Unauthorized` if the caller is neither `target` nor an admin.
+/// * `Error::UserNotFound` if `target` has no profile.
+pub fn user_management_export_user_data(env: Env, caller: Address, target: Address) -> UserDataExport {
+    caller.require_auth();
+
+    if caller != target && !is_admin(env.clone(), caller.clone()) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    let profile: UserProfile = env
+        .storage()
+        .persistent()
+        .get(&DataKey::UserProfile(target.clone()))
+        .unwrap_or_else(|| handle_error(&env, Error::UserNotFound));
+
+    let registration_timestamp: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::RegisteredAt(target.clone()))
+        .unwrap_or(0);
+
+    let export: UserDataExport = UserDataExport {
+        profile,
+        enrolled_courses: get_enrolled_courses(&env, &target),
+        skills: user_management_list_user_skills(env.clone(), target.clone()),
+        registration_timestamp,
+    };
+
+    env.events()
+        .publish((Symbol::new(&env, USER_DATA_EXPORTED_EVENT), target), caller);
+
+    export
+}
+
+fn get_enrolled_courses(env: &Env, user: &Address) -> Vec<String> {
+    let course_access_addr: Option<Address> =
+        env.storage().persistent().get(&DataKey::CourseAccessAddr);
+    match course_access_addr {
+        Some(addr) => CourseAccessClient::new(env, &addr).list_user_courses(user).courses,
+        None => Vec::new(env),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::UserProfile;
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{vec, Address, Env, String};
+
+    mod mock_course_access {
+        use super::super::UserCourses;
+        use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+
+        #[contract]
+        pub struct CourseAccess;
+
+        #[contractimpl]
+        impl CourseAccess {
+            pub fn set_user_courses(env: Env, user: Address, courses: Vec<String>) {
+                env.storage().persistent().set(&user, &courses);
+            }
+
+            pub fn list_user_courses(env: Env, user: Address) -> UserCourses {
+                let courses: Vec<String> = env
+                    .storage()
+                    .persistent()
+                    .get(&user)
+                    .unwrap_or(Vec::new(&env));
+                UserCourses { user, courses }
+            }
+        }
+    }
+
+    fn setup() -> (Env, UserManagementClient<'static>, Address, mock_course_access::CourseAccessClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_700_000_000);
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+
+        let super_admin = Address::generate(&env);
+        client.initialize(&super_admin, &None);
+
+        let course_access_id = env.register(mock_course_access::CourseAccess, ());
+        client.set_course_access_contract(&super_admin, &course_access_id);
+        let course_access = mock_course_access::CourseAccessClient::new(&env, &course_access_id);
+
+        (env, client, super_admin, course_access)
+    }
+
+    fn create_user(env: &Env, client: &UserManagementClient, email: &str) -> (Address, UserProfile) {
+        let user = Address::generate(env);
+        let profile = UserProfile {
+            full_name: String::from_str(env, "Jane Doe"),
+            contact_email: String::from_str(env, email),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        };
+        client.create_user_profile(&user, &profile);
+        (user, profile)
+    }
+
+    #[test]
+    fn test_self_export() {
+        let (env, client, _super_admin, course_access) = setup();
+        let (user, profile) = create_user(&env, &client, "jane@example.com");
+        let courses = vec![&env, String::from_str(&env, "rust-101"), String::from_str(&env, "go-101")];
+        course_access.set_user_courses(&user, &courses);
+        client.add_user_skill(&user, &String::from_str(&env, "Rust"));
+
+        let export = client.export_personal_data(&user, &user);
+
+        assert_eq!(export.profile, profile);
+        assert_eq!(export.enrolled_courses, courses);
+        assert_eq!(export.skills, vec![&env, String::from_str(&env, "Rust")]);
+        assert_eq!(export.registration_timestamp, 1_700_000_000);
+    }
+
+    #[test]
+    fn test_admin_exports_other_user() {
+        let (env, client, super_admin, course_access) = setup();
+        let (user, profile) = create_user(&env, &client, "jane@example.com");
+        let courses = vec![&env, String::from_str(&env, "rust-101")];
+        course_access.set_user_courses(&user, &courses);
+
+        let export = client.export_personal_data(&super_admin, &user);
+
+        assert_eq!(export.profile, profile);
+        assert_eq!(export.enrolled_courses, courses);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #135)")]
+    fn test_third_party_export_unauthorized() {
+        let (env, client, _super_admin, _course_access) = setup();
+        let (user, _profile) = create_user(&env, &client, "jane@example.com");
+
+        client.export_personal_data(&Address::generate(&env), &user);
+    }
+
+    #[test]
+    fn test_export_user_without_enrollments() {
+        let (env, client, _super_admin, _course_access) = setup();
+        let (user, _profile) = create_user(&env, &client, "jane@example.com");
+
+        let export = client.export_personal_data(&user, &user);
+
+        assert!(export.enrolled_courses.is_empty());
+    }
+}
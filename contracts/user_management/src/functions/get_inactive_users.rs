@@ -0,0 +1,181 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::is_admin::is_admin;
+use crate::schema::{DataKey, UserProfile, DEFAULT_MAX_PAGE_SIZE};
+
+/// Records profile activity for a user.
+///
+/// The user's entry is moved to the end of `DataKey::ActivityIndex` with the
+/// current ledger timestamp, keeping the index sorted by last activity.
+pub fn record_user_activity(env: &Env, user: &Address) {
+    let mut index: Vec<(Address, u64)> = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Vec<(Address, u64)>>(&DataKey::ActivityIndex)
+        .unwrap_or_else(|| Vec::new(env));
+
+    if let Some(position) = index.iter().position(|(addr, _)| addr == *user) {
+        index.remove(position as u32);
+    }
+    index.push_back((user.clone(), env.ledger().timestamp()));
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::ActivityIndex, &index);
+}
+
+/// Lists users whose last profile activity is older than `inactive_since` (admin-only).
+///
+/// Because `DataKey::ActivityIndex` is sorted by activity time, the scan stops at
+/// the first user active at or after `inactive_since` instead of visiting every
+/// registered user.
+///
+/// # Arguments
+///
+/// * `env` - Soroban environment.
+/// * `caller` - Address of the caller (must be admin).
+/// * `inactive_since` - Users last active strictly before this timestamp are returned.
+/// * `page` - Zero-based page index.
+/// * `page_size` - Number of profiles per page (capped at `DEFAULT_MAX_PAGE_SIZE`).
+///
+/// # Returns
+///
+/// * `Vec<UserProfile>` - Inactive user profiles, least recently active first.
+pub fn user_management_get_inactive_users(
+    env: Env,
+    caller: Address,
+    inactive_since: u64,
+    page: u32,
+    page_size: u32,
+) -> Vec<UserProfile> {
+    caller.require_auth();
+
+    if !is_admin(env.clone(), caller) {
+        handle_error(&env, Error::AccessDenied)
+    }
+
+    let page_size: u32 = page_size.min(DEFAULT_MAX_PAGE_SIZE);
+    let mut result: Vec<UserProfile> = Vec::new(&env);
+    if page_size == 0 {
+        return result;
+    }
+
+    let start: u64 = (page as u64).saturating_mul(page_size as u64);
+    let index: Vec<(Address, u64)> = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Vec<(Address, u64)>>(&DataKey::ActivityIndex)
+        .unwrap_or_else(|| Vec::new(&env));
+
+    let mut matched: u64 = 0;
+    for (user, last_active) in index.iter() {
+        if last_active >= inactive_since || result.len() >= page_size {
+            break;
+        }
+        if let Some(profile) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, UserProfile>(&DataKey::UserProfile(user))
+        {
+            if matched >= start {
+                result.push_back(profile);
+            }
+            matched += 1;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{ProfileUpdateParams, UserProfile};
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, Env, String};
+
+    fn profile(env: &Env, name: &str, email: &str) -> UserProfile {
+        UserProfile {
+            full_name: String::from_str(env, name),
+            contact_email: String::from_str(env, email),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        }
+    }
+
+    fn setup() -> (Env, UserManagementClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize_system(&admin, &admin, &None);
+        (env, client, admin)
+    }
+
+    #[test]
+    fn test_get_inactive_users_filters_by_activity() {
+        let (env, client, admin) = setup();
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let carol = Address::generate(&env);
+
+        env.ledger().set_timestamp(100);
+        client.create_user_profile(&alice, &profile(&env, "Alice", "alice@example.com"));
+        env.ledger().set_timestamp(200);
+        client.create_user_profile(&bob, &profile(&env, "Bob", "bob@example.com"));
+        env.ledger().set_timestamp(300);
+        client.create_user_profile(&carol, &profile(&env, "Carol", "carol@example.com"));
+
+        let inactive = client.get_inactive_users(&admin, &250, &0, &10);
+        assert_eq!(inactive.len(), 2);
+        assert_eq!(inactive.get(0).unwrap().full_name, String::from_str(&env, "Alice"));
+        assert_eq!(inactive.get(1).unwrap().full_name, String::from_str(&env, "Bob"));
+
+        // Updating Alice's profile marks her as active again
+        env.ledger().set_timestamp(400);
+        let updates = ProfileUpdateParams {
+            full_name: None,
+            profession: Some(String::from_str(&env, "Engineer")),
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        };
+        client.edit_user_profile(&alice, &alice, &updates);
+
+        let inactive = client.get_inactive_users(&admin, &250, &0, &10);
+        assert_eq!(inactive.len(), 1);
+        assert_eq!(inactive.get(0).unwrap().full_name, String::from_str(&env, "Bob"));
+    }
+
+    #[test]
+    fn test_get_inactive_users_pagination() {
+        let (env, client, admin) = setup();
+
+        for (i, email) in ["ann@example.com", "ben@example.com", "cat@example.com"].iter().enumerate() {
+            env.ledger().set_timestamp(100 + i as u64);
+            client.create_user_profile(&Address::generate(&env), &profile(&env, "User", email));
+        }
+
+        assert_eq!(client.get_inactive_users(&admin, &1000, &0, &2).len(), 2);
+        assert_eq!(client.get_inactive_users(&admin, &1000, &1, &2).len(), 1);
+        assert_eq!(client.get_inactive_users(&admin, &1000, &2, &2).len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #104)")]
+    fn test_get_inactive_users_requires_admin() {
+        let (env, client, _admin) = setup();
+        let user = Address::generate(&env);
+        client.get_inactive_users(&user, &1000, &0, &10);
+    }
+}
@@ -0,0 +1,272 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::is_admin::is_admin;
+use crate::schema::{DataKey, ProfileChange, UserProfile};
+
+/// Maximum number of profile changes kept per user; older ones are dropped
+pub const MAX_PROFILE_HISTORY: u32 = 20;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+fn push_if_changed<T>(
+    env: &Env,
+    name: &str,
+    old: &T,
+    new: &T,
+    changed_fields: &mut Vec<String>,
+    previous_values: &mut Bytes,
+) where
+    T: PartialEq + Clone + ToXdr,
+{
+    if old != new {
+        changed_fields.push_back(String::from_str(env, name));
+        previous_values.append(&old.clone().to_xdr(env));
+    }
+}
+
+fn to_hex(env: &Env, hash: &[u8; 32]) -> String {
+    let mut hex: [u8; 64] = [0; 64];
+    for (index, byte) in hash.iter().enumerate() {
+        hex[index * 2] = HEX_DIGITS[(byte >> 4) as usize];
+        hex[index * 2 + 1] = HEX_DIGITS[(byte & 0x0f) as usize];
+    }
+    String::from_bytes(env, &hex)
+}
+
+/// Appends the fields that differ between `old` and `new` to the user's
+/// profile history, dropping the oldest entry once `MAX_PROFILE_HISTORY` is
+/// reached. Nothing is recorded when no field changed.
+///
+/// Previous values are kept only as a SHA-256 hash of their XDR encoding, so
+/// the history holds evidence of a change without storing personal data.
+pub fn record_profile_change(env: &Env, user: &Address, old: &UserProfile, new: &UserProfile) {
+    let mut changed_fields: Vec<String> = Vec::new(env);
+    let mut previous_values: Bytes = Bytes::new(env);
+
+    push_if_changed(
+        env,
+        "full_name",
+        &old.full_name,
+        &new.full_name,
+        &mut changed_fields,
+        &mut previous_values,
+    );
+    push_if_changed(
+        env,
+        "contact_email",
+        &old.contact_email,
+        &new.contact_email,
+        &mut changed_fields,
+        &mut previous_values,
+    );
+    push_if_changed(
+        env,
+        "profession",
+        &old.profession,
+        &new.profession,
+        &mut changed_fields,
+        &mut previous_values,
+    );
+    push_if_changed(
+        env,
+        "country",
+        &old.country,
+        &new.country,
+        &mut changed_fields,
+        &mut previous_values,
+    );
+    push_if_changed(
+        env,
+        "purpose",
+        &old.purpose,
+        &new.purpose,
+        &mut changed_fields,
+        &mut previous_values,
+    );
+    push_if_changed(
+        env,
+        "profile_picture_url",
+        &old.profile_picture_url,
+        &new.profile_picture_url,
+        &mut changed_fields,
+        &mut previous_values,
+    );
+    push_if_changed(
+        env,
+        "avatar_url",
+        &old.avatar_url,
+        &new.avatar_url,
+        &mut changed_fields,
+        &mut previous_values,
+    );
+
+    if changed_fields.is_empty() {
+        return;
+    }
+
+    let hash: [u8; 32] = env.crypto().sha256(&previous_values).to_array();
+    let key: DataKey = DataKey::ProfileHistory(user.clone());
+    let mut history: Vec<ProfileChange> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    while history.len() >= MAX_PROFILE_HISTORY {
+        history.pop_front();
+    }
+    history.push_back(ProfileChange {
+        timestamp: env.ledger().timestamp(),
+        changed_fields,
+        previous_values_hash: to_hex(env, &hash),
+    });
+    env.storage().persistent().set(&key, &history);
+}
+
+/// Removes the profile history of a deleted user.
+pub fn clear_profile_history(env: &Env, user: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ProfileHistory(user.clone()));
+}
+
+/// Returns the recorded profile changes of `target`, oldest first.
+///
+/// Only the user themselves or an admin may read the history.
+///
+/// Panics with `Error::AccessDenied` for any other caller.
+pub fn user_management_get_profile_change_history(
+    env: Env,
+    caller: Address,
+    target: Address,
+) -> Vec<ProfileChange> {
+    caller.require_auth();
+
+    if caller != target && !is_admin(env.clone(), caller) {
+        handle_error(&env, Error::AccessDenied)
+    }
+
+    env.storage()
+        .persistent()
+        .get(&DataKey::ProfileHistory(target))
+        .unwrap_or(Vec::new(&env))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MAX_PROFILE_HISTORY;
+    use crate::schema::{ProfileUpdateParams, UserProfile};
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{vec, Address, Env, String};
+
+    fn setup() -> (Env, UserManagementClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+
+        let user = Address::generate(&env);
+        let profile = UserProfile {
+            full_name: String::from_str(&env, "Jane Doe"),
+            contact_email: String::from_str(&env, "jane@example.com"),
+            profession: None,
+            country: Some(String::from_str(&env, "ES")),
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        };
+        client.create_user_profile(&user, &profile);
+        (env, client, user)
+    }
+
+    fn no_updates() -> ProfileUpdateParams {
+        ProfileUpdateParams {
+            full_name: None,
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        }
+    }
+
+    #[test]
+    fn test_edit_records_profile_change() {
+        let (env, client, user) = setup();
+        env.ledger().set_timestamp(5_000);
+        let mut updates = no_updates();
+        updates.full_name = Some(String::from_str(&env, "Jane Smith"));
+        updates.profession = Some(String::from_str(&env, "Engineer"));
+
+        client.edit_user_profile(&user, &user, &updates);
+
+        let history = client.get_profile_change_history(&user, &user);
+        assert_eq!(history.len(), 1);
+        let change = history.get(0).unwrap();
+        assert_eq!(change.timestamp, 5_000);
+        assert_eq!(
+            change.changed_fields,
+            vec![
+                &env,
+                String::from_str(&env, "full_name"),
+                String::from_str(&env, "profession")
+            ]
+        );
+        assert_eq!(change.previous_values_hash.len(), 64);
+    }
+
+    #[test]
+    fn test_only_changed_fields_recorded() {
+        let (env, client, user) = setup();
+        let mut updates = no_updates();
+        // Same name as before, new country
+        updates.full_name = Some(String::from_str(&env, "Jane Doe"));
+        updates.country = Some(String::from_str(&env, "FR"));
+        client.edit_user_profile(&user, &user, &updates);
+
+        // An edit that changes nothing is not recorded
+        client.edit_user_profile(&user, &user, &updates);
+
+        let history = client.get_profile_change_history(&user, &user);
+        assert_eq!(history.len(), 1);
+        assert_eq!(
+            history.get(0).unwrap().changed_fields,
+            vec![&env, String::from_str(&env, "country")]
+        );
+    }
+
+    #[test]
+    fn test_profile_history_cap() {
+        let (env, client, user) = setup();
+        for index in 0..(MAX_PROFILE_HISTORY + 2) {
+            env.ledger().set_timestamp(index as u64);
+            let mut updates = no_updates();
+            updates.purpose = Some(if index % 2 == 0 {
+                String::from_str(&env, "Learn Rust")
+            } else {
+                String::from_str(&env, "Teach Rust")
+            });
+            client.edit_user_profile(&user, &user, &updates);
+        }
+
+        let history = client.get_profile_change_history(&user, &user);
+        assert_eq!(history.len(), MAX_PROFILE_HISTORY);
+        // The two oldest changes were evicted
+        assert_eq!(history.get(0).unwrap().timestamp, 2);
+        assert_eq!(
+            history.last().unwrap().timestamp,
+            (MAX_PROFILE_HISTORY + 1) as u64
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #104)")]
+    fn test_profile_history_unauthorized() {
+        let (env, client, user) = setup();
+        client.get_profile_change_history(&Address::generate(&env), &user);
+    }
+}
@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String};
+
+use crate::error::{handle_error, Error};
+use crate::functions::is_admin::is_admin;
+use crate::functions::utils::storage_utils::normalize_email;
+use crate::schema::{DataKey, UserProfile};
+
+/// Retrieves a user profile by its contact email.
+///
+/// The email is matched case-insensitively through `DataKey::EmailIndex`.
+///
+/// # Arguments
+///
+/// * `env` - Soroban environment.
+/// * `requester` - Address of the requester (must be an admin or the profile owner).
+/// * `email` - Contact email of the profile to look up.
+///
+/// # Returns
+///
+/// * `UserProfile` - The profile registered with the given email.
+///
+/// # Panics
+///
+/// * `Error::UserNotFound` if no profile is registered with the email.
+/// * `Error::AccessDenied` if the requester is neither an admin nor the owner.
+pub fn user_management_get_user_by_email(env: Env, requester: Address, email: String) -> UserProfile {
+    requester.require_auth();
+
+    let email: String = normalize_email(&env, &email);
+    let user: Address = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Address>(&DataKey::EmailIndex(email))
+        .unwrap_or_else(|| handle_error(&env, Error::UserNotFound));
+
+    if requester != user && !is_admin(env.clone(), requester) {
+        handle_error(&env, Error::AccessDenied)
+    }
+
+    env.storage()
+        .persistent()
+        .get::<DataKey, UserProfile>(&DataKey::UserProfile(user))
+        .unwrap_or_else(|| handle_error(&env, Error::UserNotFound))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::UserProfile;
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn profile(env: &Env, email: &str) -> UserProfile {
+        UserProfile {
+            full_name: String::from_str(env, "Test User"),
+            contact_email: String::from_str(env, email),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        }
+    }
+
+    fn setup() -> (Env, UserManagementClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize_system(&admin, &admin, &None);
+        (env, client, admin)
+    }
+
+    #[test]
+    fn test_get_user_by_email_found() {
+        let (env, client, admin) = setup();
+        let user = Address::generate(&env);
+        let created = client.create_user_profile(&user, &profile(&env, "Owner@Example.com"));
+
+        let by_admin = client.get_user_by_email(&admin, &String::from_str(&env, "owner@example.com"));
+        assert_eq!(by_admin, created);
+
+        let by_owner = client.get_user_by_email(&user, &String::from_str(&env, "OWNER@EXAMPLE.COM"));
+        assert_eq!(by_owner, created);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #120)")]
+    fn test_get_user_by_email_not_found() {
+        let (env, client, admin) = setup();
+        client.get_user_by_email(&admin, &String::from_str(&env, "nobody@example.com"));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #104)")]
+    fn test_get_user_by_email_requires_admin_or_owner() {
+        let (env, client, _admin) = setup();
+        client.create_user_profile(&Address::generate(&env), &profile(&env, "owner@example.com"));
+
+        let stranger = Address::generate(&env);
+        client.get_user_by_email(&stranger, &String::from_str(&env, "owner@example.com"));
+    }
+}
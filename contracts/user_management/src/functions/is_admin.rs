@@ -3,6 +3,7 @@
 
 use soroban_sdk::{Address, Env, Vec};
 
+use crate::error::{handle_error, Error};
 use crate::schema::{AdminConfig, DataKey};
 
 /// Returns true if the given address is an admin (either super admin or in admin list).
@@ -29,3 +30,93 @@ pub fn is_admin(env: Env, who: Address) -> bool {
         _ => false,
     }
 }
+
+/// Returns the super admin address.
+///
+/// Panics with `Error::SystemNotInitialized` if the admin system is not initialized.
+pub fn get_super_admin(env: Env) -> Address {
+    match env
+        .storage()
+        .persistent()
+        .get::<DataKey, AdminConfig>(&DataKey::AdminConfig)
+    {
+        Some(config) if config.initialized => config.super_admin,
+        _ => handle_error(&env, Error::SystemNotInitialized),
+    }
+}
+
+/// Requires the caller to be authenticated and to be the super admin.
+///
+/// Returns the admin configuration so callers don't need to load it again.
+pub fn require_super_admin(env: &Env, caller: &Address) -> AdminConfig {
+    caller.require_auth();
+
+    let config: AdminConfig = env
+        .storage()
+        .persistent()
+        .get::<DataKey, AdminConfig>(&DataKey::AdminConfig)
+        .unwrap_or_else(|| handle_error(env, Error::SystemNotInitialized));
+
+    if !config.initialized {
+        handle_error(env, Error::SystemNotInitialized)
+    }
+
+    if *caller != config.super_admin {
+        handle_error(env, Error::AccessDenied)
+    }
+
+    config
+}
+
+/// Requires the caller to be authenticated and to be an admin.
+pub fn require_admin(env: &Env, caller: &Address) {
+    caller.require_auth();
+
+    if !is_admin(env.clone(), caller.clone()) {
+        handle_error(env, Error::AccessDenied)
+    }
+}
+
+/// Requires the caller to be authenticated and to either own the target
+/// profile or be an admin.
+pub fn require_user_management_auth(env: &Env, caller: &Address, target_user: &Address) {
+    caller.require_auth();
+
+    if caller != target_user && !is_admin(env.clone(), caller.clone()) {
+        handle_error(env, Error::Unauthorized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    #[test]
+    fn test_is_admin_and_get_super_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+
+        let super_admin = Address::generate(&env);
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        client.initialize_system(&super_admin, &super_admin, &None);
+        client.add_admin(&super_admin, &admin);
+
+        assert!(client.is_admin(&super_admin));
+        assert!(client.is_admin(&admin));
+        assert!(!client.is_admin(&user));
+        assert_eq!(client.get_super_admin(), super_admin);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #103)")]
+    fn test_get_super_admin_not_initialized() {
+        let env = Env::default();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        client.get_super_admin();
+    }
+}
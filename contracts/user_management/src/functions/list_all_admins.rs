@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::is_admin::require_admin;
+use crate::schema::{AdminConfig, AdminList, DataKey};
+
+/// Returns the super admin and the regular admin roster (admin-only).
+///
+/// # Arguments
+///
+/// * `env` - Soroban environment.
+/// * `caller` - Address of the caller (must be admin).
+///
+/// # Returns
+///
+/// * `AdminList` - The super admin address and the regular admin addresses.
+///
+/// # Panics
+///
+/// * `Error::SystemNotInitialized` if the admin system is not initialized.
+/// * `Error::AccessDenied` if the caller is not an admin.
+pub fn user_management_list_all_admins(env: Env, caller: Address) -> AdminList {
+    let config: AdminConfig = env
+        .storage()
+        .persistent()
+        .get::<DataKey, AdminConfig>(&DataKey::AdminConfig)
+        .unwrap_or_else(|| handle_error(&env, Error::SystemNotInitialized));
+
+    if !config.initialized {
+        handle_error(&env, Error::SystemNotInitialized)
+    }
+
+    require_admin(&env, &caller);
+
+    let admins: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Vec<Address>>(&DataKey::Admins)
+        .unwrap_or_else(|| Vec::new(&env));
+
+    AdminList {
+        super_admin: config.super_admin,
+        admins,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    fn setup() -> (Env, UserManagementClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let super_admin = Address::generate(&env);
+        client.initialize_system(&super_admin, &super_admin, &None);
+        (env, client, super_admin)
+    }
+
+    #[test]
+    fn test_list_all_admins_reflects_changes() {
+        let (env, client, super_admin) = setup();
+
+        let roster = client.list_all_admins(&super_admin);
+        assert_eq!(roster.super_admin, super_admin);
+        assert_eq!(roster.admins.len(), 0);
+
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        client.add_admin(&super_admin, &first);
+        client.add_admin(&super_admin, &second);
+
+        // Regular admins can query the roster too
+        let roster = client.list_all_admins(&first);
+        assert_eq!(roster.admins.len(), 2);
+        assert!(roster.admins.contains(&first));
+        assert!(roster.admins.contains(&second));
+
+        client.remove_admin(&super_admin, &first);
+        let roster = client.list_all_admins(&super_admin);
+        assert_eq!(roster.admins.len(), 1);
+        assert!(!roster.admins.contains(&first));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #104)")]
+    fn test_list_all_admins_requires_admin() {
+        let (env, client, _super_admin) = setup();
+        let user = Address::generate(&env);
+        client.list_all_admins(&user);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #103)")]
+    fn test_list_all_admins_not_initialized() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        client.list_all_admins(&Address::generate(&env));
+    }
+}
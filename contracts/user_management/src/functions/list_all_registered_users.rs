@@ -683,6 +683,7 @@ mod tests {
             max_page_size: 100,
             total_user_count: 0,
             rate_limit_config: crate::functions::utils::rate_limit_utils::get_default_rate_limit_config(),
+            max_admins: crate::schema::DEFAULT_MAX_ADMINS,
         };
 
         let pagination = PaginationParams {
@@ -702,6 +703,7 @@ mod tests {
             max_page_size: 100,
             total_user_count: 0,
             rate_limit_config: crate::functions::utils::rate_limit_utils::get_default_rate_limit_config(),
+            max_admins: crate::schema::DEFAULT_MAX_ADMINS,
         };
 
         let pagination = PaginationParams {
@@ -721,6 +723,7 @@ mod tests {
             max_page_size: 100,
             total_user_count: 0,
             rate_limit_config: crate::functions::utils::rate_limit_utils::get_default_rate_limit_config(),
+            max_admins: crate::schema::DEFAULT_MAX_ADMINS,
         };
 
         let pagination = PaginationParams {
@@ -0,0 +1,227 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::is_admin::is_admin;
+use crate::schema::{DataKey, UserListPage, UserProfile};
+
+/// Maximum number of profiles returned by a single `list_users` call.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+fn get_user_count(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::UserIndexCount)
+        .unwrap_or(0)
+}
+
+/// Appends a user to the sequential user index.
+pub fn add_to_user_index(env: &Env, user: &Address) {
+    let position_key: DataKey = DataKey::UserIndexPosition(user.clone());
+    if env.storage().persistent().has(&position_key) {
+        return;
+    }
+
+    let count: u32 = get_user_count(env);
+    env.storage()
+        .persistent()
+        .set(&DataKey::UserIndex(count), user);
+    env.storage().persistent().set(&position_key, &count);
+    env.storage()
+        .persistent()
+        .set(&DataKey::UserIndexCount, &(count + 1));
+}
+
+/// Removes a user from the sequential user index.
+///
+/// The last entry is moved into the freed slot so the index stays contiguous.
+pub fn remove_from_user_index(env: &Env, user: &Address) {
+    let position_key: DataKey = DataKey::UserIndexPosition(user.clone());
+    let position: u32 = match env.storage().persistent().get(&position_key) {
+        Some(position) => position,
+        None => return,
+    };
+
+    let last: u32 = get_user_count(env) - 1;
+    if position != last {
+        let moved: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::UserIndex(last))
+            .unwrap();
+        env.storage()
+            .persistent()
+            .set(&DataKey::UserIndex(position), &moved);
+        env.storage()
+            .persistent()
+            .set(&DataKey::UserIndexPosition(moved), &position);
+    }
+
+    env.storage().persistent().remove(&DataKey::UserIndex(last));
+    env.storage().persistent().remove(&position_key);
+    env.storage()
+        .persistent()
+        .set(&DataKey::UserIndexCount, &last);
+}
+
+/// Lists registered user profiles using offset-based pagination (admin-only).
+///
+/// # Arguments
+///
+/// * `env` - Soroban environment.
+/// * `caller` - Address of the caller (must be admin).
+/// * `offset` - Number of users to skip.
+/// * `limit` - Maximum number of profiles to return (capped at `MAX_PAGE_SIZE`).
+///
+/// # Returns
+///
+/// * `UserListPage` - The requested profiles, the total user count and whether
+///   more users follow. An `offset` past the end yields an empty page.
+pub fn user_management_list_users(env: Env, caller: Address, offset: u32, limit: u32) -> UserListPage {
+    caller.require_auth();
+
+    if !is_admin(env.clone(), caller) {
+        handle_error(&env, Error::AccessDenied)
+    }
+
+    let total: u32 = get_user_count(&env);
+    let limit: u32 = limit.min(MAX_PAGE_SIZE);
+    let mut users: Vec<UserProfile> = Vec::new(&env);
+
+    if offset >= total {
+        return UserListPage {
+            users,
+            total,
+            has_more: false,
+        };
+    }
+
+    let end: u32 = offset.saturating_add(limit).min(total);
+    for position in offset..end {
+        let user: Option<Address> = env.storage().persistent().get(&DataKey::UserIndex(position));
+        if let Some(profile) = user.and_then(|user| {
+            env.storage()
+                .persistent()
+                .get::<DataKey, UserProfile>(&DataKey::UserProfile(user))
+        }) {
+            users.push_back(profile);
+        }
+    }
+
+    UserListPage {
+        users,
+        total,
+        has_more: end < total,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MAX_PAGE_SIZE;
+    use crate::schema::UserProfile;
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{Address, Env, String};
+
+    fn profile(env: &Env, email: &str) -> UserProfile {
+        UserProfile {
+            full_name: String::from_str(env, "Test User"),
+            contact_email: String::from_str(env, email),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        }
+    }
+
+    fn setup() -> (Env, UserManagementClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize_system(&admin, &admin, &None);
+        (env, client, admin)
+    }
+
+    #[test]
+    fn test_list_users_empty() {
+        let (_env, client, admin) = setup();
+
+        let page = client.list_users(&admin, &0, &10);
+        assert_eq!(page.users.len(), 0);
+        assert_eq!(page.total, 0);
+        assert!(!page.has_more);
+    }
+
+    #[test]
+    fn test_list_users_pagination_boundaries() {
+        let (env, client, admin) = setup();
+        for email in ["ann@example.com", "ben@example.com", "cat@example.com"] {
+            client.create_user_profile(&Address::generate(&env), &profile(&env, email));
+        }
+
+        let first = client.list_users(&admin, &0, &2);
+        assert_eq!(first.users.len(), 2);
+        assert_eq!(first.total, 3);
+        assert!(first.has_more);
+        assert_eq!(
+            first.users.get(0).unwrap().contact_email,
+            String::from_str(&env, "ann@example.com")
+        );
+
+        let last = client.list_users(&admin, &2, &2);
+        assert_eq!(last.users.len(), 1);
+        assert!(!last.has_more);
+
+        let past_end = client.list_users(&admin, &3, &2);
+        assert_eq!(past_end.users.len(), 0);
+        assert_eq!(past_end.total, 3);
+        assert!(!past_end.has_more);
+    }
+
+    #[test]
+    fn test_list_users_clamps_limit() {
+        let (env, client, admin) = setup();
+        for i in 0..(MAX_PAGE_SIZE + 1) {
+            let mut email = *b"user00@example.com";
+            email[4] = b'0' + (i / 10) as u8;
+            email[5] = b'0' + (i % 10) as u8;
+            let email = core::str::from_utf8(&email).unwrap();
+            client.create_user_profile(&Address::generate(&env), &profile(&env, email));
+        }
+
+        let page = client.list_users(&admin, &0, &(MAX_PAGE_SIZE * 2));
+        assert_eq!(page.users.len(), MAX_PAGE_SIZE);
+        assert_eq!(page.total, MAX_PAGE_SIZE + 1);
+        assert!(page.has_more);
+    }
+
+    #[test]
+    fn test_list_users_pruned_on_delete() {
+        let (env, client, admin) = setup();
+        let ann = Address::generate(&env);
+        client.create_user_profile(&ann, &profile(&env, "ann@example.com"));
+        client.create_user_profile(&Address::generate(&env), &profile(&env, "ben@example.com"));
+
+        client.delete_user_profile(&ann, &ann);
+
+        let page = client.list_users(&admin, &0, &10);
+        assert_eq!(page.total, 1);
+        assert_eq!(
+            page.users.get(0).unwrap().contact_email,
+            String::from_str(&env, "ben@example.com")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #104)")]
+    fn test_list_users_requires_admin() {
+        let (env, client, _admin) = setup();
+        let user = Address::generate(&env);
+        client.list_users(&user, &0, &10);
+    }
+}
@@ -1,19 +1,39 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+pub mod admin_invitation;
 pub mod admin_management;
 pub mod backup_recovery;
+pub mod blacklist;
+pub mod check_profile_completeness;
 pub mod contract_versioning;
 pub mod create_user_profile;
 pub mod delete_user;
+pub mod delete_user_profile;
+pub mod demote_admin;
 pub mod edit_user_profile;
+pub mod email_verification;
+pub mod export_user_data;
+pub mod get_inactive_users;
+pub mod get_profile_history;
+pub mod get_user_by_email;
 pub mod get_user_by_id;
 pub mod is_admin;
+pub mod list_all_admins;
 pub mod list_all_registered_users;
+pub mod list_users;
 pub mod list_users_with_access;
+pub mod multi_sig;
 pub mod rbac;
+pub mod rotate_email;
 pub mod save_profile;
+pub mod search_users_by_country;
+pub mod set_max_admin_count;
+pub mod set_user_role;
+pub mod social_links;
+pub mod transfer_super_admin;
 pub mod user;
+pub mod user_skills;
 pub mod utils;
 
 #[cfg(test)]
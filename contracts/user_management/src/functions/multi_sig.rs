@@ -0,0 +1,431 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{vec, Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::admin_management::remove_from_admin_roster;
+use crate::functions::is_admin::{is_admin, require_admin, require_super_admin};
+use crate::functions::transfer_super_admin::transfer_super_admin_role;
+use crate::schema::{AdminConfig, DataKey, OperationType, PendingOperation};
+
+/// Seconds a proposal stays open for approvals (7 days)
+pub const PROPOSAL_DURATION: u64 = 7 * 24 * 60 * 60;
+/// Approvals critical operations need until the super admin changes it.
+/// At this count only the super admin can propose them.
+pub const DEFAULT_REQUIRED_APPROVALS: u32 = 1;
+
+const PROPOSAL_ID_PREFIX: &[u8] = b"proposal_";
+
+const OPERATION_PROPOSED_EVENT: &str = "OperationProposed";
+const OPERATION_APPROVED_EVENT: &str = "OperationApproved";
+const OPERATION_EXECUTED_EVENT: &str = "OperationExecuted";
+const REQUIRED_APPROVALS_CHANGED_EVENT: &str = "RequiredApprovalsChanged";
+
+fn get_required_approvals(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RequiredApprovals)
+        .unwrap_or(DEFAULT_REQUIRED_APPROVALS)
+}
+
+/// Panics with `Error::ApprovalRequired` if critical operations need more
+/// than one admin approval, so they must go through a proposal.
+pub fn require_single_key_allowed(env: &Env) {
+    if get_required_approvals(env) > 1 {
+        handle_error(env, Error::ApprovalRequired)
+    }
+}
+
+/// Builds the ID `proposal_<seq>`.
+fn proposal_id(env: &Env, seq: u32) -> String {
+    let mut buf = [0u8; 19];
+    let prefix_len: usize = PROPOSAL_ID_PREFIX.len();
+    buf[..prefix_len].copy_from_slice(PROPOSAL_ID_PREFIX);
+
+    let mut digits = [0u8; 10];
+    let mut count: usize = 0;
+    let mut n: u32 = seq;
+    loop {
+        digits[count] = b'0' + (n % 10) as u8;
+        count += 1;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    for i in 0..count {
+        buf[prefix_len + i] = digits[count - 1 - i];
+    }
+    String::from_bytes(env, &buf[..prefix_len + count])
+}
+
+/// Runs an approved operation and discards its proposal.
+fn execute_operation(env: &Env, id: &String, operation: &PendingOperation, executor: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PendingApproval(id.clone()));
+
+    let config: AdminConfig = env
+        .storage()
+        .persistent()
+        .get(&DataKey::AdminConfig)
+        .unwrap_or_else(|| handle_error(env, Error::SystemNotInitialized));
+    let target: Address = Address::from_string(&operation.args.get(0).unwrap());
+    match operation.op_type {
+        OperationType::TransferSuperAdmin => transfer_super_admin_role(env, config, &target),
+        OperationType::RemoveAdmin => remove_from_admin_roster(env, &config, executor, &target),
+    }
+
+    env.events().publish(
+        (Symbol::new(env, OPERATION_EXECUTED_EVENT), id.clone()),
+        executor.clone(),
+    );
+}
+
+/// Stores the operation, or runs it right away if it already has enough approvals.
+///
+/// Approvals only count while the approver is still an admin, so approvers
+/// removed since they approved are dropped first.
+fn save_or_execute(env: &Env, id: &String, operation: &mut PendingOperation, approver: &Address) {
+    let mut approvals: Vec<Address> = Vec::new(env);
+    for admin in operation.approvals.iter() {
+        if is_admin(env.clone(), admin.clone()) {
+            approvals.push_back(admin);
+        }
+    }
+    operation.approvals = approvals;
+
+    if operation.approvals.len() >= operation.required_approvals {
+        execute_operation(env, id, operation, approver);
+    } else {
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingApproval(id.clone()), operation);
+    }
+}
+
+/// Proposes a critical operation and counts the proposer's approval (admin only).
+///
+/// `args` holds the target address in its string form. The operation runs as
+/// soon as it has the number of approvals required when it was proposed, and
+/// can be approved for `PROPOSAL_DURATION` seconds. While a single approval is
+/// enough, only the super admin may propose, as the operation runs at once.
+///
+/// # Panics
+///
+/// * `Error::AccessDenied` if the proposer is not an admin, or not the super
+///   admin while a single approval is enough.
+/// * `Error::InvalidField` if `args` does not hold exactly one address.
+pub fn user_management_propose_operation(
+    env: Env,
+    proposer: Address,
+    op_type: OperationType,
+    args: Vec<String>,
+) -> String {
+    let required_approvals: u32 = get_required_approvals(&env);
+    if required_approvals < 2 {
+        // The proposal would run on the proposer's key alone
+        require_super_admin(&env, &proposer);
+    } else {
+        require_admin(&env, &proposer);
+    }
+
+    if args.len() != 1 {
+        handle_error(&env, Error::InvalidField)
+    }
+    // Reject malformed addresses before anyone approves
+    Address::from_string(&args.get(0).unwrap());
+
+    let seq: u32 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ProposalSeq)
+        .unwrap_or(0)
+        + 1;
+    env.storage().persistent().set(&DataKey::ProposalSeq, &seq);
+    let id: String = proposal_id(&env, seq);
+
+    let mut operation: PendingOperation = PendingOperation {
+        op_type,
+        args,
+        approvals: vec![&env, proposer.clone()],
+        required_approvals,
+        expires_at: env.ledger().timestamp() + PROPOSAL_DURATION,
+    };
+
+    env.events().publish(
+        (Symbol::new(&env, OPERATION_PROPOSED_EVENT), id.clone()),
+        (proposer.clone(), op_type),
+    );
+
+    save_or_execute(&env, &id, &mut operation, &proposer);
+    id
+}
+
+/// Approves a pending operation (admin only), running it once it has enough
+/// approvals.
+///
+/// # Panics
+///
+/// * `Error::AccessDenied` if the approver is not an admin.
+/// * `Error::ProposalNotFound` if there is no pending proposal with this ID,
+///   including proposals that already ran.
+/// * `Error::ProposalExpired` if the approval window has closed.
+/// * `Error::AlreadyApproved` if the approver already approved the proposal.
+pub fn user_management_approve_operation(env: Env, approver: Address, proposal_id: String) {
+    require_admin(&env, &approver);
+
+    let mut operation: PendingOperation = env
+        .storage()
+        .persistent()
+        .get(&DataKey::PendingApproval(proposal_id.clone()))
+        .unwrap_or_else(|| handle_error(&env, Error::ProposalNotFound));
+
+    if env.ledger().timestamp() > operation.expires_at {
+        handle_error(&env, Error::ProposalExpired)
+    }
+    if operation.approvals.contains(&approver) {
+        handle_error(&env, Error::AlreadyApproved)
+    }
+
+    operation.approvals.push_back(approver.clone());
+    env.events().publish(
+        (
+            Symbol::new(&env, OPERATION_APPROVED_EVENT),
+            proposal_id.clone(),
+        ),
+        approver.clone(),
+    );
+
+    save_or_execute(&env, &proposal_id, &mut operation, &approver);
+}
+
+/// Sets how many admin approvals critical operations need (super admin only).
+///
+/// With more than one, `transfer_super_admin` and `remove_admin` can only run
+/// through `propose_operation`. Proposals already made keep their count.
+///
+/// # Panics
+///
+/// * `Error::AccessDenied` if the caller is not the super admin.
+/// * `Error::InvalidField` if `count` is zero or larger than the number of
+///   admins the roster can hold, super admin included.
+pub fn user_management_set_required_approvals(env: Env, super_admin: Address, count: u32) {
+    let config: AdminConfig = require_super_admin(&env, &super_admin);
+
+    if count == 0 || count > config.max_admins + 1 {
+        handle_error(&env, Error::InvalidField)
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::RequiredApprovals, &count);
+
+    env.events().publish(
+        (Symbol::new(&env, REQUIRED_APPROVALS_CHANGED_EVENT),),
+        (super_admin, count),
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::PROPOSAL_DURATION;
+    use crate::schema::{OperationType, UserProfile};
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{vec, Address, Env, String};
+
+    fn setup() -> (
+        Env,
+        UserManagementClient<'static>,
+        Address,
+        Address,
+        Address,
+    ) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+
+        let super_admin = Address::generate(&env);
+        client.initialize(&super_admin, &None);
+        let first_admin = Address::generate(&env);
+        let second_admin = Address::generate(&env);
+        client.add_admin(&super_admin, &first_admin);
+        client.add_admin(&super_admin, &second_admin);
+
+        (env, client, super_admin, first_admin, second_admin)
+    }
+
+    fn registered_user(env: &Env, client: &UserManagementClient) -> Address {
+        let user = Address::generate(env);
+        let profile = UserProfile {
+            full_name: String::from_str(env, "Jane Doe"),
+            contact_email: String::from_str(env, "jane@example.com"),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        };
+        client.create_user_profile(&user, &profile);
+        user
+    }
+
+    fn propose_transfer(
+        env: &Env,
+        client: &UserManagementClient,
+        proposer: &Address,
+        target: &Address,
+    ) -> String {
+        client.propose_operation(
+            proposer,
+            &OperationType::TransferSuperAdmin,
+            &vec![env, target.to_string()],
+        )
+    }
+
+    #[test]
+    fn test_operation_runs_at_quorum() {
+        let (env, client, super_admin, first_admin, _second_admin) = setup();
+        let new_super_admin = registered_user(&env, &client);
+        client.set_required_approvals(&super_admin, &2);
+
+        let id = propose_transfer(&env, &client, &first_admin, &new_super_admin);
+        assert_eq!(id, String::from_str(&env, "proposal_1"));
+        assert_eq!(client.get_super_admin(), super_admin);
+
+        client.approve_operation(&super_admin, &id);
+        assert_eq!(client.get_super_admin(), new_super_admin);
+    }
+
+    #[test]
+    fn test_operation_waits_for_quorum() {
+        let (env, client, super_admin, first_admin, second_admin) = setup();
+        client.set_required_approvals(&super_admin, &3);
+
+        let id = client.propose_operation(
+            &super_admin,
+            &OperationType::RemoveAdmin,
+            &vec![&env, second_admin.to_string()],
+        );
+        client.approve_operation(&first_admin, &id);
+        assert!(client.is_admin(&second_admin));
+
+        // Critical operations can no longer run on a single key
+        let result = client.try_remove_admin(&super_admin, &second_admin);
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(146)))
+        );
+
+        client.approve_operation(&second_admin, &id);
+        assert!(!client.is_admin(&second_admin));
+    }
+
+    #[test]
+    fn test_expired_proposal() {
+        let (env, client, super_admin, first_admin, _second_admin) = setup();
+        let new_super_admin = registered_user(&env, &client);
+        client.set_required_approvals(&super_admin, &2);
+        let id = propose_transfer(&env, &client, &first_admin, &new_super_admin);
+
+        env.ledger().set_timestamp(PROPOSAL_DURATION + 1);
+
+        let result = client.try_approve_operation(&super_admin, &id);
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(144)))
+        );
+        assert_eq!(client.get_super_admin(), super_admin);
+    }
+
+    #[test]
+    fn test_approvals_cannot_be_replayed() {
+        let (env, client, super_admin, first_admin, second_admin) = setup();
+        let new_super_admin = registered_user(&env, &client);
+        client.set_required_approvals(&super_admin, &2);
+        let id = propose_transfer(&env, &client, &first_admin, &new_super_admin);
+
+        let result = client.try_approve_operation(&first_admin, &id);
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(145)))
+        );
+
+        client.approve_operation(&second_admin, &id);
+        // The proposal is gone once it ran
+        let result = client.try_approve_operation(&second_admin, &id);
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(143)))
+        );
+    }
+
+    #[test]
+    fn test_regular_admin_cannot_propose_at_default_quorum() {
+        let (env, client, super_admin, first_admin, second_admin) = setup();
+
+        let result = client.try_propose_operation(
+            &first_admin,
+            &OperationType::TransferSuperAdmin,
+            &vec![&env, first_admin.to_string()],
+        );
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(104)))
+        );
+        let result = client.try_propose_operation(
+            &first_admin,
+            &OperationType::RemoveAdmin,
+            &vec![&env, second_admin.to_string()],
+        );
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(104)))
+        );
+
+        assert_eq!(client.get_super_admin(), super_admin);
+        assert!(client.is_admin(&second_admin));
+    }
+
+    #[test]
+    fn test_removed_admin_approval_no_longer_counts() {
+        let (env, client, super_admin, first_admin, second_admin) = setup();
+        let new_super_admin = registered_user(&env, &client);
+        client.set_required_approvals(&super_admin, &2);
+        let id = propose_transfer(&env, &client, &first_admin, &new_super_admin);
+
+        let removal = client.propose_operation(
+            &super_admin,
+            &OperationType::RemoveAdmin,
+            &vec![&env, first_admin.to_string()],
+        );
+        client.approve_operation(&second_admin, &removal);
+        assert!(!client.is_admin(&first_admin));
+
+        // Only the second admin's approval is left
+        client.approve_operation(&second_admin, &id);
+        assert_eq!(client.get_super_admin(), super_admin);
+
+        client.approve_operation(&super_admin, &id);
+        assert_eq!(client.get_super_admin(), new_super_admin);
+    }
+
+    #[test]
+    fn test_set_required_approvals_validation() {
+        let (_env, client, super_admin, first_admin, _second_admin) = setup();
+
+        let result = client.try_set_required_approvals(&super_admin, &0);
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(117)))
+        );
+        let result = client.try_set_required_approvals(&first_admin, &2);
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(104)))
+        );
+    }
+}
@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Symbol};
+
+use crate::error::{handle_error, Error};
+use crate::functions::get_inactive_users::record_user_activity;
+use crate::functions::get_profile_history::record_profile_change;
+use crate::functions::utils::storage_utils::normalize_email;
+use crate::schema::{DataKey, UserProfile};
+
+/// Minimum time between two contact email changes of a user
+pub const EMAIL_CHANGE_COOLDOWN_SECONDS: u64 = 86_400;
+
+const EMAIL_ROTATED_EVENT: &str = "EmailRotated";
+
+/// Changes the contact email of the caller's own profile.
+///
+/// A user may change their email once every `EMAIL_CHANGE_COOLDOWN_SECONDS`;
+/// the first change after registration is not limited. The new email must
+/// not belong to another user and starts out unverified. Passing an email
+/// that only differs from the current one in case changes nothing.
+///
+/// # Panics
+///
+/// * `Error::UserNotFound` if the user has no profile.
+/// * `Error::InvalidEmailFormat` if the new email is not valid.
+/// * `Error::EmailChangeCooldown` if the last change was less than
+///   `EMAIL_CHANGE_COOLDOWN_SECONDS` ago.
+/// * `Error::EmailAlreadyExists` if another user has the new email.
+pub fn user_management_rotate_email(env: Env, user: Address, new_email: String) {
+    user.require_auth();
+
+    let profile_key: DataKey = DataKey::UserProfile(user.clone());
+    let mut profile: UserProfile = env
+        .storage()
+        .persistent()
+        .get(&profile_key)
+        .unwrap_or_else(|| handle_error(&env, Error::UserNotFound));
+
+    if !shared::validate_email(&new_email) {
+        handle_error(&env, Error::InvalidEmailFormat)
+    }
+
+    let old_index_key: DataKey = DataKey::EmailIndex(normalize_email(&env, &profile.contact_email));
+    let new_index_key: DataKey = DataKey::EmailIndex(normalize_email(&env, &new_email));
+    if new_index_key == old_index_key {
+        return;
+    }
+
+    let now: u64 = env.ledger().timestamp();
+    let last_change_key: DataKey = DataKey::LastEmailChange(user.clone());
+    if let Some(last_change) = env.storage().persistent().get::<_, u64>(&last_change_key) {
+        if now.saturating_sub(last_change) < EMAIL_CHANGE_COOLDOWN_SECONDS {
+            handle_error(&env, Error::EmailChangeCooldown)
+        }
+    }
+
+    if env.storage().persistent().has(&new_index_key) {
+        handle_error(&env, Error::EmailAlreadyExists)
+    }
+
+    let old_profile: UserProfile = profile.clone();
+    profile.contact_email = new_email;
+    env.storage().persistent().set(&profile_key, &profile);
+    env.storage().persistent().remove(&old_index_key);
+    env.storage().persistent().set(&new_index_key, &user);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::EmailVerified(user.clone()));
+    env.storage().persistent().set(&last_change_key, &now);
+
+    record_profile_change(&env, &user, &old_profile, &profile);
+    record_user_activity(&env, &user);
+
+    env.events()
+        .publish((Symbol::new(&env, EMAIL_ROTATED_EVENT), user), now);
+}
+
+#[cfg(test)]
+mod test {
+    use super::EMAIL_CHANGE_COOLDOWN_SECONDS;
+    use crate::schema::UserProfile;
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::{Address, Env, String};
+
+    fn setup() -> (Env, UserManagementClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_000);
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let user = Address::generate(&env);
+        let profile = UserProfile {
+            full_name: String::from_str(&env, "Alice"),
+            contact_email: String::from_str(&env, "alice@example.com"),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        };
+        client.create_user_profile(&user, &profile);
+        (env, client, user)
+    }
+
+    #[test]
+    fn test_first_change_has_no_cooldown() {
+        let (env, client, user) = setup();
+        let new_email = String::from_str(&env, "alice@work.example");
+
+        client.rotate_email(&user, &new_email);
+
+        assert_eq!(client.get_user_by_id(&user, &user).contact_email, new_email);
+        assert_eq!(
+            client.get_user_by_email(&user, &new_email).contact_email,
+            new_email
+        );
+        let result =
+            client.try_get_user_by_email(&user, &String::from_str(&env, "alice@example.com"));
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(120)))
+        );
+    }
+
+    #[test]
+    fn test_rapid_change_blocked() {
+        let (env, client, user) = setup();
+        client.rotate_email(&user, &String::from_str(&env, "alice@work.example"));
+
+        env.ledger()
+            .set_timestamp(1_000 + EMAIL_CHANGE_COOLDOWN_SECONDS - 1);
+        let result = client.try_rotate_email(&user, &String::from_str(&env, "alice@new.example"));
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(149)))
+        );
+    }
+
+    #[test]
+    fn test_change_after_cooldown() {
+        let (env, client, user) = setup();
+        let super_admin = Address::generate(&env);
+        client.initialize(&super_admin, &None);
+        client.rotate_email(&user, &String::from_str(&env, "alice@work.example"));
+        client.set_email_verified(&super_admin, &user, &true);
+
+        env.ledger()
+            .set_timestamp(1_000 + EMAIL_CHANGE_COOLDOWN_SECONDS);
+        let new_email = String::from_str(&env, "alice@new.example");
+        client.rotate_email(&user, &new_email);
+
+        assert_eq!(client.get_user_by_id(&user, &user).contact_email, new_email);
+        assert!(!client.is_email_verified(&user));
+    }
+
+    #[test]
+    fn test_email_taken_by_other_user() {
+        let (env, client, user) = setup();
+        let other = Address::generate(&env);
+        let profile = UserProfile {
+            full_name: String::from_str(&env, "Bob"),
+            contact_email: String::from_str(&env, "bob@example.com"),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        };
+        client.create_user_profile(&other, &profile);
+
+        let result = client.try_rotate_email(&user, &String::from_str(&env, "BOB@example.com"));
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(116)))
+        );
+    }
+}
@@ -51,6 +51,7 @@ pub fn save_profile(
         country: None,
         purpose: None,
         profile_picture_url: None,
+        avatar_url: None,
     };
 
     // TODO: Implement profile saving
@@ -0,0 +1,283 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use shared::{Paginator, ProfileValidationError};
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::is_admin::is_admin;
+use crate::functions::list_users::MAX_PAGE_SIZE;
+use crate::schema::{DataKey, UserListPage, UserProfile};
+
+/// Strips leading and trailing whitespace from a country, upper-casing it
+/// too if `uppercase` is set. Names longer than the buffer are returned unchanged.
+fn clean_country(env: &Env, country: &String, uppercase: bool) -> String {
+    let len: usize = country.len() as usize;
+    let mut buf = [0u8; 128];
+    if len > buf.len() {
+        return country.clone();
+    }
+    country.copy_into_slice(&mut buf[..len]);
+    if uppercase {
+        buf[..len].make_ascii_uppercase();
+    }
+    String::from_bytes(env, buf[..len].trim_ascii())
+}
+
+/// Checks a country given for a profile.
+///
+/// Panics with `Error::CountryRequired` if it is empty or only whitespace,
+/// and with `Error::InvalidField` if it is too long.
+pub fn validate_country(env: &Env, country: &String) {
+    match shared::validate_country_code(country) {
+        Ok(()) => {}
+        Err(ProfileValidationError::CountryEmpty) => handle_error(env, Error::CountryRequired),
+        Err(_) => handle_error(env, Error::InvalidField),
+    }
+}
+
+/// Trims a country before it is stored in a profile.
+pub fn trim_country(env: &Env, country: &String) -> String {
+    clean_country(env, country, false)
+}
+
+/// Trims and upper-cases a country for index lookups, so a stored country
+/// and any search for it map to the same key.
+pub fn normalize_country(env: &Env, country: &String) -> String {
+    clean_country(env, country, true)
+}
+
+fn get_country_users(env: &Env, key: &DataKey) -> Vec<Address> {
+    env.storage().persistent().get(key).unwrap_or(Vec::new(env))
+}
+
+/// Adds a user to the index of their country, if they have one.
+pub fn add_to_country_index(env: &Env, user: &Address, country: &Option<String>) {
+    if let Some(country) = country {
+        let key: DataKey = DataKey::CountryUsers(normalize_country(env, country));
+        let mut users: Vec<Address> = get_country_users(env, &key);
+        if !users.contains(user) {
+            users.push_back(user.clone());
+            env.storage().persistent().set(&key, &users);
+        }
+    }
+}
+
+/// Removes a user from the index of their country, if they have one.
+pub fn remove_from_country_index(env: &Env, user: &Address, country: &Option<String>) {
+    if let Some(country) = country {
+        let key: DataKey = DataKey::CountryUsers(normalize_country(env, country));
+        let mut users: Vec<Address> = get_country_users(env, &key);
+        if let Some(index) = users.first_index_of(user) {
+            users.remove(index);
+            if users.is_empty() {
+                env.storage().persistent().remove(&key);
+            } else {
+                env.storage().persistent().set(&key, &users);
+            }
+        }
+    }
+}
+
+/// Moves a user between country buckets after their country changed.
+pub fn update_country_index(
+    env: &Env,
+    user: &Address,
+    old_country: &Option<String>,
+    new_country: &Option<String>,
+) {
+    let old_key: Option<String> = old_country.as_ref().map(|c| normalize_country(env, c));
+    let new_key: Option<String> = new_country.as_ref().map(|c| normalize_country(env, c));
+    if old_key != new_key {
+        remove_from_country_index(env, user, old_country);
+        add_to_country_index(env, user, new_country);
+    }
+}
+
+/// Lists the profiles of users from a country (admin-only).
+///
+/// The country is matched case-insensitively.
+///
+/// # Arguments
+///
+/// * `env` - Soroban environment.
+/// * `caller` - Address of the caller (must be admin).
+/// * `country` - Country to search for.
+/// * `offset` - Number of users to skip.
+/// * `limit` - Maximum number of profiles to return (capped at `MAX_PAGE_SIZE`).
+///
+/// # Returns
+///
+/// * `UserListPage` - The matching profiles, the number of users from the
+///   country and whether more users follow.
+pub fn user_management_search_users_by_country(
+    env: Env,
+    caller: Address,
+    country: String,
+    offset: u32,
+    limit: u32,
+) -> UserListPage {
+    caller.require_auth();
+
+    if !is_admin(env.clone(), caller) {
+        handle_error(&env, Error::AccessDenied)
+    }
+
+    let key: DataKey = DataKey::CountryUsers(normalize_country(&env, &country));
+    let country_users: Vec<Address> = get_country_users(&env, &key);
+    let (page_users, has_more) = Paginator::new(offset, limit, MAX_PAGE_SIZE).paginate(&country_users, &env);
+
+    let mut users: Vec<UserProfile> = Vec::new(&env);
+    for user in page_users.iter() {
+        if let Some(profile) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, UserProfile>(&DataKey::UserProfile(user))
+        {
+            users.push_back(profile);
+        }
+    }
+
+    UserListPage {
+        users,
+        total: country_users.len(),
+        has_more,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{ProfileUpdateParams, UserProfile};
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn profile(env: &Env, email: &str, country: Option<&str>) -> UserProfile {
+        UserProfile {
+            full_name: String::from_str(env, "User"),
+            contact_email: String::from_str(env, email),
+            profession: None,
+            country: country.map(|c| String::from_str(env, c)),
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        }
+    }
+
+    fn setup() -> (Env, UserManagementClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize_system(&admin, &admin, &None);
+        (env, client, admin)
+    }
+
+    #[test]
+    fn test_search_users_by_country() {
+        let (env, client, admin) = setup();
+        client.create_user_profile(&Address::generate(&env), &profile(&env, "ann@example.com", Some("Kenya")));
+        client.create_user_profile(&Address::generate(&env), &profile(&env, "ben@example.com", Some("kenya")));
+        client.create_user_profile(&Address::generate(&env), &profile(&env, "cat@example.com", Some("Peru")));
+        client.create_user_profile(&Address::generate(&env), &profile(&env, "dan@example.com", None));
+
+        let page = client.search_users_by_country(&admin, &String::from_str(&env, "KENYA"), &0, &10);
+        assert_eq!(page.total, 2);
+        assert_eq!(page.users.len(), 2);
+        assert!(!page.has_more);
+
+        let first = client.search_users_by_country(&admin, &String::from_str(&env, "Kenya"), &0, &1);
+        assert_eq!(first.users.len(), 1);
+        assert!(first.has_more);
+    }
+
+    #[test]
+    fn test_search_users_by_country_empty() {
+        let (env, client, admin) = setup();
+        client.create_user_profile(&Address::generate(&env), &profile(&env, "ann@example.com", Some("Kenya")));
+
+        let page = client.search_users_by_country(&admin, &String::from_str(&env, "Chile"), &0, &10);
+        assert_eq!(page.total, 0);
+        assert!(page.users.is_empty());
+    }
+
+    #[test]
+    fn test_search_users_by_country_ignores_surrounding_whitespace() {
+        let (env, client, admin) = setup();
+        client.create_user_profile(&Address::generate(&env), &profile(&env, "ann@example.com", Some(" us ")));
+
+        let page = client.search_users_by_country(&admin, &String::from_str(&env, "US"), &0, &10);
+        assert_eq!(page.total, 1);
+        assert_eq!(page.users.get(0).unwrap().country, Some(String::from_str(&env, "us")));
+        let padded = client.search_users_by_country(&admin, &String::from_str(&env, "  Us"), &0, &10);
+        assert_eq!(padded.total, 1);
+    }
+
+    #[test]
+    fn test_country_change_moves_user() {
+        let (env, client, admin) = setup();
+        let user = Address::generate(&env);
+        client.create_user_profile(&user, &profile(&env, "ann@example.com", Some("Kenya")));
+
+        let updates = ProfileUpdateParams {
+            full_name: None,
+            profession: None,
+            country: Some(String::from_str(&env, "Peru")),
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        };
+        client.edit_user_profile(&user, &user, &updates);
+
+        let kenya = client.search_users_by_country(&admin, &String::from_str(&env, "Kenya"), &0, &10);
+        assert_eq!(kenya.total, 0);
+        let peru = client.search_users_by_country(&admin, &String::from_str(&env, "Peru"), &0, &10);
+        assert_eq!(peru.total, 1);
+        assert_eq!(peru.users.get(0).unwrap().contact_email, String::from_str(&env, "ann@example.com"));
+    }
+
+    #[test]
+    fn test_deleted_user_removed_from_country_index() {
+        let (env, client, admin) = setup();
+        let user = Address::generate(&env);
+        client.create_user_profile(&user, &profile(&env, "ann@example.com", Some("Kenya")));
+
+        client.delete_user_profile(&user, &user);
+
+        let page = client.search_users_by_country(&admin, &String::from_str(&env, "Kenya"), &0, &10);
+        assert_eq!(page.total, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #112)")]
+    fn test_create_profile_with_empty_country() {
+        let (env, client, _admin) = setup();
+        client.create_user_profile(&Address::generate(&env), &profile(&env, "ann@example.com", Some("")));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #112)")]
+    fn test_edit_profile_to_blank_country() {
+        let (env, client, _admin) = setup();
+        let user = Address::generate(&env);
+        client.create_user_profile(&user, &profile(&env, "ann@example.com", Some("Kenya")));
+
+        let updates = ProfileUpdateParams {
+            full_name: None,
+            profession: None,
+            country: Some(String::from_str(&env, "  ")),
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        };
+        client.edit_user_profile(&user, &user, &updates);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #104)")]
+    fn test_search_users_by_country_requires_admin() {
+        let (env, client, _admin) = setup();
+        let user = Address::generate(&env);
+        client.search_users_by_country(&user, &String::from_str(&env, "Kenya"), &0, &10);
+    }
+}
@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::functions::is_admin::require_super_admin;
+use crate::schema::{AdminConfig, DataKey};
+
+const MAX_ADMIN_COUNT_SET_EVENT: &str = "MaxAdminCountSet";
+
+/// Sets how many regular admins may exist at once (super admin only).
+///
+/// The super admin does not count toward the limit. Lowering the limit below
+/// the current number of admins keeps the existing admins but blocks new ones
+/// until enough have been removed.
+///
+/// # Arguments
+///
+/// * `env` - Soroban environment.
+/// * `super_admin` - Address of the super admin performing the change.
+/// * `max` - New maximum number of regular admins.
+///
+/// # Panics
+///
+/// * `Error::SystemNotInitialized` if the admin system is not initialized.
+/// * `Error::AccessDenied` if the caller is not the super admin.
+pub fn user_management_set_max_admin_count(env: Env, super_admin: Address, max: u32) {
+    let mut config: AdminConfig = require_super_admin(&env, &super_admin);
+
+    config.max_admins = max;
+    env.storage().persistent().set(&DataKey::AdminConfig, &config);
+
+    env.events()
+        .publish((Symbol::new(&env, MAX_ADMIN_COUNT_SET_EVENT),), (super_admin, max));
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    fn setup() -> (Env, UserManagementClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+
+        let super_admin = Address::generate(&env);
+        client.initialize(&super_admin, &None);
+
+        (env, client, super_admin)
+    }
+
+    #[test]
+    fn test_add_admin_below_cap() {
+        let (env, client, super_admin) = setup();
+        client.set_max_admin_count(&super_admin, &2);
+
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        client.add_admin(&super_admin, &first);
+        client.add_admin(&super_admin, &second);
+
+        assert!(client.is_admin(&first));
+        assert!(client.is_admin(&second));
+    }
+
+    #[test]
+    fn test_add_admin_at_cap() {
+        let (env, client, super_admin) = setup();
+        client.set_max_admin_count(&super_admin, &1);
+        client.add_admin(&super_admin, &Address::generate(&env));
+
+        let result = client.try_add_admin(&super_admin, &Address::generate(&env));
+
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(107))));
+    }
+
+    #[test]
+    fn test_lower_cap_to_current_count() {
+        let (env, client, super_admin) = setup();
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        client.add_admin(&super_admin, &first);
+        client.add_admin(&super_admin, &second);
+
+        client.set_max_admin_count(&super_admin, &2);
+
+        // Existing admins keep their role, new ones are rejected
+        assert!(client.is_admin(&first));
+        assert!(client.is_admin(&second));
+        let result = client.try_add_admin(&super_admin, &Address::generate(&env));
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(107))));
+
+        // Removing an admin frees a slot
+        client.remove_admin(&super_admin, &first);
+        client.add_admin(&super_admin, &Address::generate(&env));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #104)")]
+    fn test_set_max_admin_count_not_super_admin() {
+        let (env, client, _super_admin) = setup();
+        client.set_max_admin_count(&Address::generate(&env), &5);
+    }
+}
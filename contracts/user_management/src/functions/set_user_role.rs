@@ -0,0 +1,226 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use shared::Paginator;
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::is_admin::is_admin;
+use crate::functions::list_users::MAX_PAGE_SIZE;
+use crate::functions::rbac::get_user_role;
+use crate::schema::{DataKey, LightProfile, UserListPage, UserProfile, UserRole};
+
+const USER_ROLE_CHANGED_EVENT: &str = "UserRoleChanged";
+
+fn get_role_users(env: &Env, role: &UserRole) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RoleUsers(role.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Adds a user to the index of a role.
+pub fn add_to_role_index(env: &Env, user: &Address, role: &UserRole) {
+    let mut users: Vec<Address> = get_role_users(env, role);
+    if !users.contains(user) {
+        users.push_back(user.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::RoleUsers(role.clone()), &users);
+    }
+}
+
+/// Removes a user from the index of a role.
+pub fn remove_from_role_index(env: &Env, user: &Address, role: &UserRole) {
+    let mut users: Vec<Address> = get_role_users(env, role);
+    if let Some(index) = users.first_index_of(user) {
+        users.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::RoleUsers(role.clone()), &users);
+    }
+}
+
+/// Assigns a platform role to a user (admin-only).
+///
+/// Only the non-administrative roles can be assigned here; admins are
+/// managed through `add_admin` and `remove_admin`.
+///
+/// # Panics
+///
+/// * `Error::AccessDenied` if the caller is not an admin.
+/// * `Error::InvalidField` if `role` is `Admin` or `SuperAdmin`.
+/// * `Error::UserProfileNotFound` if the target has no profile.
+pub fn user_management_set_user_role(env: Env, admin: Address, target: Address, role: UserRole) {
+    admin.require_auth();
+
+    if !is_admin(env.clone(), admin.clone()) {
+        handle_error(&env, Error::AccessDenied)
+    }
+    if role == UserRole::Admin || role == UserRole::SuperAdmin {
+        handle_error(&env, Error::InvalidField)
+    }
+
+    let light_key: DataKey = DataKey::UserProfileLight(target.clone());
+    let mut light_profile: LightProfile = env
+        .storage()
+        .persistent()
+        .get(&light_key)
+        .unwrap_or_else(|| handle_error(&env, Error::UserProfileNotFound));
+
+    let old_role: UserRole = get_user_role(&env, &target);
+    remove_from_role_index(&env, &target, &old_role);
+    add_to_role_index(&env, &target, &role);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::UserRole(target.clone()), &role);
+    light_profile.role = role.clone();
+    env.storage().persistent().set(&light_key, &light_profile);
+
+    env.events().publish(
+        (Symbol::new(&env, USER_ROLE_CHANGED_EVENT), target),
+        (old_role, role),
+    );
+}
+
+/// Lists the profiles of users with a given role (admin-only).
+///
+/// # Arguments
+///
+/// * `env` - Soroban environment.
+/// * `caller` - Address of the caller (must be admin).
+/// * `role` - Role to list.
+/// * `offset` - Number of users to skip.
+/// * `limit` - Maximum number of profiles to return (capped at `MAX_PAGE_SIZE`).
+///
+/// # Returns
+///
+/// * `UserListPage` - The matching profiles, the number of users with the
+///   role and whether more users follow.
+pub fn user_management_get_users_by_role(
+    env: Env,
+    caller: Address,
+    role: UserRole,
+    offset: u32,
+    limit: u32,
+) -> UserListPage {
+    caller.require_auth();
+
+    if !is_admin(env.clone(), caller) {
+        handle_error(&env, Error::AccessDenied)
+    }
+
+    let role_users: Vec<Address> = get_role_users(&env, &role);
+    let (page_users, has_more) = Paginator::new(offset, limit, MAX_PAGE_SIZE).paginate(&role_users, &env);
+
+    let mut users: Vec<UserProfile> = Vec::new(&env);
+    for user in page_users.iter() {
+        if let Some(profile) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, UserProfile>(&DataKey::UserProfile(user))
+        {
+            users.push_back(profile);
+        }
+    }
+
+    UserListPage {
+        users,
+        total: role_users.len(),
+        has_more,
+    }
+}
+
+/// Returns whether a user has the instructor role.
+pub fn user_management_is_instructor(env: Env, who: Address) -> bool {
+    get_user_role(&env, &who) == UserRole::Instructor
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{UserProfile, UserRole};
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_user(env: &Env, client: &UserManagementClient, email: &str) -> Address {
+        let user = Address::generate(env);
+        let profile = UserProfile {
+            full_name: String::from_str(env, "User"),
+            contact_email: String::from_str(env, email),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        };
+        client.create_user_profile(&user, &profile);
+        user
+    }
+
+    fn setup() -> (Env, UserManagementClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize_system(&admin, &admin, &None);
+        (env, client, admin)
+    }
+
+    #[test]
+    fn test_set_each_role() {
+        let (env, client, admin) = setup();
+        let user = create_user(&env, &client, "ann@example.com");
+        assert!(!client.is_instructor(&user));
+
+        client.set_user_role(&admin, &user, &UserRole::Instructor);
+        assert!(client.is_instructor(&user));
+
+        client.set_user_role(&admin, &user, &UserRole::Moderator);
+        assert!(!client.is_instructor(&user));
+        assert_eq!(client.get_users_by_role(&admin, &UserRole::Moderator, &0, &10).total, 1);
+
+        client.set_user_role(&admin, &user, &UserRole::Student);
+        assert_eq!(client.get_users_by_role(&admin, &UserRole::Moderator, &0, &10).total, 0);
+        assert_eq!(client.get_users_by_role(&admin, &UserRole::Student, &0, &10).total, 1);
+    }
+
+    #[test]
+    fn test_get_users_by_role() {
+        let (env, client, admin) = setup();
+        let ann = create_user(&env, &client, "ann@example.com");
+        let ben = create_user(&env, &client, "ben@example.com");
+        create_user(&env, &client, "cat@example.com");
+        client.set_user_role(&admin, &ann, &UserRole::Instructor);
+        client.set_user_role(&admin, &ben, &UserRole::Instructor);
+
+        let instructors = client.get_users_by_role(&admin, &UserRole::Instructor, &0, &1);
+        assert_eq!(instructors.total, 2);
+        assert_eq!(instructors.users.len(), 1);
+        assert_eq!(instructors.users.get(0).unwrap().contact_email, String::from_str(&env, "ann@example.com"));
+        assert!(instructors.has_more);
+
+        let students = client.get_users_by_role(&admin, &UserRole::Student, &0, &10);
+        assert_eq!(students.total, 1);
+        assert_eq!(students.users.get(0).unwrap().contact_email, String::from_str(&env, "cat@example.com"));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #104)")]
+    fn test_set_user_role_unauthorized() {
+        let (env, client, _admin) = setup();
+        let user = create_user(&env, &client, "ann@example.com");
+
+        client.set_user_role(&user, &user, &UserRole::Instructor);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #117)")]
+    fn test_set_user_role_rejects_admin_role() {
+        let (env, client, admin) = setup();
+        let user = create_user(&env, &client, "ann@example.com");
+
+        client.set_user_role(&admin, &user, &UserRole::Admin);
+    }
+}
@@ -0,0 +1,259 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::schema::{DataKey, SocialLink, SocialPlatform};
+
+/// Maximum number of social links per user
+pub const MAX_SOCIAL_LINKS_PER_USER: u32 = 5;
+
+const SOCIAL_LINK_ADDED_EVENT: &str = "SocialLinkAdded";
+const SOCIAL_LINK_REMOVED_EVENT: &str = "SocialLinkRemoved";
+
+fn save_social_links(env: &Env, user: &Address, links: &Vec<SocialLink>) {
+    let key: DataKey = DataKey::SocialLinks(user.clone());
+    if links.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, links);
+    }
+}
+
+fn find_platform(links: &Vec<SocialLink>, platform: &SocialPlatform) -> Option<u32> {
+    links
+        .iter()
+        .position(|link| link.platform == *platform)
+        .map(|index| index as u32)
+}
+
+/// Removes every social link of a user.
+pub fn clear_social_links(env: &Env, user: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::SocialLinks(user.clone()));
+}
+
+/// Adds a social profile link to the caller's own profile.
+///
+/// A user has at most one link per platform: adding a link for a platform
+/// that already has one replaces its URL in place.
+///
+/// # Panics
+///
+/// * `Error::UserNotFound` if the user has no profile.
+/// * `Error::InvalidSocialLinkUrl` if `url` is not a valid HTTP(S) URL.
+/// * `Error::TooManySocialLinks` if the user already has
+///   `MAX_SOCIAL_LINKS_PER_USER` links.
+pub fn user_management_add_social_link(
+    env: Env,
+    user: Address,
+    platform: SocialPlatform,
+    url: String,
+) {
+    user.require_auth();
+
+    if !env
+        .storage()
+        .persistent()
+        .has(&DataKey::UserProfile(user.clone()))
+    {
+        handle_error(&env, Error::UserNotFound)
+    }
+
+    if !shared::validate_url(&url) {
+        handle_error(&env, Error::InvalidSocialLinkUrl)
+    }
+
+    let mut links: Vec<SocialLink> = user_management_list_social_links(env.clone(), user.clone());
+    let link: SocialLink = SocialLink {
+        platform: platform.clone(),
+        url,
+    };
+    match find_platform(&links, &platform) {
+        Some(index) => links.set(index, link.clone()),
+        None => {
+            if links.len() >= MAX_SOCIAL_LINKS_PER_USER {
+                handle_error(&env, Error::TooManySocialLinks)
+            }
+            links.push_back(link.clone());
+        }
+    }
+    save_social_links(&env, &user, &links);
+
+    env.events()
+        .publish((Symbol::new(&env, SOCIAL_LINK_ADDED_EVENT), user), link);
+}
+
+/// Removes the link for a platform from the caller's own profile.
+///
+/// Removing a link the user does not have changes nothing.
+pub fn user_management_remove_social_link(env: Env, user: Address, platform: SocialPlatform) {
+    user.require_auth();
+
+    let mut links: Vec<SocialLink> = user_management_list_social_links(env.clone(), user.clone());
+    let Some(index) = find_platform(&links, &platform) else {
+        return;
+    };
+    links.remove(index);
+    save_social_links(&env, &user, &links);
+
+    env.events().publish(
+        (Symbol::new(&env, SOCIAL_LINK_REMOVED_EVENT), user),
+        platform,
+    );
+}
+
+/// Returns the social links of a user in the order they were added.
+pub fn user_management_list_social_links(env: Env, user: Address) -> Vec<SocialLink> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SocialLinks(user))
+        .unwrap_or(Vec::new(&env))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MAX_SOCIAL_LINKS_PER_USER;
+    use crate::schema::{DataKey, SocialLink, SocialPlatform, UserProfile};
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup() -> (Env, UserManagementClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let user = Address::generate(&env);
+        let profile = UserProfile {
+            full_name: String::from_str(&env, "Alice"),
+            contact_email: String::from_str(&env, "alice@example.com"),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        };
+        client.create_user_profile(&user, &profile);
+        (env, client, user)
+    }
+
+    fn link(env: &Env, platform: SocialPlatform, url: &str) -> SocialLink {
+        SocialLink {
+            platform,
+            url: String::from_str(env, url),
+        }
+    }
+
+    #[test]
+    fn test_add_and_list_social_links() {
+        let (env, client, user) = setup();
+
+        client.add_social_link(
+            &user,
+            &SocialPlatform::GitHub,
+            &String::from_str(&env, "https://github.com/alice"),
+        );
+        client.add_social_link(
+            &user,
+            &SocialPlatform::Website,
+            &String::from_str(&env, "https://alice.dev"),
+        );
+
+        assert_eq!(
+            client.list_social_links(&user),
+            soroban_sdk::vec![
+                &env,
+                link(&env, SocialPlatform::GitHub, "https://github.com/alice"),
+                link(&env, SocialPlatform::Website, "https://alice.dev")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_duplicate_platform_overrides() {
+        let (env, client, user) = setup();
+        let github = SocialPlatform::GitHub;
+        client.add_social_link(
+            &user,
+            &github,
+            &String::from_str(&env, "https://github.com/old"),
+        );
+        client.add_social_link(
+            &user,
+            &SocialPlatform::Twitter,
+            &String::from_str(&env, "https://twitter.com/alice"),
+        );
+
+        client.add_social_link(
+            &user,
+            &github,
+            &String::from_str(&env, "https://github.com/new"),
+        );
+
+        let links = client.list_social_links(&user);
+        assert_eq!(links.len(), 2);
+        assert_eq!(
+            links.get(0).unwrap(),
+            link(&env, github, "https://github.com/new")
+        );
+    }
+
+    #[test]
+    fn test_remove_social_link() {
+        let (env, client, user) = setup();
+        client.add_social_link(
+            &user,
+            &SocialPlatform::LinkedIn,
+            &String::from_str(&env, "https://linkedin.com/in/alice"),
+        );
+
+        client.remove_social_link(&user, &SocialPlatform::LinkedIn);
+        client.remove_social_link(&user, &SocialPlatform::GitHub);
+
+        assert!(client.list_social_links(&user).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_url_rejected() {
+        let (env, client, user) = setup();
+
+        let result = client.try_add_social_link(
+            &user,
+            &SocialPlatform::Website,
+            &String::from_str(&env, "alice.dev"),
+        );
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(148)))
+        );
+    }
+
+    #[test]
+    fn test_social_link_limit() {
+        let (env, client, user) = setup();
+        // Each platform holds one link, so the cap only bites once there are
+        // more platforms than `MAX_SOCIAL_LINKS_PER_USER`; seed storage to
+        // reach it with the current ones
+        let mut links = soroban_sdk::Vec::new(&env);
+        for _ in 0..MAX_SOCIAL_LINKS_PER_USER {
+            links.push_back(link(&env, SocialPlatform::Website, "https://alice.dev"));
+        }
+        env.as_contract(&client.address, || {
+            env.storage()
+                .persistent()
+                .set(&DataKey::SocialLinks(user.clone()), &links);
+        });
+
+        let result = client.try_add_social_link(
+            &user,
+            &SocialPlatform::GitHub,
+            &String::from_str(&env, "https://github.com/alice"),
+        );
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(147)))
+        );
+    }
+}
@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::multi_sig::require_single_key_allowed;
+use crate::schema::{AdminConfig, DataKey};
+
+const SUPER_ADMIN_TRANSFERRED_EVENT: &str = "SuperAdminTransferred";
+
+/// Transfers the super admin role to another registered user.
+///
+/// If the new super admin was a regular admin, it is removed from the regular
+/// admin list since the super admin is tracked separately.
+///
+/// # Arguments
+///
+/// * `env` - Soroban environment.
+/// * `current_super_admin` - Address of the current super admin (must authenticate).
+/// * `new_super_admin` - Address receiving the role (must have a user profile).
+///
+/// # Panics
+///
+/// * `Error::SystemNotInitialized` if the admin system is not initialized.
+/// * `Error::AccessDenied` if the caller is not the current super admin.
+/// * `Error::ApprovalRequired` if critical operations need several admin approvals.
+/// * `Error::UserNotFound` if `new_super_admin` has no user profile.
+pub fn user_management_transfer_super_admin(
+    env: Env,
+    current_super_admin: Address,
+    new_super_admin: Address,
+) {
+    current_super_admin.require_auth();
+
+    let config: AdminConfig = env
+        .storage()
+        .persistent()
+        .get::<DataKey, AdminConfig>(&DataKey::AdminConfig)
+        .unwrap_or_else(|| handle_error(&env, Error::SystemNotInitialized));
+
+    if !config.initialized {
+        handle_error(&env, Error::SystemNotInitialized)
+    }
+
+    if current_super_admin != config.super_admin {
+        handle_error(&env, Error::AccessDenied)
+    }
+    require_single_key_allowed(&env);
+
+    transfer_super_admin_role(&env, config, &new_super_admin);
+}
+
+/// Hands the super admin role to `new_super_admin` once the caller has been
+/// authorized.
+///
+/// Panics with `Error::UserNotFound` if `new_super_admin` has no user profile.
+pub fn transfer_super_admin_role(env: &Env, mut config: AdminConfig, new_super_admin: &Address) {
+    if !env
+        .storage()
+        .persistent()
+        .has(&DataKey::UserProfile(new_super_admin.clone()))
+    {
+        handle_error(env, Error::UserNotFound)
+    }
+
+    let mut admins: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get::<DataKey, Vec<Address>>(&DataKey::Admins)
+        .unwrap_or_else(|| Vec::new(env));
+    if let Some(index) = admins.first_index_of(new_super_admin) {
+        admins.remove(index);
+        env.storage().persistent().set(&DataKey::Admins, &admins);
+    }
+
+    let previous_super_admin: Address = config.super_admin.clone();
+    config.super_admin = new_super_admin.clone();
+    env.storage()
+        .persistent()
+        .set(&DataKey::AdminConfig, &config);
+
+    env.events().publish(
+        (Symbol::new(env, SUPER_ADMIN_TRANSFERRED_EVENT),),
+        (previous_super_admin, new_super_admin.clone()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::UserProfile;
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn profile(env: &Env, email: &str) -> UserProfile {
+        UserProfile {
+            full_name: String::from_str(env, "Test User"),
+            contact_email: String::from_str(env, email),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        }
+    }
+
+    fn setup() -> (Env, UserManagementClient<'static>, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        let super_admin = Address::generate(&env);
+        client.initialize_system(&super_admin, &super_admin, &None);
+        (env, client, super_admin)
+    }
+
+    #[test]
+    fn test_transfer_super_admin() {
+        let (env, client, super_admin) = setup();
+        let successor = Address::generate(&env);
+        client.create_user_profile(&successor, &profile(&env, "successor@example.com"));
+        client.add_admin(&super_admin, &successor);
+
+        client.transfer_super_admin(&super_admin, &successor);
+
+        // The successor now manages admins and the previous super admin no longer does
+        let new_admin = Address::generate(&env);
+        client.add_admin(&successor, &new_admin);
+        assert!(client.is_admin(&new_admin));
+        assert!(!client.is_admin(&super_admin));
+
+        let admins = client.get_admins(&successor);
+        assert_eq!(admins.get(0).unwrap(), successor);
+        assert_eq!(admins.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #104)")]
+    fn test_transfer_super_admin_by_regular_admin() {
+        let (env, client, super_admin) = setup();
+        let admin = Address::generate(&env);
+        client.add_admin(&super_admin, &admin);
+        client.create_user_profile(&admin, &profile(&env, "admin@example.com"));
+
+        client.transfer_super_admin(&admin, &admin);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #120)")]
+    fn test_transfer_super_admin_without_profile() {
+        let (env, client, super_admin) = setup();
+        let successor = Address::generate(&env);
+
+        client.transfer_super_admin(&super_admin, &successor);
+    }
+}
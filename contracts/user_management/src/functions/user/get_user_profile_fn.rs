@@ -66,6 +66,7 @@ mod tests {
                 country: country.map(|s| String::from_str(env, s)),
                 purpose: purpose.map(|s| String::from_str(env, s)),
                 profile_picture_url: profile_picture_url.map(|s| String::from_str(env, s)),
+                avatar_url: None,
             };
 
             env.storage()
@@ -0,0 +1,288 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use shared::Paginator;
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+use crate::error::{handle_error, Error};
+use crate::functions::list_users::MAX_PAGE_SIZE;
+use crate::schema::{DataKey, UserListPage, UserProfile};
+
+/// Maximum length of a skill name
+pub const MAX_SKILL_LENGTH: u32 = 50;
+/// Maximum number of skills per user
+pub const MAX_SKILLS_PER_USER: u32 = 20;
+
+const SKILL_ADDED_EVENT: &str = "UserSkillAdded";
+const SKILL_REMOVED_EVENT: &str = "UserSkillRemoved";
+
+fn get_skill_users(env: &Env, skill: &String) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SkillUsers(skill.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn remove_from_skill_index(env: &Env, user: &Address, skill: &String) {
+    let mut users: Vec<Address> = get_skill_users(env, skill);
+    if let Some(index) = users.first_index_of(user) {
+        users.remove(index);
+        if users.is_empty() {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::SkillUsers(skill.clone()));
+        } else {
+            env.storage()
+                .persistent()
+                .set(&DataKey::SkillUsers(skill.clone()), &users);
+        }
+    }
+}
+
+fn save_user_skills(env: &Env, user: &Address, skills: &Vec<String>) {
+    let key: DataKey = DataKey::UserSkills(user.clone());
+    if skills.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, skills);
+    }
+}
+
+/// Removes every skill of a user along with their skill index entries.
+pub fn clear_user_skills(env: &Env, user: &Address) {
+    for skill in user_management_list_user_skills(env.clone(), user.clone()).iter() {
+        remove_from_skill_index(env, user, &skill);
+    }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::UserSkills(user.clone()));
+}
+
+/// Adds a skill to the caller's own profile.
+///
+/// Adding a skill the user already has changes nothing. Skills are matched
+/// exactly, including case.
+///
+/// # Panics
+///
+/// * `Error::UserNotFound` if the user has no profile.
+/// * `Error::InvalidField` if the skill is empty or longer than `MAX_SKILL_LENGTH`.
+/// * `Error::TooManySkills` if the user already has `MAX_SKILLS_PER_USER` skills.
+pub fn user_management_add_user_skill(env: Env, user: Address, skill: String) {
+    user.require_auth();
+
+    if !env
+        .storage()
+        .persistent()
+        .has(&DataKey::UserProfile(user.clone()))
+    {
+        handle_error(&env, Error::UserNotFound)
+    }
+
+    if skill.is_empty() || skill.len() > MAX_SKILL_LENGTH {
+        handle_error(&env, Error::InvalidField)
+    }
+
+    let mut skills: Vec<String> = user_management_list_user_skills(env.clone(), user.clone());
+    if skills.contains(&skill) {
+        return;
+    }
+    if skills.len() >= MAX_SKILLS_PER_USER {
+        handle_error(&env, Error::TooManySkills)
+    }
+
+    skills.push_back(skill.clone());
+    save_user_skills(&env, &user, &skills);
+
+    let mut users: Vec<Address> = get_skill_users(&env, &skill);
+    users.push_back(user.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::SkillUsers(skill.clone()), &users);
+
+    env.events()
+        .publish((Symbol::new(&env, SKILL_ADDED_EVENT), user), skill);
+}
+
+/// Removes a skill from the caller's own profile.
+///
+/// Removing a skill the user does not have changes nothing.
+pub fn user_management_remove_user_skill(env: Env, user: Address, skill: String) {
+    user.require_auth();
+
+    let mut skills: Vec<String> = user_management_list_user_skills(env.clone(), user.clone());
+    let Some(index) = skills.first_index_of(&skill) else {
+        return;
+    };
+    skills.remove(index);
+    save_user_skills(&env, &user, &skills);
+    remove_from_skill_index(&env, &user, &skill);
+
+    env.events()
+        .publish((Symbol::new(&env, SKILL_REMOVED_EVENT), user), skill);
+}
+
+/// Returns the skills of a user in the order they were added.
+pub fn user_management_list_user_skills(env: Env, user: Address) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::UserSkills(user))
+        .unwrap_or(Vec::new(&env))
+}
+
+/// Lists the profiles of users holding a skill.
+///
+/// # Arguments
+///
+/// * `env` - Soroban environment.
+/// * `skill` - Skill to search for (exact match).
+/// * `offset` - Number of users to skip.
+/// * `limit` - Maximum number of profiles to return (capped at `MAX_PAGE_SIZE`).
+///
+/// # Returns
+///
+/// * `UserListPage` - The matching profiles, the number of users holding the
+///   skill and whether more users follow.
+pub fn user_management_search_users_by_skill(
+    env: Env,
+    skill: String,
+    offset: u32,
+    limit: u32,
+) -> UserListPage {
+    let skill_users: Vec<Address> = get_skill_users(&env, &skill);
+    let (page_users, has_more) =
+        Paginator::new(offset, limit, MAX_PAGE_SIZE).paginate(&skill_users, &env);
+
+    let mut users: Vec<UserProfile> = Vec::new(&env);
+    for user in page_users.iter() {
+        if let Some(profile) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, UserProfile>(&DataKey::UserProfile(user))
+        {
+            users.push_back(profile);
+        }
+    }
+
+    UserListPage {
+        users,
+        total: skill_users.len(),
+        has_more,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MAX_SKILLS_PER_USER;
+    use crate::schema::UserProfile;
+    use crate::{UserManagement, UserManagementClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn setup() -> (Env, UserManagementClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(UserManagement, ());
+        let client = UserManagementClient::new(&env, &contract_id);
+        (env, client)
+    }
+
+    fn create_user(env: &Env, client: &UserManagementClient, name: &str, email: &str) -> Address {
+        let user = Address::generate(env);
+        let profile = UserProfile {
+            full_name: String::from_str(env, name),
+            contact_email: String::from_str(env, email),
+            profession: None,
+            country: None,
+            purpose: None,
+            profile_picture_url: None,
+            avatar_url: None,
+        };
+        client.create_user_profile(&user, &profile);
+        user
+    }
+
+    #[test]
+    fn test_add_and_remove_skill() {
+        let (env, client) = setup();
+        let user = create_user(&env, &client, "Alice", "alice@example.com");
+        let rust = String::from_str(&env, "Rust");
+        let solidity = String::from_str(&env, "Solidity");
+
+        client.add_user_skill(&user, &rust);
+        client.add_user_skill(&user, &solidity);
+        assert_eq!(client.list_user_skills(&user), vec![&env, rust.clone(), solidity.clone()]);
+
+        client.remove_user_skill(&user, &rust);
+        assert_eq!(client.list_user_skills(&user), vec![&env, solidity]);
+        assert_eq!(client.search_users_by_skill(&rust, &0, &10).total, 0);
+    }
+
+    #[test]
+    fn test_add_skill_twice() {
+        let (env, client) = setup();
+        let user = create_user(&env, &client, "Alice", "alice@example.com");
+        let rust = String::from_str(&env, "Rust");
+
+        client.add_user_skill(&user, &rust);
+        client.add_user_skill(&user, &rust);
+
+        assert_eq!(client.list_user_skills(&user).len(), 1);
+        assert_eq!(client.search_users_by_skill(&rust, &0, &10).total, 1);
+    }
+
+    #[test]
+    fn test_skill_limits() {
+        let (env, client) = setup();
+        let user = create_user(&env, &client, "Alice", "alice@example.com");
+
+        let result = client.try_add_user_skill(&user, &String::from_str(&env, ""));
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(117))));
+        let too_long = String::from_str(&env, "abcdefghijabcdefghijabcdefghijabcdefghijabcdefghijk");
+        assert!(client.try_add_user_skill(&user, &too_long).is_err());
+
+        let names = [
+            "s01", "s02", "s03", "s04", "s05", "s06", "s07", "s08", "s09", "s10",
+            "s11", "s12", "s13", "s14", "s15", "s16", "s17", "s18", "s19", "s20",
+        ];
+        for name in names {
+            client.add_user_skill(&user, &String::from_str(&env, name));
+        }
+        assert_eq!(client.list_user_skills(&user).len(), MAX_SKILLS_PER_USER);
+
+        let result = client.try_add_user_skill(&user, &String::from_str(&env, "s21"));
+        assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(139))));
+    }
+
+    #[test]
+    fn test_search_users_by_skill() {
+        let (env, client) = setup();
+        let alice = create_user(&env, &client, "Alice", "alice@example.com");
+        let bob = create_user(&env, &client, "Bob", "bob@example.com");
+        let carol = create_user(&env, &client, "Carol", "carol@example.com");
+        let rust = String::from_str(&env, "Rust");
+        client.add_user_skill(&alice, &rust);
+        client.add_user_skill(&bob, &String::from_str(&env, "Go"));
+        client.add_user_skill(&carol, &rust);
+
+        let first = client.search_users_by_skill(&rust, &0, &1);
+        assert_eq!(first.users.get(0).unwrap().full_name, String::from_str(&env, "Alice"));
+        assert_eq!(first.total, 2);
+        assert!(first.has_more);
+
+        let second = client.search_users_by_skill(&rust, &1, &1);
+        assert_eq!(second.users.get(0).unwrap().full_name, String::from_str(&env, "Carol"));
+        assert!(!second.has_more);
+
+        // Deleting a profile drops its skills from the index
+        client.delete_user_profile(&carol, &carol);
+        assert_eq!(client.search_users_by_skill(&rust, &0, &10).total, 1);
+        assert!(client.list_user_skills(&carol).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #120)")]
+    fn test_add_skill_without_profile() {
+        let (env, client) = setup();
+        client.add_user_skill(&Address::generate(&env), &String::from_str(&env, "Rust"));
+    }
+}
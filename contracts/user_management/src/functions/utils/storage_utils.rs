@@ -13,23 +13,23 @@ pub fn validate_string_content(_env: &Env, content: &String, max_length: usize)
     true
 }
 
-/// Validates email format using basic checks
+/// Validates email format
 /// Returns true if email appears to be valid format
 pub fn validate_email_format(email: &String) -> bool {
-    // Basic email validation for Soroban environment
-    // Check minimum and maximum length
-    if email.len() < 5 || email.len() > 320 {
-        return false;
-    }
-    
-    // For testing purposes, reject "invalid-email" (13 characters, no @)
-    if email.len() == 13 {
-        return false;
+    shared::validate_email(email)
+}
+
+/// Normalizes an email address to lowercase for index lookups
+/// Emails longer than the RFC 5321 limit are returned unchanged
+pub fn normalize_email(env: &Env, email: &String) -> String {
+    let len: usize = email.len() as usize;
+    let mut buf = [0u8; 320];
+    if len > buf.len() {
+        return email.clone();
     }
-    
-    // In a production environment, you would implement proper email validation
-    // For now, we accept emails that meet basic length requirements
-    true
+    email.copy_into_slice(&mut buf[..len]);
+    buf[..len].make_ascii_lowercase();
+    String::from_bytes(env, &buf[..len])
 }
 
 /// Check if email is unique across all users
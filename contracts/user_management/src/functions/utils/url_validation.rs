@@ -71,6 +71,32 @@ pub fn is_valid_url(url: &String) -> bool {
     false
 }
 
+/// Maximum length of an avatar URL
+pub const MAX_AVATAR_URL_LENGTH: u32 = 2048;
+
+/// Validates an avatar URL
+///
+/// Avatar URLs must use HTTPS and be at most `MAX_AVATAR_URL_LENGTH` characters.
+///
+/// # Arguments
+/// * `url` - The URL string to validate
+///
+/// # Returns
+/// * `bool` - true if the URL is an acceptable avatar URL, false otherwise
+pub fn is_valid_avatar_url(url: &String) -> bool {
+    const HTTPS_PREFIX: &[u8] = b"https://";
+
+    let url_len: u32 = url.len();
+    if url_len <= HTTPS_PREFIX.len() as u32 || url_len > MAX_AVATAR_URL_LENGTH {
+        return false;
+    }
+
+    let mut buf = [0u8; MAX_AVATAR_URL_LENGTH as usize];
+    let bytes: &mut [u8] = &mut buf[..url_len as usize];
+    url.copy_into_slice(bytes);
+    bytes[..HTTPS_PREFIX.len()].eq_ignore_ascii_case(HTTPS_PREFIX)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
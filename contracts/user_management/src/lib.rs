@@ -15,7 +15,7 @@ pub mod schema;
 mod test;
 
 use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
-use crate::schema::{AdminConfig, LightProfile, PaginatedLightProfiles, PaginationParams, ProfileUpdateParams, UserFilter, UserProfile, UserRole, UserStatus};
+use crate::schema::{AdminConfig, AdminList, LightProfile, PaginatedLightProfiles, PaginationParams, ProfileChange, ProfileUpdateParams, SocialLink, SocialPlatform, UserFilter, UserListPage, UserProfile, UserRole, UserStatus};
 use crate::error::Error;
 
 /// User Management Contract
@@ -72,6 +72,7 @@ impl UserManagement {
             country: model_profile.country,
             purpose: model_profile.purpose,
             profile_picture_url: model_profile.profile_picture_url,
+            avatar_url: model_profile.avatar_url,
         })
     }
 
@@ -214,6 +215,51 @@ impl UserManagement {
         functions::edit_user_profile::edit_user_profile(env, caller, user_id, updates)
     }
 
+    /// Get the recorded profile changes of a user for account investigations
+    ///
+    /// Keeps the last 20 edits. Previous values are stored only as a hash.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `caller` - Address requesting the history (the user or an admin)
+    /// * `target` - Address of the user whose history is returned
+    ///
+    /// # Returns
+    /// * `Vec<ProfileChange>` - The changed fields and time of each edit, oldest first
+    ///
+    /// # Panics
+    /// * If caller is neither the user nor an admin
+    pub fn get_profile_change_history(
+        env: Env,
+        caller: Address,
+        target: Address,
+    ) -> Vec<ProfileChange> {
+        functions::get_profile_history::user_management_get_profile_change_history(
+            env, caller, target,
+        )
+    }
+
+    /// Score how much of a user's profile is filled in
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `user` - Address of the user whose profile is scored
+    ///
+    /// # Returns
+    /// * `ProfileCompletenessScore` - Score out of 95, the empty fields, and whether the
+    ///   score reaches 80
+    ///
+    /// # Panics
+    /// * If the user has no profile
+    pub fn check_profile_completeness(
+        env: Env,
+        user: Address,
+    ) -> crate::schema::ProfileCompletenessScore {
+        functions::check_profile_completeness::user_management_check_profile_completeness(
+            env, user,
+        )
+    }
+
     /// Check if an address has admin privileges.
     ///
     /// This function is used by other contracts to verify admin status
@@ -250,6 +296,19 @@ impl UserManagement {
         functions::is_admin::is_admin(env, who)
     }
 
+    /// Get the super admin address
+    ///
+    /// Intended for cross-contract callers that need to resolve the super admin.
+    ///
+    /// # Returns
+    /// * `Address` - Address of the super administrator
+    ///
+    /// # Panics
+    /// * If system is not initialized
+    pub fn get_super_admin(env: Env) -> Address {
+        functions::is_admin::get_super_admin(env)
+    }
+
     /// Delete (deactivate) a user account
     ///
     /// Performs a soft delete by marking the user as inactive instead of permanent deletion.
@@ -289,6 +348,62 @@ impl UserManagement {
         functions::delete_user::delete_user(env, caller, user_id)
     }
 
+    /// Permanently delete a user profile
+    ///
+    /// Removes the profile and its email reservation from storage, unlike
+    /// `delete_user` which only deactivates the account.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `caller` - Address performing the deletion (must be the owner or an admin)
+    /// * `target_user` - Address whose profile is deleted
+    ///
+    /// # Panics
+    /// * If caller is neither the profile owner nor an admin
+    /// * If the profile does not exist
+    ///
+    /// # Events
+    /// Emits a `ProfileDeleted` event upon successful deletion
+    pub fn delete_user_profile(env: Env, caller: Address, target_user: Address) {
+        functions::delete_user_profile::user_management_delete_user_profile(env, caller, target_user)
+    }
+
+    /// List registered user profiles with offset-based pagination (admin-only)
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `caller` - Address performing the call (must be admin)
+    /// * `offset` - Number of users to skip
+    /// * `limit` - Maximum number of profiles to return (capped at 50)
+    ///
+    /// # Returns
+    /// * `UserListPage` - Profiles in the page, total user count and whether more pages exist
+    ///
+    /// # Panics
+    /// * If caller is not an admin
+    pub fn list_users(env: Env, caller: Address, offset: u32, limit: u32) -> UserListPage {
+        functions::list_users::user_management_list_users(env, caller, offset, limit)
+    }
+
+    /// Retrieve a user profile by contact email
+    ///
+    /// The email is matched case-insensitively.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `requester` - Address performing the lookup (must be an admin or the profile owner)
+    /// * `email` - Contact email of the profile
+    ///
+    /// # Returns
+    /// * `UserProfile` - The matching user profile
+    ///
+    /// # Panics
+    /// * If no profile is registered with the email
+    /// * If requester is neither an admin nor the profile owner
+    pub fn get_user_by_email(env: Env, requester: Address, email: String) -> UserProfile {
+        functions::get_user_by_email::user_management_get_user_by_email(env, requester, email)
+    }
+
     /// Lists all registered users with pagination and filtering (admin-only)
     ///
     /// # Arguments
@@ -445,6 +560,229 @@ impl UserManagement {
         )
     }
 
+    /// Assigns a platform role to a user (admin-only)
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `admin` - Address performing the call (must be admin)
+    /// * `target` - User whose role is changed
+    /// * `role` - New role; `Admin` and `SuperAdmin` are managed through `add_admin`
+    ///
+    /// # Panics
+    /// * If caller is not an admin
+    /// * If `role` is `Admin` or `SuperAdmin`
+    /// * If the target has no profile
+    ///
+    /// # Events
+    /// Emits a `UserRoleChanged` event with the old and new role
+    pub fn set_user_role(env: Env, admin: Address, target: Address, role: UserRole) {
+        functions::set_user_role::user_management_set_user_role(env, admin, target, role)
+    }
+
+    /// Lists users holding a role with offset-based pagination (admin-only)
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `caller` - Address performing the call (must be admin)
+    /// * `role` - Role to list
+    /// * `offset` - Number of users to skip
+    /// * `limit` - Maximum number of profiles to return (capped at 50)
+    ///
+    /// # Returns
+    /// * `UserListPage` - Profiles in the page, number of users with the role and whether more pages exist
+    ///
+    /// # Panics
+    /// * If caller is not an admin
+    pub fn get_users_by_role(
+        env: Env,
+        caller: Address,
+        role: UserRole,
+        offset: u32,
+        limit: u32,
+    ) -> UserListPage {
+        functions::set_user_role::user_management_get_users_by_role(env, caller, role, offset, limit)
+    }
+
+    /// Checks whether a user has the instructor role
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `who` - Address to check
+    ///
+    /// # Returns
+    /// * `bool` - True if the user is an instructor
+    pub fn is_instructor(env: Env, who: Address) -> bool {
+        functions::set_user_role::user_management_is_instructor(env, who)
+    }
+
+    /// Lists users from a country with offset-based pagination (admin-only)
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `caller` - Address performing the call (must be admin)
+    /// * `country` - Country to search for (case-insensitive)
+    /// * `offset` - Number of users to skip
+    /// * `limit` - Maximum number of profiles to return (capped at 50)
+    ///
+    /// # Returns
+    /// * `UserListPage` - Profiles in the page, number of users from the country and whether more pages exist
+    ///
+    /// # Panics
+    /// * If caller is not an admin
+    pub fn search_users_by_country(
+        env: Env,
+        caller: Address,
+        country: String,
+        offset: u32,
+        limit: u32,
+    ) -> UserListPage {
+        functions::search_users_by_country::user_management_search_users_by_country(
+            env, caller, country, offset, limit,
+        )
+    }
+
+    /// Adds a skill to the caller's own profile
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `user` - Address of the user adding the skill (must be the caller)
+    /// * `skill` - Skill name (1-50 characters); adding an existing skill has no effect
+    ///
+    /// # Panics
+    /// * If the user has no profile
+    /// * If the skill is empty or too long
+    /// * If the user already has 20 skills
+    pub fn add_user_skill(env: Env, user: Address, skill: String) {
+        functions::user_skills::user_management_add_user_skill(env, user, skill)
+    }
+
+    /// Removes a skill from the caller's own profile
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `user` - Address of the user removing the skill (must be the caller)
+    /// * `skill` - Skill name; removing a missing skill has no effect
+    pub fn remove_user_skill(env: Env, user: Address, skill: String) {
+        functions::user_skills::user_management_remove_user_skill(env, user, skill)
+    }
+
+    /// Lists the skills of a user
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `user` - Address of the user
+    ///
+    /// # Returns
+    /// * `Vec<String>` - The user's skills in the order they were added
+    pub fn list_user_skills(env: Env, user: Address) -> Vec<String> {
+        functions::user_skills::user_management_list_user_skills(env, user)
+    }
+
+    /// Lists users holding a skill with offset-based pagination
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `skill` - Skill to search for (exact match)
+    /// * `offset` - Number of users to skip
+    /// * `limit` - Maximum number of profiles to return (capped at 50)
+    ///
+    /// # Returns
+    /// * `UserListPage` - Profiles in the page, number of users holding the skill and whether more pages exist
+    pub fn search_users_by_skill(env: Env, skill: String, offset: u32, limit: u32) -> UserListPage {
+        functions::user_skills::user_management_search_users_by_skill(env, skill, offset, limit)
+    }
+
+    /// Adds or replaces a social profile link on the caller's own profile
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `user` - Address of the user adding the link (must be the caller)
+    /// * `platform` - Platform the link points to; an existing link for it is replaced
+    /// * `url` - HTTP(S) URL of the profile
+    ///
+    /// # Panics
+    /// * If the user has no profile
+    /// * If the URL is not a valid HTTP(S) URL
+    /// * If the user already has 5 links
+    pub fn add_social_link(env: Env, user: Address, platform: SocialPlatform, url: String) {
+        functions::social_links::user_management_add_social_link(env, user, platform, url)
+    }
+
+    /// Removes a social profile link from the caller's own profile
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `user` - Address of the user removing the link (must be the caller)
+    /// * `platform` - Platform of the link; removing a missing link has no effect
+    pub fn remove_social_link(env: Env, user: Address, platform: SocialPlatform) {
+        functions::social_links::user_management_remove_social_link(env, user, platform)
+    }
+
+    /// Lists the social profile links of a user
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `user` - Address of the user
+    ///
+    /// # Returns
+    /// * `Vec<SocialLink>` - The user's links in the order they were added
+    pub fn list_social_links(env: Env, user: Address) -> Vec<SocialLink> {
+        functions::social_links::user_management_list_social_links(env, user)
+    }
+
+    /// Lists users with no profile activity since a given timestamp (admin-only)
+    ///
+    /// Used by cleanup campaigns to find accounts that have not created or
+    /// edited their profile for a long time.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `caller` - Address performing the call (must be admin)
+    /// * `inactive_since` - Users last active before this timestamp are returned
+    /// * `page` - Zero-based page index
+    /// * `page_size` - Number of items per page (capped at 100)
+    ///
+    /// # Returns
+    /// * `Vec<UserProfile>` - Inactive user profiles, least recently active first
+    ///
+    /// # Panics
+    /// * If caller is not an admin
+    pub fn get_inactive_users(
+        env: Env,
+        caller: Address,
+        inactive_since: u64,
+        page: u32,
+        page_size: u32,
+    ) -> Vec<UserProfile> {
+        functions::get_inactive_users::user_management_get_inactive_users(
+            env,
+            caller,
+            inactive_since,
+            page,
+            page_size,
+        )
+    }
+
+    /// Initialize the contract (one-time only)
+    ///
+    /// Stores the admin configuration, with the super admin acting as
+    /// initializer, and the default rate limiting configuration.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `super_admin` - Address that will become the super admin
+    /// * `max_page_size` - Optional maximum page size (default: 100, max: 1000)
+    ///
+    /// # Returns
+    /// * `AdminConfig` - The created admin configuration
+    ///
+    /// # Panics
+    /// * If the contract has already been initialized
+    /// * If max_page_size exceeds 1000
+    pub fn initialize(env: Env, super_admin: Address, max_page_size: Option<u32>) -> AdminConfig {
+        functions::admin_management::initialize(env, super_admin, max_page_size)
+    }
+
     /// Initialize the admin system (one-time only)
     ///
     /// # Arguments
@@ -505,6 +843,7 @@ impl UserManagement {
     /// * If caller is not the super admin
     /// * If system is not initialized
     /// * If new_admin is already an admin
+    /// * If the admin roster is full (see `set_max_admin_count`)
     ///
     /// # Examples
     ///
@@ -551,6 +890,60 @@ impl UserManagement {
         functions::admin_management::remove_admin(env, caller, admin_to_remove)
     }
 
+    /// Set the maximum number of regular admins (super admin only)
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `super_admin` - Address performing the change (must be super admin)
+    /// * `max` - Maximum number of regular admins; the super admin is not counted
+    ///
+    /// # Panics
+    /// * If the system is not initialized
+    /// * If caller is not the super admin
+    pub fn set_max_admin_count(env: Env, super_admin: Address, max: u32) {
+        functions::set_max_admin_count::user_management_set_max_admin_count(env, super_admin, max)
+    }
+
+    /// Revoke admin privileges from a regular admin (super admin only)
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `super_admin` - Address performing the call (must be super admin)
+    /// * `target_admin` - Regular admin to demote
+    ///
+    /// # Panics
+    /// * If caller is not the super admin
+    /// * If target_admin is the super admin
+    /// * If target_admin is not a regular admin
+    ///
+    /// # Events
+    /// Emits an `AdminDemoted` event upon successful demotion
+    pub fn demote_admin(env: Env, super_admin: Address, target_admin: Address) {
+        functions::demote_admin::user_management_demote_admin(env, super_admin, target_admin)
+    }
+
+    /// Transfer the super admin role to another user (super admin only)
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `current_super_admin` - Address of the current super admin
+    /// * `new_super_admin` - Address receiving the role (must have a user profile)
+    ///
+    /// # Panics
+    /// * If caller is not the super admin
+    /// * If system is not initialized
+    /// * If new_super_admin has no user profile
+    ///
+    /// # Events
+    /// Emits a `SuperAdminTransferred` event with the old and new super admin
+    pub fn transfer_super_admin(env: Env, current_super_admin: Address, new_super_admin: Address) {
+        functions::transfer_super_admin::user_management_transfer_super_admin(
+            env,
+            current_super_admin,
+            new_super_admin,
+        )
+    }
+
     /// Get list of all admins (admin only)
     ///
     /// # Arguments
@@ -583,6 +976,22 @@ impl UserManagement {
         functions::admin_management::get_admins(env, caller)
     }
 
+    /// Get the super admin and the regular admin roster (admin only)
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `caller` - Address performing the call (must be admin)
+    ///
+    /// # Returns
+    /// * `AdminList` - Super admin address and regular admin addresses
+    ///
+    /// # Panics
+    /// * If caller is not an admin
+    /// * If system is not initialized
+    pub fn list_all_admins(env: Env, caller: Address) -> AdminList {
+        functions::list_all_admins::user_management_list_all_admins(env, caller)
+    }
+
     /// Check if the system is initialized
     ///
     /// # Arguments
@@ -665,5 +1074,244 @@ impl UserManagement {
         functions::backup_recovery::import_user_data(env, caller, backup_data)
     }
 
+    /// Export a user's personal data for data portability
+    ///
+    /// Named apart from `export_user_data`, which exports the admin backup.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `caller` - Address requesting the export (the user or an admin)
+    /// * `target` - Address whose data is exported
+    ///
+    /// # Returns
+    /// * `UserDataExport` - Profile, enrolled courses, skills and registration timestamp
+    ///
+    /// # Panics
+    /// * If caller is neither the target user nor an admin
+    /// * If the target user has no profile
+    pub fn export_personal_data(env: Env, caller: Address, target: Address) -> crate::schema::UserDataExport {
+        functions::export_user_data::user_management_export_user_data(env, caller, target)
+    }
+
+    /// Set the course access contract used to look up enrollments (super admin only)
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `caller` - Address performing the change (must be super admin)
+    /// * `contract` - Address of the course access contract
+    ///
+    /// # Panics
+    /// * If the system is not initialized
+    /// * If caller is not the super admin
+    pub fn set_course_access_contract(env: Env, caller: Address, contract: Address) {
+        functions::export_user_data::user_management_set_course_access_contract(env, caller, contract)
+    }
+
+    /// Invite an address to become an admin (super admin only)
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `super_admin` - Address sending the invitation (must be super admin)
+    /// * `invitee` - Address invited to become an admin
+    /// * `valid_for_seconds` - How long the invitation can be accepted for
+    ///
+    /// # Returns
+    /// * `AdminInvitation` - The pending invitation, replacing any earlier one
+    ///
+    /// # Panics
+    /// * If the system is not initialized
+    /// * If caller is not the super admin
+    /// * If the invitee is the super admin or already an admin
+    pub fn invite_admin(
+        env: Env,
+        super_admin: Address,
+        invitee: Address,
+        valid_for_seconds: u64,
+    ) -> crate::schema::AdminInvitation {
+        functions::admin_invitation::user_management_invite_admin(env, super_admin, invitee, valid_for_seconds)
+    }
+
+    /// Accept a pending admin invitation
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `invitee` - Address accepting the invitation (must be the caller)
+    ///
+    /// # Panics
+    /// * If there is no pending invitation for the invitee
+    /// * If the invitation has expired
+    /// * If the maximum number of admins is reached
+    pub fn accept_admin_invitation(env: Env, invitee: Address) {
+        functions::admin_invitation::user_management_accept_admin_invitation(env, invitee)
+    }
+
+    /// Cancel a pending admin invitation (super admin only)
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `super_admin` - Address cancelling the invitation (must be super admin)
+    /// * `invitee` - Address whose invitation is cancelled
+    ///
+    /// # Panics
+    /// * If the system is not initialized
+    /// * If caller is not the super admin
+    /// * If there is no pending invitation for the invitee
+    pub fn revoke_admin_invitation(env: Env, super_admin: Address, invitee: Address) {
+        functions::admin_invitation::user_management_revoke_admin_invitation(env, super_admin, invitee)
+    }
+
+    /// Block an address from using the platform (admin only)
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `admin` - Address performing the change (must be admin)
+    /// * `target` - Address to block; blocking it again has no effect
+    ///
+    /// # Panics
+    /// * If caller is not an admin
+    pub fn add_to_blacklist(env: Env, admin: Address, target: Address) {
+        functions::blacklist::user_management_add_to_blacklist(env, admin, target)
+    }
+
+    /// Lift the block on a blacklisted address (admin only)
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `admin` - Address performing the change (must be admin)
+    /// * `target` - Address to unblock; unblocking an address that is not blocked has no effect
+    ///
+    /// # Panics
+    /// * If caller is not an admin
+    pub fn remove_from_blacklist(env: Env, admin: Address, target: Address) {
+        functions::blacklist::user_management_remove_from_blacklist(env, admin, target)
+    }
+
+    /// Check whether an address is blacklisted
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `address` - Address to check
+    ///
+    /// # Returns
+    /// * `bool` - True if the address is blacklisted
+    pub fn is_blacklisted(env: Env, address: Address) -> bool {
+        functions::blacklist::user_management_is_blacklisted(env, address)
+    }
+
+    /// List the blacklisted addresses (admin only)
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `caller` - Address requesting the list (must be admin)
+    ///
+    /// # Returns
+    /// * `Vec<Address>` - Blacklisted addresses in the order they were added
+    ///
+    /// # Panics
+    /// * If caller is not an admin
+    pub fn list_blacklist(env: Env, caller: Address) -> Vec<Address> {
+        functions::blacklist::user_management_list_blacklist(env, caller)
+    }
+
+    /// Mark a user's contact email as verified or unverified (admin only)
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `admin` - Address performing the change (must be admin)
+    /// * `user` - Address of the user whose email is marked
+    /// * `verified` - Whether the email is verified
+    ///
+    /// # Panics
+    /// * If caller is not an admin
+    /// * If the user has no profile
+    pub fn set_email_verified(env: Env, admin: Address, user: Address, verified: bool) {
+        functions::email_verification::user_management_set_email_verified(env, admin, user, verified)
+    }
+
+    /// Check whether a user's contact email has been verified
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `user` - Address of the user to check
+    ///
+    /// # Returns
+    /// * `bool` - True if the email is verified
+    pub fn is_email_verified(env: Env, user: Address) -> bool {
+        functions::email_verification::user_management_is_email_verified(env, user)
+    }
+
+    /// Changes the contact email of the caller's own profile
+    ///
+    /// Emails can be changed once every 24 hours; the first change after
+    /// registration is not limited. The new email starts out unverified.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `user` - Address of the user changing their email (must be the caller)
+    /// * `new_email` - New contact email; a case-only change has no effect
+    ///
+    /// # Panics
+    /// * If the user has no profile
+    /// * If the email format is invalid
+    /// * If the last email change was less than 24 hours ago
+    /// * If another user already has the email
+    pub fn rotate_email(env: Env, user: Address, new_email: String) {
+        functions::rotate_email::user_management_rotate_email(env, user, new_email)
+    }
+
+    /// Propose a critical admin operation, counting the proposer's approval (admin only)
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `proposer` - Address making the proposal (must be admin)
+    /// * `op_type` - Operation to run
+    /// * `args` - Target address of the operation, in its string form
+    ///
+    /// # Returns
+    /// * `String` - ID of the proposal, valid for approvals during 7 days
+    ///
+    /// # Panics
+    /// * If proposer is not an admin
+    /// * If proposer is not the super admin while a single approval is enough
+    /// * If `args` does not hold exactly one address
+    pub fn propose_operation(
+        env: Env,
+        proposer: Address,
+        op_type: crate::schema::OperationType,
+        args: Vec<String>,
+    ) -> String {
+        functions::multi_sig::user_management_propose_operation(env, proposer, op_type, args)
+    }
+
+    /// Approve a pending operation, running it once enough admins approved (admin only)
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `approver` - Address approving (must be admin)
+    /// * `proposal_id` - ID returned by `propose_operation`
+    ///
+    /// # Panics
+    /// * If approver is not an admin
+    /// * If the proposal does not exist, already ran or has expired
+    /// * If the approver already approved it
+    pub fn approve_operation(env: Env, approver: Address, proposal_id: String) {
+        functions::multi_sig::user_management_approve_operation(env, approver, proposal_id)
+    }
+
+    /// Set how many admin approvals critical operations need (super admin only)
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `super_admin` - Address of the super admin
+    /// * `count` - Approvals needed; above 1, `transfer_super_admin` and `remove_admin`
+    ///   only run through proposals
+    ///
+    /// # Panics
+    /// * If caller is not the super admin
+    /// * If `count` is zero or larger than the admin roster plus the super admin
+    pub fn set_required_approvals(env: Env, super_admin: Address, count: u32) {
+        functions::multi_sig::user_management_set_required_approvals(env, super_admin, count)
+    }
+
     // NOTE: Removed legacy duplicate wrappers that caused redefinitions.
 }
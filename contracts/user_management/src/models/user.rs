@@ -16,4 +16,6 @@ pub struct UserProfile {
     pub purpose: Option<String>,
     /// User's profile picture URL (optional)
     pub profile_picture_url: Option<String>,
+    /// User's avatar URL, HTTPS only (optional)
+    pub avatar_url: Option<String>,
 }
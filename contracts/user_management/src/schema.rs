@@ -6,7 +6,7 @@ use soroban_sdk::{contracttype, Address, String, Vec};
 /// Default and limit constants for user management configuration
 pub const DEFAULT_MAX_PAGE_SIZE: u32 = 100;
 pub const ABSOLUTE_MAX_PAGE_SIZE: u32 = 1000;
-pub const MAX_ADMINS: u32 = 10;
+pub const DEFAULT_MAX_ADMINS: u32 = 10;
 
 /// Rate limiting constants
 pub const DEFAULT_RATE_LIMIT_WINDOW: u64 = 3600; // 1 hour in seconds
@@ -39,6 +39,46 @@ pub struct UserProfile {
     pub purpose: Option<String>,
     /// User's profile picture URL (optional)
     pub profile_picture_url: Option<String>,
+    /// User's avatar URL, HTTPS only (optional)
+    pub avatar_url: Option<String>,
+}
+
+/// Personal data of a user, exported for data portability.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserDataExport {
+    /// The user's full profile
+    pub profile: UserProfile,
+    /// IDs of the courses the user has access to
+    pub enrolled_courses: Vec<String>,
+    /// Skills recorded for the user
+    pub skills: Vec<String>,
+    /// Ledger timestamp the profile was created at (0 if unknown)
+    pub registration_timestamp: u64,
+}
+
+/// How much of a user's profile is filled in.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProfileCompletenessScore {
+    /// Sum of the weights of the filled fields
+    pub score: u32,
+    /// Names of the profile fields that are still empty
+    pub missing_fields: Vec<String>,
+    /// Whether the score reaches the completeness threshold
+    pub is_complete: bool,
+}
+
+/// A recorded edit of a user's profile.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProfileChange {
+    /// Ledger timestamp of the edit
+    pub timestamp: u64,
+    /// Names of the profile fields the edit changed
+    pub changed_fields: Vec<String>,
+    /// Hex SHA-256 of the previous values of the changed fields
+    pub previous_values_hash: String,
 }
 
 /// Struct for profile update parameters
@@ -50,12 +90,14 @@ pub struct ProfileUpdateParams {
     pub full_name: Option<String>,
     /// User's profession or job title
     pub profession: Option<String>,
-    /// User's country of residence
+    /// User's country of residence (cannot be blank)
     pub country: Option<String>,
     /// User's learning goals or purpose
     pub purpose: Option<String>,
     /// User's profile picture URL
     pub profile_picture_url: Option<String>,
+    /// User's avatar URL (empty string clears it)
+    pub avatar_url: Option<String>,
 }
 
 /// User roles in the SkillCert platform.
@@ -239,6 +281,45 @@ pub struct AdminConfig {
     pub total_user_count: u32,
     /// Rate limiting configuration for user creation
     pub rate_limit_config: RateLimitConfig,
+    /// Maximum number of regular admins (the super admin is not counted)
+    pub max_admins: u32,
+}
+
+/// Pending invitation for an address to become an admin.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminInvitation {
+    /// Address invited to become an admin
+    pub invitee: Address,
+    /// Super admin who sent the invitation
+    pub invited_by: Address,
+    /// Ledger timestamp after which the invitation can no longer be accepted
+    pub expires_at: u64,
+}
+
+/// Critical admin operations that can require several admin approvals.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OperationType {
+    /// Hand the super admin role to `args[0]`
+    TransferSuperAdmin,
+    /// Remove `args[0]` from the regular admins
+    RemoveAdmin,
+}
+
+/// A critical operation waiting for admin approvals.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingOperation {
+    pub op_type: OperationType,
+    /// Operation arguments; addresses are given in their string form
+    pub args: Vec<String>,
+    /// Admins who approved so far, starting with the proposer
+    pub approvals: Vec<Address>,
+    /// Approvals needed before the operation runs
+    pub required_approvals: u32,
+    /// Ledger timestamp after which the proposal can no longer be approved
+    pub expires_at: u64,
 }
 
 /// Backup data structure for user management system.
@@ -295,6 +376,49 @@ pub struct PaginatedLightProfiles {
     pub has_more: bool,
 }
 
+/// A page of full user profiles returned by `list_users`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserListPage {
+    /// User profiles in this page
+    pub users: Vec<UserProfile>,
+    /// Total number of registered users
+    pub total: u32,
+    /// Whether there are more users after this page
+    pub has_more: bool,
+}
+
+/// Admin roster returned by `list_all_admins`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminList {
+    /// Address of the super administrator
+    pub super_admin: Address,
+    /// Addresses of the regular administrators
+    pub admins: Vec<Address>,
+}
+
+/// Platform a social profile link points to.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum SocialPlatform {
+    LinkedIn,
+    GitHub,
+    Twitter,
+    /// Personal website
+    Website,
+}
+
+/// Link to one of a user's external profiles.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SocialLink {
+    /// Platform the link points to
+    pub platform: SocialPlatform,
+    /// HTTP(S) URL of the profile
+    pub url: String,
+}
+
 /// Storage keys for different data types in the user management contract.
 ///
 /// This enum defines the various keys used to store and retrieve
@@ -326,4 +450,42 @@ pub enum DataKey {
     UserPermissions(Address),
     /// Key for storing default role permissions configuration
     DefaultRolePermissions,
+    /// Key for storing users ordered by last profile activity: Vec<(user_address, timestamp)>
+    ActivityIndex,
+    /// Key for the sequential user index: position -> user_address
+    UserIndex(u32),
+    /// Key for the reverse lookup of the sequential user index: user_address -> position
+    UserIndexPosition(Address),
+    /// Key for storing the number of entries in the sequential user index
+    UserIndexCount,
+    /// Key for the users of a country: upper-cased country -> Vec<Address>
+    CountryUsers(String),
+    /// Key for the users holding a role: role -> Vec<Address>
+    RoleUsers(UserRole),
+    /// Key for the ledger timestamp a profile was created at: user_address -> u64
+    RegisteredAt(Address),
+    /// Key for the course access contract address used for enrollment lookups
+    CourseAccessAddr,
+    /// Key for the skills of a user, in the order added: user_address -> Vec<String>
+    UserSkills(Address),
+    /// Key for the users holding a skill: skill -> Vec<Address>
+    SkillUsers(String),
+    /// Key for a pending admin invitation: invitee_address -> AdminInvitation
+    AdminInvitation(Address),
+    /// Key for the addresses blocked from using the platform
+    Blacklist,
+    /// Key marking a user's contact email as verified: user_address -> bool
+    EmailVerified(Address),
+    /// Key for a critical operation awaiting admin approvals: proposal_id -> PendingOperation
+    PendingApproval(String),
+    /// Key for the number of proposals made so far, used to derive proposal IDs
+    ProposalSeq,
+    /// Key for the number of admin approvals critical operations need
+    RequiredApprovals,
+    /// Key for storing a user's recent profile changes, oldest first: user_address -> Vec<ProfileChange>
+    ProfileHistory(Address),
+    /// Key for a user's social profile links, at most one per platform: user_address -> Vec<SocialLink>
+    SocialLinks(Address),
+    /// Key for the ledger timestamp of a user's last contact email change: user_address -> u64
+    LastEmailChange(Address),
 }
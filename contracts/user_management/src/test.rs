@@ -20,6 +20,7 @@ fn test_create_user_profile_integration() {
         country: Some(String::from_str(&env, "United States")),
         purpose: Some(String::from_str(&env, "Learn machine learning")),
         profile_picture_url: None,
+        avatar_url: None,
     };
 
     // Mock authentication
@@ -50,6 +51,7 @@ fn test_get_user_by_id_self_access() {
         country: Some(String::from_str(&env, "Canada")),
         purpose: Some(String::from_str(&env, "Improve coding skills")),
         profile_picture_url: None,
+        avatar_url: None,
     };
 
     env.mock_all_auths();
@@ -87,6 +89,7 @@ fn test_get_user_by_id_admin_access() {
         country: Some(String::from_str(&env, "Canada")),
         purpose: Some(String::from_str(&env, "Improve coding skills")),
         profile_picture_url: None,
+        avatar_url: None,
     };
 
     client.create_user_profile(&user, &profile);
@@ -128,6 +131,7 @@ fn test_list_all_users_basic() {
             country: Some(String::from_str(&env, "United States")),
             purpose: Some(String::from_str(&env, "Learn new skills")),
             profile_picture_url: None,
+            avatar_url: None,
         };
 
         client.create_user_profile(&user, &profile);
@@ -161,6 +165,7 @@ fn test_delete_user() {
         country: Some(String::from_str(&env, "United States")),
         purpose: Some(String::from_str(&env, "Learn testing")),
         profile_picture_url: None,
+        avatar_url: None,
     };
 
     env.mock_all_auths();
@@ -236,6 +241,7 @@ fn test_complete_user_lifecycle() {
         country: Some(String::from_str(&env, "United States")),
         purpose: Some(String::from_str(&env, "Learn blockchain development")),
         profile_picture_url: None,
+        avatar_url: None,
     };
 
     let created_profile: UserProfile = client.create_user_profile(&user, &initial_profile);
@@ -248,6 +254,7 @@ fn test_complete_user_lifecycle() {
         country: Some(String::from_str(&env, "Canada")),
         purpose: Some(String::from_str(&env, "Master blockchain development")),
         profile_picture_url: None,
+        avatar_url: None,
     };
 
     let updated_profile: UserProfile = client.edit_user_profile(&user, &user, &update_params);
@@ -309,6 +316,7 @@ fn test_multi_user_admin_workflow() {
             country: Some(String::from_str(&env, country)),
             purpose: Some(String::from_str(&env, "Learn new skills")),
             profile_picture_url: None,
+            avatar_url: None,
         };
         client.create_user_profile(&user, &profile);
     }
@@ -373,6 +381,7 @@ fn test_user_profile_validation_workflow() {
         country: Some(String::from_str(&env, "USA")),
         purpose: Some(String::from_str(&env, "Learning")),
         profile_picture_url: None,
+        avatar_url: None,
     };
 
     let profile2 = UserProfile {
@@ -382,6 +391,7 @@ fn test_user_profile_validation_workflow() {
         country: Some(String::from_str(&env, "Canada")),
         purpose: Some(String::from_str(&env, "Skill improvement")),
         profile_picture_url: None,
+        avatar_url: None,
     };
 
     client.create_user_profile(&user1, &profile1);
@@ -440,6 +450,7 @@ fn test_pagination_and_filtering_integration() {
             country: Some(String::from_str(&env, country)),
             purpose: Some(String::from_str(&env, "Learning")),
             profile_picture_url: None,
+            avatar_url: None,
         };
         client.create_user_profile(&user, &profile);
     }
@@ -506,6 +517,7 @@ fn test_error_handling_and_edge_cases() {
         country: Some(String::from_str(&env, "Test Country")),
         purpose: Some(String::from_str(&env, "Test purpose")),
         profile_picture_url: None,
+        avatar_url: None,
     };
 
     client.create_user_profile(&user, &profile);
@@ -526,8 +538,95 @@ fn test_error_handling_and_edge_cases() {
         country: Some(String::from_str(&env, "New Country")),
         purpose: Some(String::from_str(&env, "New Purpose")),
         profile_picture_url: None,
+        avatar_url: None,
     };
 
     let created: UserProfile = client.create_user_profile(&new_user, &new_profile);
     assert_eq!(created.full_name, String::from_str(&env, "New User"));
-}
\ No newline at end of file
+}
+fn avatar_profile(env: &Env, avatar_url: Option<String>) -> UserProfile {
+    UserProfile {
+        full_name: String::from_str(env, "Avatar User"),
+        contact_email: String::from_str(env, "avatar@example.com"),
+        profession: None,
+        country: None,
+        purpose: None,
+        profile_picture_url: None,
+        avatar_url,
+    }
+}
+
+fn avatar_updates(avatar_url: Option<String>) -> ProfileUpdateParams {
+    ProfileUpdateParams {
+        full_name: None,
+        profession: None,
+        country: None,
+        purpose: None,
+        profile_picture_url: None,
+        avatar_url,
+    }
+}
+
+#[test]
+fn test_avatar_url_https_accepted() {
+    let env: Env = Env::default();
+    env.mock_all_auths();
+    let contract_id: Address = env.register(UserManagement, ());
+    let client: UserManagementClient<'_> = UserManagementClient::new(&env, &contract_id);
+    let user: Address = Address::generate(&env);
+
+    let avatar: String = String::from_str(&env, "https://cdn.example.com/avatar.png");
+    let created: UserProfile = client.create_user_profile(&user, &avatar_profile(&env, Some(avatar.clone())));
+    assert_eq!(created.avatar_url, Some(avatar.clone()));
+
+    let updated_avatar: String = String::from_str(&env, "https://cdn.example.com/new.png");
+    let updated: UserProfile =
+        client.edit_user_profile(&user, &user, &avatar_updates(Some(updated_avatar.clone())));
+    assert_eq!(updated.avatar_url, Some(updated_avatar));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #136)")]
+fn test_avatar_url_http_rejected() {
+    let env: Env = Env::default();
+    env.mock_all_auths();
+    let contract_id: Address = env.register(UserManagement, ());
+    let client: UserManagementClient<'_> = UserManagementClient::new(&env, &contract_id);
+    let user: Address = Address::generate(&env);
+
+    let avatar: String = String::from_str(&env, "http://cdn.example.com/avatar.png");
+    client.create_user_profile(&user, &avatar_profile(&env, Some(avatar)));
+}
+
+#[test]
+#[should_panic(expected = "HostError: Error(Contract, #136)")]
+fn test_avatar_url_too_long_rejected() {
+    let env: Env = Env::default();
+    env.mock_all_auths();
+    let contract_id: Address = env.register(UserManagement, ());
+    let client: UserManagementClient<'_> = UserManagementClient::new(&env, &contract_id);
+    let user: Address = Address::generate(&env);
+    client.create_user_profile(&user, &avatar_profile(&env, None));
+
+    let mut long_url = [b'a'; 2049];
+    long_url[..8].copy_from_slice(b"https://");
+    let avatar: String = String::from_bytes(&env, &long_url);
+    client.edit_user_profile(&user, &user, &avatar_updates(Some(avatar)));
+}
+
+#[test]
+fn test_avatar_url_cleared() {
+    let env: Env = Env::default();
+    env.mock_all_auths();
+    let contract_id: Address = env.register(UserManagement, ());
+    let client: UserManagementClient<'_> = UserManagementClient::new(&env, &contract_id);
+    let user: Address = Address::generate(&env);
+
+    let avatar: String = String::from_str(&env, "https://cdn.example.com/avatar.png");
+    client.create_user_profile(&user, &avatar_profile(&env, Some(avatar)));
+
+    let updated: UserProfile =
+        client.edit_user_profile(&user, &user, &avatar_updates(Some(String::from_str(&env, ""))));
+    assert_eq!(updated.avatar_url, None);
+    assert_eq!(client.get_user_by_id(&user, &user).avatar_url, None);
+}
@@ -7,11 +7,11 @@ use soroban_sdk::{contracterror, panic_with_error, Env};
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum Error {
-    UserProfileNotFound = 1,
-    InvalidInput = 2,
-    UnauthorizedAccess = 3,
+    UserProfileNotFound = 101,
+    InvalidInput = 102,
+    UnauthorizedAccess = 103,
 }
 
 pub fn handle_error(env: &Env, error: Error) -> ! {
     panic_with_error!(env, error);
-}
\ No newline at end of file
+}
@@ -56,7 +56,7 @@ fn test_get_user_profile_not_found() {
 
     let user_address: Address = Address::generate(&env);
 
-    // Try to get a profile that doesn't exist - should panic with UserProfileNotFound error (code 1)
+    // Try to get a profile that doesn't exist - should panic with UserProfileNotFound error (code 101)
     client.get_user_profile(&user_address);
 }
 
@@ -176,7 +176,7 @@ fn test_get_user_profile_with_privacy_not_found() {
     let user_address: Address = Address::generate(&env);
     let requester_address: Address = Address::generate(&env);
 
-    // Try to get a profile that doesn't exist - should panic with UserProfileNotFound error (code 1)
+    // Try to get a profile that doesn't exist - should panic with UserProfileNotFound error (code 101)
     client.get_user_profile_with_privacy(&user_address, &requester_address);
 }
 